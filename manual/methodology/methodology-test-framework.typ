@@ -39,6 +39,10 @@ For one-sample estimators, the input contains array `x` and optional `parameters
 For two-sample estimators, input contains arrays `x` and `y`.
 For bounds estimators ($ShiftBounds$, $RatioBounds$), input additionally contains `misrate`.
 Output is a single numeric value for point estimators, or an object with `lower` and `upper` fields for bounds estimators.
+Floats in newly generated fixtures use a canonical spelling: the shortest decimal that round-trips to the same double,
+  laid out as by ECMAScript's `Number.prototype.toString` (negative zero is written `-0`),
+  so regenerated files can be compared byte for byte.
+Older fixtures keep their original spelling (`1.0` rather than `1`) until their values change.
 
 *Performance testing* — The toolkit provides $O(n log n)$ implementations for $Center$, $Spread$, and $Shift$ estimators,
 dramatically more efficient than naive implementations that materialize all pairwise combinations.
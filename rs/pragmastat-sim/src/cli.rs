@@ -1,4 +1,5 @@
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "pragmastat-sim", about = "Pragmastat simulations")]
@@ -17,9 +18,13 @@ pub enum Command {
     #[command(name = "disp-drift")]
     DispDrift(DriftArgs),
 
+    /// Run relative-efficiency simulation
+    #[command(name = "efficiency")]
+    Efficiency(EfficiencyArgs),
+
     /// Run center-bounds simulation
     #[command(name = "center-bounds")]
-    CenterBounds(BoundsArgs),
+    CenterBounds(CenterBoundsArgs),
 
     /// Run shift-bounds simulation
     #[command(name = "shift-bounds")]
@@ -41,9 +46,17 @@ pub enum Command {
     #[command(name = "avg-spread-bounds")]
     AvgSpreadBounds(TwoSampleBoundsArgs),
 
+    /// Sweep bounds coverage over misrates, sample sizes, and distributions
+    #[command(name = "coverage-sweep")]
+    CoverageSweep(CoverageSweepArgs),
+
     /// Compute bounds width convergence for linspace(1, 2, N), N=2..10000
     #[command(name = "bounds-width")]
     BoundsWidth(BoundsWidthArgs),
+
+    /// Aggregate drift outputs of several runs into per-n quantile bands
+    #[command(name = "aggregate")]
+    Aggregate(AggregateArgs),
 }
 
 #[derive(Parser)]
@@ -53,6 +66,18 @@ pub struct BoundsWidthArgs {
     pub publish: bool,
 }
 
+/// Time budget and checkpointing of long runs.
+#[derive(Args)]
+pub struct CheckpointArgs {
+    /// Stop starting new tasks after this many minutes; rerun to resume
+    #[arg(long = "time-budget")]
+    pub time_budget: Option<f64>,
+
+    /// Seconds between flushes of completed rows to the output file
+    #[arg(long = "checkpoint-interval", default_value = "10")]
+    pub checkpoint_interval: u64,
+}
+
 #[derive(Parser)]
 pub struct DriftArgs {
     /// Sample sizes (e.g. "2..100" or "2,3,4,5,10..20,50..100")
@@ -90,6 +115,55 @@ pub struct DriftArgs {
     /// Publish results to sim/ root
     #[arg(long = "publish")]
     pub publish: bool,
+
+    #[command(flatten)]
+    pub checkpoint: CheckpointArgs,
+}
+
+#[derive(Parser)]
+pub struct EfficiencyArgs {
+    /// Sample sizes (e.g. "2..100" or "2,3,4,5,10..20,50..100")
+    #[arg(short = 'n', long = "sample-sizes", default_value = "2..100")]
+    pub sample_sizes: String,
+
+    /// Number of samples for building sampling distribution
+    #[arg(short = 'm', long = "sample-count", default_value = "100000")]
+    pub sample_count: usize,
+
+    /// Comma-separated list of estimators of the same kind as the baseline
+    #[arg(short = 'e', long = "estimators", default_value = "Median,Center")]
+    pub estimators: String,
+
+    /// Estimator whose variance is the numerator of every efficiency
+    #[arg(short = 'b', long = "baseline", default_value = "Mean")]
+    pub baseline: String,
+
+    /// Comma-separated list of distributions
+    #[arg(
+        short = 'd',
+        long = "distributions",
+        default_value = "additive,multiplic,exp,power,uniform"
+    )]
+    pub distributions: String,
+
+    /// Seed for random number generation (defaults to simulation name)
+    #[arg(short = 's', long = "seed")]
+    pub seed: Option<String>,
+
+    /// Max parallelism
+    #[arg(short = 'p', long = "parallelism", default_value = "8")]
+    pub parallelism: usize,
+
+    /// Overwrite existing entries
+    #[arg(short = 'o', long = "overwrite")]
+    pub overwrite: bool,
+
+    /// Publish results to sim/ root
+    #[arg(long = "publish")]
+    pub publish: bool,
+
+    #[command(flatten)]
+    pub checkpoint: CheckpointArgs,
 }
 
 #[derive(Parser)]
@@ -133,6 +207,20 @@ pub struct BoundsArgs {
     /// Publish results to sim/ root
     #[arg(long = "publish")]
     pub publish: bool,
+
+    #[command(flatten)]
+    pub checkpoint: CheckpointArgs,
+}
+
+#[derive(Parser)]
+pub struct CenterBoundsArgs {
+    #[command(flatten)]
+    pub bounds: BoundsArgs,
+
+    /// Draw each sample as an AR(1) series with this coefficient in (-1, 1)
+    /// and the distribution as innovations; rows go to center-bounds-ar1
+    #[arg(long = "phi", allow_hyphen_values = true)]
+    pub phi: Option<f64>,
 }
 
 #[derive(Parser)]
@@ -176,4 +264,119 @@ pub struct TwoSampleBoundsArgs {
     /// Publish results to sim/ root
     #[arg(long = "publish")]
     pub publish: bool,
+
+    #[command(flatten)]
+    pub checkpoint: CheckpointArgs,
+}
+
+/// Bounds estimator covered by `coverage-sweep`.
+#[derive(Clone, Copy, ValueEnum)]
+pub enum SweepEstimator {
+    Center,
+    Shift,
+    Spread,
+    Ratio,
+    Disparity,
+}
+
+impl SweepEstimator {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Center => "center",
+            Self::Shift => "shift",
+            Self::Spread => "spread",
+            Self::Ratio => "ratio",
+            Self::Disparity => "disparity",
+        }
+    }
+
+    /// True for estimators of two samples (`m = n` in the sweep).
+    pub fn is_two_sample(self) -> bool {
+        matches!(self, Self::Shift | Self::Ratio | Self::Disparity)
+    }
+}
+
+/// Export format of `coverage-sweep` and `aggregate`.
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+impl ExportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Csv => "csv",
+        }
+    }
+}
+
+#[derive(Parser)]
+pub struct CoverageSweepArgs {
+    /// Bounds estimator to sweep
+    #[arg(short = 'e', long = "estimator", value_enum)]
+    pub estimator: SweepEstimator,
+
+    /// Sample sizes (e.g. "2,3,4,5,10,11,20,50,100")
+    #[arg(
+        short = 'n',
+        long = "sample-sizes",
+        default_value = "2,3,4,5,10,11,20,50,100"
+    )]
+    pub sample_sizes: String,
+
+    /// Number of samples per combination (default: 100/misrate)
+    #[arg(short = 'm', long = "sample-count")]
+    pub sample_count: Option<usize>,
+
+    /// Comma-separated list of distributions
+    #[arg(
+        short = 'd',
+        long = "distributions",
+        default_value = "additive,multiplic,exp,power,uniform"
+    )]
+    pub distributions: String,
+
+    /// Comma-separated list of misrates
+    #[arg(short = 'r', long = "misrates", default_value = "1e-1,5e-2,1e-2,1e-3")]
+    pub misrates: String,
+
+    /// Export format
+    #[arg(short = 'f', long = "format", value_enum, default_value = "csv")]
+    pub format: ExportFormat,
+
+    /// Seed for random number generation (defaults to the bounds simulation name)
+    #[arg(short = 's', long = "seed")]
+    pub seed: Option<String>,
+
+    /// Max parallelism
+    #[arg(short = 'p', long = "parallelism", default_value = "8")]
+    pub parallelism: usize,
+
+    /// Overwrite existing entries
+    #[arg(short = 'o', long = "overwrite")]
+    pub overwrite: bool,
+
+    /// Publish results to sim/ root
+    #[arg(long = "publish")]
+    pub publish: bool,
+
+    #[command(flatten)]
+    pub checkpoint: CheckpointArgs,
+}
+
+#[derive(Parser)]
+pub struct AggregateArgs {
+    /// Drift outputs to aggregate, one per replication (e.g. avg-drift-seed1.json)
+    #[arg(required = true)]
+    pub inputs: Vec<PathBuf>,
+
+    /// Export format
+    #[arg(short = 'f', long = "format", value_enum, default_value = "csv")]
+    pub format: ExportFormat,
+
+    /// Publish results to sim/ root
+    #[arg(long = "publish")]
+    pub publish: bool,
 }
@@ -1,4 +1,84 @@
-//! Simple statistical estimators not exposed by pragmastat's public API.
+//! Simple statistical estimators not exposed by pragmastat's public API,
+//! and the registry of named estimators the simulations dispatch on.
+
+use pragmastat::MadScale;
+
+/// One-sample estimator function type.
+pub type EstimatorFn = fn(&[f64]) -> f64;
+
+/// What an estimator measures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EstimatorKind {
+    Average,
+    Dispersion,
+}
+
+impl EstimatorKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Average => "average",
+            Self::Dispersion => "dispersion",
+        }
+    }
+}
+
+/// A named one-sample estimator.
+pub struct EstimatorEntry {
+    pub name: &'static str,
+    pub kind: EstimatorKind,
+    pub estimate: EstimatorFn,
+}
+
+/// All available simulation estimators.
+pub const ESTIMATORS: &[EstimatorEntry] = &[
+    EstimatorEntry {
+        name: "Mean",
+        kind: EstimatorKind::Average,
+        estimate: mean,
+    },
+    EstimatorEntry {
+        name: "Median",
+        kind: EstimatorKind::Average,
+        estimate: median,
+    },
+    EstimatorEntry {
+        name: "Center",
+        kind: EstimatorKind::Average,
+        estimate: |v| pragmastat::estimators::raw::center(v, false).unwrap(),
+    },
+    EstimatorEntry {
+        name: "Mode",
+        kind: EstimatorKind::Average,
+        estimate: |v| pragmastat::estimators::raw::mode_hsm(v, false).unwrap(),
+    },
+    EstimatorEntry {
+        name: "StdDev",
+        kind: EstimatorKind::Dispersion,
+        estimate: std_dev,
+    },
+    EstimatorEntry {
+        name: "MAD",
+        kind: EstimatorKind::Dispersion,
+        estimate: |v| pragmastat::estimators::raw::mad(v, MadScale::Raw, false).unwrap(),
+    },
+    EstimatorEntry {
+        name: "Spread",
+        kind: EstimatorKind::Dispersion,
+        estimate: |v| pragmastat::estimators::raw::spread(v, false).unwrap(),
+    },
+];
+
+/// Look up an estimator of the given kind by name.
+///
+/// # Panics
+///
+/// Panics if no estimator of that kind has this name.
+pub fn find_estimator(name: &str, kind: EstimatorKind) -> &'static EstimatorEntry {
+    ESTIMATORS
+        .iter()
+        .find(|e| e.name == name && e.kind == kind)
+        .unwrap_or_else(|| panic!("Unknown {} estimator: {name}", kind.as_str()))
+}
 
 /// Arithmetic mean.
 pub fn mean(values: &[f64]) -> f64 {
@@ -28,16 +108,3 @@ pub fn std_dev(values: &[f64]) -> f64 {
     let variance = values.iter().map(|&v| (v - m) * (v - m)).sum::<f64>() / (n - 1) as f64;
     variance.sqrt()
 }
-
-/// Median absolute deviation (MAD).
-pub fn mad(values: &[f64]) -> f64 {
-    let med = median(values);
-    let mut abs_devs: Vec<f64> = values.iter().map(|&v| (v - med).abs()).collect();
-    abs_devs.sort_by(|a, b| a.total_cmp(b));
-    let n = abs_devs.len();
-    if n.is_multiple_of(2) {
-        (abs_devs[n / 2 - 1] + abs_devs[n / 2]) / 2.0
-    } else {
-        abs_devs[n / 2]
-    }
-}
@@ -8,18 +8,23 @@ mod sample_sizes;
 mod sim;
 
 use clap::Parser;
-use cli::{Cli, Command};
+use cli::{CenterBoundsArgs, CheckpointArgs, Cli, Command, SweepEstimator};
 use distributions::find_distributions;
+use runner::Checkpointing;
 use sample_sizes::parse_sample_sizes;
+use sim::aggregate;
 use sim::avg_drift::AvgDriftSim;
 use sim::avg_spread_bounds::AvgSpreadBoundsSim;
 use sim::bounds_width;
 use sim::center_bounds::CenterBoundsSim;
+use sim::coverage_sweep;
 use sim::disp_drift::DispDriftSim;
 use sim::disparity_bounds::DisparityBoundsSim;
+use sim::efficiency::EfficiencySim;
 use sim::ratio_bounds::RatioBoundsSim;
 use sim::shift_bounds::ShiftBoundsSim;
 use sim::spread_bounds::SpreadBoundsSim;
+use std::time::Duration;
 
 fn parse_names(input: &str) -> Vec<String> {
     input
@@ -29,6 +34,15 @@ fn parse_names(input: &str) -> Vec<String> {
         .collect()
 }
 
+fn checkpointing(args: &CheckpointArgs) -> Checkpointing {
+    Checkpointing {
+        time_budget: args
+            .time_budget
+            .map(|minutes| Duration::from_secs_f64(minutes.max(0.0) * 60.0)),
+        interval: Duration::from_secs(args.checkpoint_interval),
+    }
+}
+
 fn main() {
     let cli = Cli::parse();
 
@@ -41,7 +55,14 @@ fn main() {
             let sizes = parse_sample_sizes(&args.sample_sizes);
             let seed = args.seed.unwrap_or_else(|| "avg-drift".to_string());
             let sim = AvgDriftSim::new(dists, estimator_names, args.sample_count, seed);
-            runner::run(&sim, &sizes, args.parallelism, args.overwrite, args.publish);
+            runner::run(
+                &sim,
+                &sizes,
+                args.parallelism,
+                args.overwrite,
+                args.publish,
+                &checkpointing(&args.checkpoint),
+            );
         }
         Command::DispDrift(args) => {
             let dist_names = parse_names(&args.distributions);
@@ -51,15 +72,58 @@ fn main() {
             let sizes = parse_sample_sizes(&args.sample_sizes);
             let seed = args.seed.unwrap_or_else(|| "disp-drift".to_string());
             let sim = DispDriftSim::new(dists, estimator_names, args.sample_count, seed);
-            runner::run(&sim, &sizes, args.parallelism, args.overwrite, args.publish);
+            runner::run(
+                &sim,
+                &sizes,
+                args.parallelism,
+                args.overwrite,
+                args.publish,
+                &checkpointing(&args.checkpoint),
+            );
         }
-        Command::CenterBounds(args) => {
+        Command::Efficiency(args) => {
+            let dist_names = parse_names(&args.distributions);
+            let dists = find_distributions(&dist_names);
+            let estimator_names = parse_names(&args.estimators);
+            let sizes = parse_sample_sizes(&args.sample_sizes);
+            let seed = args.seed.unwrap_or_else(|| "efficiency".to_string());
+            let sim = EfficiencySim::new(
+                dists,
+                estimator_names,
+                args.baseline,
+                args.sample_count,
+                seed,
+            );
+            runner::run(
+                &sim,
+                &sizes,
+                args.parallelism,
+                args.overwrite,
+                args.publish,
+                &checkpointing(&args.checkpoint),
+            );
+        }
+        Command::CenterBounds(CenterBoundsArgs { bounds: args, phi }) => {
             let dist_names = parse_names(&args.distributions);
             let dists = find_distributions(&dist_names);
             let sizes = parse_sample_sizes(&args.sample_sizes);
             let seed = args.seed.unwrap_or_else(|| "center-bounds".to_string());
-            let sim = CenterBoundsSim::new(dists, args.sample_count, &args.misrates, seed);
-            runner::run(&sim, &sizes, args.parallelism, args.overwrite, args.publish);
+            let mut sim = CenterBoundsSim::new(dists, args.sample_count, &args.misrates, seed);
+            if let Some(phi) = phi {
+                if phi.is_nan() || phi.abs() >= 1.0 {
+                    eprintln!("  Error: phi must be in (-1, 1), got {phi}");
+                    std::process::exit(1);
+                }
+                sim = sim.with_ar1(phi);
+            }
+            runner::run(
+                &sim,
+                &sizes,
+                args.parallelism,
+                args.overwrite,
+                args.publish,
+                &checkpointing(&args.checkpoint),
+            );
         }
         Command::ShiftBounds(args) => {
             let dist_names = parse_names(&args.distributions);
@@ -67,7 +131,14 @@ fn main() {
             let sizes = parse_sample_sizes(&args.sample_sizes);
             let seed = args.seed.unwrap_or_else(|| "shift-bounds".to_string());
             let sim = ShiftBoundsSim::new(dists, args.sample_count, &args.misrates, seed);
-            runner::run(&sim, &sizes, args.parallelism, args.overwrite, args.publish);
+            runner::run(
+                &sim,
+                &sizes,
+                args.parallelism,
+                args.overwrite,
+                args.publish,
+                &checkpointing(&args.checkpoint),
+            );
         }
         Command::RatioBounds(args) => {
             let dist_names = parse_names(&args.distributions);
@@ -75,7 +146,14 @@ fn main() {
             let sizes = parse_sample_sizes(&args.sample_sizes);
             let seed = args.seed.unwrap_or_else(|| "ratio-bounds".to_string());
             let sim = RatioBoundsSim::new(dists, args.sample_count, &args.misrates, seed);
-            runner::run(&sim, &sizes, args.parallelism, args.overwrite, args.publish);
+            runner::run(
+                &sim,
+                &sizes,
+                args.parallelism,
+                args.overwrite,
+                args.publish,
+                &checkpointing(&args.checkpoint),
+            );
         }
         Command::DisparityBounds(args) => {
             let dist_names = parse_names(&args.distributions);
@@ -83,7 +161,14 @@ fn main() {
             let sizes = parse_sample_sizes(&args.sample_sizes);
             let seed = args.seed.unwrap_or_else(|| "disparity-bounds".to_string());
             let sim = DisparityBoundsSim::new(dists, args.sample_count, &args.misrates, seed);
-            runner::run(&sim, &sizes, args.parallelism, args.overwrite, args.publish);
+            runner::run(
+                &sim,
+                &sizes,
+                args.parallelism,
+                args.overwrite,
+                args.publish,
+                &checkpointing(&args.checkpoint),
+            );
         }
         Command::SpreadBounds(args) => {
             let dist_names = parse_names(&args.distributions);
@@ -91,11 +176,78 @@ fn main() {
             let sizes = parse_sample_sizes(&args.sample_sizes);
             let seed = args.seed.unwrap_or_else(|| "spread-bounds".to_string());
             let sim = SpreadBoundsSim::new(dists, args.sample_count, &args.misrates, seed);
-            runner::run(&sim, &sizes, args.parallelism, args.overwrite, args.publish);
+            runner::run(
+                &sim,
+                &sizes,
+                args.parallelism,
+                args.overwrite,
+                args.publish,
+                &checkpointing(&args.checkpoint),
+            );
+        }
+        Command::CoverageSweep(args) => {
+            let dist_names = parse_names(&args.distributions);
+            let dists = find_distributions(&dist_names);
+            let sizes = parse_sample_sizes(&args.sample_sizes);
+            // Same default seed as the matching bounds command, so both share results
+            let seed = args
+                .seed
+                .unwrap_or_else(|| format!("{}-bounds", args.estimator.name()));
+            let (count, misrates) = (args.sample_count, args.misrates.as_str());
+            let (p, o, publish) = (args.parallelism, args.overwrite, args.publish);
+            let c = checkpointing(&args.checkpoint);
+            let rows = match args.estimator {
+                SweepEstimator::Center => {
+                    let sim = CenterBoundsSim::new(dists, count, misrates, seed);
+                    runner::run(&sim, &sizes, p, o, publish, &c)
+                }
+                SweepEstimator::Shift => {
+                    let sim = ShiftBoundsSim::new(dists, count, misrates, seed);
+                    runner::run(&sim, &sizes, p, o, publish, &c)
+                }
+                SweepEstimator::Spread => {
+                    let sim = SpreadBoundsSim::new(dists, count, misrates, seed);
+                    runner::run(&sim, &sizes, p, o, publish, &c)
+                }
+                SweepEstimator::Ratio => {
+                    let sim = RatioBoundsSim::new(dists, count, misrates, seed);
+                    runner::run(&sim, &sizes, p, o, publish, &c)
+                }
+                SweepEstimator::Disparity => {
+                    let sim = DisparityBoundsSim::new(dists, count, misrates, seed);
+                    runner::run(&sim, &sizes, p, o, publish, &c)
+                }
+            };
+            let path = coverage_sweep::export(args.estimator, &rows, args.format, publish);
+            eprintln!("  Coverage sweep saved: {}", path.display());
         }
         Command::BoundsWidth(args) => {
             bounds_width::run(args.publish);
         }
+        Command::Aggregate(args) => {
+            let result = args
+                .inputs
+                .iter()
+                .map(|path| aggregate::load(path))
+                .collect::<Result<Vec<_>, _>>()
+                .and_then(|replications| {
+                    let rows = aggregate::aggregate(&replications)?;
+                    let simulation = replications[0].simulation;
+                    Ok(aggregate::export(
+                        simulation,
+                        &rows,
+                        args.format,
+                        args.publish,
+                    ))
+                });
+            match result {
+                Ok(path) => eprintln!("  Aggregate saved: {}", path.display()),
+                Err(e) => {
+                    eprintln!("  Error: {e}");
+                    std::process::exit(1);
+                }
+            }
+        }
         Command::AvgSpreadBounds(args) => {
             let dist_names = parse_names(&args.distributions);
             let dists = find_distributions(&dist_names);
@@ -110,6 +262,7 @@ fn main() {
                 args.parallelism,
                 args.overwrite,
                 args.publish,
+                &checkpointing(&args.checkpoint),
             );
         }
     }
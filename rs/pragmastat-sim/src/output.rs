@@ -1,33 +1,52 @@
 use crate::sim::SimulationRow;
+use serde::Serialize;
 use std::collections::BTreeMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 /// Thread-safe incremental JSON writer backed by a BTreeMap.
+///
+/// Rows are checkpointed to disk at most once per `interval`; every write
+/// replaces the file atomically, so an interrupted run leaves a valid subset.
 pub struct OutputWriter<V: SimulationRow> {
     path: PathBuf,
+    interval: Duration,
     rows: Mutex<BTreeMap<String, V>>,
+    last_flush: Mutex<Instant>,
 }
 
 impl<V: SimulationRow> OutputWriter<V> {
-    pub fn new(path: PathBuf, existing: BTreeMap<String, V>) -> Self {
+    pub fn new(path: PathBuf, existing: BTreeMap<String, V>, interval: Duration) -> Self {
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent).ok();
         }
         Self {
             path,
+            interval,
             rows: Mutex::new(existing),
+            last_flush: Mutex::new(Instant::now()),
         }
     }
 
-    /// Insert or update a row and flush to disk.
+    /// Insert or update a row; flush to disk if the checkpoint interval has passed.
     pub fn write_row(&self, key: String, row: V) {
         {
             let mut map = self.rows.lock().unwrap();
             map.insert(key, row);
         }
-        self.flush();
+        let due = {
+            let mut last_flush = self.last_flush.lock().unwrap();
+            let due = last_flush.elapsed() >= self.interval;
+            if due {
+                *last_flush = Instant::now();
+            }
+            due
+        };
+        if due {
+            self.flush();
+        }
     }
 
     /// Final flush; returns the output path.
@@ -37,12 +56,96 @@ impl<V: SimulationRow> OutputWriter<V> {
     }
 
     fn flush(&self) {
+        // Hold the flush lock across snapshot and rename, so concurrent
+        // flushes neither share the temporary file nor land out of order.
+        let _guard = self.last_flush.lock().unwrap();
         let mut rows: Vec<V> = {
             let map = self.rows.lock().unwrap();
             map.values().cloned().collect()
         };
         rows.sort();
         let json = serde_json::to_string_pretty(&rows).expect("JSON serialization failed");
-        fs::write(&self.path, json).expect("Failed to write results file");
+        let tmp = self.path.with_extension("json.tmp");
+        fs::write(&tmp, json).expect("Failed to write results file");
+        fs::rename(&tmp, &self.path).expect("Failed to write results file");
+    }
+}
+
+/// A row that can be written as one CSV record.
+pub trait CsvRow {
+    /// Column names, in record order.
+    fn csv_header() -> &'static [&'static str];
+
+    /// Field values, in header order.
+    fn csv_fields(&self) -> Vec<String>;
+}
+
+/// Write rows as pretty-printed JSON.
+pub fn write_json<R: Serialize>(path: &Path, rows: &[R]) {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).ok();
+    }
+    let json = serde_json::to_string_pretty(rows).expect("JSON serialization failed");
+    fs::write(path, json).expect("Failed to write results file");
+}
+
+/// Write rows as CSV with a header line.
+pub fn write_csv<R: CsvRow>(path: &Path, rows: &[R]) {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).ok();
+    }
+    fs::write(path, to_csv(rows)).expect("Failed to write results file");
+}
+
+fn to_csv<R: CsvRow>(rows: &[R]) -> String {
+    let mut out = R::csv_header().join(",");
+    out.push('\n');
+    for row in rows {
+        let fields: Vec<String> = row.csv_fields().iter().map(|f| escape_csv(f)).collect();
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Quote a field if it contains a separator, a quote, or a line break.
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Pair(&'static str, Option<f64>);
+
+    impl CsvRow for Pair {
+        fn csv_header() -> &'static [&'static str] {
+            &["name", "value"]
+        }
+
+        fn csv_fields(&self) -> Vec<String> {
+            vec![
+                self.0.to_string(),
+                self.1.map(|v| v.to_string()).unwrap_or_default(),
+            ]
+        }
+    }
+
+    #[test]
+    fn header_and_records() {
+        let rows = [Pair("a", Some(0.5)), Pair("b", None)];
+        assert_eq!(to_csv(&rows), "name,value\na,0.5\nb,\n");
+    }
+
+    #[test]
+    fn special_fields_are_quoted() {
+        assert_eq!(escape_csv("plain"), "plain");
+        assert_eq!(escape_csv("a,b"), "\"a,b\"");
+        assert_eq!(escape_csv("say \"hi\""), "\"say \"\"hi\"\"\"");
     }
 }
@@ -5,19 +5,27 @@ use console::style;
 use rayon::prelude::*;
 use std::collections::BTreeMap;
 use std::fs;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+/// Wall-clock limits of a run.
+pub struct Checkpointing {
+    /// Stop starting new rows once this much time has passed.
+    /// Rows already in progress are completed and saved.
+    pub time_budget: Option<Duration>,
+    /// Minimum time between flushes of completed rows to the output file.
+    pub interval: Duration,
+}
+
 /// Load existing rows from a JSON file.
 fn load_existing<R: SimulationRow + serde::de::DeserializeOwned>(
-    name: &str,
-    publish: bool,
+    path: &Path,
 ) -> BTreeMap<String, R> {
-    let path = output_path(name, publish);
     if !path.exists() {
         return BTreeMap::new();
     }
-    let content = fs::read_to_string(&path).unwrap_or_default();
+    let content = fs::read_to_string(path).unwrap_or_default();
     let rows: Vec<R> = serde_json::from_str(&content).unwrap_or_default();
     rows.into_iter().map(|r| (r.key(), r)).collect()
 }
@@ -32,17 +40,53 @@ fn format_duration(d: Duration) -> String {
 }
 
 /// Run a simulation with parallel execution.
-pub fn run<S>(sim: &S, sample_sizes: &[usize], parallelism: usize, overwrite: bool, publish: bool)
+///
+/// Completed rows are checkpointed to the output file every
+/// `checkpointing.interval`, so an interrupted run (or one that exhausted its time budget) resumes from them
+/// on the next invocation, like any other reused rows.
+///
+/// Returns the rows of this invocation (reused and newly simulated), sorted.
+pub fn run<S>(
+    sim: &S,
+    sample_sizes: &[usize],
+    parallelism: usize,
+    overwrite: bool,
+    publish: bool,
+    checkpointing: &Checkpointing,
+) -> Vec<S::Row>
+where
+    S: Simulation,
+    S::Row: serde::de::DeserializeOwned,
+{
+    let path = output_path(sim.name(), publish);
+    run_at(
+        sim,
+        path,
+        sample_sizes,
+        parallelism,
+        overwrite,
+        checkpointing,
+    )
+}
+
+fn run_at<S>(
+    sim: &S,
+    path: PathBuf,
+    sample_sizes: &[usize],
+    parallelism: usize,
+    overwrite: bool,
+    checkpointing: &Checkpointing,
+) -> Vec<S::Row>
 where
     S: Simulation,
     S::Row: serde::de::DeserializeOwned,
 {
-    let existing = load_existing::<S::Row>(sim.name(), publish);
+    let existing = load_existing::<S::Row>(&path);
     let (inputs, reused) = sim.create_inputs(sample_sizes, &existing, overwrite);
 
     if inputs.is_empty() && reused.is_empty() {
         eprintln!("  No valid simulation combinations found.");
-        return;
+        return Vec::new();
     }
 
     let total_new = inputs.len();
@@ -62,13 +106,13 @@ where
         style("\u{2014}").dim(),
     );
 
-    let path = output_path(sim.name(), publish);
-    let writer = Arc::new(OutputWriter::new(path, existing));
+    let writer = Arc::new(OutputWriter::new(path, existing, checkpointing.interval));
 
     // Print reused rows
     for row in &reused {
         eprintln!("  {}  {}", sim.format_row(row), style("(reused)").dim(),);
     }
+    let rows = Mutex::new(reused);
 
     if total_new > 0 {
         let pool = rayon::ThreadPoolBuilder::new()
@@ -78,9 +122,17 @@ where
 
         let tracker = Arc::new(ProgressTracker::new(total_new, reused_count));
         let start = Instant::now();
+        let out_of_time = || {
+            checkpointing
+                .time_budget
+                .is_some_and(|budget| start.elapsed() >= budget)
+        };
 
         pool.install(|| {
             inputs.par_iter().enumerate().for_each(|(idx, input)| {
+                if out_of_time() {
+                    return;
+                }
                 let progress = |frac: f64| tracker.update(idx, frac);
                 let result = sim.simulate_row(input, &progress);
 
@@ -91,7 +143,8 @@ where
 
                 tracker.println(&sim.format_row(&row));
                 let rounded = sim.round_row(row, 4);
-                writer.write_row(rounded.key(), rounded);
+                writer.write_row(rounded.key(), rounded.clone());
+                rows.lock().unwrap().push(rounded);
                 tracker.complete(idx);
             });
         });
@@ -99,11 +152,20 @@ where
         tracker.finish();
 
         let elapsed = format_duration(start.elapsed());
-        eprintln!(
-            "  {} Completed in {}",
-            style("\u{2713}").green().bold(),
-            style(&elapsed).bold(),
-        );
+        let remaining = total - rows.lock().unwrap().len();
+        if remaining > 0 {
+            eprintln!(
+                "  {} Time budget exhausted after {}: {remaining} tasks left, rerun to resume",
+                style("\u{25a0}").yellow().bold(),
+                style(&elapsed).bold(),
+            );
+        } else {
+            eprintln!(
+                "  {} Completed in {}",
+                style("\u{2713}").green().bold(),
+                style(&elapsed).bold(),
+            );
+        }
     }
 
     let saved_path = writer.finalize();
@@ -112,4 +174,133 @@ where
         style("\u{2713}").green().bold(),
         style(saved_path.display()).dim(),
     );
+
+    let mut rows = rows.into_inner().unwrap();
+    rows.sort();
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use std::thread;
+
+    #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+    struct SquareRow {
+        n: usize,
+        square: usize,
+    }
+
+    impl SimulationRow for SquareRow {
+        fn key(&self) -> String {
+            self.n.to_string()
+        }
+    }
+
+    /// Squares each sample size, sleeping `delay` per row.
+    struct SquareSim {
+        delay: Duration,
+    }
+
+    impl Simulation for SquareSim {
+        type Input = usize;
+        type Row = SquareRow;
+
+        fn name(&self) -> &'static str {
+            "square"
+        }
+
+        fn create_inputs(
+            &self,
+            sample_sizes: &[usize],
+            existing: &BTreeMap<String, SquareRow>,
+            overwrite: bool,
+        ) -> (Vec<usize>, Vec<SquareRow>) {
+            let mut inputs = Vec::new();
+            let mut reused = Vec::new();
+            for &n in sample_sizes {
+                match existing.get(&n.to_string()) {
+                    Some(row) if !overwrite => reused.push(row.clone()),
+                    _ => inputs.push(n),
+                }
+            }
+            (inputs, reused)
+        }
+
+        fn simulate_row(&self, n: &usize, _: &dyn Fn(f64)) -> Result<SquareRow, SimError> {
+            thread::sleep(self.delay);
+            Ok(SquareRow {
+                n: *n,
+                square: n * n,
+            })
+        }
+
+        fn create_error_row(&self, n: &usize, _: &str) -> SquareRow {
+            SquareRow { n: *n, square: 0 }
+        }
+
+        fn format_row(&self, row: &SquareRow) -> String {
+            format!("{row:?}")
+        }
+
+        fn round_row(&self, row: SquareRow, _: u32) -> SquareRow {
+            row
+        }
+    }
+
+    fn temp_output(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("pragmastat-sim-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("{name}.json"));
+        fs::remove_file(&path).ok();
+        path
+    }
+
+    fn saved_rows(path: &Path) -> Vec<SquareRow> {
+        serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    fn checkpointing(time_budget: Option<Duration>) -> Checkpointing {
+        Checkpointing {
+            time_budget,
+            interval: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn exhausted_budget_saves_valid_subset_and_resumes() {
+        let path = temp_output("budget");
+        let sim = SquareSim {
+            delay: Duration::from_millis(20),
+        };
+        let sizes: Vec<usize> = (1..=10).collect();
+
+        let budget = checkpointing(Some(Duration::from_millis(50)));
+        let partial = run_at(&sim, path.clone(), &sizes, 1, false, &budget);
+        assert!(!partial.is_empty() && partial.len() < sizes.len());
+        let saved = saved_rows(&path);
+        assert_eq!(saved, partial);
+        assert!(saved.iter().all(|r| r.square == r.n * r.n));
+
+        let full = run_at(&sim, path.clone(), &sizes, 1, false, &checkpointing(None));
+        assert_eq!(full.len(), sizes.len());
+        assert_eq!(saved_rows(&path), full);
+        assert!(full.iter().all(|r| r.square == r.n * r.n));
+    }
+
+    #[test]
+    fn zero_budget_keeps_existing_rows() {
+        let path = temp_output("zero-budget");
+        let sim = SquareSim {
+            delay: Duration::ZERO,
+        };
+
+        let first = run_at(&sim, path.clone(), &[1, 2], 2, false, &checkpointing(None));
+        let budget = checkpointing(Some(Duration::ZERO));
+        let rows = run_at(&sim, path.clone(), &[1, 2, 3, 4], 2, false, &budget);
+        assert_eq!(rows, first);
+        assert_eq!(saved_rows(&path), first);
+        assert!(!path.with_extension("json.tmp").exists());
+    }
 }
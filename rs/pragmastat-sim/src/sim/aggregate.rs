@@ -0,0 +1,321 @@
+use super::drift::DriftRow;
+use super::{output_file, SimError};
+use crate::cli::ExportFormat;
+use crate::output::{write_csv, write_json, CsvRow};
+use pragmastat::experiment::quantile;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Simulations whose outputs can be aggregated.
+const DRIFT_SIMULATIONS: [&str; 2] = ["avg-drift", "disp-drift"];
+
+/// Every simulation writing a JSON output, to name the kind of a rejected file.
+const SIMULATIONS: [&str; 9] = [
+    "avg-drift",
+    "avg-spread-bounds",
+    "center-bounds",
+    "disp-drift",
+    "disparity-bounds",
+    "efficiency",
+    "ratio-bounds",
+    "shift-bounds",
+    "spread-bounds",
+];
+
+/// Probabilities of the reported quantiles.
+const BAND_PROBABILITIES: [f64; 5] = [0.05, 0.25, 0.5, 0.75, 0.95];
+
+/// Quantiles of one drift across replications, for one sample size.
+#[derive(Debug, Clone, Serialize)]
+pub struct BandRow {
+    pub simulation: &'static str,
+    pub distribution: String,
+    pub estimator: String,
+    pub n: usize,
+    /// Number of replications with a value (rows with an error are skipped).
+    pub count: usize,
+    pub p05: f64,
+    pub p25: f64,
+    pub p50: f64,
+    pub p75: f64,
+    pub p95: f64,
+}
+
+impl CsvRow for BandRow {
+    fn csv_header() -> &'static [&'static str] {
+        &[
+            "simulation",
+            "distribution",
+            "estimator",
+            "n",
+            "count",
+            "p05",
+            "p25",
+            "p50",
+            "p75",
+            "p95",
+        ]
+    }
+
+    fn csv_fields(&self) -> Vec<String> {
+        vec![
+            self.simulation.to_string(),
+            self.distribution.clone(),
+            self.estimator.clone(),
+            self.n.to_string(),
+            self.count.to_string(),
+            self.p05.to_string(),
+            self.p25.to_string(),
+            self.p50.to_string(),
+            self.p75.to_string(),
+            self.p95.to_string(),
+        ]
+    }
+}
+
+/// One replication: the rows of a drift output and the simulation behind them.
+#[derive(Debug)]
+pub struct Replication {
+    pub path: PathBuf,
+    pub simulation: &'static str,
+    pub rows: Vec<DriftRow>,
+}
+
+/// Reads a drift output.
+///
+/// The simulation comes from the file name: the simulation name, optionally
+/// followed by `-` and a suffix (`avg-drift.json`, `avg-drift-seed2.json`).
+pub fn load(path: &Path) -> Result<Replication, SimError> {
+    let simulation = simulation_of(path)?;
+    if !DRIFT_SIMULATIONS.contains(&simulation) {
+        return Err(SimError(format!(
+            "{}: {simulation} outputs cannot be aggregated (supported: {})",
+            path.display(),
+            DRIFT_SIMULATIONS.join(", ")
+        )));
+    }
+    let content =
+        fs::read_to_string(path).map_err(|e| SimError(format!("{}: {e}", path.display())))?;
+    let rows: Vec<DriftRow> = serde_json::from_str(&content).map_err(|e| {
+        SimError(format!(
+            "{}: not a valid {simulation} output: {e}",
+            path.display()
+        ))
+    })?;
+    // Other outputs parse too (`drifts` and `error` are optional); only drift rows have either
+    if let Some(i) = rows
+        .iter()
+        .position(|r| r.drifts.is_none() && r.error.is_none())
+    {
+        return Err(SimError(format!(
+            "{}: not a valid {simulation} output: row {i} has neither drifts nor an error",
+            path.display()
+        )));
+    }
+    Ok(Replication {
+        path: path.to_path_buf(),
+        simulation,
+        rows,
+    })
+}
+
+fn simulation_of(path: &Path) -> Result<&'static str, SimError> {
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+    SIMULATIONS
+        .iter()
+        .copied()
+        .filter(|name| {
+            stem.strip_prefix(name)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+        })
+        .max_by_key(|name| name.len())
+        .ok_or_else(|| {
+            SimError(format!(
+                "{}: the file name must start with a simulation name ({})",
+                path.display(),
+                DRIFT_SIMULATIONS.join(", ")
+            ))
+        })
+}
+
+/// Quantile bands of every (distribution, estimator, n) over the replications.
+///
+/// Rows are sorted by distribution, estimator and n.
+pub fn aggregate(replications: &[Replication]) -> Result<Vec<BandRow>, SimError> {
+    let first = replications
+        .first()
+        .ok_or_else(|| SimError("no outputs to aggregate".to_string()))?;
+    if let Some(other) = replications
+        .iter()
+        .find(|r| r.simulation != first.simulation)
+    {
+        return Err(SimError(format!(
+            "cannot mix simulation kinds: {} is {}, {} is {}",
+            first.path.display(),
+            first.simulation,
+            other.path.display(),
+            other.simulation
+        )));
+    }
+
+    let mut values: BTreeMap<(&str, &str, usize), Vec<f64>> = BTreeMap::new();
+    for row in replications.iter().flat_map(|r| &r.rows) {
+        for (estimator, &drift) in row.drifts.iter().flatten() {
+            values
+                .entry((&row.distribution, estimator, row.sample_size))
+                .or_default()
+                .push(drift);
+        }
+    }
+
+    Ok(values
+        .into_iter()
+        .map(|((distribution, estimator, n), values)| {
+            let [p05, p25, p50, p75, p95] = BAND_PROBABILITIES.map(|p| quantile(&values, p));
+            BandRow {
+                simulation: first.simulation,
+                distribution: distribution.to_string(),
+                estimator: estimator.to_string(),
+                n,
+                count: values.len(),
+                p05,
+                p25,
+                p50,
+                p75,
+                p95,
+            }
+        })
+        .collect())
+}
+
+/// Export band rows; returns the output path.
+pub fn export(simulation: &str, rows: &[BandRow], format: ExportFormat, publish: bool) -> PathBuf {
+    let name = format!("aggregate-{simulation}");
+    let path = output_file(&name, format.extension(), publish);
+    match format {
+        ExportFormat::Json => write_json(&path, rows),
+        ExportFormat::Csv => write_csv(&path, rows),
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indexmap::IndexMap;
+
+    fn temp_dir() -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("pragmastat-sim-aggregate-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn drift_row(distribution: &str, n: usize, drifts: &[(&str, f64)]) -> DriftRow {
+        DriftRow {
+            distribution: distribution.to_string(),
+            sample_size: n,
+            drifts: Some(
+                drifts
+                    .iter()
+                    .map(|&(k, v)| (k.to_string(), v))
+                    .collect::<IndexMap<_, _>>(),
+            ),
+            error: None,
+        }
+    }
+
+    fn write_output(name: &str, rows: &[DriftRow]) -> PathBuf {
+        let path = temp_dir().join(name);
+        write_json(&path, rows);
+        path
+    }
+
+    #[test]
+    fn two_replications_give_interpolated_quantiles() {
+        let first = write_output(
+            "avg-drift-seed1.json",
+            &[
+                drift_row("Additive", 5, &[("Mean", 1.0), ("Center", 2.0)]),
+                drift_row("Additive", 10, &[("Mean", 3.0), ("Center", 2.0)]),
+            ],
+        );
+        let mut failed = drift_row("Additive", 10, &[]);
+        failed.drifts = None;
+        failed.error = Some("failed".to_string());
+        let second = write_output(
+            "avg-drift-seed2.json",
+            &[
+                drift_row("Additive", 5, &[("Mean", 2.0), ("Center", 6.0)]),
+                failed,
+            ],
+        );
+        let replications = [load(&first).unwrap(), load(&second).unwrap()];
+        let rows = aggregate(&replications).unwrap();
+
+        let keys: Vec<(&str, usize, usize)> = rows
+            .iter()
+            .map(|r| (r.estimator.as_str(), r.n, r.count))
+            .collect();
+        assert_eq!(
+            keys,
+            [
+                ("Center", 5, 2),
+                ("Center", 10, 1),
+                ("Mean", 5, 2),
+                ("Mean", 10, 1)
+            ]
+        );
+        assert!(rows.iter().all(|r| r.simulation == "avg-drift"));
+        // Two values a < b: the p-quantile is a + p (b - a)
+        let center = &rows[0];
+        assert_eq!(
+            [center.p05, center.p25, center.p50, center.p75, center.p95],
+            [2.2, 3.0, 4.0, 5.0, 5.8]
+        );
+        let mean = &rows[2];
+        assert_eq!([mean.p05, mean.p50, mean.p95], [1.05, 1.5, 1.95]);
+        // One value: a degenerate band
+        let single = &rows[3];
+        assert_eq!([single.p05, single.p50, single.p95], [3.0, 3.0, 3.0]);
+    }
+
+    #[test]
+    fn mixed_simulations_are_rejected() {
+        let row = [drift_row("Exp", 5, &[("Spread", 1.0)])];
+        let avg = load(&write_output("avg-drift-mixed.json", &row)).unwrap();
+        let disp = load(&write_output("disp-drift-mixed.json", &row)).unwrap();
+        let err = aggregate(&[avg, disp]).unwrap_err().to_string();
+        assert!(err.starts_with("cannot mix simulation kinds"), "{err}");
+        assert!(
+            err.contains("avg-drift") && err.contains("disp-drift"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn other_outputs_are_rejected() {
+        let bounds = temp_dir().join("center-bounds.json");
+        fs::write(&bounds, "[]").unwrap();
+        let err = load(&bounds).unwrap_err().to_string();
+        assert!(
+            err.contains("center-bounds outputs cannot be aggregated"),
+            "{err}"
+        );
+
+        // A bounds output under a drift name fails the schema check
+        let renamed = temp_dir().join("avg-drift-renamed.json");
+        let row = r#"[{"distribution": "Exp", "sampleSize": 5, "requestedMisrate": 0.1}]"#;
+        fs::write(&renamed, row).unwrap();
+        let err = load(&renamed).unwrap_err().to_string();
+        assert!(err.contains("not a valid avg-drift output"), "{err}");
+
+        let unknown = temp_dir().join("results.json");
+        assert!(load(&unknown)
+            .unwrap_err()
+            .to_string()
+            .contains("must start with a simulation name"));
+    }
+}
@@ -1,23 +1,11 @@
 use super::drift::{format_drift_row, round_drift_row, DriftInput, DriftRow};
 use super::{SimError, Simulation};
 use crate::distributions::{self, DistributionEntry};
-use crate::estimators;
+use crate::estimators::{find_estimator, EstimatorFn, EstimatorKind};
 use indexmap::IndexMap;
-use pragmastat::Rng;
+use pragmastat::experiment::Experiment;
 use std::collections::BTreeMap;
 
-/// One-sample estimator function type.
-type EstimatorFn = fn(&[f64]) -> f64;
-
-fn lookup_estimator(name: &str) -> EstimatorFn {
-    match name {
-        "Mean" => estimators::mean,
-        "Median" => |v| crate::estimators::median(v),
-        "Center" => |v| pragmastat::estimators::raw::center(v, false).unwrap(),
-        _ => panic!("Unknown average estimator: {name}"),
-    }
-}
-
 pub struct AvgDriftSim {
     distributions: Vec<&'static DistributionEntry>,
     estimator_names: Vec<String>,
@@ -92,15 +80,20 @@ impl Simulation for AvgDriftSim {
                 .next()
                 .expect("distribution not found");
         let dist = dist_entry.create();
-        let mut rng = Rng::from_string(&format!(
-            "{}-{}-{}",
-            input.base_seed, input.distribution_name, input.sample_size
+        let mut rng = Experiment::new(&input.base_seed).rng(format_args!(
+            "{}-{}",
+            input.distribution_name, input.sample_size
         ));
 
         let estimators: Vec<(&str, EstimatorFn)> = input
             .estimator_names
             .iter()
-            .map(|name| (name.as_str(), lookup_estimator(name)))
+            .map(|name| {
+                (
+                    name.as_str(),
+                    find_estimator(name, EstimatorKind::Average).estimate,
+                )
+            })
             .collect();
 
         // Build sampling distributions
@@ -109,8 +102,9 @@ impl Simulation for AvgDriftSim {
             sampling.insert(name.clone(), Vec::with_capacity(input.sample_count));
         }
 
+        let mut sample = vec![0.0; input.sample_size];
         for i in 0..input.sample_count {
-            let sample = dist.samples(&mut rng, input.sample_size);
+            dist.sample_into(&mut rng, &mut sample);
             for &(name, f) in &estimators {
                 sampling.get_mut(name).unwrap().push(f(&sample));
             }
@@ -4,7 +4,7 @@ use super::bounds::{
 };
 use super::{SimError, Simulation};
 use crate::distributions::{asymptotic_spread, find_distributions, DistributionEntry};
-use pragmastat::Rng;
+use pragmastat::experiment::Experiment;
 use std::collections::BTreeMap;
 
 pub struct AvgSpreadBoundsSim {
@@ -96,17 +96,19 @@ impl Simulation for AvgSpreadBoundsSim {
             .next()
             .expect("distribution not found");
         let dist = dist_entry.create();
-        let mut rng = Rng::from_string(&format!(
-            "{}-{}-{}-{}",
-            input.base_seed, input.distribution_name, input.sample_size_x, input.sample_size_y
+        let mut rng = Experiment::new(&input.base_seed).rng(format_args!(
+            "{}-{}-{}",
+            input.distribution_name, input.sample_size_x, input.sample_size_y
         ));
 
         let true_value = asymptotic_spread(dist_entry);
         let mut coverage = 0_usize;
 
+        let mut x = vec![0.0; input.sample_size_x];
+        let mut y = vec![0.0; input.sample_size_y];
         for i in 0..input.sample_count {
-            let x: Vec<f64> = dist.samples(&mut rng, input.sample_size_x);
-            let y: Vec<f64> = dist.samples(&mut rng, input.sample_size_y);
+            dist.sample_into(&mut rng, &mut x);
+            dist.sample_into(&mut rng, &mut y);
             let bounds =
                 pragmastat::estimators::raw::avg_spread_bounds(&x, &y, input.misrate, false)
                     .map_err(|e| SimError(format!("{e}")))?;
@@ -12,6 +12,9 @@ pub struct BoundsRow {
     pub sample_count: usize,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub observed_misrate: Option<f64>,
+    /// Mean of `upper - lower` over all samples.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avg_width: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
 }
@@ -109,6 +112,7 @@ pub fn round_bounds_row(row: BoundsRow, digits: u32) -> BoundsRow {
     BoundsRow {
         requested_misrate: (row.requested_misrate * factor).round() / factor,
         observed_misrate: row.observed_misrate.map(|v| (v * factor).round() / factor),
+        avg_width: row.avg_width.map(|v| (v * factor).round() / factor),
         ..row
     }
 }
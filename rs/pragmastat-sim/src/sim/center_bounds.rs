@@ -4,7 +4,8 @@ use super::bounds::{
 };
 use super::{SimError, Simulation};
 use crate::distributions::{find_distributions, DistributionEntry};
-use pragmastat::Rng;
+use pragmastat::experiment::Experiment;
+use pragmastat::Ar1;
 use std::collections::BTreeMap;
 
 pub struct CenterBoundsSim {
@@ -12,6 +13,8 @@ pub struct CenterBoundsSim {
     sample_count: Option<usize>,
     misrates: Vec<f64>,
     base_seed: String,
+    /// AR(1) coefficient; each sample is then an autocorrelated series.
+    phi: Option<f64>,
 }
 
 impl CenterBoundsSim {
@@ -26,6 +29,26 @@ impl CenterBoundsSim {
             sample_count,
             misrates: parse_misrates(misrates_str),
             base_seed,
+            phi: None,
+        }
+    }
+
+    /// Draws each sample as an [`Ar1`] series with the distribution as its
+    /// innovations, to measure how coverage degrades under dependence.
+    ///
+    /// Rows go to a separate `center-bounds-ar1` output and their distribution
+    /// is labeled with `phi` (e.g. `Additive-ar1-0.5`). Symmetric innovations
+    /// keep the series symmetric around the same center, so the true value is
+    /// unchanged.
+    pub fn with_ar1(mut self, phi: f64) -> Self {
+        self.phi = Some(phi);
+        self
+    }
+
+    fn label(&self, distribution: &str) -> String {
+        match self.phi {
+            Some(phi) => format!("{distribution}-ar1-{phi}"),
+            None => distribution.to_string(),
         }
     }
 }
@@ -35,7 +58,11 @@ impl Simulation for CenterBoundsSim {
     type Row = BoundsRow;
 
     fn name(&self) -> &'static str {
-        "center-bounds"
+        if self.phi.is_some() {
+            "center-bounds-ar1"
+        } else {
+            "center-bounds"
+        }
     }
 
     fn create_inputs(
@@ -57,7 +84,7 @@ impl Simulation for CenterBoundsSim {
                     if misrate < min_misrate {
                         continue;
                     }
-                    let key = format!("{}-{}-{}", dist.name, n, misrate);
+                    let key = format!("{}-{}-{}", self.label(dist.name), n, misrate);
                     if !overwrite {
                         if let Some(row) = existing.get(&key) {
                             reused.push(row.clone());
@@ -89,21 +116,32 @@ impl Simulation for CenterBoundsSim {
             .next()
             .expect("distribution not found");
         let dist = dist_entry.create();
-        let mut rng = Rng::from_string(&format!(
-            "{}-{}-{}",
-            input.base_seed, input.distribution_name, input.sample_size
+        let series = self
+            .phi
+            .map(|phi| Ar1::new(dist_entry.create(), phi))
+            .transpose()
+            .map_err(|e| SimError(format!("{e}")))?;
+        let mut rng = Experiment::new(&input.base_seed).rng(format_args!(
+            "{}-{}",
+            input.distribution_name, input.sample_size
         ));
 
         let true_value = dist_entry
             .center
             .expect("center must be set for symmetric distributions");
         let mut coverage = 0_usize;
+        let mut total_width = 0.0;
 
+        let mut sample = vec![0.0; input.sample_size];
         for i in 0..input.sample_count {
-            let sample: Vec<f64> = dist.samples(&mut rng, input.sample_size);
+            match &series {
+                Some(ar1) => sample = ar1.sample_series(&mut rng, input.sample_size),
+                None => dist.sample_into(&mut rng, &mut sample),
+            }
             let bounds = pragmastat::estimators::raw::center_bounds(&sample, input.misrate, false)
                 .map_err(|e| SimError(format!("{e}")))?;
 
+            total_width += bounds.upper - bounds.lower;
             if bounds.lower <= true_value && true_value <= bounds.upper {
                 coverage += 1;
             }
@@ -115,22 +153,24 @@ impl Simulation for CenterBoundsSim {
 
         let observed = 1.0 - coverage as f64 / input.sample_count as f64;
         Ok(BoundsRow {
-            distribution: input.distribution_name.clone(),
+            distribution: self.label(&input.distribution_name),
             sample_size: input.sample_size,
             requested_misrate: input.misrate,
             sample_count: input.sample_count,
             observed_misrate: Some(observed),
+            avg_width: Some(total_width / input.sample_count as f64),
             error: None,
         })
     }
 
     fn create_error_row(&self, input: &BoundsInput, error: &str) -> BoundsRow {
         BoundsRow {
-            distribution: input.distribution_name.clone(),
+            distribution: self.label(&input.distribution_name),
             sample_size: input.sample_size,
             requested_misrate: input.misrate,
             sample_count: input.sample_count,
             observed_misrate: None,
+            avg_width: None,
             error: Some(error.to_string()),
         }
     }
@@ -143,3 +183,34 @@ impl Simulation for CenterBoundsSim {
         round_bounds_row(row, digits)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn observed_misrate(sim: &CenterBoundsSim) -> f64 {
+        let input = BoundsInput {
+            distribution_name: "Additive".to_string(),
+            sample_count: 2_000,
+            sample_size: 20,
+            misrate: 0.1,
+            base_seed: "center-bounds-test".to_string(),
+        };
+        let row = sim.simulate_row(&input, &|_| {}).unwrap();
+        row.observed_misrate.unwrap()
+    }
+
+    #[test]
+    fn autocorrelation_degrades_coverage() {
+        let dists = find_distributions(&["Additive".to_string()]);
+        let iid = CenterBoundsSim::new(dists.clone(), None, "0.1", "s".to_string());
+        let ar1 = CenterBoundsSim::new(dists, None, "0.1", "s".to_string()).with_ar1(0.8);
+        assert_eq!(ar1.name(), "center-bounds-ar1");
+        assert_eq!(ar1.label("Additive"), "Additive-ar1-0.8");
+
+        let iid_misrate = observed_misrate(&iid);
+        assert!((iid_misrate - 0.1).abs() < 0.03, "{iid_misrate}");
+        // Positive dependence shrinks the effective sample size: far more misses
+        assert!(observed_misrate(&ar1) > 0.3);
+    }
+}
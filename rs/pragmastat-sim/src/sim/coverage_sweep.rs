@@ -0,0 +1,91 @@
+use super::bounds::BoundsRow;
+use super::output_file;
+use crate::cli::{ExportFormat, SweepEstimator};
+use crate::output::{write_csv, write_json, CsvRow};
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// One tidy row of a coverage sweep.
+///
+/// `m` is set only for two-sample estimators; `coverage` and `avg_width` are
+/// empty for combinations that failed with an error.
+#[derive(Debug, Clone, Serialize)]
+pub struct SweepRow {
+    pub estimator: &'static str,
+    pub distribution: String,
+    pub n: usize,
+    pub m: Option<usize>,
+    pub misrate: f64,
+    pub coverage: Option<f64>,
+    pub avg_width: Option<f64>,
+    pub sample_count: usize,
+}
+
+impl SweepRow {
+    pub fn from_bounds(estimator: SweepEstimator, row: &BoundsRow) -> Self {
+        Self {
+            estimator: estimator.name(),
+            distribution: row.distribution.clone(),
+            n: row.sample_size,
+            m: estimator.is_two_sample().then_some(row.sample_size),
+            misrate: row.requested_misrate,
+            // Observed misrates are rounded to 4 digits; keep coverage on the same grid
+            coverage: row
+                .observed_misrate
+                .map(|v| ((1.0 - v) * 1e4).round() / 1e4),
+            avg_width: row.avg_width,
+            sample_count: row.sample_count,
+        }
+    }
+}
+
+impl CsvRow for SweepRow {
+    fn csv_header() -> &'static [&'static str] {
+        &[
+            "estimator",
+            "distribution",
+            "n",
+            "m",
+            "misrate",
+            "coverage",
+            "avg_width",
+            "sample_count",
+        ]
+    }
+
+    fn csv_fields(&self) -> Vec<String> {
+        fn optional<T: ToString>(value: Option<T>) -> String {
+            value.map(|v| v.to_string()).unwrap_or_default()
+        }
+        vec![
+            self.estimator.to_string(),
+            self.distribution.clone(),
+            self.n.to_string(),
+            optional(self.m),
+            self.misrate.to_string(),
+            optional(self.coverage),
+            optional(self.avg_width),
+            self.sample_count.to_string(),
+        ]
+    }
+}
+
+/// Flatten bounds rows and export them; returns the output path.
+pub fn export(
+    estimator: SweepEstimator,
+    rows: &[BoundsRow],
+    format: ExportFormat,
+    publish: bool,
+) -> PathBuf {
+    let rows: Vec<SweepRow> = rows
+        .iter()
+        .map(|row| SweepRow::from_bounds(estimator, row))
+        .collect();
+    let name = format!("coverage-sweep-{}", estimator.name());
+    let path = output_file(&name, format.extension(), publish);
+    match format {
+        ExportFormat::Json => write_json(&path, &rows),
+        ExportFormat::Csv => write_csv(&path, &rows),
+    }
+    path
+}
@@ -0,0 +1,396 @@
+use super::{SimError, Simulation};
+use crate::distributions::{self, DistributionEntry};
+use crate::estimators::{find_estimator, EstimatorFn, EstimatorKind};
+use console::style;
+use indexmap::IndexMap;
+use pragmastat::experiment::Experiment;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+/// Bootstrap resamples of the replications behind each efficiency interval.
+const BOOTSTRAP_COUNT: usize = 200;
+
+/// Coverage of the reported bootstrap percentile interval.
+const BOOTSTRAP_COVERAGE: f64 = 0.95;
+
+/// Relative efficiency of one estimator with bootstrap percentile bounds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Efficiency {
+    pub value: f64,
+    pub lower: f64,
+    pub upper: f64,
+}
+
+/// Relative efficiencies of all estimators against a baseline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EfficiencyRow {
+    pub distribution: String,
+    pub sample_size: usize,
+    pub sample_count: usize,
+    pub baseline: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub efficiencies: Option<IndexMap<String, Efficiency>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl super::SimulationRow for EfficiencyRow {
+    fn key(&self) -> String {
+        format!(
+            "{}-{}-{}",
+            self.distribution, self.sample_size, self.baseline
+        )
+    }
+}
+
+impl PartialEq for EfficiencyRow {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for EfficiencyRow {}
+
+impl PartialOrd for EfficiencyRow {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EfficiencyRow {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distribution
+            .cmp(&other.distribution)
+            .then(self.sample_size.cmp(&other.sample_size))
+            .then(self.baseline.cmp(&other.baseline))
+    }
+}
+
+/// Input for a single efficiency simulation task.
+pub struct EfficiencyInput {
+    pub distribution_name: String,
+    pub sample_size: usize,
+}
+
+/// Relative efficiency of estimators against a baseline.
+///
+/// The efficiency of an estimator is `var(baseline) / var(estimator)` over the
+/// sampling distribution, so values below 1 mean the estimator is noisier than
+/// the baseline. Dispersion estimators live on different scales, so for them
+/// the variance is taken relative to the squared mean (squared coefficient of
+/// variation). All estimators must be of the same kind.
+pub struct EfficiencySim {
+    distributions: Vec<&'static DistributionEntry>,
+    estimator_names: Vec<String>,
+    baseline: String,
+    kind: EstimatorKind,
+    sample_count: usize,
+    base_seed: String,
+}
+
+impl EfficiencySim {
+    /// # Panics
+    ///
+    /// Panics if an estimator is unknown or the estimators are of different kinds.
+    pub fn new(
+        distributions: Vec<&'static DistributionEntry>,
+        estimator_names: Vec<String>,
+        baseline: String,
+        sample_count: usize,
+        base_seed: String,
+    ) -> Self {
+        let kind = lookup_kind(&baseline);
+        let mut names = vec![baseline.clone()];
+        for name in estimator_names {
+            find_estimator(&name, kind);
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        Self {
+            distributions,
+            estimator_names: names,
+            baseline,
+            kind,
+            sample_count,
+            base_seed,
+        }
+    }
+}
+
+fn lookup_kind(name: &str) -> EstimatorKind {
+    crate::estimators::ESTIMATORS
+        .iter()
+        .find(|e| e.name == name)
+        .unwrap_or_else(|| panic!("Unknown estimator: {name}"))
+        .kind
+}
+
+/// Variance of `values[indices]`, relative to the squared mean for dispersions.
+fn noise(values: &[f64], indices: impl Iterator<Item = usize>, kind: EstimatorKind) -> f64 {
+    let (mut count, mut mean, mut m2) = (0.0, 0.0, 0.0);
+    for i in indices {
+        // Welford's update
+        count += 1.0;
+        let delta = values[i] - mean;
+        mean += delta / count;
+        m2 += delta * (values[i] - mean);
+    }
+    let variance = m2 / (count - 1.0);
+    match kind {
+        EstimatorKind::Average => variance,
+        EstimatorKind::Dispersion => variance / (mean * mean),
+    }
+}
+
+impl Simulation for EfficiencySim {
+    type Input = EfficiencyInput;
+    type Row = EfficiencyRow;
+
+    fn name(&self) -> &'static str {
+        "efficiency"
+    }
+
+    fn create_inputs(
+        &self,
+        sample_sizes: &[usize],
+        existing: &BTreeMap<String, EfficiencyRow>,
+        overwrite: bool,
+    ) -> (Vec<EfficiencyInput>, Vec<EfficiencyRow>) {
+        let mut inputs = Vec::new();
+        let mut reused = Vec::new();
+
+        for dist in &self.distributions {
+            for &n in sample_sizes {
+                let key = format!("{}-{}-{}", dist.name, n, self.baseline);
+                if !overwrite {
+                    if let Some(row) = existing.get(&key) {
+                        reused.push(row.clone());
+                        continue;
+                    }
+                }
+                inputs.push(EfficiencyInput {
+                    distribution_name: dist.name.to_string(),
+                    sample_size: n,
+                });
+            }
+        }
+
+        reused.sort();
+        (inputs, reused)
+    }
+
+    fn simulate_row(
+        &self,
+        input: &EfficiencyInput,
+        progress: &dyn Fn(f64),
+    ) -> Result<EfficiencyRow, SimError> {
+        if self.sample_count < 2 {
+            return Err(SimError("sample count must be at least 2".to_string()));
+        }
+        let dist_entry =
+            distributions::find_distributions(std::slice::from_ref(&input.distribution_name))
+                .into_iter()
+                .next()
+                .expect("distribution not found");
+        let dist = dist_entry.create();
+        let experiment = Experiment::new(&self.base_seed);
+        let cell = format!("{}-{}", input.distribution_name, input.sample_size);
+        let mut rng = experiment.rng(&cell);
+
+        let estimators: Vec<EstimatorFn> = self
+            .estimator_names
+            .iter()
+            .map(|name| find_estimator(name, self.kind).estimate)
+            .collect();
+
+        // Sampling distributions, one column per estimator; the first is the baseline
+        let mut sampling = vec![Vec::with_capacity(self.sample_count); estimators.len()];
+        let mut sample = vec![0.0; input.sample_size];
+        for i in 0..self.sample_count {
+            dist.sample_into(&mut rng, &mut sample);
+            for (column, f) in sampling.iter_mut().zip(&estimators) {
+                column.push(f(&sample));
+            }
+            progress(0.9 * (i + 1) as f64 / self.sample_count as f64);
+        }
+
+        let count = self.sample_count;
+        let baseline_noise = noise(&sampling[0], 0..count, self.kind);
+        let values: Vec<f64> = sampling[1..]
+            .iter()
+            .map(|column| baseline_noise / noise(column, 0..count, self.kind))
+            .collect();
+
+        // Bootstrap the replications, keeping estimators paired within a replication
+        let mut boot_rng = experiment.rng(format_args!("{cell}-bootstrap"));
+        let mut boots = vec![Vec::with_capacity(BOOTSTRAP_COUNT); values.len()];
+        let mut indices = vec![0; count];
+        for b in 0..BOOTSTRAP_COUNT {
+            for i in indices.iter_mut() {
+                *i = boot_rng.uniform_usize(0, count);
+            }
+            let baseline = noise(&sampling[0], indices.iter().copied(), self.kind);
+            for (boot, column) in boots.iter_mut().zip(&sampling[1..]) {
+                boot.push(baseline / noise(column, indices.iter().copied(), self.kind));
+            }
+            progress(0.9 + 0.1 * (b + 1) as f64 / BOOTSTRAP_COUNT as f64);
+        }
+
+        let alpha = (1.0 - BOOTSTRAP_COVERAGE) / 2.0;
+        let lower_index = (alpha * (BOOTSTRAP_COUNT - 1) as f64).round() as usize;
+        let upper_index = ((1.0 - alpha) * (BOOTSTRAP_COUNT - 1) as f64).round() as usize;
+        let mut efficiencies = IndexMap::new();
+        for ((name, value), mut boot) in self.estimator_names[1..].iter().zip(values).zip(boots) {
+            if !value.is_finite() {
+                return Err(SimError(format!("{name} has zero variance")));
+            }
+            boot.sort_by(|a, b| a.total_cmp(b));
+            efficiencies.insert(
+                name.clone(),
+                Efficiency {
+                    value,
+                    lower: boot[lower_index],
+                    upper: boot[upper_index],
+                },
+            );
+        }
+
+        Ok(EfficiencyRow {
+            distribution: input.distribution_name.clone(),
+            sample_size: input.sample_size,
+            sample_count: self.sample_count,
+            baseline: self.baseline.clone(),
+            efficiencies: Some(efficiencies),
+            error: None,
+        })
+    }
+
+    fn create_error_row(&self, input: &EfficiencyInput, error: &str) -> EfficiencyRow {
+        EfficiencyRow {
+            distribution: input.distribution_name.clone(),
+            sample_size: input.sample_size,
+            sample_count: self.sample_count,
+            baseline: self.baseline.clone(),
+            efficiencies: None,
+            error: Some(error.to_string()),
+        }
+    }
+
+    fn format_row(&self, row: &EfficiencyRow) -> String {
+        let dist_padded = format!("{:<9}", row.distribution);
+        let n_padded = format!("N={:<3}", row.sample_size);
+
+        if let Some(ref err) = row.error {
+            return format!(
+                "{}  {}   {}",
+                style(&dist_padded).yellow().bold(),
+                style(&n_padded).yellow(),
+                style(format!("Error: {err}")).red(),
+            );
+        }
+
+        let parts: Vec<String> = row
+            .efficiencies
+            .iter()
+            .flatten()
+            .map(|(k, e)| {
+                let label = format!("{k}/{}:", row.baseline);
+                format!(
+                    "{} {:.4} {}",
+                    style(label).cyan(),
+                    e.value,
+                    style(format!("[{:.4}; {:.4}]", e.lower, e.upper)).dim(),
+                )
+            })
+            .collect();
+        format!(
+            "{}  {}   {}",
+            style(&dist_padded).green().bold(),
+            style(&n_padded).green(),
+            parts.join("  "),
+        )
+    }
+
+    fn round_row(&self, row: EfficiencyRow, digits: u32) -> EfficiencyRow {
+        let factor = 10.0_f64.powi(digits as i32);
+        let round = |v: f64| (v * factor).round() / factor;
+        let efficiencies = row.efficiencies.map(|m| {
+            m.into_iter()
+                .map(|(k, e)| {
+                    let rounded = Efficiency {
+                        value: round(e.value),
+                        lower: round(e.lower),
+                        upper: round(e.upper),
+                    };
+                    (k, rounded)
+                })
+                .collect()
+        });
+        EfficiencyRow {
+            efficiencies,
+            ..row
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn center_efficiency_on_additive_matches_asymptotic() {
+        let dists = distributions::find_distributions(&["Additive".to_string()]);
+        let sim = EfficiencySim::new(
+            dists,
+            vec!["Center".to_string()],
+            "Mean".to_string(),
+            20_000,
+            "efficiency-test".to_string(),
+        );
+        let input = EfficiencyInput {
+            distribution_name: "Additive".to_string(),
+            sample_size: 100,
+        };
+        let row = sim.simulate_row(&input, &|_| {}).unwrap();
+        let center = &row.efficiencies.unwrap()["Center"];
+        // Asymptotic relative efficiency of Hodges-Lehmann under normality: 3/pi
+        let expected = 3.0 / std::f64::consts::PI;
+        assert!(
+            (center.value - expected).abs() < 0.02,
+            "efficiency {}",
+            center.value
+        );
+        assert!(center.lower <= center.value && center.value <= center.upper);
+        assert!(center.upper - center.lower < 0.05);
+    }
+
+    #[test]
+    fn baseline_is_excluded_and_estimators_deduplicated() {
+        let dists = distributions::find_distributions(&["Uniform".to_string()]);
+        let names = ["Mean", "Median", "Mean"].map(String::from).to_vec();
+        let sim = EfficiencySim::new(dists, names, "Mean".to_string(), 200, "x".to_string());
+        let input = EfficiencyInput {
+            distribution_name: "Uniform".to_string(),
+            sample_size: 5,
+        };
+        let row = sim.simulate_row(&input, &|_| {}).unwrap();
+        let keys: Vec<&String> = row.efficiencies.as_ref().unwrap().keys().collect();
+        assert_eq!(keys, ["Median"]);
+        assert_eq!(row.baseline, "Mean");
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown average estimator: Spread")]
+    fn mixed_kinds_are_rejected() {
+        EfficiencySim::new(
+            Vec::new(),
+            vec!["Spread".to_string()],
+            "Mean".to_string(),
+            100,
+            "x".to_string(),
+        );
+    }
+}
@@ -1,11 +1,14 @@
+pub mod aggregate;
 pub mod avg_drift;
 pub mod avg_spread_bounds;
 pub mod bounds;
 pub mod bounds_width;
 pub mod center_bounds;
+pub mod coverage_sweep;
 pub mod disp_drift;
 pub mod disparity_bounds;
 pub mod drift;
+pub mod efficiency;
 pub mod ratio_bounds;
 pub mod shift_bounds;
 pub mod spread_bounds;
@@ -62,6 +65,11 @@ impl std::fmt::Display for SimError {
 
 /// Resolve the output path for a simulation.
 pub fn output_path(name: &str, publish: bool) -> PathBuf {
+    output_file(name, "json", publish)
+}
+
+/// Resolve the output path for a simulation export with the given extension.
+pub fn output_file(name: &str, extension: &str, publish: bool) -> PathBuf {
     let manifest_dir = env!("CARGO_MANIFEST_DIR");
     let repo_root = PathBuf::from(manifest_dir)
         .parent()
@@ -74,5 +82,5 @@ pub fn output_path(name: &str, publish: bool) -> PathBuf {
     } else {
         repo_root.join("sim").join("rs")
     };
-    sim_dir.join(format!("{name}.json"))
+    sim_dir.join(format!("{name}.{extension}"))
 }
@@ -4,7 +4,7 @@ use super::bounds::{
 };
 use super::{SimError, Simulation};
 use crate::distributions::{self, DistributionEntry};
-use pragmastat::Rng;
+use pragmastat::experiment::Experiment;
 use std::collections::BTreeMap;
 
 pub struct ShiftBoundsSim {
@@ -87,21 +87,25 @@ impl Simulation for ShiftBoundsSim {
                 .next()
                 .expect("distribution not found");
         let dist = dist_entry.create();
-        let mut rng = Rng::from_string(&format!(
-            "{}-{}-{}",
-            input.base_seed, input.distribution_name, input.sample_size
+        let mut rng = Experiment::new(&input.base_seed).rng(format_args!(
+            "{}-{}",
+            input.distribution_name, input.sample_size
         ));
 
         let true_value = 0.0;
         let mut coverage = 0_usize;
+        let mut total_width = 0.0;
 
+        let mut x = vec![0.0; input.sample_size];
+        let mut y = vec![0.0; input.sample_size];
         for i in 0..input.sample_count {
-            let x: Vec<f64> = dist.samples(&mut rng, input.sample_size);
-            let y: Vec<f64> = dist.samples(&mut rng, input.sample_size);
+            dist.sample_into(&mut rng, &mut x);
+            dist.sample_into(&mut rng, &mut y);
 
             let bounds = pragmastat::estimators::raw::shift_bounds(&x, &y, input.misrate, false)
                 .map_err(|e| SimError(format!("{e}")))?;
 
+            total_width += bounds.upper - bounds.lower;
             if bounds.lower <= true_value && true_value <= bounds.upper {
                 coverage += 1;
             }
@@ -118,6 +122,7 @@ impl Simulation for ShiftBoundsSim {
             requested_misrate: input.misrate,
             sample_count: input.sample_count,
             observed_misrate: Some(observed),
+            avg_width: Some(total_width / input.sample_count as f64),
             error: None,
         })
     }
@@ -129,6 +134,7 @@ impl Simulation for ShiftBoundsSim {
             requested_misrate: input.misrate,
             sample_count: input.sample_count,
             observed_misrate: None,
+            avg_width: None,
             error: Some(error.to_string()),
         }
     }
@@ -0,0 +1,224 @@
+//! Benchmarks for the estimators and their bounds.
+//!
+//! Run with: `cargo bench` (or `cargo bench -- shift` to filter by name).
+//!
+//! Inputs come from `pragmastat::bench_inputs`, so every run measures the same
+//! data. Each case is timed over repeated calls (at least 3, up to a ~1 s budget)
+//! and summarized by its median. The summary is written as a JSON array of rows
+//! sorted by name (like the simulation outputs) to
+//! `target/bench/estimators.json`, or to `$PRAGMASTAT_BENCH_OUTPUT` if set. If
+//! `$PRAGMASTAT_BENCH_BASELINE` points to a previous summary, the relative
+//! change of each median is printed next to it.
+
+use pragmastat::bench_inputs::{one_sample, two_sample, MISRATE, SIZES};
+use pragmastat::estimators::raw;
+use pragmastat::margins;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::hint::black_box;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+const MIN_ITERATIONS: usize = 3;
+const MAX_ITERATIONS: usize = 1_000;
+const TIME_BUDGET: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BenchRow {
+    name: String,
+    estimator: String,
+    n: usize,
+    iterations: usize,
+    median_ns: u128,
+    min_ns: u128,
+    mean_ns: u128,
+}
+
+type OneSampleFn = fn(&[f64]) -> f64;
+type TwoSampleFn = fn(&[f64], &[f64]) -> f64;
+
+/// Cases as `(estimator, largest n to run, function)`. `center_bounds` is
+/// capped because its quantile selection over Walsh averages grows much faster
+/// than n log n (about 1 s per call at n = 10k).
+const ONE_SAMPLE: [(&str, usize, OneSampleFn); 4] = [
+    ("center", usize::MAX, |x| raw::center(x, false).unwrap()),
+    ("spread", usize::MAX, |x| raw::spread(x, false).unwrap()),
+    ("center_bounds", 10_000, |x| {
+        raw::center_bounds(x, MISRATE, false).unwrap().lower
+    }),
+    ("spread_bounds", usize::MAX, |x| {
+        raw::spread_bounds_with_seed(x, MISRATE, "bench", false)
+            .unwrap()
+            .lower
+    }),
+];
+
+const TWO_SAMPLE: [(&str, usize, TwoSampleFn); 4] = [
+    ("shift", usize::MAX, |x, y| raw::shift(x, y, false).unwrap()),
+    ("disparity", usize::MAX, |x, y| {
+        raw::disparity(x, y, false).unwrap()
+    }),
+    ("shift_bounds", usize::MAX, |x, y| {
+        raw::shift_bounds(x, y, MISRATE, false).unwrap().lower
+    }),
+    ("disparity_bounds", usize::MAX, |x, y| {
+        raw::disparity_bounds_with_seed(x, y, MISRATE, "bench", false)
+            .unwrap()
+            .lower
+    }),
+];
+
+/// Sizes for the large-sample spread bounds, where the asymptotic path is
+/// meant to replace the randomized one; `spread_bounds` is timed here only at
+/// the sizes not already in `SIZES`, so each size has both rows.
+const LARGE_SIZES: [usize; 4] = [1_000, 10_000, 100_000, 1_000_000];
+
+const LARGE: [(&str, OneSampleFn); 2] = [
+    ("spread_bounds", |x| {
+        raw::spread_bounds_with_seed(x, MISRATE, "bench", false)
+            .unwrap()
+            .lower
+    }),
+    ("spread_bounds_asymptotic", |x| {
+        raw::spread_bounds_asymptotic(x, MISRATE, false)
+            .unwrap()
+            .lower
+    }),
+];
+
+/// Small sizes for the margin cases, where repeated bounds calls are dominated
+/// by the exact signed-rank and binomial tables rather than by the data.
+const MARGIN_SIZES: [usize; 3] = [10, 20, 30];
+
+type MarginFn = fn(&[f64]) -> f64;
+
+/// Margin cases, timed over a batch of 1000 calls each so that the cached
+/// exact tables show up against a single cold computation.
+const MARGIN: [(&str, MarginFn); 3] = [
+    ("signed_rank_margin", |x| {
+        (0..1_000)
+            .map(|_| margins::signed_rank_margin(x.len(), 0.05).unwrap())
+            .sum::<usize>() as f64
+    }),
+    ("sign_margin", |x| {
+        (0..1_000)
+            .map(|_| margins::sign_margin(x.len(), 0.1).unwrap())
+            .sum::<usize>() as f64
+    }),
+    ("center_bounds_small", |x| {
+        (0..1_000)
+            .map(|_| raw::center_bounds(x, 0.1, false).unwrap().lower)
+            .sum()
+    }),
+];
+
+fn measure(estimator: &str, n: usize, mut run: impl FnMut() -> f64) -> BenchRow {
+    black_box(run()); // warm-up
+    let mut times = Vec::new();
+    let started = Instant::now();
+    while times.len() < MIN_ITERATIONS
+        || (times.len() < MAX_ITERATIONS && started.elapsed() < TIME_BUDGET)
+    {
+        let start = Instant::now();
+        black_box(run());
+        times.push(start.elapsed().as_nanos());
+    }
+    times.sort_unstable();
+    BenchRow {
+        name: format!("{estimator}/{n}"),
+        estimator: estimator.to_string(),
+        n,
+        iterations: times.len(),
+        median_ns: times[times.len() / 2],
+        min_ns: times[0],
+        mean_ns: times.iter().sum::<u128>() / times.len() as u128,
+    }
+}
+
+fn load_baseline() -> BTreeMap<String, BenchRow> {
+    let Ok(path) = std::env::var("PRAGMASTAT_BENCH_BASELINE") else {
+        return BTreeMap::new();
+    };
+    let content = fs::read_to_string(&path).expect("Failed to read baseline file");
+    let rows: Vec<BenchRow> = serde_json::from_str(&content).expect("Invalid baseline file");
+    rows.into_iter().map(|r| (r.name.clone(), r)).collect()
+}
+
+fn report(row: &BenchRow, baseline: &BTreeMap<String, BenchRow>) {
+    let median = Duration::from_nanos(row.median_ns as u64);
+    match baseline.get(&row.name) {
+        Some(base) => {
+            let change = row.median_ns as f64 / base.median_ns as f64 - 1.0;
+            println!(
+                "{:<24} {:>12.3?}  ({:+.1}% vs baseline)",
+                row.name,
+                median,
+                change * 100.0
+            );
+        }
+        None => println!("{:<24} {:>12.3?}", row.name, median),
+    }
+}
+
+fn main() {
+    // cargo passes `--bench`; any other argument is a substring filter.
+    let filters: Vec<String> = std::env::args()
+        .skip(1)
+        .filter(|a| !a.starts_with("--"))
+        .collect();
+    let selected = |name: &str| filters.is_empty() || filters.iter().any(|f| name.contains(f));
+    let baseline = load_baseline();
+
+    let mut rows = Vec::new();
+    for n in SIZES {
+        let x = one_sample(n);
+        for (estimator, max_n, f) in ONE_SAMPLE {
+            if n <= max_n && selected(&format!("{estimator}/{n}")) {
+                let row = measure(estimator, n, || f(&x));
+                report(&row, &baseline);
+                rows.push(row);
+            }
+        }
+        let (x, y) = two_sample(n);
+        for (estimator, max_n, f) in TWO_SAMPLE {
+            if n <= max_n && selected(&format!("{estimator}/{n}")) {
+                let row = measure(estimator, n, || f(&x, &y));
+                report(&row, &baseline);
+                rows.push(row);
+            }
+        }
+    }
+    for n in LARGE_SIZES {
+        let x = one_sample(n);
+        for (estimator, f) in LARGE {
+            let timed_above = estimator == "spread_bounds" && SIZES.contains(&n);
+            if !timed_above && selected(&format!("{estimator}/{n}")) {
+                let row = measure(estimator, n, || f(&x));
+                report(&row, &baseline);
+                rows.push(row);
+            }
+        }
+    }
+    for n in MARGIN_SIZES {
+        let x = one_sample(n);
+        for (estimator, f) in MARGIN {
+            if selected(&format!("{estimator}/{n}")) {
+                let row = measure(estimator, n, || f(&x));
+                report(&row, &baseline);
+                rows.push(row);
+            }
+        }
+    }
+    rows.sort_by(|a, b| (&a.estimator, a.n).cmp(&(&b.estimator, b.n)));
+
+    let path = std::env::var("PRAGMASTAT_BENCH_OUTPUT")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from("target/bench/estimators.json"));
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).ok();
+    }
+    let json = serde_json::to_string_pretty(&rows).expect("JSON serialization failed");
+    fs::write(&path, json).expect("Failed to write bench summary");
+    println!("\nSummary written to {}", path.display());
+}
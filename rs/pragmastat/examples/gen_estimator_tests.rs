@@ -0,0 +1,246 @@
+//! Generate cross-language reference test data for the estimators
+//!
+//! Run with: cargo run --example gen_estimator_tests
+//!
+//! Inputs are drawn deterministically with `Rng::from_seed` over a grid of
+//! distributions and sample sizes: `x` takes the first `n` draws and `y` the
+//! next `m` draws from the same generator. Each file in `tests/estimators/`
+//! stores the inputs together with center, spread, shift, ratio, disparity and
+//! avg_spread, and the bounds of all six at each misrate. Randomized bounds
+//! (spread, disparity, avg_spread) use the string seed recorded in the input. Bounds whose misrate
+//! is below the minimum achievable for the sample sizes are `null`.
+//!
+//! `output` holds full-precision values. `display` repeats them rounded to
+//! `display_digits` significant digits (correctly rounded decimal, ties to
+//! even), for documentation and human-readable reports; ports must compare
+//! against `output`.
+
+use pragmastat::assumptions::{EstimatorError, Subject};
+use pragmastat::distributions::{Additive, Distribution, Exp, Multiplic, Uniform};
+use pragmastat::estimators::raw::{self, RawBounds};
+use pragmastat::Rng;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const SEED: i64 = 1729;
+const BOUNDS_SEED: &str = "estimator-tests";
+const MISRATES: [f64; 3] = [0.1, 0.05, 0.01];
+const SIZES: [(usize, usize); 6] = [(5, 5), (10, 10), (10, 20), (20, 10), (30, 30), (50, 40)];
+const DISPLAY_DIGITS: usize = 6;
+
+#[derive(Serialize)]
+struct EstimatorTestInput {
+    distribution: &'static str,
+    parameters: Vec<f64>,
+    seed: i64,
+    bounds_seed: &'static str,
+    misrates: Vec<f64>,
+    x: Vec<f64>,
+    y: Vec<f64>,
+}
+
+#[derive(Serialize, Clone, Copy)]
+struct BoundsOutput {
+    lower: f64,
+    upper: f64,
+}
+
+#[derive(Serialize)]
+struct MisrateBounds {
+    misrate: f64,
+    center: Option<BoundsOutput>,
+    spread: Option<BoundsOutput>,
+    shift: Option<BoundsOutput>,
+    ratio: Option<BoundsOutput>,
+    disparity: Option<BoundsOutput>,
+    avg_spread: Option<BoundsOutput>,
+}
+
+#[derive(Serialize)]
+struct EstimatorOutput {
+    center: f64,
+    spread: f64,
+    shift: f64,
+    ratio: f64,
+    disparity: f64,
+    avg_spread: f64,
+    bounds: Vec<MisrateBounds>,
+}
+
+#[derive(Serialize)]
+struct EstimatorTestCase {
+    input: EstimatorTestInput,
+    output: EstimatorOutput,
+    display_digits: usize,
+    display: EstimatorOutput,
+}
+
+fn find_tests_dir() -> PathBuf {
+    // Find repository root by looking for CITATION.cff
+    let mut current = std::env::current_dir().expect("Cannot get current dir");
+    loop {
+        if current.join("CITATION.cff").exists() {
+            return current.join("tests");
+        }
+        if !current.pop() {
+            panic!("Could not find repository root (CITATION.cff not found)");
+        }
+    }
+}
+
+fn write_json<T: Serialize>(path: &Path, data: &T) {
+    if pragmastat::reference::write_fixture(path, data).expect("Failed to write file") {
+        println!("  Written: {}", path.display());
+    } else {
+        println!("  Unchanged: {}", path.display());
+    }
+}
+
+/// Rounds to `digits` significant digits via decimal formatting
+fn round_significant(value: f64, digits: usize) -> f64 {
+    format!("{:.*e}", digits - 1, value)
+        .parse()
+        .expect("Failed to parse rounded value")
+}
+
+/// Keeps successful bounds, maps an unachievable misrate to `None`
+fn bounds_or_none(result: Result<RawBounds, EstimatorError>) -> Option<BoundsOutput> {
+    match result {
+        Ok(b) => Some(BoundsOutput {
+            lower: b.lower,
+            upper: b.upper,
+        }),
+        Err(EstimatorError::Assumption(e)) if e.violation().subject == Subject::Misrate => None,
+        Err(e) => panic!("Unexpected estimator error: {e}"),
+    }
+}
+
+fn compute(x: &[f64], y: &[f64]) -> EstimatorOutput {
+    let bounds = MISRATES
+        .iter()
+        .map(|&misrate| MisrateBounds {
+            misrate,
+            center: bounds_or_none(raw::center_bounds(x, misrate, false)),
+            spread: bounds_or_none(raw::spread_bounds_with_seed(x, misrate, BOUNDS_SEED, false)),
+            shift: bounds_or_none(raw::shift_bounds(x, y, misrate, false)),
+            ratio: bounds_or_none(raw::ratio_bounds(x, y, misrate, false)),
+            disparity: bounds_or_none(raw::disparity_bounds_with_seed(
+                x,
+                y,
+                misrate,
+                BOUNDS_SEED,
+                false,
+            )),
+            avg_spread: bounds_or_none(raw::avg_spread_bounds_with_seed(
+                x,
+                y,
+                misrate,
+                BOUNDS_SEED,
+                false,
+            )),
+        })
+        .collect();
+
+    EstimatorOutput {
+        center: raw::center(x, false).unwrap(),
+        spread: raw::spread(x, false).unwrap(),
+        shift: raw::shift(x, y, false).unwrap(),
+        ratio: raw::ratio(x, y, false).unwrap(),
+        disparity: raw::disparity(x, y, false).unwrap(),
+        avg_spread: raw::avg_spread(x, y, false).unwrap(),
+        bounds,
+    }
+}
+
+fn rounded(output: &EstimatorOutput, digits: usize) -> EstimatorOutput {
+    let r = |v: f64| round_significant(v, digits);
+    let rb = |b: Option<BoundsOutput>| {
+        b.map(|b| BoundsOutput {
+            lower: r(b.lower),
+            upper: r(b.upper),
+        })
+    };
+    EstimatorOutput {
+        center: r(output.center),
+        spread: r(output.spread),
+        shift: r(output.shift),
+        ratio: r(output.ratio),
+        disparity: r(output.disparity),
+        avg_spread: r(output.avg_spread),
+        bounds: output
+            .bounds
+            .iter()
+            .map(|b| MisrateBounds {
+                misrate: b.misrate,
+                center: rb(b.center),
+                spread: rb(b.spread),
+                shift: rb(b.shift),
+                ratio: rb(b.ratio),
+                disparity: rb(b.disparity),
+                avg_spread: rb(b.avg_spread),
+            })
+            .collect(),
+    }
+}
+
+fn generate_estimator_tests(tests_dir: &Path) {
+    let estimators_dir = tests_dir.join("estimators");
+    fs::create_dir_all(&estimators_dir).expect("Failed to create estimators test dir");
+
+    let distributions: Vec<(&'static str, Vec<f64>, Box<dyn Distribution>)> = vec![
+        (
+            "additive",
+            vec![10.0, 1.0],
+            Box::new(Additive::new(10.0, 1.0)),
+        ),
+        ("uniform", vec![1.0, 2.0], Box::new(Uniform::new(1.0, 2.0))),
+        ("exp", vec![1.0], Box::new(Exp::new(1.0))),
+        (
+            "multiplic",
+            vec![0.0, 0.5],
+            Box::new(Multiplic::new(0.0, 0.5)),
+        ),
+    ];
+
+    for (name, parameters, distribution) in &distributions {
+        for (n, m) in SIZES {
+            let mut rng = Rng::from_seed(SEED);
+            let x = distribution.samples(&mut rng, n);
+            let y = distribution.samples(&mut rng, m);
+            let output = compute(&x, &y);
+            let display = rounded(&output, DISPLAY_DIGITS);
+
+            let test_case = EstimatorTestCase {
+                input: EstimatorTestInput {
+                    distribution: name,
+                    parameters: parameters.clone(),
+                    seed: SEED,
+                    bounds_seed: BOUNDS_SEED,
+                    misrates: MISRATES.to_vec(),
+                    x,
+                    y,
+                },
+                output,
+                display_digits: DISPLAY_DIGITS,
+                display,
+            };
+
+            let filename = format!("{name}-{n}-{m}.json");
+            write_json(&estimators_dir.join(filename), &test_case);
+        }
+    }
+}
+
+fn main() {
+    let tests_dir = find_tests_dir();
+
+    println!("Generating estimator test data in: {}", tests_dir.display());
+    println!();
+
+    println!("Generating estimator tests...");
+    generate_estimator_tests(&tests_dir);
+    println!();
+
+    println!("Done! Test data generated successfully.");
+}
@@ -4,12 +4,24 @@
 //!
 //! This generates JSON test files in the tests/ directory that all language
 //! implementations must pass to ensure cross-language consistency.
-
-use pragmastat::distributions::{Additive, Distribution, Exp, Multiplic, Power, Uniform};
+//!
+//! With `--features rayon` the configs of each suite run on the rayon thread
+//! pool. Every config seeds its own `Rng` and writes its own file, so the
+//! output is byte-identical to the serial run; only the log order changes. The
+//! parallel run then regenerates a few suites serially into a scratch
+//! directory and asserts that the files match.
+
+use pragmastat::distributions::{
+    Additive, Ar1, Binomial, BivariateAdditive, ChiSquared, Distribution, Exp, Gamma,
+    GaussianCopula, Multiplic, Poisson, Power, StudentT, Uniform, UniformInt,
+};
+use pragmastat::permutation;
 use pragmastat::Rng;
 use serde::Serialize;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+#[cfg(feature = "rayon")]
+use std::sync::atomic::{AtomicBool, Ordering};
 
 #[derive(Serialize)]
 struct UniformTestInput {
@@ -89,6 +101,32 @@ struct UniformBoolTestCase {
     output: Vec<bool>,
 }
 
+#[derive(Serialize)]
+struct NextU64TestInput {
+    seed: i64,
+    count: usize,
+}
+
+/// Values are decimal strings: many JSON parsers cannot represent u64 exactly.
+#[derive(Serialize)]
+struct NextU64TestCase {
+    input: NextU64TestInput,
+    output: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct SplitTestInput {
+    seed: i64,
+    k: usize,
+    count: usize,
+}
+
+#[derive(Serialize)]
+struct SplitTestCase {
+    input: SplitTestInput,
+    output: Vec<Vec<String>>,
+}
+
 #[derive(Serialize)]
 struct StringSeedTestInput {
     seed: String,
@@ -139,6 +177,20 @@ struct ResampleTestCase {
     output: Vec<f64>,
 }
 
+#[derive(Serialize)]
+struct PermutationTestInput {
+    seed: String,
+    x: Vec<f64>,
+    y: Vec<f64>,
+    iterations: usize,
+}
+
+#[derive(Serialize)]
+struct PermutationTestCase {
+    input: PermutationTestInput,
+    output: f64,
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct UniformDistInput {
@@ -213,6 +265,152 @@ struct PowerDistTestCase {
     output: Vec<f64>,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GammaDistInput {
+    seed: i64,
+    shape: f64,
+    rate: f64,
+    count: usize,
+}
+
+#[derive(Serialize)]
+struct GammaDistTestCase {
+    input: GammaDistInput,
+    output: Vec<f64>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChiSquaredDistInput {
+    seed: i64,
+    df: f64,
+    count: usize,
+}
+
+#[derive(Serialize)]
+struct ChiSquaredDistTestCase {
+    input: ChiSquaredDistInput,
+    output: Vec<f64>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PoissonDistInput {
+    seed: i64,
+    lambda: f64,
+    count: usize,
+}
+
+#[derive(Serialize)]
+struct PoissonDistTestCase {
+    input: PoissonDistInput,
+    output: Vec<f64>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BinomialDistInput {
+    seed: i64,
+    n: u64,
+    p: f64,
+    count: usize,
+}
+
+#[derive(Serialize)]
+struct BinomialDistTestCase {
+    input: BinomialDistInput,
+    output: Vec<f64>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StudentTDistInput {
+    seed: i64,
+    df: f64,
+    count: usize,
+}
+
+#[derive(Serialize)]
+struct StudentTDistTestCase {
+    input: StudentTDistInput,
+    output: Vec<f64>,
+}
+
+#[derive(Serialize)]
+struct PairOutput {
+    x: Vec<f64>,
+    y: Vec<f64>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BivariateAdditiveDistInput {
+    seed: i64,
+    mean_x: f64,
+    mean_y: f64,
+    sd_x: f64,
+    sd_y: f64,
+    rho: f64,
+    count: usize,
+}
+
+#[derive(Serialize)]
+struct BivariateAdditiveDistTestCase {
+    input: BivariateAdditiveDistInput,
+    output: PairOutput,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AdditiveAntitheticInput {
+    seed: i64,
+    mean: f64,
+    std_dev: f64,
+    count: usize,
+}
+
+/// Antithetic pairs as two columns: `x[i]` and `y[i]` come from the same draw.
+#[derive(Serialize)]
+struct AdditiveAntitheticTestCase {
+    input: AdditiveAntitheticInput,
+    output: PairOutput,
+}
+
+/// Gaussian copula with an `Exp(rate)` marginal for x and `Uniform(min, max)` for y.
+#[derive(Serialize)]
+struct GaussianCopulaDistInput {
+    seed: i64,
+    rho: f64,
+    rate: f64,
+    min: f64,
+    max: f64,
+    count: usize,
+}
+
+#[derive(Serialize)]
+struct GaussianCopulaDistTestCase {
+    input: GaussianCopulaDistInput,
+    output: PairOutput,
+}
+
+/// AR(1) series with `Additive(mean, std_dev)` innovations.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Ar1DistInput {
+    seed: i64,
+    mean: f64,
+    std_dev: f64,
+    phi: f64,
+    count: usize,
+}
+
+#[derive(Serialize)]
+struct Ar1DistTestCase {
+    input: Ar1DistInput,
+    output: Vec<f64>,
+}
+
 fn find_tests_dir() -> PathBuf {
     // Find repository root by looking for CITATION.cff
     let mut current = std::env::current_dir().expect("Cannot get current dir");
@@ -226,10 +424,72 @@ fn find_tests_dir() -> PathBuf {
     }
 }
 
-fn write_json<T: Serialize>(path: &PathBuf, data: &T) {
-    let json = serde_json::to_string_pretty(data).expect("Failed to serialize");
-    fs::write(path, json).expect("Failed to write file");
-    println!("  Written: {}", path.display());
+fn write_json<T: Serialize>(path: &Path, data: &T) {
+    if pragmastat::reference::write_fixture(path, data).expect("Failed to write file") {
+        println!("  Written: {}", path.display());
+    } else {
+        println!("  Unchanged: {}", path.display());
+    }
+}
+
+/// Makes [`each`] run serially, for the check in [`check_against_serial`].
+#[cfg(feature = "rayon")]
+static FORCE_SERIAL: AtomicBool = AtomicBool::new(false);
+
+/// Runs `f` on every config, on the rayon thread pool with the `rayon`
+/// feature. Each call must write its own file.
+fn each<I, F>(configs: I, f: F)
+where
+    I: IntoIterator,
+    I::Item: Send,
+    F: Fn(I::Item) + Sync + Send,
+{
+    #[cfg(feature = "rayon")]
+    if !FORCE_SERIAL.load(Ordering::Relaxed) {
+        use rayon::prelude::*;
+        let configs: Vec<I::Item> = configs.into_iter().collect();
+        configs.into_par_iter().for_each(f);
+        return;
+    }
+    configs.into_iter().for_each(f);
+}
+
+/// Regenerates a few suites serially into a scratch directory and asserts
+/// that every file matches the parallel output in `tests_dir` byte for byte.
+#[cfg(feature = "rayon")]
+fn check_against_serial(tests_dir: &Path) {
+    fn files(dir: &Path, out: &mut Vec<PathBuf>) {
+        for entry in fs::read_dir(dir).expect("Failed to read scratch dir") {
+            let path = entry.expect("Failed to read scratch entry").path();
+            if path.is_dir() {
+                files(&path, out);
+            } else {
+                out.push(path);
+            }
+        }
+    }
+
+    let scratch = std::env::temp_dir().join(format!("pragmastat-gen-rng-{}", std::process::id()));
+    FORCE_SERIAL.store(true, Ordering::Relaxed);
+    generate_uniform_tests(&scratch);
+    generate_split_tests(&scratch);
+    generate_permutation_tests(&scratch);
+    generate_gamma_distribution_tests(&scratch);
+    FORCE_SERIAL.store(false, Ordering::Relaxed);
+
+    let mut serial = Vec::new();
+    files(&scratch, &mut serial);
+    for path in &serial {
+        let relative = path.strip_prefix(&scratch).expect("scratch file");
+        let parallel = fs::read(tests_dir.join(relative)).expect("Failed to read parallel file");
+        assert!(
+            fs::read(path).expect("Failed to read serial file") == parallel,
+            "parallel and serial output differ: {}",
+            relative.display()
+        );
+    }
+    fs::remove_dir_all(&scratch).expect("Failed to remove scratch dir");
+    println!("  {} files match the serial run", serial.len());
 }
 
 fn string_seed_filename(seed: &str) -> String {
@@ -252,7 +512,7 @@ fn string_seed_filename(seed: &str) -> String {
     format!("utf8-{}", hex)
 }
 
-fn generate_uniform_tests(tests_dir: &PathBuf) {
+fn generate_uniform_tests(tests_dir: &Path) {
     let rng_dir = tests_dir.join("rng");
     fs::create_dir_all(&rng_dir).expect("Failed to create rng test dir");
 
@@ -270,9 +530,10 @@ fn generate_uniform_tests(tests_dir: &PathBuf) {
     ];
     let count = 20;
 
-    for seed in &seeds {
+    each(&seeds, |seed| {
         let mut rng = Rng::from_seed(*seed);
-        let values: Vec<f64> = (0..count).map(|_| rng.uniform_f64()).collect();
+        let mut values = vec![0.0; count];
+        rng.fill_uniform(&mut values);
 
         let test_case = UniformTestCase {
             input: UniformTestInput { seed: *seed, count },
@@ -281,10 +542,31 @@ fn generate_uniform_tests(tests_dir: &PathBuf) {
 
         let filename = format!("uniform-seed-{}.json", seed);
         write_json(&rng_dir.join(filename), &test_case);
-    }
+    });
+}
+
+fn generate_normal_tests(tests_dir: &Path) {
+    let rng_dir = tests_dir.join("rng");
+    fs::create_dir_all(&rng_dir).expect("Failed to create rng test dir");
+
+    let seeds: Vec<i64> = vec![0, 1, 1729, 123, 999, -1, -42];
+    let count = 20;
+
+    each(&seeds, |seed| {
+        let mut rng = Rng::from_seed(*seed);
+        let values: Vec<f64> = (0..count).map(|_| rng.normal()).collect();
+
+        let test_case = UniformTestCase {
+            input: UniformTestInput { seed: *seed, count },
+            output: values,
+        };
+
+        let filename = format!("normal-seed-{}.json", seed);
+        write_json(&rng_dir.join(filename), &test_case);
+    });
 }
 
-fn generate_uniform_range_tests(tests_dir: &PathBuf) {
+fn generate_uniform_range_tests(tests_dir: &Path) {
     let rng_dir = tests_dir.join("rng");
     fs::create_dir_all(&rng_dir).expect("Failed to create rng test dir");
 
@@ -299,11 +581,10 @@ fn generate_uniform_range_tests(tests_dir: &PathBuf) {
         (999, 0.0, 100.0, 20),
     ];
 
-    for (seed, min, max, count) in test_configs {
+    each(test_configs, |(seed, min, max, count)| {
         let mut rng = Rng::from_seed(seed);
-        let values: Vec<f64> = (0..count)
-            .map(|_| rng.uniform_f64_range(min, max))
-            .collect();
+        let mut values = vec![0.0; count];
+        rng.fill_uniform_range(&mut values, min, max);
 
         let test_case = UniformRangeTestCase {
             input: UniformRangeTestInput {
@@ -317,17 +598,17 @@ fn generate_uniform_range_tests(tests_dir: &PathBuf) {
 
         let filename = format!("uniform-range-seed-{}-{}-{}.json", seed, min, max);
         write_json(&rng_dir.join(filename), &test_case);
-    }
+    });
 }
 
-fn generate_uniform_f32_tests(tests_dir: &PathBuf) {
+fn generate_uniform_f32_tests(tests_dir: &Path) {
     let rng_dir = tests_dir.join("rng");
     fs::create_dir_all(&rng_dir).expect("Failed to create rng test dir");
 
     let seeds: Vec<i64> = vec![0, 1, 1729, 123, 999, -1, -42];
     let count = 20;
 
-    for seed in &seeds {
+    each(&seeds, |seed| {
         let mut rng = Rng::from_seed(*seed);
         let values: Vec<f32> = (0..count).map(|_| rng.uniform_f32()).collect();
 
@@ -338,10 +619,10 @@ fn generate_uniform_f32_tests(tests_dir: &PathBuf) {
 
         let filename = format!("uniform-f32-seed-{}.json", seed);
         write_json(&rng_dir.join(filename), &test_case);
-    }
+    });
 }
 
-fn generate_uniform_i32_tests(tests_dir: &PathBuf) {
+fn generate_uniform_i32_tests(tests_dir: &Path) {
     let rng_dir = tests_dir.join("rng");
     fs::create_dir_all(&rng_dir).expect("Failed to create rng test dir");
 
@@ -354,7 +635,7 @@ fn generate_uniform_i32_tests(tests_dir: &PathBuf) {
         (999, 0, 100, 20),
     ];
 
-    for (seed, min, max, count) in test_configs {
+    each(test_configs, |(seed, min, max, count)| {
         let mut rng = Rng::from_seed(seed);
         let values: Vec<i32> = (0..count).map(|_| rng.uniform_i32(min, max)).collect();
 
@@ -370,17 +651,17 @@ fn generate_uniform_i32_tests(tests_dir: &PathBuf) {
 
         let filename = format!("uniform-i32-seed-{}-{}-{}.json", seed, min, max);
         write_json(&rng_dir.join(filename), &test_case);
-    }
+    });
 }
 
-fn generate_uniform_bool_tests(tests_dir: &PathBuf) {
+fn generate_uniform_bool_tests(tests_dir: &Path) {
     let rng_dir = tests_dir.join("rng");
     fs::create_dir_all(&rng_dir).expect("Failed to create rng test dir");
 
     let seeds: Vec<i64> = vec![0, 1, 1729, 123, 999, -1, -42];
     let count = 100;
 
-    for seed in &seeds {
+    each(&seeds, |seed| {
         let mut rng = Rng::from_seed(*seed);
         let values: Vec<bool> = (0..count).map(|_| rng.uniform_bool()).collect();
 
@@ -391,26 +672,108 @@ fn generate_uniform_bool_tests(tests_dir: &PathBuf) {
 
         let filename = format!("uniform-bool-seed-{}.json", seed);
         write_json(&rng_dir.join(filename), &test_case);
+    });
+}
+
+fn generate_next_u64_tests(tests_dir: &Path) {
+    let rng_dir = tests_dir.join("rng");
+    fs::create_dir_all(&rng_dir).expect("Failed to create rng test dir");
+
+    let seeds: Vec<i64> = vec![0, 1, 1729, 123, 999, -1, -42];
+    let count = 20;
+
+    each(&seeds, |seed| {
+        let mut rng = Rng::from_seed(*seed);
+        let values: Vec<String> = (0..count).map(|_| rng.next_u64().to_string()).collect();
+
+        let test_case = NextU64TestCase {
+            input: NextU64TestInput { seed: *seed, count },
+            output: values,
+        };
+
+        let filename = format!("next-u64-seed-{}.json", seed);
+        write_json(&rng_dir.join(filename), &test_case);
+    });
+}
+
+fn generate_jump_tests(tests_dir: &Path) {
+    let rng_dir = tests_dir.join("rng");
+    fs::create_dir_all(&rng_dir).expect("Failed to create rng test dir");
+
+    let seeds: Vec<i64> = vec![0, 1, 1729, -42];
+    let count = 10;
+    for (prefix, long) in [("jump", false), ("long-jump", true)] {
+        each(&seeds, |seed| {
+            let mut rng = Rng::from_seed(*seed);
+            if long {
+                rng.long_jump();
+            } else {
+                rng.jump();
+            }
+            let values: Vec<String> = (0..count).map(|_| rng.next_u64().to_string()).collect();
+
+            let test_case = NextU64TestCase {
+                input: NextU64TestInput { seed: *seed, count },
+                output: values,
+            };
+
+            let filename = format!("{}-seed-{}.json", prefix, seed);
+            write_json(&rng_dir.join(filename), &test_case);
+        });
     }
 }
 
-fn generate_uniform_int_tests(tests_dir: &PathBuf) {
+fn generate_split_tests(tests_dir: &Path) {
     let rng_dir = tests_dir.join("rng");
     fs::create_dir_all(&rng_dir).expect("Failed to create rng test dir");
 
-    let test_configs: Vec<(i64, i64, i64, usize)> = vec![
-        // (seed, min, max, count)
-        (1729, 0, 100, 20),
-        (1729, -50, 50, 20),
-        (1729, 0, 10, 20),
-        (1729, 1000, 2000, 20),
-        (123, 0, 100, 20),
-        (0, 0, 100, 20),
-        (999, -100, 100, 20),
-        (-42, 0, 100, 20),
-    ];
+    let seeds: Vec<i64> = vec![0, 1, 1729, -42];
+    let k = 3;
+    let count = 5;
+
+    each(&seeds, |seed| {
+        let mut rng = Rng::from_seed(*seed);
+        let values: Vec<Vec<String>> = rng
+            .split(k)
+            .iter_mut()
+            .map(|substream| {
+                (0..count)
+                    .map(|_| substream.next_u64().to_string())
+                    .collect()
+            })
+            .collect();
+
+        let test_case = SplitTestCase {
+            input: SplitTestInput {
+                seed: *seed,
+                k,
+                count,
+            },
+            output: values,
+        };
 
-    for (seed, min, max, count) in test_configs {
+        let filename = format!("split-seed-{}.json", seed);
+        write_json(&rng_dir.join(filename), &test_case);
+    });
+}
+
+/// (seed, min, max, count) of `rng/uniform-int-*` and `distributions/uniform-int/*`.
+const UNIFORM_INT_CONFIGS: [(i64, i64, i64, usize); 8] = [
+    (1729, 0, 100, 20),
+    (1729, -50, 50, 20),
+    (1729, 0, 10, 20),
+    (1729, 1000, 2000, 20),
+    (123, 0, 100, 20),
+    (0, 0, 100, 20),
+    (999, -100, 100, 20),
+    (-42, 0, 100, 20),
+];
+
+fn generate_uniform_int_tests(tests_dir: &Path) {
+    let rng_dir = tests_dir.join("rng");
+    fs::create_dir_all(&rng_dir).expect("Failed to create rng test dir");
+
+    each(UNIFORM_INT_CONFIGS, |(seed, min, max, count)| {
         let mut rng = Rng::from_seed(seed);
         let values: Vec<i64> = (0..count).map(|_| rng.uniform_i64(min, max)).collect();
 
@@ -426,10 +789,10 @@ fn generate_uniform_int_tests(tests_dir: &PathBuf) {
 
         let filename = format!("uniform-int-seed-{}-{}-{}.json", seed, min, max);
         write_json(&rng_dir.join(filename), &test_case);
-    }
+    });
 }
 
-fn generate_string_seed_tests(tests_dir: &PathBuf) {
+fn generate_string_seed_tests(tests_dir: &Path) {
     let rng_dir = tests_dir.join("rng");
     fs::create_dir_all(&rng_dir).expect("Failed to create rng test dir");
 
@@ -448,9 +811,10 @@ fn generate_string_seed_tests(tests_dir: &PathBuf) {
     ];
     let count = 20;
 
-    for seed in &seeds {
+    each(&seeds, |seed| {
         let mut rng = Rng::from_string(seed);
-        let values: Vec<f64> = (0..count).map(|_| rng.uniform_f64()).collect();
+        let mut values = vec![0.0; count];
+        rng.fill_uniform(&mut values);
 
         let test_case = StringSeedTestCase {
             input: StringSeedTestInput {
@@ -464,10 +828,10 @@ fn generate_string_seed_tests(tests_dir: &PathBuf) {
         let safe_name = string_seed_filename(seed);
         let filename = format!("uniform-string-{}.json", safe_name);
         write_json(&rng_dir.join(filename), &test_case);
-    }
+    });
 }
 
-fn generate_shuffle_tests(tests_dir: &PathBuf) {
+fn generate_shuffle_tests(tests_dir: &Path) {
     let shuffle_dir = tests_dir.join("shuffle");
     fs::create_dir_all(&shuffle_dir).expect("Failed to create shuffle test dir");
 
@@ -488,7 +852,7 @@ fn generate_shuffle_tests(tests_dir: &PathBuf) {
         (1729, vec![], "empty"),
     ];
 
-    for (seed, x, suffix) in test_configs {
+    each(test_configs, |(seed, x, suffix)| {
         let mut rng = Rng::from_seed(seed);
         let shuffled = rng.shuffle(&x);
 
@@ -499,10 +863,10 @@ fn generate_shuffle_tests(tests_dir: &PathBuf) {
 
         let filename = format!("seed-{}-n{}-{}.json", seed, x.len(), suffix);
         write_json(&shuffle_dir.join(filename), &test_case);
-    }
+    });
 }
 
-fn generate_sample_tests(tests_dir: &PathBuf) {
+fn generate_sample_tests(tests_dir: &Path) {
     let sample_dir = tests_dir.join("sample");
     fs::create_dir_all(&sample_dir).expect("Failed to create sample test dir");
 
@@ -528,7 +892,7 @@ fn generate_sample_tests(tests_dir: &PathBuf) {
         (1729, vec![], 0), // empty input, k = 0
     ];
 
-    for (seed, x, k) in test_configs {
+    each(test_configs, |(seed, x, k)| {
         let mut rng = Rng::from_seed(seed);
         let sampled = rng.sample(&x, k);
 
@@ -543,10 +907,10 @@ fn generate_sample_tests(tests_dir: &PathBuf) {
 
         let filename = format!("seed-{}-n{}-k{}.json", seed, x.len(), k);
         write_json(&sample_dir.join(filename), &test_case);
-    }
+    });
 }
 
-fn generate_resample_tests(tests_dir: &PathBuf) {
+fn generate_resample_tests(tests_dir: &Path) {
     let resample_dir = tests_dir.join("resample");
     fs::create_dir_all(&resample_dir).expect("Failed to create resample test dir");
 
@@ -575,7 +939,7 @@ fn generate_resample_tests(tests_dir: &PathBuf) {
         (2718, (0..100).map(|i| i as f64).collect(), 25),
     ];
 
-    for (seed, x, k) in test_configs {
+    each(test_configs, |(seed, x, k)| {
         let mut rng = Rng::from_seed(seed);
         let resampled = rng.resample(&x, k);
 
@@ -590,20 +954,83 @@ fn generate_resample_tests(tests_dir: &PathBuf) {
 
         let filename = format!("seed-{}-n{}-k{}.json", seed, x.len(), k);
         write_json(&resample_dir.join(filename), &test_case);
+    });
+}
+
+fn generate_permutation_tests(tests_dir: &Path) {
+    let permutation_dir = tests_dir.join("permutation");
+    fs::create_dir_all(&permutation_dir).expect("Failed to create permutation test dir");
+
+    // (x, y, suffix for unique naming)
+    let shift_configs: Vec<(Vec<f64>, Vec<f64>, &str)> = vec![
+        (vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0], "separated"),
+        (
+            vec![1.0, 3.0, 5.0, 7.0, 9.0],
+            vec![2.0, 4.0, 6.0, 8.0, 10.0],
+            "interleaved",
+        ),
+        (
+            vec![10.0, 12.0, 11.0, 14.0, 13.0, 15.0],
+            vec![11.0, 9.0, 10.0, 8.0],
+            "unbalanced",
+        ),
+        (vec![1.0, 1.0, 2.0, 2.0], vec![1.0, 2.0, 2.0, 3.0], "ties"),
+    ];
+    let paired_configs: Vec<(Vec<f64>, Vec<f64>, &str)> = vec![
+        (
+            vec![5.0, 6.0, 7.0, 8.0, 9.0],
+            vec![1.0, 2.0, 3.0, 4.0, 5.0],
+            "shifted",
+        ),
+        (
+            vec![1.0, 4.0, 2.0, 6.0, 3.0, 5.0],
+            vec![2.0, 3.0, 3.0, 5.0, 4.0, 4.0],
+            "mixed",
+        ),
+        (
+            vec![10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0],
+            vec![9.0, 21.0, 28.0, 43.0, 49.0, 57.0, 71.0, 76.0],
+            "noisy",
+        ),
+    ];
+    let iterations = 200;
+
+    for (kind, configs) in [("shift", shift_configs), ("paired", paired_configs)] {
+        each(configs, |(x, y, suffix)| {
+            let seed = format!("permutation-{}-{}", kind, suffix);
+            let pvalue = match kind {
+                "shift" => permutation::shift_pvalue(&x, &y, iterations, &seed),
+                _ => permutation::paired_pvalue(&x, &y, iterations, &seed),
+            }
+            .expect("permutation test failed");
+
+            let test_case = PermutationTestCase {
+                input: PermutationTestInput {
+                    seed,
+                    x,
+                    y,
+                    iterations,
+                },
+                output: pvalue,
+            };
+
+            let filename = format!("{}-{}.json", kind, suffix);
+            write_json(&permutation_dir.join(filename), &test_case);
+        });
     }
 }
 
-fn generate_uniform_distribution_tests(tests_dir: &PathBuf) {
+fn generate_uniform_distribution_tests(tests_dir: &Path) {
     let dist_dir = tests_dir.join("distributions").join("uniform");
     fs::create_dir_all(&dist_dir).expect("Failed to create uniform distribution test dir");
 
     let test_configs: Vec<(i64, f64, f64, usize)> =
         vec![(1729, -1.0, 1.0, 10), (123, 0.0, 10.0, 10)];
 
-    for (seed, min, max, count) in test_configs {
+    each(test_configs, |(seed, min, max, count)| {
         let mut rng = Rng::from_seed(seed);
         let dist = Uniform::new(min, max);
-        let values: Vec<f64> = (0..count).map(|_| dist.sample(&mut rng)).collect();
+        let values = dist.sample_n(&mut rng, count);
 
         let test_case = UniformDistTestCase {
             input: UniformDistInput {
@@ -617,20 +1044,45 @@ fn generate_uniform_distribution_tests(tests_dir: &PathBuf) {
 
         let filename = format!("seed-{}-min-{}-max-{}.json", seed, min, max);
         write_json(&dist_dir.join(filename), &test_case);
-    }
+    });
+}
+
+fn generate_uniform_int_distribution_tests(tests_dir: &Path) {
+    let dist_dir = tests_dir.join("distributions").join("uniform-int");
+    fs::create_dir_all(&dist_dir).expect("Failed to create uniform-int distribution test dir");
+
+    // Same inputs as rng/uniform-int-*, so the outputs must match those files
+    each(UNIFORM_INT_CONFIGS, |(seed, min, max, count)| {
+        let mut rng = Rng::from_seed(seed);
+        let dist = UniformInt::new(min, max);
+        let values: Vec<i64> = (0..count).map(|_| dist.sample(&mut rng) as i64).collect();
+
+        let test_case = UniformIntTestCase {
+            input: UniformIntTestInput {
+                seed,
+                min,
+                max,
+                count,
+            },
+            output: values,
+        };
+
+        let filename = format!("seed-{}-min-{}-max-{}.json", seed, min, max);
+        write_json(&dist_dir.join(filename), &test_case);
+    });
 }
 
-fn generate_additive_distribution_tests(tests_dir: &PathBuf) {
+fn generate_additive_distribution_tests(tests_dir: &Path) {
     let dist_dir = tests_dir.join("distributions").join("additive");
     fs::create_dir_all(&dist_dir).expect("Failed to create additive distribution test dir");
 
     let test_configs: Vec<(i64, f64, f64, usize)> =
         vec![(1729, 0.0, 1.0, 10), (123, 10.0, 2.0, 10)];
 
-    for (seed, mean, std_dev, count) in test_configs {
+    each(test_configs, |(seed, mean, std_dev, count)| {
         let mut rng = Rng::from_seed(seed);
         let dist = Additive::new(mean, std_dev);
-        let values: Vec<f64> = (0..count).map(|_| dist.sample(&mut rng)).collect();
+        let values = dist.sample_n(&mut rng, count);
 
         let test_case = AdditiveDistTestCase {
             input: AdditiveDistInput {
@@ -644,20 +1096,20 @@ fn generate_additive_distribution_tests(tests_dir: &PathBuf) {
 
         let filename = format!("seed-{}-mean-{}-stddev-{}.json", seed, mean, std_dev);
         write_json(&dist_dir.join(filename), &test_case);
-    }
+    });
 }
 
-fn generate_multiplic_distribution_tests(tests_dir: &PathBuf) {
+fn generate_multiplic_distribution_tests(tests_dir: &Path) {
     let dist_dir = tests_dir.join("distributions").join("multiplic");
     fs::create_dir_all(&dist_dir).expect("Failed to create multiplic distribution test dir");
 
     let test_configs: Vec<(i64, f64, f64, usize)> =
         vec![(1729, 0.0, 1.0, 10), (123, -1.0, 2.0, 10)];
 
-    for (seed, log_mean, log_std_dev, count) in test_configs {
+    each(test_configs, |(seed, log_mean, log_std_dev, count)| {
         let mut rng = Rng::from_seed(seed);
         let dist = Multiplic::new(log_mean, log_std_dev);
-        let values: Vec<f64> = (0..count).map(|_| dist.sample(&mut rng)).collect();
+        let values = dist.sample_n(&mut rng, count);
 
         let test_case = MultiplicDistTestCase {
             input: MultiplicDistInput {
@@ -674,19 +1126,19 @@ fn generate_multiplic_distribution_tests(tests_dir: &PathBuf) {
             seed, log_mean, log_std_dev
         );
         write_json(&dist_dir.join(filename), &test_case);
-    }
+    });
 }
 
-fn generate_exp_distribution_tests(tests_dir: &PathBuf) {
+fn generate_exp_distribution_tests(tests_dir: &Path) {
     let dist_dir = tests_dir.join("distributions").join("exp");
     fs::create_dir_all(&dist_dir).expect("Failed to create exp distribution test dir");
 
     let test_configs: Vec<(i64, f64, usize)> = vec![(1729, 1.0, 10), (123, 2.0, 10)];
 
-    for (seed, rate, count) in test_configs {
+    each(test_configs, |(seed, rate, count)| {
         let mut rng = Rng::from_seed(seed);
         let dist = Exp::new(rate);
-        let values: Vec<f64> = (0..count).map(|_| dist.sample(&mut rng)).collect();
+        let values = dist.sample_n(&mut rng, count);
 
         let test_case = ExpDistTestCase {
             input: ExpDistInput { seed, rate, count },
@@ -695,19 +1147,19 @@ fn generate_exp_distribution_tests(tests_dir: &PathBuf) {
 
         let filename = format!("seed-{}-rate-{}.json", seed, rate);
         write_json(&dist_dir.join(filename), &test_case);
-    }
+    });
 }
 
-fn generate_power_distribution_tests(tests_dir: &PathBuf) {
+fn generate_power_distribution_tests(tests_dir: &Path) {
     let dist_dir = tests_dir.join("distributions").join("power");
     fs::create_dir_all(&dist_dir).expect("Failed to create power distribution test dir");
 
     let test_configs: Vec<(i64, f64, f64, usize)> = vec![(1729, 1.0, 2.0, 10), (123, 2.0, 3.0, 10)];
 
-    for (seed, min, shape, count) in test_configs {
+    each(test_configs, |(seed, min, shape, count)| {
         let mut rng = Rng::from_seed(seed);
         let dist = Power::new(min, shape);
-        let values: Vec<f64> = (0..count).map(|_| dist.sample(&mut rng)).collect();
+        let values = dist.sample_n(&mut rng, count);
 
         let test_case = PowerDistTestCase {
             input: PowerDistInput {
@@ -721,7 +1173,273 @@ fn generate_power_distribution_tests(tests_dir: &PathBuf) {
 
         let filename = format!("seed-{}-min-{}-shape-{}.json", seed, min, shape);
         write_json(&dist_dir.join(filename), &test_case);
-    }
+    });
+}
+
+fn generate_gamma_distribution_tests(tests_dir: &Path) {
+    let dist_dir = tests_dir.join("distributions").join("gamma");
+    fs::create_dir_all(&dist_dir).expect("Failed to create gamma distribution test dir");
+
+    let test_configs: Vec<(i64, f64, f64, usize)> = vec![
+        (1729, 1.0, 1.0, 10),
+        (123, 2.5, 0.5, 10),
+        (42, 0.3, 2.0, 10),
+    ];
+
+    each(test_configs, |(seed, shape, rate, count)| {
+        let mut rng = Rng::from_seed(seed);
+        let dist = Gamma::new(shape, rate);
+        let values = dist.sample_n(&mut rng, count);
+
+        let test_case = GammaDistTestCase {
+            input: GammaDistInput {
+                seed,
+                shape,
+                rate,
+                count,
+            },
+            output: values,
+        };
+
+        let filename = format!("seed-{}-shape-{}-rate-{}.json", seed, shape, rate);
+        write_json(&dist_dir.join(filename), &test_case);
+    });
+}
+
+fn generate_chisquared_distribution_tests(tests_dir: &Path) {
+    let dist_dir = tests_dir.join("distributions").join("chisquared");
+    fs::create_dir_all(&dist_dir).expect("Failed to create chisquared distribution test dir");
+
+    let test_configs: Vec<(i64, f64, usize)> = vec![(1729, 1.0, 10), (123, 4.0, 10)];
+
+    each(test_configs, |(seed, df, count)| {
+        let mut rng = Rng::from_seed(seed);
+        let dist = ChiSquared::new(df);
+        let values = dist.sample_n(&mut rng, count);
+
+        let test_case = ChiSquaredDistTestCase {
+            input: ChiSquaredDistInput { seed, df, count },
+            output: values,
+        };
+
+        let filename = format!("seed-{}-df-{}.json", seed, df);
+        write_json(&dist_dir.join(filename), &test_case);
+    });
+}
+
+fn generate_poisson_distribution_tests(tests_dir: &Path) {
+    let dist_dir = tests_dir.join("distributions").join("poisson");
+    fs::create_dir_all(&dist_dir).expect("Failed to create poisson distribution test dir");
+
+    let test_configs: Vec<(i64, f64, usize)> =
+        vec![(1729, 1.0, 10), (123, 4.5, 10), (42, 75.0, 10)];
+
+    each(test_configs, |(seed, lambda, count)| {
+        let mut rng = Rng::from_seed(seed);
+        let dist = Poisson::new(lambda);
+        let values = dist.sample_n(&mut rng, count);
+
+        let test_case = PoissonDistTestCase {
+            input: PoissonDistInput {
+                seed,
+                lambda,
+                count,
+            },
+            output: values,
+        };
+
+        let filename = format!("seed-{}-lambda-{}.json", seed, lambda);
+        write_json(&dist_dir.join(filename), &test_case);
+    });
+}
+
+fn generate_binomial_distribution_tests(tests_dir: &Path) {
+    let dist_dir = tests_dir.join("distributions").join("binomial");
+    fs::create_dir_all(&dist_dir).expect("Failed to create binomial distribution test dir");
+
+    let test_configs: Vec<(i64, u64, f64, usize)> = vec![(1729, 10, 0.5, 10), (123, 40, 0.1, 10)];
+
+    each(test_configs, |(seed, n, p, count)| {
+        let mut rng = Rng::from_seed(seed);
+        let dist = Binomial::new(n, p);
+        let values = dist.sample_n(&mut rng, count);
+
+        let test_case = BinomialDistTestCase {
+            input: BinomialDistInput { seed, n, p, count },
+            output: values,
+        };
+
+        let filename = format!("seed-{}-n-{}-p-{}.json", seed, n, p);
+        write_json(&dist_dir.join(filename), &test_case);
+    });
+}
+
+fn generate_bivariate_additive_distribution_tests(tests_dir: &Path) {
+    let dist_dir = tests_dir.join("distributions").join("bivariate-additive");
+    fs::create_dir_all(&dist_dir)
+        .expect("Failed to create bivariate-additive distribution test dir");
+
+    // (seed, mean_x, mean_y, sd_x, sd_y, rho)
+    let test_configs: Vec<(i64, f64, f64, f64, f64, f64)> = vec![
+        (1729, 0.0, 0.0, 1.0, 1.0, 0.5),
+        (123, 10.0, 20.0, 2.0, 3.0, -0.8),
+        (42, 0.0, 5.0, 1.0, 0.5, 0.0),
+        (2024, 1.0, 1.0, 1.0, 1.0, 1.0),
+    ];
+    let count = 10;
+
+    each(test_configs, |(seed, mean_x, mean_y, sd_x, sd_y, rho)| {
+        let mut rng = Rng::from_seed(seed);
+        let dist = BivariateAdditive::new(mean_x, mean_y, sd_x, sd_y, rho).unwrap();
+        let (x, y) = dist.sample_pairs(&mut rng, count);
+
+        let test_case = BivariateAdditiveDistTestCase {
+            input: BivariateAdditiveDistInput {
+                seed,
+                mean_x,
+                mean_y,
+                sd_x,
+                sd_y,
+                rho,
+                count,
+            },
+            output: PairOutput { x, y },
+        };
+
+        let filename = format!("seed-{}-rho-{}.json", seed, rho);
+        write_json(&dist_dir.join(filename), &test_case);
+    });
+}
+
+fn generate_additive_antithetic_distribution_tests(tests_dir: &Path) {
+    let dist_dir = tests_dir.join("distributions").join("additive-antithetic");
+    fs::create_dir_all(&dist_dir)
+        .expect("Failed to create additive-antithetic distribution test dir");
+
+    // (seed, mean, std_dev)
+    let test_configs: Vec<(i64, f64, f64)> = vec![(1729, 0.0, 1.0), (123, 10.0, 2.0)];
+    let count = 10;
+
+    each(test_configs, |(seed, mean, std_dev)| {
+        let mut rng = Rng::from_seed(seed);
+        let dist = Additive::new(mean, std_dev);
+        let (x, y) = (0..count).map(|_| dist.sample_antithetic(&mut rng)).unzip();
+
+        let test_case = AdditiveAntitheticTestCase {
+            input: AdditiveAntitheticInput {
+                seed,
+                mean,
+                std_dev,
+                count,
+            },
+            output: PairOutput { x, y },
+        };
+
+        let filename = format!("seed-{}-mean-{}-stddev-{}.json", seed, mean, std_dev);
+        write_json(&dist_dir.join(filename), &test_case);
+    });
+}
+
+fn generate_gaussian_copula_distribution_tests(tests_dir: &Path) {
+    let dist_dir = tests_dir.join("distributions").join("gaussian-copula");
+    fs::create_dir_all(&dist_dir).expect("Failed to create gaussian-copula distribution test dir");
+
+    // (seed, rho, rate, min, max)
+    let test_configs: Vec<(i64, f64, f64, f64, f64)> = vec![
+        (1729, 0.5, 1.0, 0.0, 1.0),
+        (123, -0.9, 2.0, 10.0, 20.0),
+        (42, 0.0, 0.5, -1.0, 1.0),
+    ];
+    let count = 10;
+
+    each(test_configs, |(seed, rho, rate, min, max)| {
+        let mut rng = Rng::from_seed(seed);
+        let dist = GaussianCopula::new(
+            Box::new(Exp::new(rate)),
+            Box::new(Uniform::new(min, max)),
+            rho,
+        )
+        .unwrap();
+        let (x, y) = dist.sample_pairs(&mut rng, count);
+
+        let test_case = GaussianCopulaDistTestCase {
+            input: GaussianCopulaDistInput {
+                seed,
+                rho,
+                rate,
+                min,
+                max,
+                count,
+            },
+            output: PairOutput { x, y },
+        };
+
+        let filename = format!("seed-{}-rho-{}.json", seed, rho);
+        write_json(&dist_dir.join(filename), &test_case);
+    });
+}
+
+fn generate_studentt_distribution_tests(tests_dir: &Path) {
+    let dist_dir = tests_dir.join("distributions").join("studentt");
+    fs::create_dir_all(&dist_dir).expect("Failed to create studentt distribution test dir");
+
+    let test_configs: Vec<(i64, f64, usize)> = vec![
+        (1729, 1.0, 10),
+        (123, 3.0, 10),
+        (42, 0.5, 10),
+        (2024, 30.0, 10),
+    ];
+
+    each(test_configs, |(seed, df, count)| {
+        let mut rng = Rng::from_seed(seed);
+        let dist = StudentT::new(df);
+        let values = dist.sample_n(&mut rng, count);
+
+        let test_case = StudentTDistTestCase {
+            input: StudentTDistInput { seed, df, count },
+            output: values,
+        };
+
+        let filename = format!("seed-{}-df-{}.json", seed, df);
+        write_json(&dist_dir.join(filename), &test_case);
+    });
+}
+
+fn generate_ar1_distribution_tests(tests_dir: &Path) {
+    let dist_dir = tests_dir.join("distributions").join("ar1");
+    fs::create_dir_all(&dist_dir).expect("Failed to create ar1 distribution test dir");
+
+    // (seed, mean, std_dev, phi)
+    let test_configs: Vec<(i64, f64, f64, f64)> = vec![
+        (1729, 0.0, 1.0, 0.5),
+        (123, 10.0, 2.0, 0.9),
+        (42, 0.0, 1.0, -0.7),
+        (0, 5.0, 1.0, 0.0),
+    ];
+    let count = 10;
+
+    each(test_configs, |(seed, mean, std_dev, phi)| {
+        let mut rng = Rng::from_seed(seed);
+        let dist = Ar1::new(Box::new(Additive::new(mean, std_dev)), phi).expect("valid Ar1");
+        let values = dist.sample_series(&mut rng, count);
+
+        let test_case = Ar1DistTestCase {
+            input: Ar1DistInput {
+                seed,
+                mean,
+                std_dev,
+                phi,
+                count,
+            },
+            output: values,
+        };
+
+        let filename = format!(
+            "seed-{}-mean-{}-stddev-{}-phi-{}.json",
+            seed, mean, std_dev, phi
+        );
+        write_json(&dist_dir.join(filename), &test_case);
+    });
 }
 
 fn main() {
@@ -734,6 +1452,10 @@ fn main() {
     generate_uniform_tests(&tests_dir);
     println!();
 
+    println!("Generating normal tests...");
+    generate_normal_tests(&tests_dir);
+    println!();
+
     println!("Generating uniform_range tests...");
     generate_uniform_range_tests(&tests_dir);
     println!();
@@ -754,6 +1476,18 @@ fn main() {
     generate_uniform_bool_tests(&tests_dir);
     println!();
 
+    println!("Generating next_u64 tests...");
+    generate_next_u64_tests(&tests_dir);
+    println!();
+
+    println!("Generating jump tests...");
+    generate_jump_tests(&tests_dir);
+    println!();
+
+    println!("Generating split tests...");
+    generate_split_tests(&tests_dir);
+    println!();
+
     println!("Generating string seed tests...");
     generate_string_seed_tests(&tests_dir);
     println!();
@@ -770,13 +1504,34 @@ fn main() {
     generate_resample_tests(&tests_dir);
     println!();
 
+    println!("Generating permutation tests...");
+    generate_permutation_tests(&tests_dir);
+    println!();
+
     println!("Generating distribution tests...");
     generate_uniform_distribution_tests(&tests_dir);
+    generate_uniform_int_distribution_tests(&tests_dir);
     generate_additive_distribution_tests(&tests_dir);
     generate_multiplic_distribution_tests(&tests_dir);
     generate_exp_distribution_tests(&tests_dir);
     generate_power_distribution_tests(&tests_dir);
+    generate_gamma_distribution_tests(&tests_dir);
+    generate_chisquared_distribution_tests(&tests_dir);
+    generate_studentt_distribution_tests(&tests_dir);
+    generate_poisson_distribution_tests(&tests_dir);
+    generate_binomial_distribution_tests(&tests_dir);
+    generate_bivariate_additive_distribution_tests(&tests_dir);
+    generate_additive_antithetic_distribution_tests(&tests_dir);
+    generate_gaussian_copula_distribution_tests(&tests_dir);
+    generate_ar1_distribution_tests(&tests_dir);
     println!();
 
+    #[cfg(feature = "rayon")]
+    {
+        println!("Checking parallel output against a serial run...");
+        check_against_serial(&tests_dir);
+        println!();
+    }
+
     println!("Done! Test data generated successfully.");
 }
@@ -12,8 +12,43 @@
 //!
 //! When multiple assumptions are violated, the violation with highest priority
 //! is reported. For two-sample functions, subject `X` is checked before `Y`.
+//!
+//! Estimators stop at the first violation. For pre-flight checks,
+//! [`check_all`] runs every sample assumption and returns all failures, each
+//! with a [`Severity`]; [`AssumptionError::aggregate`] turns such a list into
+//! one error led by the most severe violation. Domain-specific preconditions
+//! can be added with [`register_assumption`]: [`check_all`] reports them, and
+//! the estimators they concern reject samples that fail them, like samples
+//! failing the built-in minimum size and positivity checks, which run from
+//! the same registry.
+//!
+//! # Non-finite input
+//!
+//! Every estimator, raw or `Sample`-based, point or bounds, rejects NaN, `+inf`
+//! and `-inf` with a `validity` error whose [`ViolationDetail::NonFiniteValues`]
+//! lists the offending positions in the caller's order; inputs are checked
+//! before they are sorted, in one O(n) pass. `-0.0` is a finite value and is
+//! accepted. Non-finite input is deliberately not a separate assumption id:
+//! the ids above are shared by every port and the cross-language fixtures
+//! (e.g. `tests/sample-construction/error-nan.json`) expect `validity(x)` for
+//! it, so the structured detail carries the distinction instead. Samples
+//! built under [`NonFinitePolicy::Drop`](crate::NonFinitePolicy::Drop) drop
+//! such values instead of failing.
+//!
+//! # Diagnostics
+//!
+//! Heavily tied data (e.g., quantized measurements) never fails an estimator,
+//! but the `*_with_diagnostics` bounds functions report it as a warning
+//! because rank-based margins assume continuous data and may give misleadingly
+//! narrow bounds. Ties are not an assumption: they are reported as
+//! [`Diagnostic::Ties`], next to the assumption violations of
+//! [`Diagnostic::Violation`], so the shared ids above stay unchanged. See
+//! [`check_ties`] and [`TIE_FRACTION_THRESHOLD`].
 
+use crate::estimators::EstimatorId;
+use crate::float::Float;
 use std::fmt;
+use std::sync::{Arc, OnceLock, PoisonError, RwLock};
 
 /// Assumption identifiers in canonical priority order.
 ///
@@ -34,6 +69,13 @@ pub enum AssumptionId {
 }
 
 impl AssumptionId {
+    /// Default severity of a violation of this assumption. Every assumption is
+    /// a hard requirement, so this is [`Severity::Error`]; checks that only
+    /// degrade a result lower it with [`Violation::with_severity`].
+    pub fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
     /// Returns the string identifier for this assumption.
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -51,6 +93,33 @@ impl fmt::Display for AssumptionId {
     }
 }
 
+/// How serious an assumption violation is.
+///
+/// Ordered by seriousness: `Warning < Error`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The result is still computed but may be degraded.
+    Warning,
+    /// The estimator rejects the input.
+    Error,
+}
+
+impl Severity {
+    /// Returns the string identifier for this severity.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// Subject identifier for two-sample functions.
 ///
 /// For two-sample functions, violations are checked in order: X before Y.
@@ -82,18 +151,100 @@ impl fmt::Display for Subject {
 }
 
 /// Represents a specific assumption violation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Checks that can point at a culprit record the first offending value and its
+/// position in [`index`](Self::index) and [`value`](Self::value), and checks
+/// with an obvious fix suggest it in [`remedy`](Self::remedy); the remaining
+/// numbers live in the error's [`ViolationDetail`].
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Violation {
     /// The assumption that was violated.
     pub id: AssumptionId,
     /// The sample that caused the violation.
     pub subject: Subject,
+    /// How serious the violation is.
+    pub severity: Severity,
+    /// Zero-based position of the first offending value, in the order the
+    /// checked values were given.
+    pub index: Option<usize>,
+    /// The first offending value (e.g. the first non-positive one for
+    /// `positivity`).
+    pub value: Option<f64>,
+    /// A suggested way to fix the input.
+    pub remedy: Option<&'static str>,
 }
 
 impl Violation {
-    /// Creates a new violation for the given assumption and subject.
+    /// Creates a new violation for the given assumption and subject, with the
+    /// assumption's default [`severity`](AssumptionId::severity).
     pub fn new(id: AssumptionId, subject: Subject) -> Self {
-        Self { id, subject }
+        Self {
+            id,
+            subject,
+            severity: id.severity(),
+            index: None,
+            value: None,
+            remedy: None,
+        }
+    }
+
+    /// Overrides the severity of this violation.
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Records the first offending value and its position.
+    pub fn with_offender(mut self, index: usize, value: f64) -> Self {
+        self.index = Some(index);
+        self.value = Some(value);
+        self
+    }
+
+    /// Records a suggested fix.
+    pub fn with_remedy(mut self, remedy: &'static str) -> Self {
+        self.remedy = Some(remedy);
+        self
+    }
+
+    /// Estimators whose results this violation concerns.
+    ///
+    /// A `domain` violation of a sample means it is too small (see
+    /// [`min_sample_size`](crate::estimators::min_sample_size)); one of the
+    /// misrate concerns every bounds estimator. Violations of subject `Y` only
+    /// concern two-sample estimators.
+    pub fn affected_estimators(&self) -> Vec<EstimatorId> {
+        EstimatorId::ALL
+            .into_iter()
+            .filter(|&e| self.subject != Subject::Y || e.is_two_sample())
+            .filter(|&e| match self.id {
+                AssumptionId::Validity => true,
+                AssumptionId::Domain if self.subject == Subject::Misrate => e.is_bounds(),
+                AssumptionId::Domain => crate::estimators::min_sample_size(e) > 1,
+                AssumptionId::Positivity => needs_positivity(e),
+                AssumptionId::Sparity => needs_sparity(e),
+            })
+            .collect()
+    }
+
+    /// Estimators that fail on this violation: the
+    /// [`affected_estimators`](Self::affected_estimators) of an error, none for
+    /// a warning.
+    pub fn blocks(&self) -> Vec<EstimatorId> {
+        match self.severity {
+            Severity::Error => self.affected_estimators(),
+            Severity::Warning => Vec::new(),
+        }
+    }
+
+    /// Estimators that still run but give degraded results: the
+    /// [`affected_estimators`](Self::affected_estimators) of a warning, none
+    /// for an error.
+    pub fn degrades(&self) -> Vec<EstimatorId> {
+        match self.severity {
+            Severity::Error => Vec::new(),
+            Severity::Warning => self.affected_estimators(),
+        }
     }
 }
 
@@ -103,16 +254,217 @@ impl fmt::Display for Violation {
     }
 }
 
+/// Structured context explaining why an assumption was violated.
+///
+/// Carries the numbers involved so callers can branch on the cause and render
+/// actionable messages without parsing strings.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ViolationDetail {
+    /// The misrate is NaN or outside its valid range.
+    MisrateOutOfRange {
+        /// The requested misrate.
+        requested: f64,
+    },
+    /// The misrate is below the minimum achievable for the sample size(s).
+    MisrateTooLow {
+        /// The requested misrate.
+        requested: f64,
+        /// The smallest misrate achievable for the given sizes.
+        minimum: f64,
+        /// Size of the first sample.
+        n: usize,
+        /// Size of the second sample (two-sample estimators only).
+        m: Option<usize>,
+    },
+    /// The sample has fewer values than the estimator requires.
+    SampleTooSmall {
+        /// Actual sample size.
+        n: usize,
+        /// Minimum required sample size.
+        required: usize,
+    },
+    /// The input contains NaN or infinite values.
+    NonFiniteValues {
+        /// Zero-based positions of the offending values.
+        indices: Vec<usize>,
+    },
+    /// The input contains integers beyond ±2^53, which `f64` cannot represent exactly.
+    InexactIntegers {
+        /// Zero-based positions of the offending values.
+        indices: Vec<usize>,
+    },
+    /// The input contains zero or negative values where strictly positive
+    /// values are required.
+    NonPositiveValues {
+        /// Number of values equal to zero.
+        zeros: usize,
+        /// Number of negative values.
+        negatives: usize,
+    },
+    /// Both samples of a disparity are tie-dominant, so the average spread
+    /// it divides by is zero, and the shift is not. Reported on the
+    /// `sparity(x)` error; with zero shift the disparity is `0` instead.
+    ZeroAvgSpread {
+        /// The shift between the samples; disparity would be `shift / 0`.
+        shift: f64,
+    },
+}
+
+impl fmt::Display for ViolationDetail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ViolationDetail::MisrateOutOfRange { requested } => {
+                write!(f, "misrate {requested} is out of range")
+            }
+            ViolationDetail::MisrateTooLow {
+                requested,
+                minimum,
+                n,
+                m,
+            } => {
+                write!(
+                    f,
+                    "misrate {requested} is below the minimum achievable {minimum} for n={n}"
+                )?;
+                if let Some(m) = m {
+                    write!(f, ", m={m}")?;
+                }
+                Ok(())
+            }
+            ViolationDetail::SampleTooSmall { n, required } => {
+                write!(f, "sample size {n} is below the required {required}")
+            }
+            ViolationDetail::NonFiniteValues { indices } => {
+                write!(f, "non-finite values at indices {indices:?}")
+            }
+            ViolationDetail::InexactIntegers { indices } => {
+                write!(f, "integers beyond 2^53 at indices {indices:?}")
+            }
+            ViolationDetail::NonPositiveValues { zeros, negatives } => match (zeros, negatives) {
+                (0, _) => write!(f, "{negatives} negative value(s)"),
+                (_, 0) => write!(f, "{zeros} zero value(s)"),
+                _ => write!(f, "{zeros} zero and {negatives} negative value(s)"),
+            },
+            ViolationDetail::ZeroAvgSpread { shift } => write!(
+                f,
+                "both samples have zero spread, so disparity is undefined \
+                 (shift {shift}); use shift or shift_bounds instead"
+            ),
+        }
+    }
+}
+
 /// Error type for assumption violations.
 #[derive(Debug, Clone)]
 pub struct AssumptionError {
     violation: Violation,
+    // Boxed so that `Result<_, AssumptionError>` stays small
+    detail: Option<Box<ViolationDetail>>,
+    others: Vec<Violation>,
+    estimator: Option<EstimatorId>,
 }
 
 impl AssumptionError {
     /// Creates a new assumption error.
     pub fn new(violation: Violation) -> Self {
-        Self { violation }
+        Self {
+            violation,
+            detail: None,
+            others: Vec::new(),
+            estimator: None,
+        }
+    }
+
+    /// Combines several violations (e.g. from [`check_all`]) into one error.
+    ///
+    /// The reported [`violation`](Self::violation) is the most severe one;
+    /// among equally severe violations, the first in the list wins, so a list
+    /// in canonical priority order surfaces the highest-priority assumption.
+    /// The rest are available through [`others`](Self::others). Returns `None`
+    /// for an empty list.
+    pub fn aggregate(violations: &[Violation]) -> Option<Self> {
+        let lead = violations
+            .iter()
+            .enumerate()
+            .max_by_key(|(i, v)| (v.severity, std::cmp::Reverse(*i)))
+            .map(|(i, _)| i)?;
+        let mut error = Self::new(violations[lead]);
+        error.others = violations
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != lead)
+            .map(|(_, v)| *v)
+            .collect();
+        Some(error)
+    }
+
+    /// Attaches structured context to this error.
+    pub fn with_detail(mut self, detail: ViolationDetail) -> Self {
+        self.detail = Some(Box::new(detail));
+        self
+    }
+
+    /// Records the first offending value and its position on the violation,
+    /// see [`Violation::with_offender`].
+    pub fn with_offender(mut self, index: usize, value: f64) -> Self {
+        self.violation = self.violation.with_offender(index, value);
+        self
+    }
+
+    /// Records a suggested fix on the violation, see [`Violation::with_remedy`].
+    pub fn with_remedy(mut self, remedy: &'static str) -> Self {
+        self.violation = self.violation.with_remedy(remedy);
+        self
+    }
+
+    /// Creates a `domain(misrate)` error for a NaN or out-of-range misrate.
+    pub fn misrate_out_of_range(requested: f64) -> Self {
+        Self::domain(Subject::Misrate)
+            .with_detail(ViolationDetail::MisrateOutOfRange { requested })
+            .with_remedy("use a misrate between 0 and 1")
+    }
+
+    /// Creates a `domain(misrate)` error for a misrate below the achievable minimum.
+    pub fn misrate_too_low(requested: f64, minimum: f64, n: usize, m: Option<usize>) -> Self {
+        Self::domain(Subject::Misrate)
+            .with_detail(ViolationDetail::MisrateTooLow {
+                requested,
+                minimum,
+                n,
+                m,
+            })
+            .with_remedy("raise the misrate to the minimum or collect more values")
+    }
+
+    /// Creates a `domain` error for a sample smaller than `required`.
+    pub fn sample_too_small(subject: Subject, n: usize, required: usize) -> Self {
+        Self::domain(subject)
+            .with_detail(ViolationDetail::SampleTooSmall { n, required })
+            .with_remedy("collect more values")
+    }
+
+    /// Creates a `validity` error for an empty sample.
+    pub fn empty_sample(subject: Subject) -> Self {
+        Self::validity(subject).with_remedy("provide at least one value")
+    }
+
+    /// Creates a `validity` error for NaN/infinite values at the given positions.
+    pub fn non_finite_values(subject: Subject, indices: Vec<usize>) -> Self {
+        Self::validity(subject)
+            .with_detail(ViolationDetail::NonFiniteValues { indices })
+            .with_remedy("remove or replace the NaN and infinite values")
+    }
+
+    /// Creates a `validity` error for integers that do not convert exactly to `f64`.
+    pub fn inexact_integers(subject: Subject, indices: Vec<usize>) -> Self {
+        Self::validity(subject).with_detail(ViolationDetail::InexactIntegers { indices })
+    }
+
+    /// Creates a `positivity` error counting the zero and negative values.
+    pub fn non_positive_values(subject: Subject, zeros: usize, negatives: usize) -> Self {
+        Self::positivity(subject)
+            .with_detail(ViolationDetail::NonPositiveValues { zeros, negatives })
+            .with_remedy("use shift for data that can be zero or negative")
     }
 
     /// Creates an error for the `validity` assumption.
@@ -139,11 +491,55 @@ impl AssumptionError {
     pub fn violation(&self) -> Violation {
         self.violation
     }
+
+    /// Returns the structured context, if any was recorded.
+    pub fn detail(&self) -> Option<&ViolationDetail> {
+        self.detail.as_deref()
+    }
+
+    /// Records the estimator that raised this error, replacing any earlier one
+    /// (so an estimator built on another one reports itself).
+    pub fn in_estimator(mut self, estimator: EstimatorId) -> Self {
+        self.estimator = Some(estimator);
+        self
+    }
+
+    /// The estimator that raised this error. Set by the typed [`Sample`]
+    /// estimators; the raw slice functions leave it empty.
+    ///
+    /// [`Sample`]: crate::Sample
+    pub fn estimator(&self) -> Option<EstimatorId> {
+        self.estimator
+    }
+
+    /// Severity of the reported violation.
+    pub fn severity(&self) -> Severity {
+        self.violation.severity
+    }
+
+    /// Violations other than the reported one (non-empty only for
+    /// [`aggregate`](Self::aggregate)d errors), in their original order.
+    pub fn others(&self) -> &[Violation] {
+        &self.others
+    }
 }
 
 impl fmt::Display for AssumptionError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.violation)
+        if let Some(estimator) = self.estimator {
+            write!(f, "{estimator}: ")?;
+        }
+        match &self.detail {
+            Some(detail) => write!(f, "{}: {}", self.violation, detail)?,
+            None => write!(f, "{}", self.violation)?,
+        }
+        if let (Some(index), Some(value)) = (self.violation.index, self.violation.value) {
+            write!(f, " (first at index {index}: {value})")?;
+        }
+        if let Some(remedy) = self.violation.remedy {
+            write!(f, "; {remedy}")?;
+        }
+        Ok(())
     }
 }
 
@@ -167,7 +563,25 @@ impl fmt::Display for EstimatorError {
     }
 }
 
-impl std::error::Error for EstimatorError {}
+impl std::error::Error for EstimatorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EstimatorError::Assumption(e) => Some(e),
+            EstimatorError::Other(_) => None,
+        }
+    }
+}
+
+impl EstimatorError {
+    /// Records the estimator on an [`EstimatorError::Assumption`] error, see
+    /// [`AssumptionError::in_estimator`]. `Other` errors are returned as is.
+    pub fn in_estimator(self, estimator: EstimatorId) -> Self {
+        match self {
+            EstimatorError::Assumption(e) => EstimatorError::Assumption(e.in_estimator(estimator)),
+            other => other,
+        }
+    }
+}
 
 impl From<AssumptionError> for EstimatorError {
     fn from(e: AssumptionError) -> Self {
@@ -187,37 +601,553 @@ impl From<crate::measurement_unit::UnitMismatchError> for EstimatorError {
     }
 }
 
+// =============================================================================
+// Diagnostics
+// =============================================================================
+
+/// Tie fraction above which the `*_with_diagnostics` bounds functions report a
+/// [`Diagnostic::Ties`] warning.
+pub const TIE_FRACTION_THRESHOLD: f64 = 0.25;
+
+/// Summary of tied values in a sample.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TieReport {
+    /// Number of values.
+    pub n: usize,
+    /// Number of distinct values.
+    pub distinct: usize,
+    /// Size of the largest group of equal values (1 if there are no ties).
+    pub max_tie_run: usize,
+    /// Fraction of values that are equal to at least one other value.
+    pub tie_fraction: f64,
+}
+
+impl TieReport {
+    /// Returns true if the tie fraction exceeds [`TIE_FRACTION_THRESHOLD`].
+    pub fn is_heavily_tied(&self) -> bool {
+        self.tie_fraction > TIE_FRACTION_THRESHOLD
+    }
+}
+
+/// Reports how heavily tied `x` is. An empty slice yields an all-zero report.
+pub fn check_ties(x: &[f64]) -> TieReport {
+    let mut sorted = x.to_vec();
+    sorted.sort_unstable_by(|a, b| a.total_cmp(b));
+    check_ties_sorted(&sorted)
+}
+
+/// [`check_ties`] for input already sorted ascending.
+pub(crate) fn check_ties_sorted(sorted: &[f64]) -> TieReport {
+    let n = sorted.len();
+    let mut distinct = 0;
+    let mut max_tie_run = 0;
+    let mut tied = 0;
+    for run in sorted.chunk_by(|a, b| a == b) {
+        distinct += 1;
+        max_tie_run = max_tie_run.max(run.len());
+        if run.len() > 1 {
+            tied += run.len();
+        }
+    }
+    TieReport {
+        n,
+        distinct,
+        max_tie_run,
+        tie_fraction: if n == 0 { 0.0 } else { tied as f64 / n as f64 },
+    }
+}
+
+/// A finding about the input of an estimator or an audit.
+///
+/// Assumption violations keep their [`AssumptionId`]. Those ids are shared by
+/// every port and the cross-language fixtures, so conditions that only this
+/// crate reports get a variant of their own instead of a new id.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Diagnostic {
+    /// An assumption violation.
+    Violation(Violation),
+    /// The sample is heavily tied (see [`TieReport::is_heavily_tied`]). Always
+    /// a warning, concerning the bounds estimators.
+    Ties {
+        /// The tied sample.
+        subject: Subject,
+        /// How the sample is tied.
+        report: TieReport,
+    },
+    /// The units of `x` and `y` are not convertible to each other. Always an
+    /// error, concerning the two-sample estimators.
+    UnitMismatch(crate::measurement_unit::UnitMismatchError),
+}
+
+impl Diagnostic {
+    /// The sample or parameter this finding is about.
+    pub fn subject(&self) -> Subject {
+        match self {
+            Diagnostic::Violation(v) => v.subject,
+            Diagnostic::Ties { subject, .. } => *subject,
+            Diagnostic::UnitMismatch(_) => Subject::Y,
+        }
+    }
+
+    /// How serious this finding is; ties are always a [`Severity::Warning`]
+    /// and unit mismatches always a [`Severity::Error`].
+    pub fn severity(&self) -> Severity {
+        match self {
+            Diagnostic::Violation(v) => v.severity,
+            Diagnostic::Ties { .. } => Severity::Warning,
+            Diagnostic::UnitMismatch(_) => Severity::Error,
+        }
+    }
+
+    /// The assumption violation, if this finding is one.
+    pub fn violation(&self) -> Option<&Violation> {
+        match self {
+            Diagnostic::Violation(v) => Some(v),
+            Diagnostic::Ties { .. } | Diagnostic::UnitMismatch(_) => None,
+        }
+    }
+
+    /// Estimators whose results this finding concerns: see
+    /// [`Violation::affected_estimators`]; ties concern the bounds estimators
+    /// and unit mismatches the two-sample ones.
+    pub fn affected_estimators(&self) -> Vec<EstimatorId> {
+        match self {
+            Diagnostic::Violation(v) => v.affected_estimators(),
+            Diagnostic::Ties { subject, .. } => EstimatorId::ALL
+                .into_iter()
+                .filter(|&e| e.is_bounds() && (*subject != Subject::Y || e.is_two_sample()))
+                .collect(),
+            Diagnostic::UnitMismatch(_) => EstimatorId::ALL
+                .into_iter()
+                .filter(|e| e.is_two_sample())
+                .collect(),
+        }
+    }
+
+    /// Estimators that fail on this finding (none for a warning).
+    pub fn blocks(&self) -> Vec<EstimatorId> {
+        match self.severity() {
+            Severity::Error => self.affected_estimators(),
+            Severity::Warning => Vec::new(),
+        }
+    }
+
+    /// Estimators that still run but give degraded results (none for an error).
+    pub fn degrades(&self) -> Vec<EstimatorId> {
+        match self.severity() {
+            Severity::Error => Vec::new(),
+            Severity::Warning => self.affected_estimators(),
+        }
+    }
+}
+
+impl From<Violation> for Diagnostic {
+    fn from(v: Violation) -> Self {
+        Diagnostic::Violation(v)
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Diagnostic::Violation(v) => write!(f, "{v}"),
+            Diagnostic::Ties { subject, .. } => write!(f, "ties({subject})"),
+            Diagnostic::UnitMismatch(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// Reports a [`Diagnostic::Ties`] warning if `sorted` is heavily tied.
+pub(crate) fn tie_diagnostic(sorted: &[f64], subject: Subject) -> Option<Diagnostic> {
+    let report = check_ties_sorted(sorted);
+    report
+        .is_heavily_tied()
+        .then_some(Diagnostic::Ties { subject, report })
+}
+
+/// A successful result together with non-fatal warnings.
+#[derive(Debug, Clone)]
+pub struct WithDiagnostics<T> {
+    /// The estimator result, identical to the non-diagnostic function's output.
+    pub value: T,
+    /// Warnings about conditions that degrade (but do not invalidate) the result.
+    pub warnings: Vec<Diagnostic>,
+}
+
 // =============================================================================
 // Validation helpers
 // =============================================================================
 
 /// Checks that a sample is valid (non-empty with finite values).
+///
+/// Non-finite values are reported with their positions in `values`.
 pub fn check_validity(values: &[f64], subject: Subject) -> Result<(), AssumptionError> {
+    check_validity_of(values, subject)
+}
+
+/// [`check_validity`] for any element type the kernels accept.
+pub(crate) fn check_validity_of<T: Float>(
+    values: &[T],
+    subject: Subject,
+) -> Result<(), AssumptionError> {
     if values.is_empty() {
-        return Err(AssumptionError::validity(subject));
+        return Err(AssumptionError::empty_sample(subject));
     }
-    if values.iter().any(|v| !v.is_finite()) {
-        return Err(AssumptionError::validity(subject));
+    if let Some(first) = values.iter().position(|v| !v.to_f64().is_finite()) {
+        let indices = (first..values.len())
+            .filter(|&i| !values[i].to_f64().is_finite())
+            .collect();
+        return Err(AssumptionError::non_finite_values(subject, indices)
+            .with_offender(first, values[first].to_f64()));
+    }
+    Ok(())
+}
+
+/// Runs every registered sample assumption on `values` and returns all
+/// failures, in canonical priority order, instead of stopping at the first.
+///
+/// The built-in checks are registered by default and report, with their
+/// default severities:
+/// - `validity` if `values` is empty or contains NaN/infinite values;
+/// - `domain` if `values` is smaller than the largest
+///   [`min_sample_size`](crate::estimators::min_sample_size) of any estimator;
+/// - `positivity` if any value is `<= 0`;
+/// - `sparity` if the finite values have zero Spread (including a single value).
+///
+/// The `validity` and `positivity` violations carry the first offending value
+/// and its index, and a remedy.
+///
+/// Checks added with [`register_assumption`] run after the built-in checks of
+/// the same assumption, so violations stay sorted by [`AssumptionId`] and, for
+/// equal ids, by registration order.
+///
+/// Positivity is only required by ratio-based estimators, so callers should
+/// ignore it when it does not apply. Other `domain` conditions concern
+/// parameters rather than samples and are not checked here.
+///
+/// Estimators run the same registry, scoped to themselves, see
+/// [`register_assumption`].
+pub fn check_all(values: &[f64], subject: Subject) -> Vec<Violation> {
+    checks_for(None)
+        .iter()
+        .filter_map(|c| c.run(None, values, subject).err())
+        .map(|e| e.violation())
+        .collect()
+}
+
+/// Runs the registered checks that concern `estimator` on its samples and
+/// returns the first error, in canonical priority order and, for equal ids,
+/// subject `X` before `Y`. Warnings never fail an estimator.
+///
+/// Estimators call this after their own validity and misrate checks.
+pub(crate) fn check_registered<T: Float>(
+    estimator: EstimatorId,
+    samples: &[(&[T], Subject)],
+) -> Result<(), AssumptionError> {
+    let checks = checks_for(Some(estimator));
+    for group in checks.chunk_by(|a, b| a.id == b.id) {
+        for &(values, subject) in samples {
+            for check in group {
+                match check.run(Some(estimator), values, subject) {
+                    Err(e) if e.violation().severity == Severity::Error => return Err(e),
+                    _ => {}
+                }
+            }
+        }
     }
     Ok(())
 }
 
+/// The registered checks that apply to `estimator`, or all of them for an
+/// audit (`None`), sorted by assumption id.
+fn checks_for(estimator: Option<EstimatorId>) -> Vec<RegisteredCheck> {
+    // Snapshot the checks so they run without holding the lock; a check may
+    // itself register or unregister checks.
+    let mut checks: Vec<RegisteredCheck> = registry()
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .checks
+        .iter()
+        .filter(|c| c.applies_to(estimator))
+        .cloned()
+        .collect();
+    checks.sort_by_key(|c| c.id as u8);
+    checks
+}
+
+/// A sample check: returns the violation it detects, if any.
+type AssumptionCheck = dyn Fn(&[f64]) -> Option<Violation> + Send + Sync;
+
+/// What a registered check tests.
+#[derive(Clone)]
+enum Check {
+    /// Non-empty input with finite values.
+    Validity,
+    /// At least [`min_sample_size`](crate::estimators::min_sample_size) values.
+    MinSize,
+    /// Strictly positive values.
+    Positivity,
+    /// Non-zero Spread.
+    Sparity,
+    /// A check added with [`register_assumption`].
+    Custom(Arc<AssumptionCheck>),
+}
+
+/// A registered check together with the assumption it reports.
+#[derive(Clone)]
+struct RegisteredCheck {
+    key: u64,
+    id: AssumptionId,
+    check: Check,
+}
+
+impl RegisteredCheck {
+    /// Whether this check runs for `estimator`; an audit (`None`) runs all.
+    fn applies_to(&self, estimator: Option<EstimatorId>) -> bool {
+        let Some(estimator) = estimator else {
+            return true;
+        };
+        match &self.check {
+            // Estimators check validity before anything else and detect zero
+            // Spread from the spread they compute
+            Check::Validity | Check::Sparity => false,
+            // A single value has zero Spread, so point estimators reach their
+            // minimum through sparity
+            Check::MinSize => {
+                estimator.is_bounds() && crate::estimators::min_sample_size(estimator) > 1
+            }
+            Check::Positivity => needs_positivity(estimator),
+            Check::Custom(_) => match self.id {
+                AssumptionId::Positivity => needs_positivity(estimator),
+                AssumptionId::Sparity => needs_sparity(estimator),
+                AssumptionId::Validity | AssumptionId::Domain => true,
+            },
+        }
+    }
+
+    /// Runs this check on one sample of `estimator` (`None` in an audit).
+    fn run<T: Float>(
+        &self,
+        estimator: Option<EstimatorId>,
+        values: &[T],
+        subject: Subject,
+    ) -> Result<(), AssumptionError> {
+        match &self.check {
+            Check::Validity => check_validity_of(values, subject),
+            Check::MinSize => check_min_size(estimator, values.len(), subject),
+            Check::Positivity => check_positivity_of(values, subject),
+            Check::Sparity => {
+                let mut finite: Vec<f64> = values
+                    .iter()
+                    .map(|v| v.to_f64())
+                    .filter(|v| v.is_finite())
+                    .collect();
+                finite.sort_unstable_by(|a, b| a.total_cmp(b));
+                let spread = crate::spread_impl::spread_impl(&finite, true).unwrap_or(0.0);
+                if !finite.is_empty() && spread <= 0.0 {
+                    return Err(AssumptionError::sparity(subject));
+                }
+                Ok(())
+            }
+            Check::Custom(check) => match check(&T::widen(values)) {
+                Some(v) => Err(AssumptionError::new(Violation { subject, ..v })),
+                None => Ok(()),
+            },
+        }
+    }
+}
+
+/// The minimum sample size of `estimator`, or of every estimator in an audit.
+fn check_min_size(
+    estimator: Option<EstimatorId>,
+    n: usize,
+    subject: Subject,
+) -> Result<(), AssumptionError> {
+    let Some(estimator) = estimator else {
+        let required = EstimatorId::ALL
+            .into_iter()
+            .map(crate::estimators::min_sample_size)
+            .max()
+            .unwrap_or(1);
+        if n < required {
+            return Err(AssumptionError::domain(subject));
+        }
+        return Ok(());
+    };
+    let required = crate::estimators::min_sample_size(estimator);
+    if n >= required {
+        return Ok(());
+    }
+    Err(match estimator {
+        // Spread bounds pair up values: a lone value has no pair to spread
+        EstimatorId::SpreadBounds => AssumptionError::sparity(subject)
+            .with_detail(ViolationDetail::SampleTooSmall { n, required }),
+        _ => AssumptionError::sample_too_small(subject, n, required),
+    })
+}
+
+/// Estimators that take logs of their samples.
+fn needs_positivity(estimator: EstimatorId) -> bool {
+    matches!(estimator, EstimatorId::Ratio | EstimatorId::RatioBounds)
+}
+
+/// Estimators that divide by or pair up a Spread.
+fn needs_sparity(estimator: EstimatorId) -> bool {
+    matches!(
+        estimator,
+        EstimatorId::Spread
+            | EstimatorId::Volatility
+            | EstimatorId::Precision
+            | EstimatorId::Disparity
+            | EstimatorId::SpreadRatio
+            | EstimatorId::AvgSpread
+            | EstimatorId::SpreadBounds
+            | EstimatorId::DisparityBounds
+            | EstimatorId::SpreadRatioBounds
+            | EstimatorId::AvgSpreadBounds
+    )
+}
+
+/// The checks run by [`check_all`] and the estimators, in registration order.
+struct Registry {
+    next_key: u64,
+    checks: Vec<RegisteredCheck>,
+}
+
+/// Registers a custom sample check for assumption `id`, to be run while the
+/// returned registration is alive.
+///
+/// The check receives a sample and returns a violation if the sample fails
+/// it. The returned violation is kept as is, except that its subject is
+/// replaced by the one of the sample checked. The registry is process-wide;
+/// dropping the [`AssumptionRegistration`] removes the check again, and
+/// [`AssumptionRegistration::keep`] keeps it for the rest of the process.
+///
+/// Audits with [`check_all`] run every check. Estimators run the checks that
+/// concern them, after their own validity and misrate checks, and reject
+/// their input on the first violation of [`Severity::Error`]:
+/// - `validity` and `domain` checks concern every estimator;
+/// - `positivity` checks concern [`ratio`](crate::ratio) and
+///   [`ratio_bounds`](crate::ratio_bounds);
+/// - `sparity` checks concern the estimators built on Spread.
+///
+/// Samples of subject `Y` are only checked by two-sample estimators. An
+/// estimator may pass its samples sorted.
+///
+/// # Example
+/// ```
+/// use pragmastat::assumptions::{check_all, register_assumption, AssumptionId, Subject, Violation};
+/// use pragmastat::estimators::raw;
+///
+/// // Latencies must be below one hour
+/// let latency_check = register_assumption(AssumptionId::Domain, |x| {
+///     x.iter()
+///         .any(|&v| v >= 3600.0)
+///         .then(|| Violation::new(AssumptionId::Domain, Subject::X))
+/// });
+/// let violations = check_all(&[1.0, 2.0, 7200.0], Subject::Y);
+/// assert_eq!(violations[0].id, AssumptionId::Domain);
+/// assert_eq!(violations[0].subject, Subject::Y);
+/// assert!(raw::center(&[1.0, 2.0, 7200.0], false).is_err());
+///
+/// drop(latency_check);
+/// assert!(check_all(&[1.0, 2.0, 7200.0], Subject::Y).is_empty());
+/// assert!(raw::center(&[1.0, 2.0, 7200.0], false).is_ok());
+/// ```
+pub fn register_assumption(
+    id: AssumptionId,
+    check: impl Fn(&[f64]) -> Option<Violation> + Send + Sync + 'static,
+) -> AssumptionRegistration {
+    let mut registry = registry().write().unwrap_or_else(PoisonError::into_inner);
+    let key = registry.next_key;
+    registry.next_key += 1;
+    registry.checks.push(RegisteredCheck {
+        key,
+        id,
+        check: Check::Custom(Arc::new(check)),
+    });
+    AssumptionRegistration { key }
+}
+
+/// A check added with [`register_assumption`]; dropping it unregisters the
+/// check.
+#[derive(Debug)]
+#[must_use = "the check is unregistered as soon as the registration is dropped"]
+pub struct AssumptionRegistration {
+    key: u64,
+}
+
+impl AssumptionRegistration {
+    /// Keeps the check registered for the rest of the process.
+    pub fn keep(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for AssumptionRegistration {
+    fn drop(&mut self) {
+        registry()
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .checks
+            .retain(|c| c.key != self.key);
+    }
+}
+
+fn registry() -> &'static RwLock<Registry> {
+    static REGISTRY: OnceLock<RwLock<Registry>> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let checks: Vec<RegisteredCheck> = [
+            (AssumptionId::Validity, Check::Validity),
+            (AssumptionId::Domain, Check::MinSize),
+            (AssumptionId::Positivity, Check::Positivity),
+            (AssumptionId::Sparity, Check::Sparity),
+        ]
+        .into_iter()
+        .zip(0..)
+        .map(|((id, check), key)| RegisteredCheck { key, id, check })
+        .collect();
+        RwLock::new(Registry {
+            next_key: checks.len() as u64,
+            checks,
+        })
+    })
+}
+
 /// Checks that all values are strictly positive.
+///
+/// The error carries [`ViolationDetail::NonPositiveValues`], so callers can
+/// tell a sample containing zero from one with negative values.
 pub fn check_positivity(values: &[f64], subject: Subject) -> Result<(), AssumptionError> {
-    if values.iter().any(|&v| v <= 0.0) {
-        return Err(AssumptionError::positivity(subject));
+    check_positivity_of(values, subject)
+}
+
+/// [`check_positivity`] for any element type the kernels accept.
+pub(crate) fn check_positivity_of<T: Float>(
+    values: &[T],
+    subject: Subject,
+) -> Result<(), AssumptionError> {
+    if let Some(first) = values.iter().position(|v| v.to_f64() <= 0.0) {
+        let zeros = values.iter().filter(|v| v.to_f64() == 0.0).count();
+        let negatives = values.iter().filter(|v| v.to_f64() < 0.0).count();
+        return Err(
+            AssumptionError::non_positive_values(subject, zeros, negatives)
+                .with_offender(first, values[first].to_f64()),
+        );
     }
     Ok(())
 }
 
 /// Log-transforms a slice. Returns error if any value is non-positive.
 pub fn log(values: &[f64], subject: Subject) -> Result<Vec<f64>, AssumptionError> {
-    let mut result = Vec::with_capacity(values.len());
-    for &v in values {
-        if v <= 0.0 {
-            return Err(AssumptionError::positivity(subject));
-        }
-        result.push(v.ln());
-    }
-    Ok(result)
+    log_of(values, subject)
+}
+
+/// [`log`] for any element type the kernels accept; the logs are taken in f64.
+pub(crate) fn log_of<T: Float>(
+    values: &[T],
+    subject: Subject,
+) -> Result<Vec<f64>, AssumptionError> {
+    check_positivity_of(values, subject)?;
+    Ok(values.iter().map(|v| v.to_f64().ln()).collect())
 }
@@ -0,0 +1,48 @@
+//! Deterministic inputs for the benchmark harness (`cargo bench`).
+//!
+//! The benches in `benches/estimators.rs` draw their data from here so that
+//! external tooling (CI scripts, other language ports, ad-hoc profiling) can
+//! reproduce exactly the same inputs. Values come from the crate's own [`Rng`]
+//! with fixed string seeds, so they are identical across runs and platforms.
+
+use crate::distributions::{Additive, Distribution};
+use crate::Rng;
+
+/// Sample sizes covered by the benchmark harness.
+pub const SIZES: [usize; 4] = [100, 1_000, 10_000, 100_000];
+
+/// Misrate used by the bounds benchmarks.
+pub const MISRATE: f64 = 1e-3;
+
+/// One-sample input of size `n`: draws from `Additive(100, 10)`.
+pub fn one_sample(n: usize) -> Vec<f64> {
+    let mut rng = Rng::from_string(&format!("bench-x-{n}"));
+    Additive::new(100.0, 10.0).samples(&mut rng, n)
+}
+
+/// Two-sample input of sizes `n` and `n`: `x` as in [`one_sample`], `y` drawn
+/// from `Additive(105, 10)` with an independent seed.
+pub fn two_sample(n: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut rng = Rng::from_string(&format!("bench-y-{n}"));
+    let y = Additive::new(105.0, 10.0).samples(&mut rng, n);
+    (one_sample(n), y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inputs_are_deterministic() {
+        assert_eq!(one_sample(100), one_sample(100));
+        let (x, y) = two_sample(50);
+        assert_eq!((x.len(), y.len()), (50, 50));
+        assert_eq!(x, one_sample(50));
+        assert_ne!(x, y);
+    }
+
+    #[test]
+    fn sizes_produce_distinct_streams() {
+        assert_ne!(one_sample(100)[0], one_sample(1_000)[0]);
+    }
+}
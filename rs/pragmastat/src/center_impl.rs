@@ -1,29 +1,38 @@
 /// O(n log n) implementation of the Center (Hodges-Lehmann) estimator.
 /// Based on Monahan's Algorithm 616 (1984).
 ///
+/// Order-invariant: everything after the sort, including the seed of the
+/// randomized pivot choice, depends only on the sorted values. Any permutation
+/// of the same values (ties and signed zeros included, as `total_cmp` orders
+/// them) gives a bit-identical result.
+///
 /// Internal implementation - not part of public API.
+use crate::float::Float;
 use crate::fnv1a::hash_f64_slice;
 use crate::rng::Rng;
 
-pub(crate) fn center_impl(values: &[f64], assume_sorted: bool) -> Result<f64, &'static str> {
+pub(crate) fn center_impl<T: Float>(
+    values: &[T],
+    assume_sorted: bool,
+) -> Result<f64, &'static str> {
     let n = values.len();
     if n == 0 {
         return Err("Input slice cannot be empty");
     }
     if n == 1 {
-        return Ok(values[0]);
+        return Ok(values[0].to_f64());
     }
     if n == 2 {
-        return Ok(0.5 * values[0] + 0.5 * values[1]);
+        return Ok(0.5 * values[0].to_f64() + 0.5 * values[1].to_f64());
     }
 
     // Validate for NaN/infinite values
-    if values.iter().any(|v| !v.is_finite()) {
+    if values.iter().any(|v| !v.to_f64().is_finite()) {
         return Err("Input contains NaN or infinite values");
     }
 
     let owned_sorted;
-    let sorted_values: &[f64] = if assume_sorted {
+    let sorted_values: &[T] = if assume_sorted {
         values
     } else {
         owned_sorted = {
@@ -44,11 +53,12 @@ pub(crate) fn center_impl(values: &[f64], assume_sorted: bool) -> Result<f64, &'
     let mut right_bounds = vec![n; n];
 
     // Start with a good pivot: sum of middle elements
-    let mut pivot = sorted_values[(n - 1) / 2] + sorted_values[n / 2];
+    let mut pivot = sorted_values[(n - 1) / 2].to_f64() + sorted_values[n / 2].to_f64();
     let mut active_set_size = total_pairs;
     let mut previous_count = 0;
 
-    let mut rng = Rng::from_seed(hash_f64_slice(values));
+    // Seed from the sorted values so the pivot sequence ignores input order
+    let mut rng = Rng::from_seed(hash_f64_slice(sorted_values));
 
     let mut partition_counts = vec![0; n];
 
@@ -75,7 +85,8 @@ pub(crate) fn center_impl(values: &[f64], assume_sorted: bool) -> Result<f64, &'
         for row in 1..=n {
             // Move left from current column until we find sums < pivot
             while current_column >= row
-                && sorted_values[row - 1] + sorted_values[current_column - 1] >= pivot
+                && sorted_values[row - 1].to_f64() + sorted_values[current_column - 1].to_f64()
+                    >= pivot
             {
                 current_column -= 1;
             }
@@ -100,9 +111,9 @@ pub(crate) fn center_impl(values: &[f64], assume_sorted: bool) -> Result<f64, &'
                     continue;
                 }
 
-                let row_value = sorted_values[i];
-                let smallest_in_row = sorted_values[left_bounds[i] - 1] + row_value;
-                let largest_in_row = sorted_values[right_bounds[i] - 1] + row_value;
+                let row_value = sorted_values[i].to_f64();
+                let smallest_in_row = sorted_values[left_bounds[i] - 1].to_f64() + row_value;
+                let largest_in_row = sorted_values[right_bounds[i] - 1].to_f64() + row_value;
 
                 min_active_sum = min_active_sum.min(smallest_in_row);
                 max_active_sum = max_active_sum.max(largest_in_row);
@@ -130,13 +141,13 @@ pub(crate) fn center_impl(values: &[f64], assume_sorted: bool) -> Result<f64, &'
 
             for i in 0..n {
                 let count_in_row = partition_counts[i];
-                let row_value = sorted_values[i];
+                let row_value = sorted_values[i].to_f64();
                 let total_in_row = n - i;
 
                 // Find largest sum in this row that's < pivot
                 if count_in_row > 0 {
                     let last_below_index = i + count_in_row;
-                    let last_below_value = row_value + sorted_values[last_below_index - 1];
+                    let last_below_value = row_value + sorted_values[last_below_index - 1].to_f64();
                     largest_below_pivot = largest_below_pivot.max(last_below_value);
                 }
 
@@ -144,7 +155,7 @@ pub(crate) fn center_impl(values: &[f64], assume_sorted: bool) -> Result<f64, &'
                 if count_in_row < total_in_row {
                     let first_at_or_above_index = i + count_in_row + 1;
                     let first_at_or_above_value =
-                        row_value + sorted_values[first_at_or_above_index - 1];
+                        row_value + sorted_values[first_at_or_above_index - 1].to_f64();
                     smallest_at_or_above_pivot =
                         smallest_at_or_above_pivot.min(first_at_or_above_value);
                 }
@@ -226,7 +237,8 @@ pub(crate) fn center_impl(values: &[f64], assume_sorted: bool) -> Result<f64, &'
             // Use median element of the selected row as pivot
             let median_column_in_row =
                 usize::midpoint(left_bounds[selected_row], right_bounds[selected_row]);
-            pivot = sorted_values[selected_row] + sorted_values[median_column_in_row - 1];
+            pivot = sorted_values[selected_row].to_f64()
+                + sorted_values[median_column_in_row - 1].to_f64();
         } else {
             // Few elements remain - use midrange strategy
             let mut min_remaining_sum = f64::INFINITY;
@@ -237,9 +249,9 @@ pub(crate) fn center_impl(values: &[f64], assume_sorted: bool) -> Result<f64, &'
                     continue;
                 }
 
-                let row_value = sorted_values[i];
-                let min_in_row = sorted_values[left_bounds[i] - 1] + row_value;
-                let max_in_row = sorted_values[right_bounds[i] - 1] + row_value;
+                let row_value = sorted_values[i].to_f64();
+                let min_in_row = sorted_values[left_bounds[i] - 1].to_f64() + row_value;
+                let max_in_row = sorted_values[right_bounds[i] - 1].to_f64() + row_value;
 
                 min_remaining_sum = min_remaining_sum.min(min_in_row);
                 max_remaining_sum = max_remaining_sum.max(max_in_row);
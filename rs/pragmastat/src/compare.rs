@@ -3,12 +3,12 @@
 //! These high-level APIs compare estimates (Center, Spread, Shift, Ratio, Disparity)
 //! against practical thresholds and return verdicts (Less, Greater, or Inconclusive).
 
-use crate::assumptions::{AssumptionError, EstimatorError, Subject};
+use crate::assumptions::{AssumptionError, EstimatorError};
 use crate::bounds::Bounds;
 use crate::estimators;
 use crate::measurement::Measurement;
 use crate::measurement_unit::{
-    conversion_factor, finer, is_compatible, MeasurementUnit, UnitMismatchError,
+    conversion_factor, convert, finer, is_compatible, MeasurementUnit, UnitMismatchError,
 };
 use crate::sample::{check_compatible_units, check_non_weighted, Sample};
 
@@ -84,8 +84,8 @@ impl Threshold {
     /// Returns an error if misrate is not in (0, 1] or value is not finite.
     pub fn new(metric: Metric, value: Measurement, misrate: f64) -> Result<Self, EstimatorError> {
         if !misrate.is_finite() || misrate <= 0.0 || misrate > 1.0 {
-            return Err(EstimatorError::from(AssumptionError::domain(
-                Subject::Misrate,
+            return Err(EstimatorError::from(AssumptionError::misrate_out_of_range(
+                misrate,
             )));
         }
         if !value.value.is_finite() {
@@ -257,31 +257,38 @@ const COMPARE2_SPECS: &[MetricSpec] = &[
 ];
 
 /// Validates and normalizes a Center threshold.
+///
+/// Center is an absolute value, so affine offsets are applied.
 fn validate_center(
     threshold: &Threshold,
     x: &Sample,
     _: Option<&Sample>,
 ) -> Result<Measurement, EstimatorError> {
-    if !is_compatible(&threshold.value.unit, x.unit()) {
-        return Err(UnitMismatchError::new(&threshold.value.unit, x.unit()).into());
-    }
-    let factor = conversion_factor(&threshold.value.unit, x.unit());
-    Ok(Measurement::new(
-        threshold.value.value * factor,
-        x.unit().clone(),
-    ))
+    let value = convert(threshold.value.value, &threshold.value.unit, x.unit())?;
+    Ok(Measurement::new(value, x.unit().clone()))
 }
 
 /// Validates and normalizes a Spread threshold.
+///
+/// Spread is a difference, so only the scale factor applies (offsets cancel).
 fn validate_spread(
     threshold: &Threshold,
     x: &Sample,
     _: Option<&Sample>,
 ) -> Result<Measurement, EstimatorError> {
-    validate_center(threshold, x, None) // Same validation as Center
+    if !is_compatible(&threshold.value.unit, x.unit()) {
+        return Err(UnitMismatchError::new(&threshold.value.unit, x.unit()).into());
+    }
+    let factor = conversion_factor(&threshold.value.unit, x.unit());
+    Ok(Measurement::new(
+        threshold.value.value * factor,
+        x.unit().clone(),
+    ))
 }
 
 /// Validates and normalizes a Shift threshold.
+///
+/// Shift is a difference, so only the scale factor applies (offsets cancel).
 fn validate_shift(
     threshold: &Threshold,
     x: &Sample,
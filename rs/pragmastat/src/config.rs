@@ -0,0 +1,154 @@
+//! Shared estimator settings: bounds defaults and non-finite handling.
+//!
+//! An [`EstimatorConfig`] fixes the misrate, the number of randomized
+//! replicates, and the seed once, so call sites do not have to repeat them.
+//! Pass it to the `*_with_config` bounds functions, or attach it to a sample
+//! with [`Sample::with_config`](crate::Sample::with_config) so that the
+//! sample's bounds methods use it. The plain bounds functions are unaffected
+//! and keep taking an explicit misrate.
+//!
+//! The config also carries a [`NonFinitePolicy`], applied when values enter a
+//! sample through [`Sample::with_unit_and_config`](crate::Sample::with_unit_and_config)
+//! or [`Sample::extend`](crate::Sample::extend). Estimators themselves always
+//! reject NaN and infinite values with a `validity` error listing their
+//! indices; see the [`assumptions`](crate::assumptions#non-finite-input)
+//! module.
+
+use crate::assumptions::{AssumptionError, EstimatorError};
+use crate::estimators::DEFAULT_MISRATE;
+
+/// What a sample does with NaN and infinite input values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NonFinitePolicy {
+    /// Fail with a `validity` error listing the offending indices.
+    #[default]
+    Reject,
+    /// Silently drop the offending values; an input with no finite values is
+    /// still rejected as empty.
+    Drop,
+}
+
+/// Validated defaults for the bounds estimators.
+///
+/// Built with [`EstimatorConfig::builder`]; validation happens in
+/// [`EstimatorConfigBuilder::build`], so a config that exists is always usable.
+///
+/// # Example
+/// ```
+/// use pragmastat::{EstimatorConfig, Sample};
+///
+/// let config = EstimatorConfig::builder()
+///     .misrate(0.01)
+///     .seed("my-org")
+///     .build()
+///     .unwrap();
+/// let x = Sample::new((1..=30).map(f64::from).collect())
+///     .unwrap()
+///     .with_config(config);
+/// let bounds = x.center_bounds().unwrap();
+/// assert!(bounds.lower < 15.5 && 15.5 < bounds.upper);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct EstimatorConfig {
+    misrate: f64,
+    bootstrap_iterations: usize,
+    seed: Option<String>,
+    non_finite: NonFinitePolicy,
+}
+
+impl EstimatorConfig {
+    /// Starts a builder from the defaults.
+    pub fn builder() -> EstimatorConfigBuilder {
+        EstimatorConfigBuilder::default()
+    }
+
+    /// Misclassification rate, in (0, 0.5].
+    pub fn misrate(&self) -> f64 {
+        self.misrate
+    }
+
+    /// Number of randomized replicates behind the Monte Carlo error of the
+    /// disparity bounds (at least one); see
+    /// [`DisparityBoundsOptions::iterations`](crate::estimators::DisparityBoundsOptions::iterations).
+    pub fn bootstrap_iterations(&self) -> usize {
+        self.bootstrap_iterations
+    }
+
+    /// Seed for the randomized bounds (spread, disparity); `None` uses system
+    /// entropy.
+    pub fn seed(&self) -> Option<&str> {
+        self.seed.as_deref()
+    }
+
+    /// Handling of NaN and infinite values entering a sample.
+    pub fn non_finite(&self) -> NonFinitePolicy {
+        self.non_finite
+    }
+}
+
+/// The crate defaults: [`DEFAULT_MISRATE`], one replicate, no seed, and
+/// [`NonFinitePolicy::Reject`].
+impl Default for EstimatorConfig {
+    fn default() -> Self {
+        Self {
+            misrate: DEFAULT_MISRATE,
+            bootstrap_iterations: 1,
+            seed: None,
+            non_finite: NonFinitePolicy::Reject,
+        }
+    }
+}
+
+/// Builder for [`EstimatorConfig`].
+#[derive(Debug, Clone, Default)]
+pub struct EstimatorConfigBuilder {
+    config: EstimatorConfig,
+}
+
+impl EstimatorConfigBuilder {
+    /// Sets the misclassification rate.
+    pub fn misrate(mut self, misrate: f64) -> Self {
+        self.config.misrate = misrate;
+        self
+    }
+
+    /// Sets the number of randomized replicates.
+    pub fn bootstrap_iterations(mut self, iterations: usize) -> Self {
+        self.config.bootstrap_iterations = iterations;
+        self
+    }
+
+    /// Sets a deterministic seed for the randomized bounds.
+    pub fn seed(mut self, seed: impl Into<String>) -> Self {
+        self.config.seed = Some(seed.into());
+        self
+    }
+
+    /// Sets the handling of NaN and infinite values entering a sample.
+    pub fn non_finite(mut self, policy: NonFinitePolicy) -> Self {
+        self.config.non_finite = policy;
+        self
+    }
+
+    /// Validates and returns the config.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `domain(misrate)` error if the misrate is NaN or outside
+    /// (0, 0.5], and [`EstimatorError::Other`] if `bootstrap_iterations` is
+    /// zero.
+    pub fn build(self) -> Result<EstimatorConfig, EstimatorError> {
+        let misrate = self.config.misrate;
+        if misrate.is_nan() || misrate <= 0.0 || misrate > 0.5 {
+            return Err(EstimatorError::from(AssumptionError::misrate_out_of_range(
+                misrate,
+            )));
+        }
+        if self.config.bootstrap_iterations == 0 {
+            return Err(EstimatorError::Other(
+                "bootstrap_iterations must be positive".to_string(),
+            ));
+        }
+        Ok(self.config)
+    }
+}
@@ -189,3 +189,126 @@ fn disparity_bounds_misrate_below_min() {
         panic!("Expected AssumptionError::Domain for misrate");
     }
 }
+
+mod opts {
+    use crate::estimators::{
+        disparity_bounds_opts, disparity_bounds_with_seed, raw, DisparityBoundsOptions,
+    };
+    use crate::{Bounds, MeasurementUnit, Sample};
+
+    fn samples() -> (Sample, Sample) {
+        let x = Sample::new((1..=30).map(|i| (i as f64).sqrt() * 3.0).collect()).unwrap();
+        let y = Sample::new((1..=30).map(|i| (i as f64).ln() * 4.0 + 2.0).collect()).unwrap();
+        (x, y)
+    }
+
+    fn opts(iterations: usize) -> DisparityBoundsOptions {
+        DisparityBoundsOptions {
+            iterations,
+            seed: Some("disparity-opts".to_string()),
+            misrate: 0.05,
+        }
+    }
+
+    #[test]
+    fn bounds_match_with_seed_for_any_iterations() {
+        let (x, y) = samples();
+        let expected = disparity_bounds_with_seed(&x, &y, 0.05, "disparity-opts").unwrap();
+        for iterations in [1, 2, 25] {
+            let actual = disparity_bounds_opts(&x, &y, &opts(iterations)).unwrap();
+            assert_eq!(actual.bounds.lower.to_bits(), expected.lower.to_bits());
+            assert_eq!(actual.bounds.upper.to_bits(), expected.upper.to_bits());
+        }
+        let single = disparity_bounds_opts(&x, &y, &opts(1)).unwrap();
+        assert_eq!(single.lower_std_error, None);
+        assert_eq!(single.upper_std_error, None);
+    }
+
+    #[test]
+    fn std_error_stabilizes_with_iterations() {
+        // The seed-to-seed range of the estimated standard error narrows as
+        // iterations grow.
+        let (x, y) = samples();
+        let range = |iterations: usize| {
+            let errors: Vec<f64> = (0..10)
+                .map(|s| {
+                    let o = DisparityBoundsOptions {
+                        seed: Some(format!("seed-{s}")),
+                        ..opts(iterations)
+                    };
+                    disparity_bounds_opts(&x, &y, &o)
+                        .unwrap()
+                        .lower_std_error
+                        .unwrap()
+                })
+                .collect();
+            let max = errors.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            let min = errors.iter().cloned().fold(f64::INFINITY, f64::min);
+            max - min
+        };
+        assert!(range(200) < range(5));
+    }
+
+    #[test]
+    fn seeded_result_is_deterministic() {
+        let (x, y) = samples();
+        let a = disparity_bounds_opts(&x, &y, &opts(50)).unwrap();
+        let b = disparity_bounds_opts(&x, &y, &opts(50)).unwrap();
+        assert_eq!(a.bounds.lower, b.bounds.lower);
+        assert_eq!(a.bounds.upper, b.bounds.upper);
+        assert_eq!(a.lower_std_error, b.lower_std_error);
+        assert_eq!(a.upper_std_error, b.upper_std_error);
+    }
+
+    /// Replicate `i` must equal the serial per-index bounds, so the parallel
+    /// run under the `rayon` feature reproduces a serial loop bit-for-bit.
+    #[test]
+    fn replicates_match_serial_per_index_bounds() {
+        let std_dev = |values: &[f64]| {
+            let k = values.len() as f64;
+            let mean = values.iter().sum::<f64>() / k;
+            let ss: f64 = values.iter().map(|&v| (v - mean) * (v - mean)).sum();
+            (ss / (k - 1.0)).sqrt()
+        };
+        let (x, y) = samples();
+        for seed in ["replicate-1", "replicate-2", "replicate-3"] {
+            for iterations in [2, 7, 64] {
+                let mut lowers = Vec::new();
+                let mut uppers = Vec::new();
+                for i in 0..iterations {
+                    let b = if i == 0 {
+                        disparity_bounds_with_seed(&x, &y, 0.05, seed).unwrap()
+                    } else {
+                        let rb = raw::disparity_bounds_seeded_by_index(
+                            x.values(),
+                            y.values(),
+                            0.05,
+                            seed,
+                            i,
+                            false,
+                        )
+                        .unwrap();
+                        Bounds::new(rb.lower, rb.upper, MeasurementUnit::disparity())
+                    };
+                    lowers.push(b.lower);
+                    uppers.push(b.upper);
+                }
+                let o = DisparityBoundsOptions {
+                    seed: Some(seed.to_string()),
+                    ..opts(iterations)
+                };
+                let actual = disparity_bounds_opts(&x, &y, &o).unwrap();
+                assert_eq!(actual.bounds.lower.to_bits(), lowers[0].to_bits());
+                assert_eq!(actual.bounds.upper.to_bits(), uppers[0].to_bits());
+                assert_eq!(actual.lower_std_error, Some(std_dev(&lowers)));
+                assert_eq!(actual.upper_std_error, Some(std_dev(&uppers)));
+            }
+        }
+    }
+
+    #[test]
+    fn zero_iterations_rejected() {
+        let (x, y) = samples();
+        assert!(disparity_bounds_opts(&x, &y, &opts(0)).is_err());
+    }
+}
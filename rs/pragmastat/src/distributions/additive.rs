@@ -1,8 +1,9 @@
 //! Additive (Normal/Gaussian) distribution.
 
+use crate::gauss_cdf::gauss_cdf;
 use crate::Rng;
 
-use super::{Distribution, SMALLEST_POSITIVE_SUBNORMAL};
+use super::Distribution;
 
 /// Additive (Normal/Gaussian) distribution with given mean and standard deviation.
 ///
@@ -31,28 +32,46 @@ impl Additive {
         assert!(std_dev > 0.0, "std_dev must be positive");
         Self { mean, std_dev }
     }
+
+    /// Draw an antithetic pair `(mean + z * std_dev, mean - z * std_dev)` from
+    /// a single standard normal draw `z`.
+    ///
+    /// The two values are perfectly negatively correlated and average to
+    /// `mean`, which reduces the variance of Monte Carlo estimates of
+    /// monotone functions. `z` is one [`Rng::normal`] draw (two
+    /// `uniform_f64` values), exactly as consumed by [`Distribution::sample`],
+    /// so the first value equals the one `sample` would return and the `rng`
+    /// advances by the same amount as for one ordinary draw.
+    ///
+    /// # Example
+    /// ```
+    /// use pragmastat::{Rng, distributions::Additive};
+    ///
+    /// let mut rng = Rng::from_string("demo-dist-antithetic");
+    /// let (a, b) = Additive::new(10.0, 2.0).sample_antithetic(&mut rng);
+    /// assert!(((a + b) / 2.0 - 10.0).abs() < 1e-12);
+    /// ```
+    pub fn sample_antithetic(&self, rng: &mut Rng) -> (f64, f64) {
+        let z = rng.normal();
+        (self.mean + z * self.std_dev, self.mean - z * self.std_dev)
+    }
 }
 
 impl Distribution for Additive {
     fn sample(&self, rng: &mut Rng) -> f64 {
-        // Box-Muller transform
-        // We use both uniforms each time to maintain determinism across languages
-        let u1 = rng.uniform_f64();
-        let u2 = rng.uniform_f64();
-
-        // Avoid log(0) - use smallest positive subnormal for cross-language consistency
-        let u1 = if u1 == 0.0 {
-            SMALLEST_POSITIVE_SUBNORMAL
-        } else {
-            u1
-        };
+        // Box-Muller transform, shared with `Rng::normal`
+        rng.normal_range(self.mean, self.std_dev)
+    }
 
-        let r = (-2.0 * u1.ln()).sqrt();
-        let theta = 2.0 * std::f64::consts::PI * u2;
+    fn cdf(&self, x: f64) -> f64 {
+        gauss_cdf((x - self.mean) / self.std_dev)
+    }
 
-        // Use the first of the two Box-Muller outputs
-        let z = r * theta.cos();
+    fn mean(&self) -> f64 {
+        self.mean
+    }
 
-        self.mean + z * self.std_dev
+    fn variance(&self) -> f64 {
+        self.std_dev * self.std_dev
     }
 }
@@ -0,0 +1,84 @@
+//! First-order autoregressive series over an innovation distribution.
+
+use crate::assumptions::EstimatorError;
+use crate::Rng;
+
+use super::Distribution;
+
+/// Autocorrelated series: an AR(1) process whose innovations are drawn from
+/// another distribution.
+///
+/// With `m` the median of the innovation distribution, a series of length `n`
+/// consumes exactly `n` innovation draws `e[0..n]`, in order, and is built as
+///
+/// ```text
+/// x[0] = m + (e[0] - m) / sqrt(1 - phi²)
+/// x[t] = m + phi · (x[t-1] - m) + (e[t] - m)
+/// ```
+///
+/// The first value is scaled to the stationary dispersion, so the series starts
+/// in equilibrium rather than drifting into it; with normal innovations its
+/// marginal is exactly the stationary `Additive(m, σ / sqrt(1 - phi²))`. With
+/// symmetric innovations every `x[t]` is symmetric around `m`. With `phi = 0`
+/// the series is (up to rounding) `inner.samples(rng, n)`.
+///
+/// `Ar1` is not a [`Distribution`]: a single draw has no predecessor to depend
+/// on, and the stationary marginal has a closed form only for normal
+/// innovations, so there is no meaningful `sample()`. Draw whole series with
+/// [`Ar1::sample_series`].
+///
+/// # Example
+/// ```
+/// use pragmastat::{Rng, distributions::{Additive, Ar1}};
+///
+/// let mut rng = Rng::from_string("demo-dist-ar1");
+/// let dist = Ar1::new(Box::new(Additive::new(10.0, 1.0)), 0.8).unwrap();
+/// let series = dist.sample_series(&mut rng, 100);
+/// assert_eq!(series.len(), 100);
+/// ```
+pub struct Ar1 {
+    inner: Box<dyn Distribution>,
+    phi: f64,
+    median: f64,
+}
+
+impl Ar1 {
+    /// Create an AR(1) process with autoregressive coefficient `phi` and
+    /// innovations from `inner`.
+    ///
+    /// # Errors
+    /// Returns [`EstimatorError::Other`] if `phi` is not in `(-1, 1)`, where the
+    /// process is not stationary.
+    pub fn new(inner: Box<dyn Distribution>, phi: f64) -> Result<Self, EstimatorError> {
+        if phi.is_nan() || phi.abs() >= 1.0 {
+            return Err(EstimatorError::Other(format!(
+                "phi must be in (-1, 1), got {phi}"
+            )));
+        }
+        let median = inner.quantile(0.5);
+        Ok(Self { inner, phi, median })
+    }
+
+    /// Autoregressive coefficient: the correlation of neighboring values.
+    pub fn phi(&self) -> f64 {
+        self.phi
+    }
+
+    /// Draw a series of `n` values in time order.
+    pub fn sample_series(&self, rng: &mut Rng, n: usize) -> Vec<f64> {
+        let m = self.median;
+        let mut series = Vec::with_capacity(n);
+        let mut previous = 0.0;
+        for t in 0..n {
+            let innovation = self.inner.sample(rng) - m;
+            let deviation = if t == 0 {
+                innovation / (1.0 - self.phi * self.phi).sqrt()
+            } else {
+                self.phi * previous + innovation
+            };
+            series.push(m + deviation);
+            previous = deviation;
+        }
+        series
+    }
+}
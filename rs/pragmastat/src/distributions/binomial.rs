@@ -0,0 +1,74 @@
+//! Binomial distribution.
+
+use crate::Rng;
+
+use super::distribution::discrete_quantile;
+use super::special::regularized_beta;
+use super::Distribution;
+
+/// Binomial distribution: the number of successes in `n` independent trials
+/// with success probability `p`.
+///
+/// Samples are integer counts in `0..=n` returned as `f64`.
+///
+/// To stay deterministic across languages, every implementation draws exactly
+/// `n` uniforms per sample, in order, and counts those with `u < p`. The cost
+/// therefore grows linearly with `n`.
+///
+/// # Example
+/// ```
+/// use pragmastat::{Rng, distributions::{Distribution, Binomial}};
+///
+/// let mut rng = Rng::from_string("demo-dist-binomial");
+/// let dist = Binomial::new(10, 0.3);
+/// let sample = dist.sample(&mut rng);
+/// assert!((0.0..=10.0).contains(&sample));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Binomial {
+    n: u64,
+    p: f64,
+}
+
+impl Binomial {
+    /// Create a new binomial distribution with `n` trials and success probability `p`.
+    ///
+    /// # Panics
+    /// Panics if `p` is not in `[0, 1]`.
+    pub fn new(n: u64, p: f64) -> Self {
+        assert!((0.0..=1.0).contains(&p), "p must be in [0, 1]");
+        Self { n, p }
+    }
+}
+
+impl Distribution for Binomial {
+    fn sample(&self, rng: &mut Rng) -> f64 {
+        let successes = (0..self.n).filter(|_| rng.uniform_f64() < self.p).count();
+        successes as f64
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+        let k = x.floor();
+        let n = self.n as f64;
+        if k >= n {
+            return 1.0;
+        }
+        // P(X <= k) = I_{1-p}(n - k, k + 1)
+        regularized_beta(n - k, k + 1.0, 1.0 - self.p)
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        discrete_quantile(|x| self.cdf(x), p, Some(self.n))
+    }
+
+    fn mean(&self) -> f64 {
+        self.n as f64 * self.p
+    }
+
+    fn variance(&self) -> f64 {
+        self.n as f64 * self.p * (1.0 - self.p)
+    }
+}
@@ -8,7 +8,139 @@ pub trait Distribution {
     fn sample(&self, rng: &mut Rng) -> f64;
 
     /// Generate multiple samples from this distribution.
+    ///
+    /// Same as [`Distribution::sample_n`].
     fn samples(&self, rng: &mut Rng, count: usize) -> Vec<f64> {
-        (0..count).map(|_| self.sample(rng)).collect()
+        self.sample_n(rng, count)
     }
+
+    /// Fill `out` with samples, in order.
+    ///
+    /// Each element is drawn with [`Distribution::sample`], so the values (and
+    /// the `Rng` state afterwards) match calling `sample` in a loop.
+    fn sample_into(&self, rng: &mut Rng, out: &mut [f64]) {
+        for value in out.iter_mut() {
+            *value = self.sample(rng);
+        }
+    }
+
+    /// Generate `n` samples into a new vector; see [`Distribution::sample_into`].
+    fn sample_n(&self, rng: &mut Rng, n: usize) -> Vec<f64> {
+        let mut out = vec![0.0; n];
+        self.sample_into(rng, &mut out);
+        out
+    }
+
+    /// Cumulative distribution function: `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64;
+
+    /// Quantile function (inverse CDF): the smallest `x` with `cdf(x) >= p`.
+    ///
+    /// The default implementation bisects [`Distribution::cdf`] over the whole
+    /// real line (to full `f64` precision) and returns `-inf`/`+inf` for
+    /// `p = 0`/`p = 1`. Implementations override it with a closed form where one
+    /// exists, and to report finite support bounds at `p = 0` or `p = 1`.
+    ///
+    /// # Panics
+    /// Panics if `p` is not in `[0, 1]`.
+    fn quantile(&self, p: f64) -> f64 {
+        bisect_quantile(|x| self.cdf(x), p, f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    /// Theoretical mean. `f64::INFINITY` if the mean diverges and `f64::NAN`
+    /// if it is undefined.
+    fn mean(&self) -> f64;
+
+    /// Theoretical variance. `f64::INFINITY` if the variance is infinite or
+    /// undefined.
+    fn variance(&self) -> f64;
+}
+
+/// Asserts that `p` is a valid probability.
+pub(crate) fn check_probability(p: f64) {
+    assert!((0.0..=1.0).contains(&p), "p must be in [0, 1]");
+}
+
+/// Smallest `x` in `[lower, upper]` with `cdf(x) >= p`, by bisection.
+///
+/// Infinite bounds are replaced by a bracket found by doubling outward from
+/// `±1`. Returns `lower` for `p = 0` and `upper` for `p = 1`.
+pub(crate) fn bisect_quantile(cdf: impl Fn(f64) -> f64, p: f64, lower: f64, upper: f64) -> f64 {
+    check_probability(p);
+    if p == 0.0 {
+        return lower;
+    }
+    if p == 1.0 {
+        return upper;
+    }
+
+    let mut lo = if lower.is_finite() { lower } else { -1.0 };
+    while !lower.is_finite() && cdf(lo) >= p {
+        lo *= 2.0;
+        if lo.is_infinite() {
+            return lo;
+        }
+    }
+    let mut hi = if upper.is_finite() { upper } else { 1.0 };
+    while !upper.is_finite() && cdf(hi) < p {
+        hi *= 2.0;
+        if hi.is_infinite() {
+            return hi;
+        }
+    }
+
+    // Each step halves the bracket; 2100 steps span the full f64 range down to
+    // adjacent representable values.
+    for _ in 0..2100 {
+        let mid = 0.5 * lo + 0.5 * hi;
+        if mid <= lo || mid >= hi {
+            break;
+        }
+        if cdf(mid) >= p {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    hi
+}
+
+/// Smallest integer `k` in `0..=max` with `cdf(k) >= p`, for distributions on
+/// the non-negative integers. Returns `max` (or `+inf` when unbounded) for `p = 1`.
+pub(crate) fn discrete_quantile(cdf: impl Fn(f64) -> f64, p: f64, max: Option<u64>) -> f64 {
+    check_probability(p);
+    if p == 1.0 {
+        return max.map_or(f64::INFINITY, |m| m as f64);
+    }
+    if cdf(0.0) >= p {
+        return 0.0;
+    }
+
+    // cdf(lo) < p <= cdf(hi)
+    let mut lo: u64 = 0;
+    let mut hi: u64 = 1;
+    while cdf(hi as f64) < p {
+        if let Some(m) = max {
+            if hi >= m {
+                return m as f64;
+            }
+        }
+        if hi == u64::MAX {
+            return f64::INFINITY;
+        }
+        lo = hi;
+        hi = hi.saturating_mul(2);
+        if let Some(m) = max {
+            hi = hi.min(m);
+        }
+    }
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if cdf(mid as f64) >= p {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    hi as f64
 }
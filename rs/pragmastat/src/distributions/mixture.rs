@@ -0,0 +1,121 @@
+//! Mixture distribution.
+
+use crate::Rng;
+
+use super::Distribution;
+
+/// Finite mixture of distributions with fixed weights.
+///
+/// Each draw consumes `Rng` values in this order:
+/// 1. one `uniform_f64()` value `u` selects the component: the first index `i`
+///    with `u < w[0] + ... + w[i]`, using the normalized weights (the last
+///    component with a positive weight if rounding leaves `u` above every
+///    cumulative sum);
+/// 2. the selected component then draws its sample as usual.
+///
+/// Components with zero weight are never selected.
+///
+/// # Example
+/// ```
+/// use pragmastat::{Rng, distributions::{Additive, Distribution, Mixture}};
+///
+/// let mut rng = Rng::from_string("demo-dist-mixture");
+/// let dist = Mixture::new(
+///     vec![Box::new(Additive::new(10.0, 1.0)), Box::new(Additive::new(50.0, 5.0))],
+///     vec![0.9, 0.1],
+/// );
+/// let sample = dist.sample(&mut rng);
+/// assert!(sample.is_finite());
+/// ```
+pub struct Mixture {
+    components: Vec<Box<dyn Distribution>>,
+    weights: Vec<f64>,
+    cumulative: Vec<f64>,
+}
+
+impl Mixture {
+    /// Create a mixture of `components` with the given relative `weights`.
+    ///
+    /// Weights are normalized to sum to one.
+    ///
+    /// # Panics
+    /// Panics if there are no components, if `components` and `weights` differ
+    /// in length, if any weight is negative or not finite, or if all weights
+    /// are zero.
+    pub fn new(components: Vec<Box<dyn Distribution>>, weights: Vec<f64>) -> Self {
+        assert!(!components.is_empty(), "components must not be empty");
+        assert_eq!(
+            components.len(),
+            weights.len(),
+            "components and weights must have the same length"
+        );
+        assert!(
+            weights.iter().all(|w| w.is_finite() && *w >= 0.0),
+            "weights must be non-negative and finite"
+        );
+        let total: f64 = weights.iter().sum();
+        assert!(total > 0.0, "weights must not all be zero");
+
+        let weights: Vec<f64> = weights.iter().map(|w| w / total).collect();
+        let cumulative = weights
+            .iter()
+            .scan(0.0, |acc, w| {
+                *acc += w;
+                Some(*acc)
+            })
+            .collect();
+        Self {
+            components,
+            weights,
+            cumulative,
+        }
+    }
+
+    /// Normalized component weights (summing to one).
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    /// Index of the component selected by `u` in `[0, 1)`.
+    fn select(&self, u: f64) -> usize {
+        self.cumulative
+            .iter()
+            .position(|&c| u < c)
+            .unwrap_or_else(|| self.weights.iter().rposition(|&w| w > 0.0).unwrap())
+    }
+}
+
+impl Distribution for Mixture {
+    fn sample(&self, rng: &mut Rng) -> f64 {
+        let index = self.select(rng.uniform_f64());
+        self.components[index].sample(rng)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        self.components
+            .iter()
+            .zip(&self.weights)
+            .map(|(c, w)| w * c.cdf(x))
+            .sum()
+    }
+
+    fn mean(&self) -> f64 {
+        self.components
+            .iter()
+            .zip(&self.weights)
+            .filter(|(_, w)| **w > 0.0)
+            .map(|(c, w)| w * c.mean())
+            .sum()
+    }
+
+    fn variance(&self) -> f64 {
+        // Law of total variance: E[Var] + Var[E]
+        let mean = self.mean();
+        self.components
+            .iter()
+            .zip(&self.weights)
+            .filter(|(_, w)| **w > 0.0)
+            .map(|(c, w)| w * (c.variance() + (c.mean() - mean).powi(2)))
+            .sum()
+    }
+}
@@ -1,14 +1,35 @@
 //! Statistical distributions for sampling
 //!
-//! This module provides five distributions for generating random samples:
+//! This module provides twelve distributions for generating random samples:
 //! - [`Uniform`]: uniform distribution on a bounded interval
+//! - [`UniformInt`]: discrete uniform distribution on an integer range
 //! - [`Additive`]: normal (Gaussian) distribution
 //! - [`Multiplic`]: log-normal distribution
 //! - [`Exp`]: exponential distribution
+//! - [`Gamma`]: gamma distribution
+//! - [`ChiSquared`]: chi-squared distribution (a special case of [`Gamma`])
 //! - [`Power`]: Pareto (power-law) distribution
+//! - [`StudentT`]: Student's t distribution (heavy-tailed)
+//! - [`Poisson`]: Poisson distribution (integer counts)
+//! - [`Binomial`]: binomial distribution (integer counts)
+//! - [`Mixture`]: weighted mixture of other distributions (e.g. multimodal data)
+//!
+//! Correlated pairs come from two bivariate types with a `sample_pair` method
+//! (they are not [`Distribution`]s, which draw single values):
+//! - [`BivariateAdditive`]: bivariate normal with correlation `rho`
+//! - [`GaussianCopula`]: any two marginal distributions joined by a Gaussian copula
+//!
+//! Autocorrelated series (e.g. benchmark timelines with drift) come from
+//! [`Ar1`], whose `sample_series` method draws a whole AR(1) series.
 //!
 //! All distributions produce identical sequences across all Pragmastat language
 //! implementations when using the same seed.
+//!
+//! Every distribution also exposes its CDF and quantile function
+//! ([`Distribution::cdf`], [`Distribution::quantile`]) for goodness-of-fit
+//! checks. Quantiles without a closed form fall back to bisection on the CDF.
+//! [`Distribution::mean`] and [`Distribution::variance`] give the theoretical
+//! moments in closed form.
 
 /// Machine epsilon for IEEE 754 double-precision (binary64).
 ///
@@ -33,21 +54,42 @@ const MACHINE_EPSILON: f64 = 2.220446049250313e-16;
 /// Used to avoid log(0) in Box-Muller transform when uniform_f64() returns exactly 0.
 /// All language implementations use this same value to ensure cross-language
 /// determinism in distribution sampling.
-const SMALLEST_POSITIVE_SUBNORMAL: f64 = 5e-324;
+pub(crate) const SMALLEST_POSITIVE_SUBNORMAL: f64 = 5e-324;
 
 mod additive;
+mod ar1;
+mod binomial;
+mod bivariate_additive;
+mod chi_squared;
 mod distribution;
 mod exp;
+mod gamma;
+mod gaussian_copula;
+mod mixture;
 mod multiplic;
+mod poisson;
 mod power;
+mod special;
+mod studentt;
 mod uniform;
+mod uniform_int;
 
 pub use additive::Additive;
+pub use ar1::Ar1;
+pub use binomial::Binomial;
+pub use bivariate_additive::BivariateAdditive;
+pub use chi_squared::ChiSquared;
 pub use distribution::Distribution;
 pub use exp::Exp;
+pub use gamma::Gamma;
+pub use gaussian_copula::GaussianCopula;
+pub use mixture::Mixture;
 pub use multiplic::Multiplic;
+pub use poisson::Poisson;
 pub use power::Power;
+pub use studentt::StudentT;
 pub use uniform::Uniform;
+pub use uniform_int::UniformInt;
 
 #[cfg(test)]
 mod tests {
@@ -60,8 +102,27 @@ mod tests {
         let dist = Uniform::new(5.0, 10.0);
         for _ in 0..100 {
             let x = dist.sample(&mut rng);
-            assert!(x >= 5.0 && x < 10.0);
+            assert!((5.0..10.0).contains(&x));
+        }
+    }
+
+    #[test]
+    fn uniform_int_matches_rng() {
+        let dist = UniformInt::new(-50, 50);
+        let mut rng = Rng::from_string("test-dist-uniform-int");
+        let mut expected = Rng::from_string("test-dist-uniform-int");
+        for _ in 0..1000 {
+            let x = dist.sample(&mut rng);
+            assert_eq!(x, expected.uniform_i64(-50, 50) as f64);
+            assert!((-50.0..50.0).contains(&x));
         }
+        assert_eq!(UniformInt::new(7, 8).sample(&mut rng), 7.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "min must be less than max")]
+    fn uniform_int_rejects_empty_range() {
+        UniformInt::new(3, 3);
     }
 
     #[test]
@@ -74,6 +135,23 @@ mod tests {
         assert!((mean - 100.0).abs() < 1.0);
     }
 
+    #[test]
+    fn additive_antithetic_pairs() {
+        let mut rng = Rng::from_string("test-dist-antithetic");
+        let mut replay = rng.clone();
+        let dist = Additive::new(100.0, 10.0);
+        let mut sum = 0.0;
+        for _ in 0..1000 {
+            let (a, b) = dist.sample_antithetic(&mut rng);
+            assert!(((a + b) / 2.0 - 100.0).abs() < 1e-12);
+            // The first value is the ordinary draw, and one draw is consumed
+            assert_eq!(a, dist.sample(&mut replay));
+            sum += a + b;
+        }
+        assert!((sum / 2000.0 - 100.0).abs() < 1e-12);
+        assert_eq!(rng.uniform_f64(), replay.uniform_f64());
+    }
+
     #[test]
     fn multiplic_positive() {
         let mut rng = Rng::from_string("test-dist-multiplic");
@@ -84,6 +162,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn multiplic_median_and_geometric_mean() {
+        let mut rng = Rng::from_string("test-dist-multiplic-median");
+        let dist = Multiplic::new(1.5, 0.8);
+        assert_eq!(dist.median(), 1.5f64.exp());
+        assert_eq!(dist.geometric_mean(), dist.median());
+        assert!(dist.geometric_mean() < dist.mean());
+
+        let mut samples = dist.samples(&mut rng, 50_000);
+        let n = samples.len() as f64;
+        let geometric_mean = (samples.iter().map(|x| x.ln()).sum::<f64>() / n).exp();
+        samples.sort_by(f64::total_cmp);
+        let median = samples[samples.len() / 2];
+        // Both relative errors have a standard error of about 0.8 / sqrt(n)
+        assert!(
+            (geometric_mean / dist.geometric_mean() - 1.0).abs() < 0.02,
+            "geometric mean {geometric_mean}"
+        );
+        assert!(
+            (median / dist.median() - 1.0).abs() < 0.025,
+            "median {median}"
+        );
+    }
+
     #[test]
     fn exp_positive() {
         let mut rng = Rng::from_string("test-dist-exp");
@@ -94,6 +196,474 @@ mod tests {
         }
     }
 
+    #[test]
+    fn exp_arrival_times() {
+        let dist = Exp::new(4.0);
+        let mut rng = Rng::from_string("test-dist-exp-arrivals");
+        let arrivals = dist.arrival_times(&mut rng, 250.0);
+        // The count is Poisson(rate * horizon = 1000), sd ~ 32
+        assert!((arrivals.len() as f64 - 1000.0).abs() < 150.0);
+        assert!(arrivals.iter().all(|&t| t > 0.0 && t <= 250.0));
+
+        // Same values and rng state as composing `sample` by hand
+        let mut rng = Rng::from_string("test-dist-exp-arrivals");
+        let mut t = 0.0;
+        for &arrival in &arrivals {
+            t += dist.sample(&mut rng);
+            assert_eq!(arrival, t);
+        }
+        assert!(t + dist.sample(&mut rng) > 250.0);
+
+        let mut rng = Rng::from_string("test-dist-exp-arrivals");
+        assert!(Exp::new(1e-9).arrival_times(&mut rng, 0.0).is_empty());
+    }
+
+    #[test]
+    fn gamma_basic() {
+        let mut rng = Rng::from_string("test-dist-gamma");
+        let dist = Gamma::new(3.0, 2.0);
+        let samples: Vec<f64> = (0..5000).map(|_| dist.sample(&mut rng)).collect();
+        let n = samples.len() as f64;
+        let mean: f64 = samples.iter().sum::<f64>() / n;
+        let variance: f64 = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        // Mean = shape / rate = 1.5, variance = shape / rate^2 = 0.75
+        assert!((mean - 1.5).abs() < 0.05);
+        assert!((variance - 0.75).abs() < 0.07);
+    }
+
+    #[test]
+    fn gamma_small_shape_positive() {
+        let mut rng = Rng::from_string("test-dist-gamma-small");
+        let dist = Gamma::new(0.3, 1.0);
+        for _ in 0..100 {
+            assert!(dist.sample(&mut rng) >= 0.0);
+        }
+    }
+
+    #[test]
+    fn chi_squared_matches_gamma() {
+        let mut rng1 = Rng::from_string("test-dist-chisquared");
+        let mut rng2 = rng1.clone();
+        let chi = ChiSquared::new(5.0);
+        let gamma = Gamma::new(2.5, 0.5);
+        for _ in 0..100 {
+            assert_eq!(chi.sample(&mut rng1), gamma.sample(&mut rng2));
+        }
+    }
+
+    #[test]
+    fn poisson_mean_matches_lambda() {
+        for (lambda, tolerance) in [(0.5, 0.05), (4.0, 0.1), (75.0, 0.5)] {
+            let mut rng = Rng::from_string("test-dist-poisson");
+            let dist = Poisson::new(lambda);
+            let samples: Vec<f64> = (0..4000).map(|_| dist.sample(&mut rng)).collect();
+            assert!(samples.iter().all(|x| *x >= 0.0 && x.fract() == 0.0));
+            let mean: f64 = samples.iter().sum::<f64>() / samples.len() as f64;
+            assert!((mean - lambda).abs() < tolerance, "lambda={lambda}: {mean}");
+        }
+    }
+
+    #[test]
+    fn binomial_mean_matches_np() {
+        let mut rng = Rng::from_string("test-dist-binomial");
+        let dist = Binomial::new(20, 0.3);
+        let samples: Vec<f64> = (0..4000).map(|_| dist.sample(&mut rng)).collect();
+        assert!(samples
+            .iter()
+            .all(|x| (0.0..=20.0).contains(x) && x.fract() == 0.0));
+        let mean: f64 = samples.iter().sum::<f64>() / samples.len() as f64;
+        assert!((mean - 6.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn binomial_degenerate_probabilities() {
+        let mut rng = Rng::from_string("test-dist-binomial-degenerate");
+        assert_eq!(Binomial::new(7, 0.0).sample(&mut rng), 0.0);
+        assert_eq!(Binomial::new(7, 1.0).sample(&mut rng), 7.0);
+        assert_eq!(Binomial::new(0, 0.5).sample(&mut rng), 0.0);
+    }
+
+    const PROBABILITIES: [f64; 9] = [1e-6, 0.001, 0.025, 0.1, 0.5, 0.9, 0.975, 0.999, 1.0 - 1e-6];
+
+    #[test]
+    fn moments_match_empirical_estimates() {
+        let cases: Vec<(&str, Box<dyn Distribution>)> = vec![
+            ("uniform", Box::new(Uniform::new(-2.0, 5.0))),
+            ("additive", Box::new(Additive::new(10.0, 3.0))),
+            ("multiplic", Box::new(Multiplic::new(1.0, 0.5))),
+            ("exp", Box::new(Exp::new(2.0))),
+            ("gamma", Box::new(Gamma::new(2.5, 0.5))),
+            ("chisquared", Box::new(ChiSquared::new(4.0))),
+            ("power", Box::new(Power::new(1.0, 6.0))),
+            ("studentt", Box::new(StudentT::new(10.0))),
+            ("poisson", Box::new(Poisson::new(4.5))),
+            ("binomial", Box::new(Binomial::new(40, 0.3))),
+            ("uniform-int", Box::new(UniformInt::new(-10, 25))),
+        ];
+        for (name, dist) in cases {
+            let mut rng = Rng::from_string(&format!("test-dist-moments-{name}"));
+            let samples = dist.samples(&mut rng, 50_000);
+            let n = samples.len() as f64;
+            let mean = samples.iter().sum::<f64>() / n;
+            let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+            let standard_error = (dist.variance() / n).sqrt();
+            assert!(
+                (mean - dist.mean()).abs() < 5.0 * standard_error,
+                "{name}: mean {mean} vs {}",
+                dist.mean()
+            );
+            assert!(
+                (variance / dist.variance() - 1.0).abs() < 0.05,
+                "{name}: variance {variance} vs {}",
+                dist.variance()
+            );
+        }
+    }
+
+    #[test]
+    fn infinite_and_undefined_moments() {
+        assert_eq!(Power::new(1.0, 1.0).mean(), f64::INFINITY);
+        assert_eq!(Power::new(1.0, 2.0).mean(), 2.0);
+        assert_eq!(Power::new(1.0, 2.0).variance(), f64::INFINITY);
+        assert!(StudentT::new(1.0).mean().is_nan());
+        assert_eq!(StudentT::new(2.0).mean(), 0.0);
+        assert_eq!(StudentT::new(2.0).variance(), f64::INFINITY);
+        assert_eq!(StudentT::new(3.0).variance(), 3.0);
+    }
+
+    #[test]
+    fn power_heavy_tails_still_sample() {
+        for shape in [0.3, 1.0, 1.5, 2.0] {
+            let mut rng = Rng::from_string("test-dist-power-heavy");
+            let dist = Power::new(2.0, shape);
+            let mut samples = dist.samples(&mut rng, 20_000);
+            assert!(samples.iter().all(|x| x.is_finite() && *x >= 2.0));
+            // The median exists for every shape even when the mean does not
+            samples.sort_by(f64::total_cmp);
+            let median = samples[samples.len() / 2];
+            let p = dist.cdf(median);
+            assert!(
+                (p - 0.5).abs() < 0.015,
+                "shape = {shape}: cdf(median) = {p}"
+            );
+            assert_eq!(dist.has_finite_mean(), shape > 1.0);
+            assert!(!dist.has_finite_variance());
+        }
+        assert!(Power::new(1.0, 2.5).has_finite_variance());
+    }
+
+    #[test]
+    fn power_new_checked_validates_parameters() {
+        for (min, shape) in [
+            (1.0, 0.0),
+            (1.0, -1.0),
+            (1.0, f64::NAN),
+            (1.0, f64::INFINITY),
+            (0.0, 2.0),
+            (-1.0, 2.0),
+            (f64::INFINITY, 2.0),
+        ] {
+            assert!(
+                Power::new_checked(min, shape).is_err(),
+                "min = {min}, shape = {shape}"
+            );
+        }
+        let heavy = Power::new_checked(1.0, 0.5).unwrap();
+        assert_eq!((heavy.min(), heavy.shape()), (1.0, 0.5));
+        assert_eq!(heavy.mean(), f64::INFINITY);
+    }
+
+    #[test]
+    fn mixture_bimodal() {
+        let mut rng = Rng::from_string("test-dist-mixture");
+        let dist = Mixture::new(
+            vec![
+                Box::new(Additive::new(0.0, 1.0)),
+                Box::new(Additive::new(20.0, 1.0)),
+            ],
+            vec![1.0, 3.0],
+        );
+        assert_eq!(dist.weights(), &[0.25, 0.75]);
+        let samples = dist.samples(&mut rng, 10_000);
+        let upper = samples.iter().filter(|&&x| x > 10.0).count() as f64 / 10_000.0;
+        assert!((upper - 0.75).abs() < 0.02, "upper mode fraction {upper}");
+        assert!(samples.iter().all(|&x| !(6.0..14.0).contains(&x)));
+        assert_eq!(dist.mean(), 15.0);
+        assert_eq!(dist.variance(), 1.0 + 0.25 * 0.75 * 400.0);
+        assert!((dist.cdf(10.0) - 0.25).abs() < 1e-12);
+    }
+
+    #[test]
+    fn mixture_draw_order() {
+        // One uniform selects the component, then the component draws
+        let dist = Mixture::new(
+            vec![Box::new(Uniform::new(0.0, 1.0)), Box::new(Exp::new(1.0))],
+            vec![0.5, 0.5],
+        );
+        let mut rng = Rng::from_string("test-dist-mixture-order");
+        let mut replay = rng.clone();
+        for _ in 0..50 {
+            let actual = dist.sample(&mut rng);
+            let expected = if replay.uniform_f64() < 0.5 {
+                Uniform::new(0.0, 1.0).sample(&mut replay)
+            } else {
+                Exp::new(1.0).sample(&mut replay)
+            };
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn mixture_skips_zero_weight_components() {
+        let mut rng = Rng::from_string("test-dist-mixture-zero");
+        let dist = Mixture::new(
+            vec![
+                Box::new(Uniform::new(0.0, 1.0)),
+                Box::new(Uniform::new(5.0, 6.0)),
+            ],
+            vec![0.0, 2.0],
+        );
+        assert!(dist.samples(&mut rng, 100).iter().all(|&x| x >= 5.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "components and weights must have the same length")]
+    fn mixture_rejects_mismatched_lengths() {
+        Mixture::new(vec![Box::new(Exp::new(1.0))], vec![0.5, 0.5]);
+    }
+
+    fn pearson(x: &[f64], y: &[f64]) -> f64 {
+        let n = x.len() as f64;
+        let (mx, my) = (x.iter().sum::<f64>() / n, y.iter().sum::<f64>() / n);
+        let sxy: f64 = x.iter().zip(y).map(|(a, b)| (a - mx) * (b - my)).sum();
+        let sxx: f64 = x.iter().map(|a| (a - mx).powi(2)).sum();
+        let syy: f64 = y.iter().map(|b| (b - my).powi(2)).sum();
+        sxy / (sxx * syy).sqrt()
+    }
+
+    #[test]
+    fn bivariate_additive_correlation() {
+        for rho in [-0.9, -0.3, 0.0, 0.5, 0.95] {
+            let mut rng = Rng::from_string("test-dist-bivariate");
+            let dist = BivariateAdditive::new(10.0, -5.0, 2.0, 0.5, rho).unwrap();
+            let (x, y) = dist.sample_pairs(&mut rng, 20_000);
+            assert!((pearson(&x, &y) - rho).abs() < 0.02, "rho = {rho}");
+            let mean_y = y.iter().sum::<f64>() / y.len() as f64;
+            assert!((mean_y + 5.0).abs() < 0.02);
+        }
+    }
+
+    #[test]
+    fn bivariate_additive_draw_order() {
+        let dist = BivariateAdditive::new(1.0, 2.0, 3.0, 4.0, 0.6).unwrap();
+        let mut rng = Rng::from_seed(1729);
+        let mut reference = Rng::from_seed(1729);
+        for _ in 0..10 {
+            let (x, y) = dist.sample_pair(&mut rng);
+            let z1 = reference.normal();
+            let z2 = reference.normal();
+            assert_eq!(x, 1.0 + 3.0 * z1);
+            assert_eq!(y, 2.0 + 4.0 * (0.6 * z1 + (1.0f64 - 0.36).sqrt() * z2));
+        }
+        // Perfect correlation makes y an affine function of x
+        let perfect = BivariateAdditive::new(0.0, 0.0, 1.0, 2.0, 1.0).unwrap();
+        let (x, y) = perfect.sample_pair(&mut rng);
+        assert_eq!(y, 2.0 * x);
+    }
+
+    #[test]
+    fn bivariate_rejects_invalid_parameters() {
+        assert!(BivariateAdditive::new(0.0, 0.0, 1.0, 1.0, 1.5).is_err());
+        assert!(BivariateAdditive::new(0.0, 0.0, 0.0, 1.0, 0.5).is_err());
+        assert!(BivariateAdditive::new(0.0, 0.0, 1.0, -1.0, 0.5).is_err());
+        assert!(BivariateAdditive::new(f64::NAN, 0.0, 1.0, 1.0, 0.5).is_err());
+        assert!(BivariateAdditive::new(0.0, 0.0, 1.0, 1.0, f64::NAN).is_err());
+        let marginal = || -> Box<dyn Distribution> { Box::new(Exp::new(1.0)) };
+        assert!(GaussianCopula::new(marginal(), marginal(), -1.01).is_err());
+    }
+
+    #[test]
+    fn gaussian_copula_rank_correlation() {
+        // With uniform marginals the Pearson correlation equals Spearman's rho,
+        // which is (6 / pi) * asin(rho / 2) for a Gaussian copula.
+        for rho in [-0.7, 0.0, 0.4, 0.9] {
+            let mut rng = Rng::from_string("test-dist-copula");
+            let dist = GaussianCopula::new(
+                Box::new(Uniform::new(0.0, 1.0)),
+                Box::new(Uniform::new(0.0, 1.0)),
+                rho,
+            )
+            .unwrap();
+            let (x, y) = dist.sample_pairs(&mut rng, 20_000);
+            let expected = 6.0 / std::f64::consts::PI * (rho / 2.0).asin();
+            assert!((pearson(&x, &y) - expected).abs() < 0.02, "rho = {rho}");
+        }
+    }
+
+    #[test]
+    fn ar1_autocorrelation_and_stationary_spread() {
+        for phi in [-0.5, 0.0, 0.8] {
+            let mut rng = Rng::from_string("test-dist-ar1");
+            let dist = Ar1::new(Box::new(Additive::new(10.0, 2.0)), phi).unwrap();
+            let x = dist.sample_series(&mut rng, 50_000);
+            let mean = x.iter().sum::<f64>() / x.len() as f64;
+            let var = x.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / x.len() as f64;
+            assert!((mean - 10.0).abs() < 0.1, "phi = {phi}: mean {mean}");
+            let expected_var = 4.0 / (1.0 - phi * phi);
+            assert!(
+                (var / expected_var - 1.0).abs() < 0.05,
+                "phi = {phi}: var {var}"
+            );
+            assert!((pearson(&x[..x.len() - 1], &x[1..]) - phi).abs() < 0.02);
+        }
+    }
+
+    #[test]
+    fn ar1_consumes_one_innovation_per_value() {
+        let inner = Exp::new(1.0);
+        let mut rng = Rng::from_string("test-dist-ar1-draws");
+        let innovations = inner.samples(&mut rng, 20);
+        let next = rng.uniform_f64();
+
+        let mut rng = Rng::from_string("test-dist-ar1-draws");
+        let series = Ar1::new(Box::new(inner), 0.0)
+            .unwrap()
+            .sample_series(&mut rng, 20);
+        assert_eq!(rng.uniform_f64(), next);
+        for (s, e) in series.iter().zip(&innovations) {
+            assert!((s - e).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn ar1_rejects_non_stationary_phi() {
+        for phi in [1.0, -1.0, 1.5, f64::NAN] {
+            assert!(Ar1::new(Box::new(Additive::new(0.0, 1.0)), phi).is_err());
+        }
+    }
+
+    #[test]
+    fn gaussian_copula_keeps_marginals() {
+        let mut rng = Rng::from_string("test-dist-copula-marginals");
+        let dist = GaussianCopula::new(
+            Box::new(Exp::new(2.0)),
+            Box::new(Uniform::new(5.0, 6.0)),
+            0.8,
+        )
+        .unwrap();
+        let (x, y) = dist.sample_pairs(&mut rng, 10_000);
+        assert!(x.iter().all(|v| v.is_finite() && *v >= 0.0));
+        assert!(y.iter().all(|v| (5.0..6.0).contains(v)));
+        let mean_x = x.iter().sum::<f64>() / x.len() as f64;
+        assert!((mean_x - 0.5).abs() < 0.02);
+    }
+
+    #[test]
+    fn sample_into_matches_sample_loop() {
+        let dists: Vec<Box<dyn Distribution>> = vec![
+            Box::new(Additive::new(0.0, 1.0)),
+            Box::new(Gamma::new(0.5, 1.0)),
+            Box::new(Poisson::new(75.0)),
+        ];
+        for dist in dists {
+            let mut rng = Rng::from_string("test-dist-sample-into");
+            let expected: Vec<f64> = (0..100).map(|_| dist.sample(&mut rng)).collect();
+            let next = rng.uniform_f64();
+
+            let mut rng = Rng::from_string("test-dist-sample-into");
+            let mut actual = [0.0; 100];
+            dist.sample_into(&mut rng, &mut actual);
+            assert_eq!(actual.to_vec(), expected);
+            assert_eq!(rng.uniform_f64(), next);
+
+            let mut rng = Rng::from_string("test-dist-sample-into");
+            assert_eq!(dist.sample_n(&mut rng, 100), expected);
+        }
+    }
+
+    fn assert_quantile_roundtrip(name: &str, dist: &dyn Distribution) {
+        for p in PROBABILITIES {
+            let q = dist.quantile(p);
+            let actual = dist.cdf(q);
+            assert!(
+                (actual - p).abs() < 1e-9,
+                "{name}: cdf(quantile({p})) = {actual}"
+            );
+        }
+    }
+
+    #[test]
+    fn continuous_cdf_quantile_roundtrip() {
+        assert_quantile_roundtrip("uniform", &Uniform::new(-3.0, 5.0));
+        assert_quantile_roundtrip("additive", &Additive::new(10.0, 2.0));
+        assert_quantile_roundtrip("multiplic", &Multiplic::new(1.0, 0.5));
+        assert_quantile_roundtrip("exp", &Exp::new(2.0));
+        assert_quantile_roundtrip("power", &Power::new(1.5, 3.0));
+        assert_quantile_roundtrip("gamma", &Gamma::new(2.5, 0.5));
+        assert_quantile_roundtrip("gamma-small", &Gamma::new(0.3, 1.0));
+        assert_quantile_roundtrip("chisquared", &ChiSquared::new(4.0));
+        assert_quantile_roundtrip("studentt", &StudentT::new(3.0));
+        assert_quantile_roundtrip("studentt-cauchy", &StudentT::new(1.0));
+    }
+
+    #[test]
+    fn cdf_known_values() {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-7;
+        assert!(close(Additive::new(0.0, 1.0).cdf(1.959964), 0.975));
+        assert!(close(Exp::new(1.0).cdf(1.0), 1.0 - (-1.0f64).exp()));
+        assert!(close(Gamma::new(1.0, 1.0).cdf(1.0), 1.0 - (-1.0f64).exp()));
+        assert!(close(ChiSquared::new(2.0).cdf(3.0), 1.0 - (-1.5f64).exp()));
+        assert!(close(StudentT::new(1.0).cdf(1.0), 0.75));
+        assert!(close(StudentT::new(1.0).cdf(-1.0), 0.25));
+        assert!(close(Poisson::new(2.0).cdf(1.0), 3.0 * (-2.0f64).exp()));
+        assert!(close(Binomial::new(4, 0.5).cdf(1.0), 5.0 / 16.0));
+        assert_eq!(Uniform::new(0.0, 2.0).cdf(-1.0), 0.0);
+        assert_eq!(Power::new(1.0, 2.0).cdf(0.5), 0.0);
+        assert_eq!(Binomial::new(4, 0.5).cdf(4.0), 1.0);
+    }
+
+    #[test]
+    fn discrete_quantile_is_smallest_count_reaching_p() {
+        let dists: [(&str, &dyn Distribution, f64); 5] = [
+            ("poisson", &Poisson::new(4.5), 0.0),
+            ("poisson-large", &Poisson::new(200.0), 0.0),
+            ("binomial", &Binomial::new(30, 0.2), 0.0),
+            ("uniform-int", &UniformInt::new(-3, 7), -3.0),
+            ("uniform-int-wide", &UniformInt::new(0, 1000), 0.0),
+        ];
+        for (name, dist, min) in dists {
+            for p in PROBABILITIES {
+                let k = dist.quantile(p);
+                assert_eq!(k.fract(), 0.0, "{name}: {k}");
+                assert!(dist.cdf(k) >= p, "{name}: p={p}, k={k}");
+                assert!(k == min || dist.cdf(k - 1.0) < p, "{name}: p={p}, k={k}");
+            }
+        }
+        assert_eq!(Binomial::new(30, 0.2).quantile(1.0), 30.0);
+        let dice = UniformInt::new(1, 7);
+        for k in 1..=6 {
+            assert_eq!(dice.quantile(dice.cdf(k as f64)), k as f64);
+        }
+        assert_eq!(dice.quantile(0.0), 1.0);
+        assert_eq!(dice.quantile(1.0), 6.0);
+    }
+
+    #[test]
+    fn quantile_support_bounds() {
+        assert_eq!(Exp::new(1.0).quantile(0.0), 0.0);
+        assert_eq!(Exp::new(1.0).quantile(1.0), f64::INFINITY);
+        assert_eq!(Gamma::new(2.0, 1.0).quantile(0.0), 0.0);
+        assert_eq!(Power::new(3.0, 2.0).quantile(0.0), 3.0);
+        assert_eq!(Additive::new(0.0, 1.0).quantile(0.0), f64::NEG_INFINITY);
+        assert_eq!(Poisson::new(1.0).quantile(0.0), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "p must be in [0, 1]")]
+    fn quantile_rejects_invalid_probability() {
+        Additive::new(0.0, 1.0).quantile(1.5);
+    }
+
     #[test]
     fn power_bounds() {
         let mut rng = Rng::from_string("test-dist-power");
@@ -103,4 +673,27 @@ mod tests {
             assert!(x >= 5.0);
         }
     }
+
+    #[test]
+    fn studentt_symmetric_heavy_tails() {
+        let mut rng = Rng::from_string("test-dist-studentt");
+        let dist = StudentT::new(3.0);
+        let mut samples: Vec<f64> = (0..2000).map(|_| dist.sample(&mut rng)).collect();
+        assert!(samples.iter().all(|x| x.is_finite()));
+        samples.sort_by(|a, b| a.total_cmp(b));
+        // Median near 0; the 97.5% quantile of t(3) is about 3.18 (normal: 1.96)
+        let median = samples[1000];
+        let q975 = samples[1950];
+        assert!(median.abs() < 0.15);
+        assert!((2.6..3.8).contains(&q975));
+    }
+
+    #[test]
+    fn studentt_small_df_is_finite() {
+        let mut rng = Rng::from_string("test-dist-studentt-small");
+        let dist = StudentT::new(0.5);
+        for _ in 0..100 {
+            assert!(!dist.sample(&mut rng).is_nan());
+        }
+    }
 }
@@ -32,10 +32,45 @@ impl Multiplic {
             additive: Additive::new(log_mean, log_std_dev),
         }
     }
+
+    /// Theoretical median, `exp(log_mean)`.
+    pub fn median(&self) -> f64 {
+        self.additive.mean().exp()
+    }
+
+    /// Theoretical geometric mean, `exp(E[ln X]) = exp(log_mean)`.
+    ///
+    /// Equal to the [`median`](Self::median) for a log-normal distribution;
+    /// both sit below the arithmetic [`mean`](Distribution::mean) by the factor
+    /// `exp(log_std_dev^2 / 2)`.
+    pub fn geometric_mean(&self) -> f64 {
+        self.additive.mean().exp()
+    }
 }
 
 impl Distribution for Multiplic {
     fn sample(&self, rng: &mut Rng) -> f64 {
         self.additive.sample(rng).exp()
     }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            self.additive.cdf(x.ln())
+        }
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        self.additive.quantile(p).exp()
+    }
+
+    fn mean(&self) -> f64 {
+        (self.additive.mean() + 0.5 * self.additive.variance()).exp()
+    }
+
+    fn variance(&self) -> f64 {
+        let log_variance = self.additive.variance();
+        log_variance.exp_m1() * (2.0 * self.additive.mean() + log_variance).exp()
+    }
 }
@@ -0,0 +1,100 @@
+//! Poisson distribution.
+
+use crate::Rng;
+
+use super::distribution::discrete_quantile;
+use super::special::regularized_gamma_q;
+use super::Distribution;
+
+/// Largest per-draw mean handled by a single run of Knuth's algorithm.
+///
+/// Beyond this, `exp(-lambda)` loses too much precision (and the product of
+/// uniforms would need too many terms), so the draw is split into equal chunks.
+const KNUTH_MAX_LAMBDA: f64 = 30.0;
+
+/// Poisson distribution with given mean `lambda`.
+///
+/// Samples are non-negative integer counts returned as `f64`.
+///
+/// Uses Knuth's multiplication method. To stay deterministic across languages,
+/// every implementation follows the same sequence of `Rng` draws:
+///
+/// 1. Let `k = ceil(lambda / 30)` and `mu = lambda / k`
+///    (`k = 1` when `lambda <= 30`).
+/// 2. For each of the `k` chunks, let `limit = exp(-mu)`, `p = 1`, `count = 0`;
+///    repeatedly draw a uniform `u` and set `p = p * u`; while `p > limit`
+///    increment `count` and draw again.
+/// 3. Return the sum of the chunk counts.
+///
+/// The sum of independent Poisson(`mu`) counts is exactly Poisson(`lambda`).
+/// Each chunk consumes `count + 1` uniforms, so the cost grows linearly with
+/// `lambda`.
+///
+/// # Example
+/// ```
+/// use pragmastat::{Rng, distributions::{Distribution, Poisson}};
+///
+/// let mut rng = Rng::from_string("demo-dist-poisson");
+/// let dist = Poisson::new(4.0);
+/// let sample = dist.sample(&mut rng);
+/// assert!(sample >= 0.0 && sample.fract() == 0.0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Poisson {
+    lambda: f64,
+}
+
+impl Poisson {
+    /// Create a new Poisson distribution with mean `lambda`.
+    ///
+    /// # Panics
+    /// Panics if `lambda <= 0` or `lambda` is not finite.
+    pub fn new(lambda: f64) -> Self {
+        assert!(
+            lambda > 0.0 && lambda.is_finite(),
+            "lambda must be positive and finite"
+        );
+        Self { lambda }
+    }
+}
+
+/// Knuth's multiplication method for a single Poisson(`mu`) draw.
+fn knuth(mu: f64, rng: &mut Rng) -> u64 {
+    let limit = (-mu).exp();
+    let mut p = rng.uniform_f64();
+    let mut count = 0;
+    while p > limit {
+        count += 1;
+        p *= rng.uniform_f64();
+    }
+    count
+}
+
+impl Distribution for Poisson {
+    fn sample(&self, rng: &mut Rng) -> f64 {
+        let chunks = (self.lambda / KNUTH_MAX_LAMBDA).ceil();
+        let mu = self.lambda / chunks;
+        let total: u64 = (0..chunks as u64).map(|_| knuth(mu, rng)).sum();
+        total as f64
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+        // P(X <= k) = Q(k + 1, lambda)
+        regularized_gamma_q(x.floor() + 1.0, self.lambda)
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        discrete_quantile(|x| self.cdf(x), p, None)
+    }
+
+    fn mean(&self) -> f64 {
+        self.lambda
+    }
+
+    fn variance(&self) -> f64 {
+        self.lambda
+    }
+}
@@ -1,13 +1,25 @@
 //! Power (Pareto) distribution.
 
+use crate::assumptions::EstimatorError;
 use crate::Rng;
 
+use super::distribution::check_probability;
 use super::{Distribution, MACHINE_EPSILON};
 
 /// Power (Pareto) distribution with minimum value and shape parameter.
 ///
 /// Follows a power-law distribution where large values are rare but possible.
 ///
+/// # Tail exponent
+///
+/// The tail is `P(X > x) = (min / x)^shape` for `x >= min`, so `shape` is the
+/// tail exponent: the moment `E[X^k]` is finite only for `k < shape`. In
+/// particular, `shape <= 1` has an infinite mean and `shape <= 2` an infinite
+/// variance; [`Distribution::mean`] and [`Distribution::variance`] return
+/// `f64::INFINITY` there. Sampling, the CDF, and quantiles work for any
+/// positive shape, and such heavy tails are exactly where the median-based
+/// estimators stay usable while sample means do not converge.
+///
 /// # Example
 /// ```
 /// use pragmastat::{Rng, distributions::{Distribution, Power}};
@@ -26,6 +38,10 @@ pub struct Power {
 impl Power {
     /// Create a new power (Pareto) distribution.
     ///
+    /// Accepts any positive `shape`, including the heavy-tailed range
+    /// `shape <= 2` where the mean or variance is infinite (see the
+    /// [tail exponent](Power#tail-exponent) notes).
+    ///
     /// # Panics
     /// Panics if `min <= 0` or `shape <= 0`.
     pub fn new(min: f64, shape: f64) -> Self {
@@ -33,6 +49,50 @@ impl Power {
         assert!(shape > 0.0, "shape must be positive");
         Self { min, shape }
     }
+
+    /// Create a new power (Pareto) distribution, returning an error instead of
+    /// panicking on invalid parameters.
+    ///
+    /// Heavy tails (`shape <= 2`) are valid; check
+    /// [`has_finite_mean`](Self::has_finite_mean) or
+    /// [`has_finite_variance`](Self::has_finite_variance) if moments are needed.
+    ///
+    /// # Errors
+    /// Returns [`EstimatorError::Other`] if `min` or `shape` is not positive
+    /// and finite.
+    pub fn new_checked(min: f64, shape: f64) -> Result<Self, EstimatorError> {
+        if !(min.is_finite() && min > 0.0) {
+            return Err(EstimatorError::Other(
+                "min must be positive and finite".to_string(),
+            ));
+        }
+        if !(shape.is_finite() && shape > 0.0) {
+            return Err(EstimatorError::Other(
+                "shape must be positive and finite".to_string(),
+            ));
+        }
+        Ok(Self { min, shape })
+    }
+
+    /// Minimum value (scale).
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// Shape, which is also the tail exponent.
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// True if the mean is finite (`shape > 1`).
+    pub fn has_finite_mean(&self) -> bool {
+        self.shape > 1.0
+    }
+
+    /// True if the variance is finite (`shape > 2`).
+    pub fn has_finite_variance(&self) -> bool {
+        self.shape > 2.0
+    }
 }
 
 impl Distribution for Power {
@@ -43,4 +103,34 @@ impl Distribution for Power {
         let u = if u == 1.0 { 1.0 - MACHINE_EPSILON } else { u };
         self.min / (1.0 - u).powf(1.0 / self.shape)
     }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.min {
+            0.0
+        } else {
+            1.0 - (self.min / x).powf(self.shape)
+        }
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        check_probability(p);
+        self.min / (1.0 - p).powf(1.0 / self.shape)
+    }
+
+    fn mean(&self) -> f64 {
+        if self.shape <= 1.0 {
+            f64::INFINITY
+        } else {
+            self.shape * self.min / (self.shape - 1.0)
+        }
+    }
+
+    fn variance(&self) -> f64 {
+        if self.shape <= 2.0 {
+            f64::INFINITY
+        } else {
+            let a = self.shape;
+            self.min * self.min * a / ((a - 1.0) * (a - 1.0) * (a - 2.0))
+        }
+    }
 }
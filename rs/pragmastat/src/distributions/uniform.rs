@@ -2,6 +2,7 @@
 
 use crate::Rng;
 
+use super::distribution::check_probability;
 use super::Distribution;
 
 /// Uniform distribution on `[min, max)`.
@@ -36,4 +37,22 @@ impl Distribution for Uniform {
     fn sample(&self, rng: &mut Rng) -> f64 {
         rng.uniform_f64().mul_add(self.max - self.min, self.min)
     }
+
+    fn cdf(&self, x: f64) -> f64 {
+        ((x - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        check_probability(p);
+        p.mul_add(self.max - self.min, self.min)
+    }
+
+    fn mean(&self) -> f64 {
+        0.5 * (self.min + self.max)
+    }
+
+    fn variance(&self) -> f64 {
+        let width = self.max - self.min;
+        width * width / 12.0
+    }
 }
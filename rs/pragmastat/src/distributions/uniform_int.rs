@@ -0,0 +1,81 @@
+//! Discrete uniform distribution over an integer range.
+
+use crate::Rng;
+
+use super::distribution::check_probability;
+use super::Distribution;
+
+/// Discrete uniform distribution on the integers `min..max` (`max` excluded).
+///
+/// Each draw is one [`Rng::uniform_i64`] call, so a sequence of draws equals
+/// the `uniform-int` RNG fixtures for the same seed and range. Samples are
+/// returned as `f64` through [`Distribution`]; they are exact as long as the
+/// range stays within ±2^53. [`UniformInt::sample_i64`] returns the integer
+/// itself.
+///
+/// # Example
+/// ```
+/// use pragmastat::{Rng, distributions::{Distribution, UniformInt}};
+///
+/// let mut rng = Rng::from_string("demo-dist-uniform-int");
+/// let dist = UniformInt::new(1, 7);  // a die
+/// let sample = dist.sample(&mut rng);
+/// assert!((1.0..=6.0).contains(&sample) && sample.fract() == 0.0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct UniformInt {
+    min: i64,
+    max: i64,
+}
+
+impl UniformInt {
+    /// Create a new discrete uniform distribution on `min..max`.
+    ///
+    /// # Panics
+    /// Panics if `min >= max` or if `max - min` overflows `i64`.
+    pub fn new(min: i64, max: i64) -> Self {
+        assert!(min < max, "min must be less than max");
+        assert!(max.checked_sub(min).is_some(), "max - min must fit in i64");
+        Self { min, max }
+    }
+
+    /// Draw one value as an integer; [`Distribution::sample`] is this value
+    /// converted to `f64`.
+    pub fn sample_i64(&self, rng: &mut Rng) -> i64 {
+        rng.uniform_i64(self.min, self.max)
+    }
+
+    fn width(&self) -> f64 {
+        self.max.abs_diff(self.min) as f64
+    }
+}
+
+impl Distribution for UniformInt {
+    fn sample(&self, rng: &mut Rng) -> f64 {
+        self.sample_i64(rng) as f64
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        ((x.floor() - self.min as f64 + 1.0) / self.width()).clamp(0.0, 1.0)
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        check_probability(p);
+        // Smallest k with (k + 1) / width >= p; the cdf check undoes a
+        // rounding-up of p * width past an exact multiple
+        let mut k = ((p * self.width()).ceil() - 1.0).max(0.0);
+        if k > 0.0 && self.cdf(self.min as f64 + k - 1.0) >= p {
+            k -= 1.0;
+        }
+        self.min as f64 + k
+    }
+
+    fn mean(&self) -> f64 {
+        0.5 * (self.min as f64 + self.max as f64 - 1.0)
+    }
+
+    fn variance(&self) -> f64 {
+        let width = self.width();
+        (width * width - 1.0) / 12.0
+    }
+}
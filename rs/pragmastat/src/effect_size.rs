@@ -0,0 +1,198 @@
+//! Effect-size conversions between Disparity and common scales.
+//!
+//! Two kinds of helpers live here:
+//!
+//! - **Sample-based estimators** computed directly from pairwise comparisons,
+//!   with no distributional assumptions: [`cliffs_delta`] and
+//!   [`probability_of_superiority`].
+//! - **Pure scale conversions** between Disparity, Cohen's d, the probability of
+//!   superiority, and Cliff's delta. These are exact only under the normal
+//!   model: both samples normal with a common standard deviation `σ`. Under
+//!   that model `Spread = √2 · z₀.₇₅ · σ ≈ 0.954 σ` (the median absolute
+//!   difference of two draws) and the probability of superiority is
+//!   `Φ(d / √2)` for Cohen's `d`. For other distributions the conversions are
+//!   approximations whose quality depends on how normal the data look; prefer
+//!   the sample-based estimators when the raw data are available.
+//!
+//! Sign conventions follow the estimators: positive values mean `x` tends to
+//! exceed `y`. Disparity and Cohen's d are unbounded; the probability of
+//! superiority lies in `[0, 1]` and Cliff's delta in `[-1, 1]`.
+
+use crate::assumptions::EstimatorError;
+use crate::distributions::{Additive, Distribution};
+use crate::estimators::raw;
+use crate::measurement::Measurement;
+use crate::measurement_unit::MeasurementUnit;
+use crate::sample::{check_non_weighted, prepare_pair, Sample};
+
+/// Upper quartile of the standard normal distribution, `Φ⁻¹(0.75)`.
+const Z_075: f64 = 0.6744897501960817;
+
+/// Ratio of Spread to the standard deviation under the normal model,
+/// `√2 · Φ⁻¹(0.75)`.
+const NORMAL_SPREAD_TO_SIGMA: f64 = 0.9538725524089398;
+
+// =============================================================================
+// Sample-based estimators
+// =============================================================================
+
+/// Cliff's delta: `P(X > Y) - P(X < Y)`, from all pairwise comparisons.
+///
+/// Equals `2 * dominance - 1`. Exact (distribution-free); lies in `[-1, 1]`.
+///
+/// Returns a [`Measurement`] with the number unit.
+pub fn cliffs_delta(x: &Sample, y: &Sample) -> Result<Measurement, EstimatorError> {
+    let p = probability_of_superiority(x, y)?;
+    Ok(Measurement::new(2.0 * p.value - 1.0, p.unit))
+}
+
+/// Probability of superiority: `P(X > Y)` with ties counted half.
+///
+/// Same value as [`dominance`](crate::estimators::dominance), reported on the
+/// number scale. Exact (distribution-free); lies in `[0, 1]`.
+///
+/// Returns a [`Measurement`] with the number unit.
+pub fn probability_of_superiority(x: &Sample, y: &Sample) -> Result<Measurement, EstimatorError> {
+    check_non_weighted("x", x)?;
+    check_non_weighted("y", y)?;
+    let (x, y) = prepare_pair(x, y)?;
+    let result = raw::dominance(x.sorted_values(), y.sorted_values(), true)?;
+    Ok(Measurement::new(result, MeasurementUnit::number()))
+}
+
+// =============================================================================
+// Scale conversions (exact under the normal model)
+// =============================================================================
+
+/// Converts Disparity to Cohen's d: `d = disparity · √2 · Φ⁻¹(0.75)`.
+///
+/// Exact under the normal model with equal variances.
+pub fn disparity_to_cohens_d(disparity: f64) -> f64 {
+    disparity * NORMAL_SPREAD_TO_SIGMA
+}
+
+/// Converts Cohen's d to Disparity; inverse of [`disparity_to_cohens_d`].
+pub fn cohens_d_to_disparity(cohens_d: f64) -> f64 {
+    cohens_d / NORMAL_SPREAD_TO_SIGMA
+}
+
+/// Converts Disparity to the probability of superiority:
+/// `Φ(disparity · Φ⁻¹(0.75))`.
+///
+/// Exact under the normal model with equal variances; a Disparity of 1 maps to
+/// 0.75 and 0 maps to 0.5.
+pub fn disparity_to_probability_of_superiority(disparity: f64) -> f64 {
+    standard_normal().cdf(disparity * Z_075)
+}
+
+/// Converts the probability of superiority to Disparity; inverse of
+/// [`disparity_to_probability_of_superiority`].
+///
+/// Returns `-inf`/`+inf` for `p = 0`/`p = 1`.
+///
+/// # Panics
+/// Panics if `p` is not in `[0, 1]`.
+pub fn probability_of_superiority_to_disparity(p: f64) -> f64 {
+    standard_normal().quantile(p) / Z_075
+}
+
+/// Converts Disparity to Cliff's delta: `2 · Φ(disparity · Φ⁻¹(0.75)) - 1`.
+///
+/// Exact under the normal model with equal variances.
+pub fn disparity_to_cliffs_delta(disparity: f64) -> f64 {
+    2.0 * disparity_to_probability_of_superiority(disparity) - 1.0
+}
+
+/// Converts Cliff's delta to Disparity; inverse of [`disparity_to_cliffs_delta`].
+///
+/// Returns `-inf`/`+inf` for `delta = -1`/`delta = 1`.
+///
+/// # Panics
+/// Panics if `delta` is not in `[-1, 1]`.
+pub fn cliffs_delta_to_disparity(delta: f64) -> f64 {
+    assert!((-1.0..=1.0).contains(&delta), "delta must be in [-1, 1]");
+    probability_of_superiority_to_disparity((delta + 1.0) / 2.0)
+}
+
+fn standard_normal() -> Additive {
+    Additive::new(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(values: &[f64]) -> Sample {
+        Sample::new(values.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn cliffs_delta_hand_computed() {
+        // x > y in 10 of 12 pairs, one tie (4 vs 4), one loss (3 vs 4):
+        // P(X > Y) = 10.5 / 12, P(X < Y) = 1.5 / 12
+        let x = sample(&[3.0, 4.0, 5.0, 6.0]);
+        let y = sample(&[1.0, 2.0, 4.0]);
+        let p = probability_of_superiority(&x, &y).unwrap();
+        assert_eq!(p.value, 10.5 / 12.0);
+        assert_eq!(p.unit.id(), "number");
+        let delta = cliffs_delta(&x, &y).unwrap();
+        assert_eq!(delta.value, 0.75);
+        assert_eq!(delta.unit.id(), "number");
+        assert_eq!(cliffs_delta(&y, &x).unwrap().value, -0.75);
+    }
+
+    #[test]
+    fn cliffs_delta_extremes() {
+        let low = sample(&[1.0, 2.0]);
+        let high = sample(&[3.0, 4.0, 5.0]);
+        assert_eq!(cliffs_delta(&high, &low).unwrap().value, 1.0);
+        assert_eq!(cliffs_delta(&low, &high).unwrap().value, -1.0);
+        assert_eq!(cliffs_delta(&low, &low).unwrap().value, 0.0);
+    }
+
+    #[test]
+    fn cohens_d_roundtrip() {
+        assert_eq!(disparity_to_cohens_d(0.0), 0.0);
+        assert!((disparity_to_cohens_d(1.0) - 0.9538725524089398).abs() < 1e-15);
+        for d in [-2.0, -0.3, 0.5, 4.0] {
+            assert!((cohens_d_to_disparity(disparity_to_cohens_d(d)) - d).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn probability_of_superiority_known_values() {
+        assert!((disparity_to_probability_of_superiority(0.0) - 0.5).abs() < 1e-12);
+        assert!((disparity_to_probability_of_superiority(1.0) - 0.75).abs() < 1e-7);
+        assert!((disparity_to_probability_of_superiority(-1.0) - 0.25).abs() < 1e-7);
+        // Cohen's d = 0.5 ("medium") gives Φ(0.5 / √2) ≈ 0.6382
+        let disparity = cohens_d_to_disparity(0.5);
+        assert!(
+            (disparity_to_probability_of_superiority(disparity) - 0.6381631950841185).abs() < 1e-7
+        );
+    }
+
+    #[test]
+    fn cliffs_delta_known_values() {
+        assert!(disparity_to_cliffs_delta(0.0).abs() < 1e-12);
+        assert!((disparity_to_cliffs_delta(1.0) - 0.5).abs() < 1e-7);
+        assert!((disparity_to_cliffs_delta(-1.0) + 0.5).abs() < 1e-7);
+    }
+
+    #[test]
+    fn reverse_conversions_roundtrip() {
+        for d in [-3.0, -1.0, -0.2, 0.0, 0.7, 2.5] {
+            let p = disparity_to_probability_of_superiority(d);
+            assert!((probability_of_superiority_to_disparity(p) - d).abs() < 1e-6);
+            let delta = disparity_to_cliffs_delta(d);
+            assert!((cliffs_delta_to_disparity(delta) - d).abs() < 1e-6);
+        }
+        assert_eq!(probability_of_superiority_to_disparity(1.0), f64::INFINITY);
+        assert_eq!(cliffs_delta_to_disparity(-1.0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    #[should_panic(expected = "delta must be in [-1, 1]")]
+    fn cliffs_delta_out_of_range_panics() {
+        cliffs_delta_to_disparity(1.5);
+    }
+}
@@ -0,0 +1,169 @@
+//! EstimateWithBounds: a point estimate paired with its bounds.
+
+use crate::bounds::Bounds;
+use crate::measurement::Measurement;
+use crate::measurement_unit::MeasurementUnit;
+use std::fmt;
+
+/// A point estimate and the bounds around it, in the unit of the bounds.
+///
+/// The bounds are not required to be symmetric around the estimate (spread,
+/// ratio, and disparity bounds usually are not), so the distances to the two
+/// endpoints are reported separately by [`margin_lower`](Self::margin_lower)
+/// and [`margin_upper`](Self::margin_upper).
+///
+/// # Display
+///
+/// The estimate is followed by the signed offsets of the upper and lower bound:
+/// `3.42 ms (+0.31 / −0.28)`. Offsets are rounded to two significant digits of
+/// the smaller non-zero offset, and the estimate to the same decimal place, so
+/// no digit is shown that the bounds do not support. Without a non-zero finite
+/// offset, values are printed unrounded.
+///
+/// # Example
+/// ```
+/// use pragmastat::{Bounds, EstimateWithBounds};
+///
+/// let e = EstimateWithBounds::new(3.4213, Bounds::number(3.1414, 3.7318));
+/// assert_eq!(e.to_string(), "3.42 (+0.31 / −0.28)");
+/// assert!(!e.is_symmetric(0.01));
+/// ```
+#[derive(Debug, Clone)]
+pub struct EstimateWithBounds {
+    pub estimate: f64,
+    pub bounds: Bounds,
+}
+
+impl EstimateWithBounds {
+    /// Creates an estimate with the given bounds; the unit is the bounds' unit.
+    pub fn new(estimate: f64, bounds: Bounds) -> Self {
+        Self { estimate, bounds }
+    }
+
+    /// Creates an estimate with bounds in the default number unit.
+    pub fn number(estimate: f64, lower: f64, upper: f64) -> Self {
+        Self::new(estimate, Bounds::number(lower, upper))
+    }
+
+    /// The unit of the estimate and its bounds.
+    pub fn unit(&self) -> &MeasurementUnit {
+        &self.bounds.unit
+    }
+
+    /// The estimate as a [`Measurement`].
+    pub fn measurement(&self) -> Measurement {
+        Measurement::new(self.estimate, self.bounds.unit.clone())
+    }
+
+    /// Distance from the lower bound up to the estimate, `estimate - lower`.
+    ///
+    /// Negative if the estimate lies below the lower bound.
+    pub fn margin_lower(&self) -> f64 {
+        self.estimate - self.bounds.lower
+    }
+
+    /// Distance from the estimate up to the upper bound, `upper - estimate`.
+    ///
+    /// Negative if the estimate lies above the upper bound.
+    pub fn margin_upper(&self) -> f64 {
+        self.bounds.upper - self.estimate
+    }
+
+    /// Returns true if the two margins differ by at most `tolerance`
+    /// (an absolute difference, in the unit of the estimate).
+    pub fn is_symmetric(&self, tolerance: f64) -> bool {
+        (self.margin_upper() - self.margin_lower()).abs() <= tolerance
+    }
+}
+
+/// Number of decimals that keeps two significant digits of `margin`;
+/// negative when the last kept digit is left of the decimal point.
+fn decimals_for(margin: f64) -> i32 {
+    1 - margin.log10().floor() as i32
+}
+
+/// Formats `value` rounded to `decimals` decimal places (tens, hundreds, ...
+/// for negative `decimals`).
+fn format_rounded(value: f64, decimals: i32) -> String {
+    if decimals >= 0 {
+        format!("{:.*}", decimals as usize, value)
+    } else {
+        let step = 10f64.powi(-decimals);
+        format!("{:.0}", (value / step).round() * step)
+    }
+}
+
+impl fmt::Display for EstimateWithBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let up = self.margin_upper();
+        let down = self.margin_lower();
+        let smallest = [up.abs(), down.abs()]
+            .into_iter()
+            .filter(|m| m.is_finite() && *m > 0.0)
+            .reduce(f64::min);
+        let format = |v: f64| match smallest {
+            Some(margin) if self.estimate.is_finite() => format_rounded(v, decimals_for(margin)),
+            _ => v.to_string(),
+        };
+        let abbr = self.bounds.unit.abbreviation();
+        write!(f, "{}", format(self.estimate))?;
+        if !abbr.is_empty() {
+            write!(f, " {abbr}")?;
+        }
+        let up_sign = if up < 0.0 { '−' } else { '+' };
+        let down_sign = if down < 0.0 { '+' } else { '−' };
+        write!(
+            f,
+            " ({up_sign}{} / {down_sign}{})",
+            format(up.abs()),
+            format(down.abs())
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn margins() {
+        let e = EstimateWithBounds::number(3.0, 1.0, 7.0);
+        assert_eq!(e.margin_lower(), 2.0);
+        assert_eq!(e.margin_upper(), 4.0);
+        assert!(!e.is_symmetric(1.0));
+        assert!(e.is_symmetric(2.0));
+    }
+
+    #[test]
+    fn display_rounds_to_the_smaller_margin() {
+        let e = EstimateWithBounds::number(3.4213, 3.1414, 3.7318);
+        assert_eq!(format!("{e}"), "3.42 (+0.31 / −0.28)");
+        // The larger margin decides nothing: 0.0123 keeps three decimals
+        let e = EstimateWithBounds::number(10.0, 9.9877, 12.5);
+        assert_eq!(format!("{e}"), "10.000 (+2.500 / −0.012)");
+    }
+
+    #[test]
+    fn display_rounds_left_of_the_decimal_point() {
+        let e = EstimateWithBounds::number(51234.0, 50247.0, 52468.0);
+        assert_eq!(format!("{e}"), "51230 (+1230 / −990)");
+    }
+
+    #[test]
+    fn display_with_unit() {
+        let unit = MeasurementUnit::new("ms", "Time", "ms", "Millisecond", 1_000_000);
+        let e = EstimateWithBounds::new(3.4213, Bounds::new(3.1414, 3.7318, unit));
+        assert_eq!(format!("{e}"), "3.42 ms (+0.31 / −0.28)");
+    }
+
+    #[test]
+    fn display_zero_and_outside_margins() {
+        let e = EstimateWithBounds::number(2.0, 2.0, 2.0);
+        assert_eq!(format!("{e}"), "2 (+0 / −0)");
+        let e = EstimateWithBounds::number(2.0, 2.0, 2.5);
+        assert_eq!(format!("{e}"), "2.00 (+0.50 / −0.00)");
+        // An estimate below the lower bound has a positive lower offset
+        let e = EstimateWithBounds::number(1.0, 1.25, 1.5);
+        assert_eq!(format!("{e}"), "1.00 (+0.50 / +0.25)");
+    }
+}
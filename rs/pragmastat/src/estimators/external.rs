@@ -0,0 +1,334 @@
+//! External-memory shift for samples that do not fit in memory together.
+//!
+//! [`shift_external`] reads both samples as [`SortedChunks`]: a sequence of
+//! chunks, each ascending on its own. It never holds more than one chunk of
+//! each sample, and never materializes a pairwise difference.
+//!
+//! # Algorithm
+//!
+//! The same binary search on the value domain as the in-memory
+//! [`shift`](crate::estimators::shift): every step counts the pairs with
+//! `x[i] - y[j] <= t` and the closest differences on either side of `t`. Here
+//! the count is the sum of two-pointer merges over every (x chunk, y chunk)
+//! pair. Counts, minima and maxima combine exactly, so the result is
+//! bit-for-bit the in-memory one, however the samples are chunked.
+//!
+//! # Cost
+//!
+//! A first pass validates both samples and finds their extremes. Each search
+//! step then reads every x chunk once and all of `y` once per x chunk, so one
+//! step reads `n + (n / chunk_x) * m` values and merges `O(n + m)` per chunk
+//! pair. The search takes up to 128 steps per rank (about 60 for typical
+//! data), twice for an even number of pairs. Large x chunks cut the re-reads
+//! of `y` most; memory is one chunk of each sample.
+//!
+//! # Example
+//! ```
+//! use pragmastat::estimators::external::{shift_external, ShiftExternalOptions, SliceChunks};
+//! use pragmastat::estimators::raw;
+//!
+//! let x: Vec<f64> = (0..1000).map(|i| f64::from(i) * 0.5).collect();
+//! let y: Vec<f64> = (0..700).map(|i| f64::from(i) * 0.75 - 20.0).collect();
+//! let external = shift_external(
+//!     SliceChunks::new(&x, 128),
+//!     SliceChunks::new(&y, 100),
+//!     &ShiftExternalOptions::default(),
+//! )
+//! .unwrap();
+//! assert_eq!(external, raw::shift(&x, &y, true).unwrap());
+//! ```
+
+use crate::assumptions::{AssumptionError, EstimatorError, Subject};
+use crate::parallel::Execution;
+use crate::shift_impl::{count_pairs_le, quantile_ranks, select_kth_by_count};
+use std::fs::File;
+use std::io::{BufReader, ErrorKind, Read, Seek, SeekFrom};
+use std::path::Path;
+
+/// Sequential access to a sample stored as ascending chunks.
+///
+/// Chunks are read front to back after each [`rewind`](Self::rewind). Each
+/// chunk must be ascending; the chunks need not be ordered among themselves
+/// (sorted runs of an external sort work as they are).
+pub trait SortedChunks {
+    /// Total number of values over all chunks.
+    fn total_count(&self) -> u64;
+
+    /// Restarts reading from the first chunk.
+    fn rewind(&mut self) -> Result<(), EstimatorError>;
+
+    /// Replaces the contents of `buf` with the next chunk; returns `false`
+    /// (leaving `buf` empty) once every chunk has been read.
+    fn next_chunk(&mut self, buf: &mut Vec<f64>) -> Result<bool, EstimatorError>;
+}
+
+/// An in-memory slice read in consecutive chunks of `chunk_len` values.
+///
+/// Each chunk must be ascending, so a sorted slice always qualifies.
+#[derive(Debug, Clone)]
+pub struct SliceChunks<'a> {
+    values: &'a [f64],
+    chunk_len: usize,
+    position: usize,
+}
+
+impl<'a> SliceChunks<'a> {
+    /// Reads `values` in chunks of `chunk_len` (the last one may be shorter).
+    ///
+    /// # Panics
+    /// Panics if `chunk_len` is zero.
+    pub fn new(values: &'a [f64], chunk_len: usize) -> Self {
+        assert!(chunk_len > 0, "chunk_len must be positive");
+        Self {
+            values,
+            chunk_len,
+            position: 0,
+        }
+    }
+}
+
+impl SortedChunks for SliceChunks<'_> {
+    fn total_count(&self) -> u64 {
+        self.values.len() as u64
+    }
+
+    fn rewind(&mut self) -> Result<(), EstimatorError> {
+        self.position = 0;
+        Ok(())
+    }
+
+    fn next_chunk(&mut self, buf: &mut Vec<f64>) -> Result<bool, EstimatorError> {
+        buf.clear();
+        let end = (self.position + self.chunk_len).min(self.values.len());
+        buf.extend_from_slice(&self.values[self.position..end]);
+        self.position = end;
+        Ok(!buf.is_empty())
+    }
+}
+
+/// Little-endian `f64` values from a seekable reader (usually a file), read
+/// in consecutive chunks of `chunk_len` values.
+///
+/// Each chunk must be ascending: a sorted file, or sorted runs of exactly
+/// `chunk_len` values each.
+#[derive(Debug)]
+pub struct ReaderChunks<R> {
+    reader: R,
+    chunk_len: usize,
+    count: u64,
+    bytes: Vec<u8>,
+}
+
+impl ReaderChunks<BufReader<File>> {
+    /// Opens the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EstimatorError::Other`] if the file cannot be opened or its
+    /// length is not a multiple of 8 bytes.
+    ///
+    /// # Panics
+    /// Panics if `chunk_len` is zero.
+    pub fn open(path: impl AsRef<Path>, chunk_len: usize) -> Result<Self, EstimatorError> {
+        let path = path.as_ref();
+        let file = File::open(path)
+            .map_err(|e| EstimatorError::Other(format!("cannot open {}: {e}", path.display())))?;
+        Self::new(BufReader::new(file), chunk_len)
+    }
+}
+
+impl<R: Read + Seek> ReaderChunks<R> {
+    /// Reads from `reader`, whose length determines the total count.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EstimatorError::Other`] on an I/O error or if the length is
+    /// not a multiple of 8 bytes.
+    ///
+    /// # Panics
+    /// Panics if `chunk_len` is zero.
+    pub fn new(mut reader: R, chunk_len: usize) -> Result<Self, EstimatorError> {
+        assert!(chunk_len > 0, "chunk_len must be positive");
+        let bytes = reader.seek(SeekFrom::End(0)).map_err(io_error)?;
+        if bytes % 8 != 0 {
+            return Err(EstimatorError::Other(format!(
+                "input length {bytes} is not a multiple of 8 bytes"
+            )));
+        }
+        reader.seek(SeekFrom::Start(0)).map_err(io_error)?;
+        Ok(Self {
+            reader,
+            chunk_len,
+            count: bytes / 8,
+            bytes: Vec::new(),
+        })
+    }
+}
+
+impl<R: Read + Seek> SortedChunks for ReaderChunks<R> {
+    fn total_count(&self) -> u64 {
+        self.count
+    }
+
+    fn rewind(&mut self) -> Result<(), EstimatorError> {
+        self.reader.seek(SeekFrom::Start(0)).map_err(io_error)?;
+        Ok(())
+    }
+
+    fn next_chunk(&mut self, buf: &mut Vec<f64>) -> Result<bool, EstimatorError> {
+        buf.clear();
+        self.bytes.resize(8 * self.chunk_len, 0);
+        let mut filled = 0;
+        while filled < self.bytes.len() {
+            match self.reader.read(&mut self.bytes[filled..]) {
+                Ok(0) => break,
+                Ok(k) => filled += k,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(io_error(e)),
+            }
+        }
+        if filled % 8 != 0 {
+            return Err(EstimatorError::Other(format!(
+                "input ends with a partial value ({} of 8 bytes)",
+                filled % 8
+            )));
+        }
+        buf.extend(
+            self.bytes[..filled]
+                .chunks_exact(8)
+                .map(|b| f64::from_le_bytes(b.try_into().expect("8 bytes"))),
+        );
+        Ok(!buf.is_empty())
+    }
+}
+
+fn io_error(e: std::io::Error) -> EstimatorError {
+    EstimatorError::Other(format!("read failed: {e}"))
+}
+
+/// Options for [`shift_external`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShiftExternalOptions {
+    /// Quantile of the pairwise differences; `0.5` (the default) is the shift.
+    pub quantile: f64,
+    /// Check in the first pass that every chunk is ascending (the default).
+    /// Without the check, a chunk out of order gives a wrong result silently.
+    pub verify_sorted: bool,
+}
+
+impl Default for ShiftExternalOptions {
+    fn default() -> Self {
+        Self {
+            quantile: 0.5,
+            verify_sorted: true,
+        }
+    }
+}
+
+/// Shift (median of `x[i] - y[j]`, or the `opts.quantile` quantile) of two
+/// samples read chunk by chunk, equal to
+/// [`raw::shift`](crate::estimators::raw::shift) on the same values.
+///
+/// # Errors
+///
+/// Returns a `validity` error for an empty sample or a NaN/infinite value
+/// (with its position in the sample), and [`EstimatorError::Other`] for an
+/// I/O error, a quantile outside `[0, 1]`, a chunk that is not ascending, or
+/// a total that differs from [`SortedChunks::total_count`].
+pub fn shift_external(
+    mut x: impl SortedChunks,
+    mut y: impl SortedChunks,
+    opts: &ShiftExternalOptions,
+) -> Result<f64, EstimatorError> {
+    if !(0.0..=1.0).contains(&opts.quantile) {
+        return Err(EstimatorError::Other(format!(
+            "quantile must be within [0, 1], got {}",
+            opts.quantile
+        )));
+    }
+    let (x_min, x_max) = scan(&mut x, Subject::X, opts.verify_sorted)?;
+    let (y_min, y_max) = scan(&mut y, Subject::Y, opts.verify_sorted)?;
+
+    let total = (x.total_count() as i64) * (y.total_count() as i64);
+    let ranks = quantile_ranks(total, opts.quantile);
+    let mut buf_x = Vec::new();
+    let mut buf_y = Vec::new();
+    let mut select = |k: i64| {
+        select_kth_by_count(k, x_min - y_max, x_max - y_min, |threshold| {
+            count_pairs_le_chunked(&mut x, &mut y, &mut buf_x, &mut buf_y, threshold)
+        })
+    };
+    let lower = select(ranks.lower_rank)?;
+    let upper = if ranks.upper_rank == ranks.lower_rank {
+        lower
+    } else {
+        select(ranks.upper_rank)?
+    };
+    Ok(ranks.interpolate(lower, upper))
+}
+
+/// Validates one sample and returns its minimum and maximum.
+fn scan(
+    chunks: &mut impl SortedChunks,
+    subject: Subject,
+    verify_sorted: bool,
+) -> Result<(f64, f64), EstimatorError> {
+    chunks.rewind()?;
+    let mut buf = Vec::new();
+    let mut seen: u64 = 0;
+    let mut chunk_index = 0;
+    let (mut min, mut max) = (f64::INFINITY, f64::NEG_INFINITY);
+    while chunks.next_chunk(&mut buf)? {
+        if let Some(i) = buf.iter().position(|v| !v.is_finite()) {
+            let index = seen as usize + i;
+            return Err(AssumptionError::non_finite_values(subject, vec![index])
+                .with_offender(index, buf[i])
+                .into());
+        }
+        if verify_sorted {
+            if let Some(i) = buf.windows(2).position(|w| w[0] > w[1]) {
+                return Err(EstimatorError::Other(format!(
+                    "chunk {chunk_index} of {} is not ascending at index {}",
+                    subject.as_str(),
+                    seen as usize + i + 1
+                )));
+            }
+        }
+        min = min.min(buf[0]);
+        max = max.max(buf[buf.len() - 1]);
+        seen += buf.len() as u64;
+        chunk_index += 1;
+    }
+    if seen == 0 {
+        return Err(AssumptionError::empty_sample(subject).into());
+    }
+    if seen != chunks.total_count() {
+        return Err(EstimatorError::Other(format!(
+            "{} has {seen} values but reports {}",
+            subject.as_str(),
+            chunks.total_count()
+        )));
+    }
+    Ok((min, max))
+}
+
+/// One counting pass: every x chunk against every y chunk.
+fn count_pairs_le_chunked(
+    x: &mut impl SortedChunks,
+    y: &mut impl SortedChunks,
+    buf_x: &mut Vec<f64>,
+    buf_y: &mut Vec<f64>,
+    threshold: f64,
+) -> Result<(i64, f64, f64), EstimatorError> {
+    let mut acc = (0, f64::NEG_INFINITY, f64::INFINITY);
+    x.rewind()?;
+    while x.next_chunk(buf_x)? {
+        y.rewind()?;
+        while y.next_chunk(buf_y)? {
+            let (count, below, above) =
+                count_pairs_le(buf_x, buf_y, threshold, Execution::auto(buf_x.len()));
+            acc = (acc.0 + count, acc.1.max(below), acc.2.min(above));
+        }
+    }
+    Ok(acc)
+}
@@ -0,0 +1,255 @@
+//! Sliding-window estimators for streaming data.
+//!
+//! [`RollingCenter`] and [`RollingSpread`] keep the last `window` observations
+//! and report `center`/`spread` over them after every push. The window is held
+//! twice: in arrival order (to know which value leaves) and as a sorted
+//! multiset (binary-search insert and exact removal, O(window) moves). The
+//! pairwise sums and differences of sorted values form matrices with sorted
+//! rows and columns, so the estimate is selected from them in O(window),
+//! reading the O(window²) pairs on demand without storing them. Each push
+//! therefore costs O(window), and results are bit-for-bit identical to the
+//! batch estimators on the window contents.
+//!
+//! [`IncrementalSpread`] is the append-only counterpart without a window: it
+//! keeps every value seen so far in one sorted vector.
+//!
+//! NaN and infinite values have no place in the window: the rolling `push`
+//! panics on them, and `try_push` (like [`IncrementalSpread::push`] and
+//! [`CenterSketch`](super::streaming::CenterSketch)) rejects them with a
+//! `validity` error whose index is the position of the value in the stream.
+//! A rejected value leaves the state unchanged.
+
+use crate::assumptions::{AssumptionError, EstimatorError, Subject};
+use crate::pairwise_select::{center_sorted, spread_sorted};
+use std::collections::VecDeque;
+
+/// Rejects a non-finite `x` arriving at stream position `index`.
+fn check_finite(x: f64, index: usize) -> Result<(), EstimatorError> {
+    if x.is_finite() {
+        Ok(())
+    } else {
+        Err(EstimatorError::from(AssumptionError::non_finite_values(
+            Subject::X,
+            vec![index],
+        )))
+    }
+}
+
+/// The last `capacity` values, in arrival order and sorted.
+#[derive(Debug, Clone)]
+struct SortedWindow {
+    capacity: usize,
+    seen: usize,
+    arrival: VecDeque<f64>,
+    sorted: Vec<f64>,
+}
+
+impl SortedWindow {
+    fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "window must be positive");
+        Self {
+            capacity,
+            seen: 0,
+            arrival: VecDeque::with_capacity(capacity + 1),
+            sorted: Vec::with_capacity(capacity + 1),
+        }
+    }
+
+    /// Adds `x`, evicting the oldest value once full. Returns true if the
+    /// window is full after the push.
+    fn push(&mut self, x: f64) -> Result<bool, EstimatorError> {
+        check_finite(x, self.seen)?;
+        self.seen += 1;
+        self.arrival.push_back(x);
+        let at = self.sorted.partition_point(|&v| v < x);
+        self.sorted.insert(at, x);
+        if self.arrival.len() > self.capacity {
+            let old = self.arrival.pop_front().unwrap();
+            // Any copy of an equal value is interchangeable in the multiset
+            let at = self.sorted.partition_point(|&v| v < old);
+            self.sorted.remove(at);
+        }
+        Ok(self.arrival.len() == self.capacity)
+    }
+}
+
+/// Rolling [`center`](crate::estimators::center) over the last `window` values.
+///
+/// # Example
+/// ```
+/// use pragmastat::estimators::rolling::RollingCenter;
+///
+/// let mut rolling = RollingCenter::new(3);
+/// assert_eq!(rolling.push(1.0), None);
+/// assert_eq!(rolling.push(2.0), None);
+/// assert_eq!(rolling.push(3.0), Some(2.0));
+/// assert_eq!(rolling.push(10.0), Some(4.5)); // window is now [2, 3, 10]
+/// ```
+#[derive(Debug, Clone)]
+pub struct RollingCenter {
+    window: SortedWindow,
+}
+
+impl RollingCenter {
+    /// Creates an empty rolling center over windows of `window` values.
+    ///
+    /// # Panics
+    /// Panics if `window` is zero.
+    pub fn new(window: usize) -> Self {
+        Self {
+            window: SortedWindow::new(window),
+        }
+    }
+
+    /// Window size.
+    pub fn window(&self) -> usize {
+        self.window.capacity
+    }
+
+    /// Adds `x` and returns the center of the last `window` values, or `None`
+    /// until `window` values have been pushed. Costs O(window).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is NaN or infinite; use [`try_push`](Self::try_push) to
+    /// get an error instead.
+    pub fn push(&mut self, x: f64) -> Option<f64> {
+        self.try_push(x).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// [`push`](Self::push) that returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `validity` error if `x` is NaN or infinite.
+    pub fn try_push(&mut self, x: f64) -> Result<Option<f64>, EstimatorError> {
+        Ok(self
+            .window
+            .push(x)?
+            .then(|| center_sorted(&self.window.sorted)))
+    }
+}
+
+/// Rolling [`spread`](crate::estimators::spread) over the last `window` values.
+///
+/// Unlike the batch estimator, a tie-dominant window is not an error: it
+/// yields `Some(0.0)`, so the stream continues past flat stretches.
+///
+/// # Example
+/// ```
+/// use pragmastat::estimators::rolling::RollingSpread;
+///
+/// let mut rolling = RollingSpread::new(3);
+/// rolling.push(1.0);
+/// rolling.push(2.0);
+/// assert_eq!(rolling.push(4.0), Some(2.0)); // median of |differences| {1, 3, 2}
+/// ```
+#[derive(Debug, Clone)]
+pub struct RollingSpread {
+    window: SortedWindow,
+}
+
+impl RollingSpread {
+    /// Creates an empty rolling spread over windows of `window` values.
+    ///
+    /// # Panics
+    /// Panics if `window` is zero.
+    pub fn new(window: usize) -> Self {
+        Self {
+            window: SortedWindow::new(window),
+        }
+    }
+
+    /// Window size.
+    pub fn window(&self) -> usize {
+        self.window.capacity
+    }
+
+    /// Adds `x` and returns the spread of the last `window` values, or `None`
+    /// until `window` values have been pushed. Costs O(window).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is NaN or infinite; use [`try_push`](Self::try_push) to
+    /// get an error instead.
+    pub fn push(&mut self, x: f64) -> Option<f64> {
+        self.try_push(x).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// [`push`](Self::push) that returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `validity` error if `x` is NaN or infinite.
+    pub fn try_push(&mut self, x: f64) -> Result<Option<f64>, EstimatorError> {
+        Ok(self
+            .window
+            .push(x)?
+            .then(|| spread_sorted(&self.window.sorted)))
+    }
+}
+
+/// [`spread`](crate::estimators::spread) of all values pushed so far.
+///
+/// Values are kept in a single sorted vector: [`push`](Self::push) is a binary
+/// search plus an O(n) insert, and [`value`](Self::value) runs the O(n log n)
+/// selection on the sorted values without re-sorting them. Memory is O(n),
+/// one `f64` per value; the O(n²) pairwise differences are never stored.
+/// Results are bit-for-bit identical to the batch estimator on the same
+/// values. As with [`RollingSpread`], fewer than two values or a tie-dominant
+/// sample yield `0.0` rather than an error.
+///
+/// # Example
+/// ```
+/// use pragmastat::estimators::rolling::IncrementalSpread;
+///
+/// let mut spread = IncrementalSpread::new();
+/// spread.push(1.0).unwrap();
+/// spread.push(2.0).unwrap();
+/// spread.push(4.0).unwrap();
+/// assert_eq!(spread.value(), 2.0); // median of |differences| {1, 3, 2}
+/// spread.push(8.0).unwrap();
+/// assert_eq!(spread.len(), 4);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct IncrementalSpread {
+    sorted: Vec<f64>,
+}
+
+impl IncrementalSpread {
+    /// Creates an empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of values pushed so far.
+    pub fn len(&self) -> usize {
+        self.sorted.len()
+    }
+
+    /// True if no value has been pushed yet.
+    pub fn is_empty(&self) -> bool {
+        self.sorted.is_empty()
+    }
+
+    /// Adds `x`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `validity` error if `x` is NaN or infinite.
+    pub fn push(&mut self, x: f64) -> Result<(), EstimatorError> {
+        check_finite(x, self.sorted.len())?;
+        let at = self.sorted.partition_point(|&v| v < x);
+        self.sorted.insert(at, x);
+        Ok(())
+    }
+
+    /// Spread of all values pushed so far.
+    pub fn value(&self) -> f64 {
+        if self.sorted.len() < 2 {
+            return 0.0;
+        }
+        crate::spread_impl::spread_impl(&self.sorted, true)
+            .expect("selection on sorted values converges")
+    }
+}
@@ -0,0 +1,165 @@
+//! Fixed-memory center estimation for data that does not fit in memory.
+//!
+//! [`CenterSketch`] keeps a uniform random subsample (reservoir) of at most
+//! `capacity` values from a stream of any length, and reports the exact
+//! [`center`](crate::estimators::center) of that subsample.
+//! [`center_from_reader`] feeds it from a reader of little-endian `f64`s.
+//!
+//! # Accuracy
+//!
+//! While at most `capacity` values have been pushed, the reservoir holds all
+//! of them and the estimate equals the exact center bit-for-bit. Beyond that,
+//! the estimate is the center of a simple random sample of size
+//! `k = capacity` drawn from the `n` values. For a roughly normal population
+//! with standard deviation `σ`, its deviation from the exact center has
+//! standard error about
+//!
+//! ```text
+//! σ · sqrt(π / 3) · sqrt(1/k − 1/n)   ≈   1.02 · σ / sqrt(k)   for n ≫ k
+//! ```
+//!
+//! (the Hodges-Lehmann asymptotic variance `π σ² / 3n`), so `k = 10⁶` keeps
+//! the error around `0.001 σ`. Heavy tails do not inflate it the way they
+//! would for a mean. Memory is `8 · capacity` bytes regardless of `n`; each
+//! push costs O(1) and [`CenterSketch::estimate`] costs O(k log k). The
+//! subsample depends only on the seed and the order of the values, so results
+//! are reproducible.
+
+use crate::assumptions::{AssumptionError, EstimatorError, Subject};
+use crate::rng::Rng;
+use std::io::{BufReader, ErrorKind, Read};
+
+/// Reservoir subsample of a stream, for an approximate [`center`](crate::estimators::center).
+///
+/// # Example
+/// ```
+/// use pragmastat::estimators::streaming::CenterSketch;
+///
+/// let mut sketch = CenterSketch::new(1_000, "stream");
+/// for i in 0..100_000 {
+///     sketch.push(f64::from(i % 1_000)).unwrap();
+/// }
+/// assert_eq!(sketch.len(), 100_000);
+/// let center = sketch.estimate().unwrap();
+/// assert!((center - 499.5).abs() < 40.0);
+/// ```
+#[derive(Clone)]
+pub struct CenterSketch {
+    capacity: usize,
+    reservoir: Vec<f64>,
+    seen: u64,
+    rng: Rng,
+}
+
+impl CenterSketch {
+    /// Creates an empty sketch holding at most `capacity` values, drawing its
+    /// subsample with `Rng::from_string(seed)`.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize, seed: &str) -> Self {
+        assert!(capacity > 0, "capacity must be positive");
+        Self {
+            capacity,
+            reservoir: Vec::new(),
+            seen: 0,
+            rng: Rng::from_string(seed),
+        }
+    }
+
+    /// Maximum number of values kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of values pushed so far.
+    pub fn len(&self) -> u64 {
+        self.seen
+    }
+
+    /// True if no value has been pushed yet.
+    pub fn is_empty(&self) -> bool {
+        self.seen == 0
+    }
+
+    /// True while the estimate is exact (no value has been discarded).
+    pub fn is_exact(&self) -> bool {
+        self.seen <= self.capacity as u64
+    }
+
+    /// Adds `x` to the stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `validity` error if `x` is NaN or infinite; the index in the
+    /// error is the position of `x` in the stream.
+    pub fn push(&mut self, x: f64) -> Result<(), EstimatorError> {
+        if !x.is_finite() {
+            return Err(EstimatorError::from(AssumptionError::non_finite_values(
+                Subject::X,
+                vec![self.seen as usize],
+            )));
+        }
+        if self.reservoir.len() < self.capacity {
+            self.reservoir.push(x);
+        } else {
+            // Algorithm R: keep the new value with probability capacity / (seen + 1)
+            let j = self.rng.uniform_i64(0, self.seen as i64 + 1) as usize;
+            if j < self.capacity {
+                self.reservoir[j] = x;
+            }
+        }
+        self.seen += 1;
+        Ok(())
+    }
+
+    /// Center of the retained values; exact while [`is_exact`](Self::is_exact).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `validity` error if nothing has been pushed.
+    pub fn estimate(&self) -> Result<f64, EstimatorError> {
+        crate::estimators::raw::center(&self.reservoir, false)
+    }
+}
+
+/// Approximate center of the little-endian `f64` values in `reader`, using a
+/// [`CenterSketch`] of the given capacity and seed.
+///
+/// Reads through a buffer, so `reader` may be unbuffered (e.g., a `File`).
+///
+/// # Errors
+///
+/// Returns [`EstimatorError::Other`] on an I/O error or if the input length is
+/// not a multiple of 8 bytes, and a `validity` error if the input is empty or
+/// holds a NaN or infinite value.
+pub fn center_from_reader(
+    reader: impl Read,
+    capacity: usize,
+    seed: &str,
+) -> Result<f64, EstimatorError> {
+    let mut reader = BufReader::new(reader);
+    let mut sketch = CenterSketch::new(capacity, seed);
+    let mut buf = [0u8; 8];
+    loop {
+        let mut filled = 0;
+        while filled < buf.len() {
+            match reader.read(&mut buf[filled..]) {
+                Ok(0) => break,
+                Ok(k) => filled += k,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(EstimatorError::Other(format!("read failed: {e}"))),
+            }
+        }
+        match filled {
+            0 => break,
+            8 => sketch.push(f64::from_le_bytes(buf))?,
+            _ => {
+                return Err(EstimatorError::Other(format!(
+                    "input ends with a partial value ({filled} of 8 bytes)"
+                )))
+            }
+        }
+    }
+    sketch.estimate()
+}
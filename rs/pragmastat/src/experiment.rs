@@ -0,0 +1,98 @@
+//! Reproducible Monte Carlo experiments: seeded replications and aggregates.
+//!
+//! An [`Experiment`] derives every random stream from one seed string. The
+//! stream named `key` is `Rng::from_string("{seed}-{key}")`, and replication
+//! `i` of [`Experiment::replicate`] uses the stream named `i`. Each replication
+//! thus depends only on the seed and its index, not on how many replications
+//! ran before it or in which order.
+//!
+//! `pragmastat-sim` derives its streams with the same rule: the cell of a
+//! distribution and sample size uses the stream `"{distribution}-{n}"` of
+//! `Experiment::new(base_seed)` (`"{distribution}-{n}-{m}"` for two sample
+//! sizes) and draws its samples from it one after another. So
+//! `Experiment::new("avg-drift").rng("Additive-10")` reproduces the draws
+//! behind the `Additive`, `n = 10` row of `avg-drift.json`.
+//!
+//! # Example
+//! ```
+//! use pragmastat::distributions::{Additive, Distribution};
+//! use pragmastat::estimators::raw;
+//! use pragmastat::experiment::{coverage, Experiment};
+//!
+//! let experiment = Experiment::new("center-bounds-coverage");
+//! let bounds = experiment.replicate(200, |rng| {
+//!     let x = Additive::new(0.0, 1.0).samples(rng, 10);
+//!     raw::center_bounds(&x, 0.1, false).unwrap()
+//! });
+//! let covered = coverage(&bounds, |b| b.lower <= 0.0 && 0.0 <= b.upper);
+//! assert!(covered > 0.8);
+//! ```
+
+use crate::Rng;
+use std::fmt::Display;
+
+/// A family of random streams derived from one seed string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Experiment {
+    seed: String,
+}
+
+impl Experiment {
+    /// Creates an experiment whose streams all derive from `seed`.
+    pub fn new(seed: &str) -> Self {
+        Self {
+            seed: seed.to_string(),
+        }
+    }
+
+    /// The seed string.
+    pub fn seed(&self) -> &str {
+        &self.seed
+    }
+
+    /// The stream named `key`: `Rng::from_string("{seed}-{key}")`.
+    pub fn rng(&self, key: impl Display) -> Rng {
+        Rng::from_string(&format!("{}-{}", self.seed, key))
+    }
+
+    /// Runs `f` once per replication `0..count`, replication `i` drawing from
+    /// [`rng(i)`](Self::rng), and collects the results in index order.
+    pub fn replicate<T>(&self, count: usize, f: impl Fn(&mut Rng) -> T) -> Vec<T> {
+        (0..count).map(|i| f(&mut self.rng(i))).collect()
+    }
+}
+
+/// Arithmetic mean of replication results.
+///
+/// # Panics
+/// Panics if `values` is empty.
+pub fn mean(values: &[f64]) -> f64 {
+    assert!(!values.is_empty(), "values must be non-empty");
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+/// Hyndman-Fan type 7 quantile (linear interpolation between order
+/// statistics) of replication results.
+///
+/// # Panics
+/// Panics if `values` is empty, contains NaN, or `p` is not in `[0, 1]`.
+pub fn quantile(values: &[f64], p: f64) -> f64 {
+    assert!(!values.is_empty(), "values must be non-empty");
+    assert!((0.0..=1.0).contains(&p), "p must be in [0, 1]");
+    assert!(values.iter().all(|v| !v.is_nan()), "values must not be NaN");
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable_by(f64::total_cmp);
+    let h = (sorted.len() - 1) as f64 * p;
+    let lo = h.floor() as usize;
+    let hi = h.ceil() as usize;
+    sorted[lo] + (h - lo as f64) * (sorted[hi] - sorted[lo])
+}
+
+/// Fraction of replication results for which `hit` holds.
+///
+/// # Panics
+/// Panics if `values` is empty.
+pub fn coverage<T>(values: &[T], hit: impl Fn(&T) -> bool) -> f64 {
+    assert!(!values.is_empty(), "values must be non-empty");
+    values.iter().filter(|v| hit(v)).count() as f64 / values.len() as f64
+}
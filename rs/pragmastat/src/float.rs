@@ -0,0 +1,52 @@
+//! Element types accepted by the selection kernels.
+//!
+//! The center, spread and shift kernels are generic over [`Float`] so that
+//! `f32` input can be sorted and scanned in place of an `f64` copy. Every value
+//! is widened to `f64` (exactly) when it is read, and all arithmetic happens in
+//! `f64`, so running a kernel on `f32` data gives the same result, bit for bit,
+//! as running it on the widened `f64` data.
+//!
+//! Internal implementation - not part of public API.
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+pub(crate) trait Float: Copy + Send + Sync {
+    fn to_f64(self) -> f64;
+    fn total_cmp(&self, other: &Self) -> Ordering;
+    /// `values` as `f64`, borrowed when they already are.
+    fn widen(values: &[Self]) -> Cow<'_, [f64]>;
+}
+
+impl Float for f64 {
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    #[inline]
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        f64::total_cmp(self, other)
+    }
+
+    #[inline]
+    fn widen(values: &[Self]) -> Cow<'_, [f64]> {
+        Cow::Borrowed(values)
+    }
+}
+
+impl Float for f32 {
+    #[inline]
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+
+    #[inline]
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        f32::total_cmp(self, other)
+    }
+
+    fn widen(values: &[Self]) -> Cow<'_, [f64]> {
+        Cow::Owned(values.iter().map(|&v| f64::from(v)).collect())
+    }
+}
@@ -1,6 +1,8 @@
 //! FNV-1a hash algorithm (64-bit)
 //! Reference: http://www.isthe.com/chongo/tech/comp/fnv/
 
+use crate::float::Float;
+
 const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
 const FNV_PRIME: u64 = 0x00000100000001b3;
 
@@ -15,12 +17,13 @@ pub(crate) fn fnv1a_hash(s: &str) -> u64 {
     hash
 }
 
-/// Compute FNV-1a 64-bit hash of an f64 slice, returning it as i64 for use as an RNG seed.
+/// Compute FNV-1a 64-bit hash of a float slice (over the bits of each value
+/// widened to f64), returning it as i64 for use as an RNG seed.
 #[inline]
-pub(crate) fn hash_f64_slice(values: &[f64]) -> i64 {
+pub(crate) fn hash_f64_slice<T: Float>(values: &[T]) -> i64 {
     let mut hash = FNV_OFFSET_BASIS;
     for v in values {
-        let bits = v.to_bits();
+        let bits = v.to_f64().to_bits();
         for i in 0..8 {
             hash ^= (bits >> (i * 8)) & 0xff;
             hash = hash.wrapping_mul(FNV_PRIME);
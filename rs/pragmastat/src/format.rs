@@ -0,0 +1,222 @@
+//! Canonical decimal strings for floating-point values.
+//!
+//! Reference outputs are compared across ports, and every language prints
+//! floats a little differently (`0.30000000000000004` in one, `0.3` in another,
+//! `1e21` against `1E+21`). [`shortest_roundtrip`] fixes one spelling so that
+//! the JSON fixtures written by this crate can be reproduced byte for byte:
+//!
+//! 1. The digits are the shortest decimal that parses back to the same `f64`;
+//!    among equally short candidates, the one closest to the exact value.
+//!    `f32` fixture values use the shortest digits that parse back to the same
+//!    `f32`.
+//! 2. With `k` digits `d₁…d_k` and the value equal to `0.d₁…d_k × 10ⁿ`, the
+//!    layout is that of ECMAScript's `Number::toString`:
+//!    - `k ≤ n ≤ 21`: the digits followed by `n - k` zeros (`1`, `1500`);
+//!    - `0 < n ≤ 21`: a decimal point after `n` digits (`12.5`);
+//!    - `-6 < n ≤ 0`: `0.`, then `-n` zeros, then the digits (`0.000001`);
+//!    - otherwise exponential: `d₁`, `.d₂…d_k` if `k > 1`, `e`, a sign and
+//!      `n - 1` (`1e+21`, `1.5e-7`, `5e-324`).
+//! 3. Negative values take a leading `-`, including negative zero (`-0`),
+//!    where ECMAScript would print `0`.
+//! 4. Non-finite values are `NaN`, `Infinity` and `-Infinity`; they are not
+//!    valid JSON numbers and never appear in fixtures.
+//!
+//! [`parse_exact`] is the inverse: it accepts only canonical strings, so a
+//! successful parse guarantees the bits survived the trip.
+//!
+//! # Example
+//! ```
+//! use pragmastat::format::{parse_exact, shortest_roundtrip};
+//!
+//! assert_eq!(shortest_roundtrip(0.1 + 0.2), "0.30000000000000004");
+//! assert_eq!(shortest_roundtrip(1e21), "1e+21");
+//! assert_eq!(parse_exact("0.1"), 0.1);
+//! ```
+
+/// Formats `x` as the shortest decimal string that parses back to `x`.
+///
+/// See the [module documentation](self) for the exact layout.
+pub fn shortest_roundtrip(x: f64) -> String {
+    if x.is_nan() {
+        return "NaN".to_string();
+    }
+    if x.is_infinite() {
+        return if x > 0.0 { "Infinity" } else { "-Infinity" }.to_string();
+    }
+    if x == 0.0 {
+        return if x.is_sign_negative() { "-0" } else { "0" }.to_string();
+    }
+    // `{:e}` prints the shortest round-trip digits; only the layout differs
+    layout(&format!("{:e}", x.abs()), x < 0.0)
+}
+
+/// [`shortest_roundtrip`] for `f32`: the shortest digits that parse back to
+/// the same `f32`, in the same layout.
+pub fn shortest_roundtrip_f32(x: f32) -> String {
+    if !x.is_finite() || x == 0.0 {
+        return shortest_roundtrip(f64::from(x));
+    }
+    layout(&format!("{:e}", x.abs()), x < 0.0)
+}
+
+/// Parses a canonical string produced by [`shortest_roundtrip`].
+///
+/// # Panics
+///
+/// Panics if `s` is not a number or is not in canonical form (e.g. `1.0`,
+/// `0.10`, `1e21`), since a non-canonical string does not guarantee a
+/// bit-exact round trip.
+pub fn parse_exact(s: &str) -> f64 {
+    let x: f64 = s
+        .parse()
+        .unwrap_or_else(|_| panic!("{s:?} is not a number"));
+    assert_eq!(shortest_roundtrip(x), s, "{s:?} is not in canonical form");
+    x
+}
+
+/// Lays out the positive value printed by `{:e}` (`d[.ddd]e[-]N`).
+fn layout(scientific: &str, negative: bool) -> String {
+    let (mantissa, exponent) = scientific
+        .split_once('e')
+        .expect("`{:e}` always prints an exponent");
+    let digits: String = mantissa.chars().filter(|&c| c != '.').collect();
+    let k = digits.len() as i32;
+    let n = exponent.parse::<i32>().expect("exponent is an integer") + 1;
+
+    let mut out = String::with_capacity(digits.len() + 8);
+    if negative {
+        out.push('-');
+    }
+    if k <= n && n <= 21 {
+        out.push_str(&digits);
+        out.extend(std::iter::repeat_n('0', (n - k) as usize));
+    } else if 0 < n && n <= 21 {
+        let (int, frac) = digits.split_at(n as usize);
+        out.push_str(int);
+        out.push('.');
+        out.push_str(frac);
+    } else if -6 < n && n <= 0 {
+        out.push_str("0.");
+        out.extend(std::iter::repeat_n('0', (-n) as usize));
+        out.push_str(&digits);
+    } else {
+        let (first, rest) = digits.split_at(1);
+        out.push_str(first);
+        if !rest.is_empty() {
+            out.push('.');
+            out.push_str(rest);
+        }
+        out.push('e');
+        out.push(if n > 0 { '+' } else { '-' });
+        out.push_str(&(n - 1).abs().to_string());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rng;
+
+    #[test]
+    fn classic_values() {
+        assert_eq!(shortest_roundtrip(0.1), "0.1");
+        assert_eq!(shortest_roundtrip(0.1 + 0.2), "0.30000000000000004");
+        assert_eq!(shortest_roundtrip(1.0), "1");
+        assert_eq!(shortest_roundtrip(-2.5), "-2.5");
+        assert_eq!(shortest_roundtrip(123.456), "123.456");
+        assert_eq!(shortest_roundtrip(0.0), "0");
+        assert_eq!(shortest_roundtrip(-0.0), "-0");
+        assert_eq!(shortest_roundtrip(f64::MAX), "1.7976931348623157e+308");
+        assert_eq!(
+            shortest_roundtrip(f64::MIN_POSITIVE),
+            "2.2250738585072014e-308"
+        );
+        // The literal 1e-324 underflows to zero; 5e-324 is the smallest subnormal
+        assert_eq!(shortest_roundtrip(1e-324), "0");
+        assert_eq!(shortest_roundtrip(5e-324), "5e-324");
+        assert_eq!(shortest_roundtrip(f64::NAN), "NaN");
+        assert_eq!(shortest_roundtrip(f64::NEG_INFINITY), "-Infinity");
+    }
+
+    #[test]
+    fn layout_boundaries() {
+        assert_eq!(shortest_roundtrip(1e20), "100000000000000000000");
+        assert_eq!(shortest_roundtrip(1e21), "1e+21");
+        assert_eq!(shortest_roundtrip(1.5e21), "1.5e+21");
+        assert_eq!(
+            shortest_roundtrip(123456789012345680000.0),
+            "123456789012345680000"
+        );
+        assert_eq!(shortest_roundtrip(1e-6), "0.000001");
+        assert_eq!(shortest_roundtrip(1.5e-6), "0.0000015");
+        assert_eq!(shortest_roundtrip(1e-7), "1e-7");
+        assert_eq!(shortest_roundtrip(-1.25e-7), "-1.25e-7");
+    }
+
+    #[test]
+    fn f32_uses_f32_digits() {
+        assert_eq!(shortest_roundtrip_f32(0.1), "0.1");
+        assert_eq!(shortest_roundtrip_f32(f32::MAX), "3.4028235e+38");
+        assert_eq!(shortest_roundtrip_f32(-0.0), "-0");
+    }
+
+    #[test]
+    fn parse_exact_round_trips() {
+        for s in [
+            "0.1",
+            "-0",
+            "0",
+            "5e-324",
+            "1.7976931348623157e+308",
+            "-Infinity",
+        ] {
+            assert_eq!(shortest_roundtrip(parse_exact(s)), s);
+        }
+        assert!(parse_exact("-0").is_sign_negative());
+        assert_eq!(parse_exact("1.7976931348623157e+308"), f64::MAX);
+        assert!(parse_exact("NaN").is_nan());
+    }
+
+    #[test]
+    #[should_panic(expected = "not in canonical form")]
+    fn parse_exact_rejects_non_canonical() {
+        parse_exact("1.0");
+    }
+
+    #[test]
+    #[should_panic(expected = "not in canonical form")]
+    fn parse_exact_rejects_underflow() {
+        parse_exact("1e-324");
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a number")]
+    fn parse_exact_rejects_garbage() {
+        parse_exact("0x1p3");
+    }
+
+    #[test]
+    fn random_bit_patterns_round_trip() {
+        let mut rng = Rng::from_string("shortest-roundtrip");
+        for _ in 0..100_000 {
+            let x = f64::from_bits(rng.next_u64());
+            if x.is_nan() {
+                continue;
+            }
+            let s = shortest_roundtrip(x);
+            assert_eq!(parse_exact(&s).to_bits(), x.to_bits(), "{s}");
+        }
+    }
+
+    #[test]
+    fn ordinary_magnitudes_round_trip() {
+        let mut rng = Rng::from_string("shortest-roundtrip-ordinary");
+        for _ in 0..100_000 {
+            let exponent = rng.uniform_i64(-10, 25) as i32;
+            let x = (rng.uniform_f64() - 0.5) * 10f64.powi(exponent);
+            let s = shortest_roundtrip(x);
+            assert_eq!(parse_exact(&s).to_bits(), x.to_bits(), "{s}");
+        }
+    }
+}
@@ -0,0 +1,228 @@
+//! Executable invariants of the estimators, for property tests and fuzzers.
+//!
+//! Each checker evaluates one invariant on concrete inputs and returns an
+//! [`InvariantResult`] instead of asserting, so external harnesses can shrink
+//! or report failures their own way. Estimator errors (e.g., a misrate that
+//! is too low for the sample size) are passed through: an input the estimator
+//! rejects is not a counterexample.
+//!
+//! Equalities hold up to floating-point rounding. The checkers allow a
+//! relative error of [`TOLERANCE`] scaled by the magnitude of the inputs
+//! (not of the result), because transforming the inputs, e.g. `x + c`, already
+//! rounds each value at that scale.
+//!
+//! # Known limitations
+//!
+//! - Scale equivariance with a negative factor holds for location estimators
+//!   (center, shift) but not for spread, which is scale-equivariant in `|k|`;
+//!   check dispersion estimators with `k > 0` (or pass `k.abs()` as expected).
+//! - [`bounds_contain_estimate`] holds by construction for the rank-based
+//!   bounds (center, shift, ratio). The randomized spread and disparity bounds
+//!   come from a disjoint-pair subsample rather than from all pairs, so at a
+//!   large misrate they can exclude the full-sample estimate; harnesses should
+//!   not treat that as a bug.
+//!
+//! # Example
+//! ```
+//! use pragmastat::estimators::raw;
+//! use pragmastat::invariants::location_equivariance;
+//!
+//! let x = [1.0, 4.0, 2.5, 7.0, 3.0];
+//! let result = location_equivariance(|v| raw::center(v, false), &x, 100.0).unwrap();
+//! assert!(result.holds(), "{result}");
+//! ```
+
+use crate::assumptions::EstimatorError;
+use crate::estimators::raw::{self, RawBounds};
+use std::fmt;
+
+/// Relative tolerance of the equality checks.
+pub const TOLERANCE: f64 = 1e-9;
+
+/// The invariants that can be checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Invariant {
+    /// `T(x + c) == T(x) + c`.
+    LocationEquivariance,
+    /// `T(x + c) == T(x)`.
+    LocationInvariance,
+    /// `T(k * x) == k * T(x)`.
+    ScaleEquivariance,
+    /// `T(x, y) == -T(y, x)`.
+    SignSymmetry,
+    /// `lower <= T(x) <= upper`.
+    BoundsContainEstimate,
+    /// `Ratio(x, y) * Ratio(y, x) == 1`.
+    RatioInversion,
+}
+
+impl Invariant {
+    /// Returns the invariant name as a kebab-case string.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Invariant::LocationEquivariance => "location-equivariance",
+            Invariant::LocationInvariance => "location-invariance",
+            Invariant::ScaleEquivariance => "scale-equivariance",
+            Invariant::SignSymmetry => "sign-symmetry",
+            Invariant::BoundsContainEstimate => "bounds-contain-estimate",
+            Invariant::RatioInversion => "ratio-inversion",
+        }
+    }
+}
+
+impl fmt::Display for Invariant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Outcome of one invariant check.
+///
+/// `expected` is what the invariant predicts and `actual` what the estimator
+/// returned; the invariant holds if they differ by at most `tolerance`. For
+/// [`bounds_contain_estimate`], `actual` is the estimate, `expected` is the
+/// nearest point of the bounds, and the tolerance is zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InvariantResult {
+    pub invariant: Invariant,
+    pub expected: f64,
+    pub actual: f64,
+    pub tolerance: f64,
+}
+
+impl InvariantResult {
+    fn new(invariant: Invariant, expected: f64, actual: f64, tolerance: f64) -> Self {
+        Self {
+            invariant,
+            expected,
+            actual,
+            tolerance,
+        }
+    }
+
+    /// True if `actual` is within `tolerance` of `expected`.
+    pub fn holds(&self) -> bool {
+        (self.actual - self.expected).abs() <= self.tolerance
+    }
+}
+
+impl fmt::Display for InvariantResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verdict = if self.holds() { "holds" } else { "violated" };
+        write!(
+            f,
+            "{} {}: expected {}, actual {} (tolerance {})",
+            self.invariant, verdict, self.expected, self.actual, self.tolerance
+        )
+    }
+}
+
+fn max_abs(x: &[f64]) -> f64 {
+    x.iter().fold(0.0, |m, v| m.max(v.abs()))
+}
+
+/// Checks `estimator(x + c) == estimator(x) + c`.
+pub fn location_equivariance<F>(
+    estimator: F,
+    x: &[f64],
+    c: f64,
+) -> Result<InvariantResult, EstimatorError>
+where
+    F: Fn(&[f64]) -> Result<f64, EstimatorError>,
+{
+    let shifted: Vec<f64> = x.iter().map(|v| v + c).collect();
+    let expected = estimator(x)? + c;
+    let actual = estimator(&shifted)?;
+    let tolerance = TOLERANCE * (1.0 + max_abs(x) + c.abs());
+    Ok(InvariantResult::new(
+        Invariant::LocationEquivariance,
+        expected,
+        actual,
+        tolerance,
+    ))
+}
+
+/// Checks `estimator(x + c) == estimator(x)`, e.g. for spread.
+pub fn location_invariance<F>(
+    estimator: F,
+    x: &[f64],
+    c: f64,
+) -> Result<InvariantResult, EstimatorError>
+where
+    F: Fn(&[f64]) -> Result<f64, EstimatorError>,
+{
+    let shifted: Vec<f64> = x.iter().map(|v| v + c).collect();
+    let expected = estimator(x)?;
+    let actual = estimator(&shifted)?;
+    let tolerance = TOLERANCE * (1.0 + max_abs(x) + c.abs());
+    Ok(InvariantResult::new(
+        Invariant::LocationInvariance,
+        expected,
+        actual,
+        tolerance,
+    ))
+}
+
+/// Checks `estimator(k * x) == k * estimator(x)`.
+///
+/// Use `k > 0` for dispersion estimators (see the module-level limitations).
+pub fn scale_equivariance<F>(
+    estimator: F,
+    x: &[f64],
+    k: f64,
+) -> Result<InvariantResult, EstimatorError>
+where
+    F: Fn(&[f64]) -> Result<f64, EstimatorError>,
+{
+    let scaled: Vec<f64> = x.iter().map(|v| v * k).collect();
+    let expected = estimator(x)? * k;
+    let actual = estimator(&scaled)?;
+    let tolerance = TOLERANCE * (1.0 + k.abs() * max_abs(x));
+    Ok(InvariantResult::new(
+        Invariant::ScaleEquivariance,
+        expected,
+        actual,
+        tolerance,
+    ))
+}
+
+/// Checks `estimator(x, y) == -estimator(y, x)`, e.g. for shift and disparity.
+pub fn sign_symmetry<F>(
+    estimator: F,
+    x: &[f64],
+    y: &[f64],
+) -> Result<InvariantResult, EstimatorError>
+where
+    F: Fn(&[f64], &[f64]) -> Result<f64, EstimatorError>,
+{
+    let expected = -estimator(y, x)?;
+    let actual = estimator(x, y)?;
+    let tolerance = TOLERANCE * (1.0 + max_abs(x) + max_abs(y)).max(1.0 + expected.abs());
+    Ok(InvariantResult::new(
+        Invariant::SignSymmetry,
+        expected,
+        actual,
+        tolerance,
+    ))
+}
+
+/// Checks `bounds.lower <= estimate <= bounds.upper`.
+pub fn bounds_contain_estimate(bounds: RawBounds, estimate: f64) -> InvariantResult {
+    InvariantResult::new(
+        Invariant::BoundsContainEstimate,
+        estimate.clamp(bounds.lower, bounds.upper),
+        estimate,
+        0.0,
+    )
+}
+
+/// Checks `ratio(x, y) * ratio(y, x) == 1`; both samples must be positive.
+pub fn ratio_inversion(x: &[f64], y: &[f64]) -> Result<InvariantResult, EstimatorError> {
+    let actual = raw::ratio(x, y, false)? * raw::ratio(y, x, false)?;
+    Ok(InvariantResult::new(
+        Invariant::RatioInversion,
+        1.0,
+        actual,
+        TOLERANCE,
+    ))
+}
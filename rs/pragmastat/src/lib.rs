@@ -7,16 +7,30 @@
 //! - Provide clear explanations accessible to practitioners without deep statistical training
 
 pub mod assumptions;
+pub mod bench_inputs;
 pub mod bounds;
 pub mod compare;
+pub mod config;
 pub mod distributions;
+pub mod effect_size;
+pub mod estimate_with_bounds;
 pub mod estimators;
+pub mod experiment;
+pub mod format;
+pub mod gauss_cdf;
+pub mod invariants;
+pub mod margins;
 pub mod measurement;
 pub mod measurement_unit;
+pub mod meta_analysis;
+pub mod permutation;
+pub mod planning;
+#[cfg(feature = "reference-tests")]
+pub mod reference;
 pub mod sample;
+pub mod tests;
 pub mod unit_registry;
 
-pub(crate) mod gauss_cdf;
 pub(crate) mod min_misrate;
 pub(crate) mod pairwise_margin;
 pub mod rng;
@@ -26,6 +40,12 @@ pub(crate) mod signed_rank_margin;
 // Internal algorithm implementations
 mod center_impl;
 mod center_quantiles_impl;
+mod dominance_impl;
+mod float;
+mod mode_impl;
+mod naive_impl;
+mod pairwise_select;
+mod parallel;
 mod shift_impl;
 mod spread_impl;
 
@@ -42,27 +62,56 @@ mod disparity_bounds_tests;
 #[cfg(test)]
 mod pairwise_margin_tests;
 #[cfg(test)]
+mod parallel_tests;
+#[cfg(test)]
 mod ratio_bounds_tests;
 #[cfg(test)]
 mod signed_rank_margin_tests;
 
 // Re-exports for convenient access
-pub use assumptions::{AssumptionError, AssumptionId, EstimatorError, Subject, Violation};
+pub use assumptions::{
+    check_all, check_ties, register_assumption, AssumptionError, AssumptionId,
+    AssumptionRegistration, Diagnostic, EstimatorError, Severity, Subject, TieReport, Violation,
+    ViolationDetail, WithDiagnostics,
+};
 pub use bounds::Bounds;
 pub use compare::{
     compare1, compare1_with_seed, compare2, compare2_with_seed, ComparisonVerdict, Metric,
     Projection, Threshold,
 };
-pub use distributions::{Additive, Distribution, Exp, Multiplic, Power, Uniform};
+pub use config::{EstimatorConfig, EstimatorConfigBuilder, NonFinitePolicy};
+pub use distributions::{
+    Additive, Ar1, Binomial, BivariateAdditive, ChiSquared, Distribution, Exp, Gamma,
+    GaussianCopula, Mixture, Multiplic, Poisson, Power, StudentT, Uniform, UniformInt,
+};
+pub use estimate_with_bounds::EstimateWithBounds;
 pub use estimators::{
-    center, center_bounds, disparity, disparity_bounds, disparity_bounds_with_seed, ratio,
-    ratio_bounds, shift, shift_bounds, spread, spread_bounds, spread_bounds_with_seed,
-    DEFAULT_MISRATE,
+    avg_spread, avg_spread_bounds, avg_spread_bounds_with_rng, avg_spread_bounds_with_seed, center,
+    center_bounds, center_bounds_clamped, center_bounds_with_config,
+    center_bounds_with_diagnostics, center_bounds_with_method, center_stderr, center_with_bounds,
+    disparity, disparity_bounds, disparity_bounds_decomposed,
+    disparity_bounds_decomposed_with_seed, disparity_bounds_opts, disparity_bounds_seeded_by_index,
+    disparity_bounds_with_config, disparity_bounds_with_diagnostics, disparity_bounds_with_rng,
+    disparity_bounds_with_seed, disparity_decomposed, disparity_with_bounds,
+    disparity_with_bounds_with_seed, disparity_with_diagnostics, dominance, dominance_bounds,
+    dominance_bounds_with_config, flag_outliers, group_center_bounds, iqr, mad, min_misrate_center,
+    min_misrate_shift, min_misrate_spread, min_sample_size, mode_hsm, precision, ratio,
+    ratio_bounds, ratio_bounds_with_config, ratio_bounds_with_diagnostics, ratio_with_bounds,
+    required_sample_size_center, shift, shift_bounds, shift_bounds_with_config,
+    shift_bounds_with_diagnostics, shift_stderr, shift_with, shift_with_bounds, spread,
+    spread_bounds, spread_bounds_asymptotic, spread_bounds_with_config,
+    spread_bounds_with_diagnostics, spread_bounds_with_rng, spread_bounds_with_seed, spread_ratio,
+    spread_ratio_bounds, spread_ratio_bounds_with_rng, spread_ratio_bounds_with_seed, spread_with,
+    spread_with_bounds, spread_with_bounds_with_seed, volatility, Algorithm, BoundsMethod,
+    DisparityBoundsOptions, DisparityBoundsParts, DisparityBoundsResult, DisparityParts,
+    EstimatorId, MadScale, OutlierReport, DEFAULT_MISRATE, DEFAULT_OUTLIER_K,
+    SPREAD_BOUNDS_ASYMPTOTIC_MIN_SIZE,
 };
 pub use measurement::Measurement;
 pub use measurement_unit::{
-    conversion_factor, finer, is_compatible, MeasurementUnit, UnitMismatchError,
+    conversion_factor, convert, finer, is_compatible, MeasurementUnit, UnitMismatchError,
 };
-pub use rng::Rng;
-pub use sample::Sample;
+pub use meta_analysis::MetaWeight;
+pub use rng::{Rng, WeightedIndex};
+pub use sample::{audit_pair, Sample};
 pub use unit_registry::UnitRegistry;
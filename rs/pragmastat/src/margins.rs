@@ -0,0 +1,31 @@
+//! Margin functions used by the bounds estimators.
+//!
+//! A margin is the total number of extreme order statistics excluded from both
+//! tails (split evenly between lower and upper) so that the resulting interval
+//! misses the true value with probability at most `misrate`:
+//!
+//! - [`pairwise_margin`]: Mann-Whitney dominance distribution, used by
+//!   `shift_bounds` and `ratio_bounds`. Exact (Löffler's recurrence) for
+//!   `n + m <= 400`, Edgeworth approximation above.
+//! - [`signed_rank_margin`]: Wilcoxon signed-rank distribution, used by
+//!   `center_bounds`. Exact for `n <= 63`, Edgeworth approximation above.
+//! - [`sign_margin`] / [`sign_margin_randomized`]: Binomial(n, 0.5)
+//!   distribution, used by `spread_bounds` over `n / 2` disjoint pairs.
+//!   Always exact.
+//!
+//! All functions return a domain error for an empty sample, for a NaN misrate
+//! or one outside [0, 1], and for a misrate below the minimum achievable for
+//! the given sample size(s).
+//!
+//! [`sign_coverage`], [`signed_rank_coverage`] and [`pairwise_coverage`] go
+//! the other way: for a given margin they return the exact probability that
+//! the resulting bounds cover the true value, with no simulation (pairwise:
+//! exact while the smaller sample has at most 400 values). The sign
+//! margin is conservative, so its coverage is at least `1 - misrate`; the
+//! exact signed-rank and pairwise margins take the first rank whose tail
+//! reaches `misrate / 2`, so theirs can fall short of it by up to one step of
+//! the discrete distribution.
+
+pub use crate::pairwise_margin::{pairwise_coverage, pairwise_margin, PairwiseMargin};
+pub use crate::sign_margin::{sign_coverage, sign_margin, sign_margin_randomized};
+pub use crate::signed_rank_margin::{signed_rank_coverage, signed_rank_margin};
@@ -53,6 +53,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn display_with_abbreviation() {
         let unit = MeasurementUnit::new("ms", "Time", "ms", "Millisecond", 1_000_000);
         let m = Measurement::new(3.14, unit);
@@ -60,6 +61,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn into_f64() {
         let m = Measurement::number(2.718);
         let v: f64 = m.into();
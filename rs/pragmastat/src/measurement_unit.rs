@@ -3,21 +3,46 @@
 //! Provides [`MeasurementUnit`] — a concrete type for both standard and
 //! user-defined units — plus free functions for compatibility, conversion,
 //! and precision comparison.
+//!
+//! # Affine units
+//!
+//! Most units are purely multiplicative: `base = value * base_units`. Affine units
+//! (temperature scales, decibel-style offsets) additionally carry an `offset`, so
+//! `base = value * base_units + offset`. Absolute values must be converted with
+//! [`convert`], which honors the offset. Differences between two values are
+//! offset-free and scale with [`conversion_factor`] alone, so `center`, `spread`,
+//! `shift`, and `disparity` are safe on affine samples. Ratios of affine values
+//! depend on the arbitrary zero point, so `ratio` and `ratio_bounds` reject them.
+//!
+//! # Percent and parts per million
+//!
+//! [`MeasurementUnit::percent`] and [`MeasurementUnit::parts_per_million`] belong
+//! to the `Number` family, so they convert freely with plain fractions:
+//! 0.5 = 50 % = 500000 ppm. The base unit of the family is one ppm.
+//!
+//! Estimators keep the usual semantics on such samples. `ratio` and `disparity`
+//! are dimensionless (the ratio of 20 % to 10 % is 2, not 200 %), while `shift`,
+//! `center`, and `spread` stay in the sample unit: the shift between hit rates of
+//! 92 % and 90 % is 2 *percentage points*, reported as `2 %`, not a relative
+//! change of 2 percent (which would be `ratio - 1`, here about 0.022).
 
 use std::fmt;
+use std::hash::{Hash, Hasher};
 
 /// A unit of measurement with identity, family membership, and conversion support.
 ///
 /// Units in the same family are compatible and can be converted between each other.
 /// The `base_units` value determines the conversion factor relative to the family's
 /// base unit: `conversion_factor(from, to) = from.base_units / to.base_units`.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Affine units also carry an `offset` (in base units) applied by [`convert`].
+#[derive(Debug, Clone)]
 pub struct MeasurementUnit {
     id: String,
     family: String,
     abbreviation: String,
     full_name: String,
     base_units: i64,
+    offset: f64,
 }
 
 impl MeasurementUnit {
@@ -35,6 +60,35 @@ impl MeasurementUnit {
             abbreviation: abbreviation.into(),
             full_name: full_name.into(),
             base_units,
+            offset: 0.0,
+        }
+    }
+
+    /// Creates a new affine measurement unit: `base = value * base_units + offset`.
+    ///
+    /// Use this for scales with an arbitrary zero point (e.g., Celsius, Fahrenheit).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pragmastat::{convert, MeasurementUnit};
+    ///
+    /// // Base unit: 1/9 of a kelvin, so both scales have integer base_units.
+    /// let c = MeasurementUnit::affine("C", "Temperature", "°C", "Celsius", 9, 2458.35);
+    /// let f = MeasurementUnit::affine("F", "Temperature", "°F", "Fahrenheit", 5, 2298.35);
+    /// assert!((convert(100.0, &c, &f).unwrap() - 212.0).abs() < 1e-9);
+    /// ```
+    pub fn affine(
+        id: impl Into<String>,
+        family: impl Into<String>,
+        abbreviation: impl Into<String>,
+        full_name: impl Into<String>,
+        base_units: i64,
+        offset: f64,
+    ) -> Self {
+        Self {
+            offset,
+            ..Self::new(id, family, abbreviation, full_name, base_units)
         }
     }
 
@@ -65,14 +119,40 @@ impl MeasurementUnit {
         self.base_units
     }
 
+    /// Offset (in base units) of this unit's zero point. Zero for multiplicative units.
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    /// Returns true if this unit has a non-zero offset (values are not ratio-scaled).
+    pub fn is_affine(&self) -> bool {
+        self.offset != 0.0
+    }
+
     /// Returns true if this unit is compatible (same family) with `other`.
     pub fn is_compatible(&self, other: &Self) -> bool {
         self.family == other.family
     }
 
     /// Dimensionless numeric unit. Default unit for raw numeric samples.
+    ///
+    /// Also the plain fraction of [`percent`](Self::percent) and
+    /// [`parts_per_million`](Self::parts_per_million): 1 = 100 % = 1000000 ppm.
     pub fn number() -> Self {
-        Self::new("number", "Number", "", "Number", 1)
+        Self::new("number", "Number", "", "Number", 1_000_000)
+    }
+
+    /// Percent: one hundredth of a [`number`](Self::number) fraction.
+    ///
+    /// Differences between percent values (e.g., `shift`) are percentage points,
+    /// not relative changes: see the [module docs](self#percent-and-parts-per-million).
+    pub fn percent() -> Self {
+        Self::new("percent", "Number", "%", "Percent", 10_000)
+    }
+
+    /// Parts per million: one millionth of a [`number`](Self::number) fraction.
+    pub fn parts_per_million() -> Self {
+        Self::new("ppm", "Number", "ppm", "Parts per million", 1)
     }
 
     /// Dimensionless ratio unit. Used for ratio estimator results.
@@ -84,6 +164,35 @@ impl MeasurementUnit {
     pub fn disparity() -> Self {
         Self::new("disparity", "Disparity", "", "Disparity", 1)
     }
+
+    /// Dimensionless probability unit. Used for dominance estimator results.
+    pub fn probability() -> Self {
+        Self::new("probability", "Probability", "", "Probability", 1)
+    }
+}
+
+impl PartialEq for MeasurementUnit {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.family == other.family
+            && self.abbreviation == other.abbreviation
+            && self.full_name == other.full_name
+            && self.base_units == other.base_units
+            && self.offset.to_bits() == other.offset.to_bits()
+    }
+}
+
+impl Eq for MeasurementUnit {}
+
+impl Hash for MeasurementUnit {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.family.hash(state);
+        self.abbreviation.hash(state);
+        self.full_name.hash(state);
+        self.base_units.hash(state);
+        self.offset.to_bits().hash(state);
+    }
 }
 
 impl fmt::Display for MeasurementUnit {
@@ -109,12 +218,38 @@ pub fn finer<'a>(a: &'a MeasurementUnit, b: &'a MeasurementUnit) -> &'a Measurem
 /// Returns the multiplier to convert a value from one unit to another.
 ///
 /// `converted_value = original_value * conversion_factor(from, to)`
+///
+/// The factor ignores unit offsets, so it is exact for multiplicative units and
+/// for differences (shift, spread) between affine values. Use [`convert`] for
+/// absolute values of affine units.
 pub fn conversion_factor(from: &MeasurementUnit, to: &MeasurementUnit) -> f64 {
     from.base_units() as f64 / to.base_units() as f64
 }
 
+/// Converts an absolute value from one unit to another, honoring affine offsets.
+///
+/// `converted_value = (value * from.base_units + from.offset - to.offset) / to.base_units`
+///
+/// # Errors
+///
+/// Returns [`UnitMismatchError`] if the units belong to different families.
+pub fn convert(
+    value: f64,
+    from: &MeasurementUnit,
+    to: &MeasurementUnit,
+) -> Result<f64, UnitMismatchError> {
+    if !is_compatible(from, to) {
+        return Err(UnitMismatchError::new(from, to));
+    }
+    if from.offset() == to.offset() {
+        return Ok(value * conversion_factor(from, to));
+    }
+    let base = value.mul_add(from.base_units() as f64, from.offset());
+    Ok((base - to.offset()) / to.base_units() as f64)
+}
+
 /// Error returned when incompatible units are used together.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct UnitMismatchError {
     pub unit1_name: String,
     pub unit2_name: String,
@@ -150,6 +285,34 @@ mod tests {
         assert_eq!(MeasurementUnit::number().id(), "number");
         assert_eq!(MeasurementUnit::ratio().id(), "ratio");
         assert_eq!(MeasurementUnit::disparity().id(), "disparity");
+        assert_eq!(MeasurementUnit::probability().id(), "probability");
+        assert_eq!(MeasurementUnit::percent().id(), "percent");
+        assert_eq!(MeasurementUnit::parts_per_million().id(), "ppm");
+    }
+
+    #[test]
+    fn fraction_percent_ppm_round_trip() {
+        let (number, percent, ppm) = (
+            MeasurementUnit::number(),
+            MeasurementUnit::percent(),
+            MeasurementUnit::parts_per_million(),
+        );
+        assert!(is_compatible(&number, &percent) && is_compatible(&percent, &ppm));
+        assert_eq!(convert(0.5, &number, &percent).unwrap(), 50.0);
+        assert_eq!(convert(50.0, &percent, &ppm).unwrap(), 500_000.0);
+        assert_eq!(convert(500_000.0, &ppm, &number).unwrap(), 0.5);
+        assert_eq!(finer(&number, &percent).id(), "percent");
+        assert_eq!(finer(&percent, &ppm).id(), "ppm");
+    }
+
+    #[test]
+    fn percent_and_time_are_incompatible() {
+        let ms = MeasurementUnit::new("ms", "Time", "ms", "Millisecond", 1_000_000);
+        assert!(convert(1.0, &MeasurementUnit::percent(), &ms).is_err());
+        assert!(!is_compatible(
+            &MeasurementUnit::ratio(),
+            &MeasurementUnit::percent()
+        ));
     }
 
     #[test]
@@ -198,6 +361,54 @@ mod tests {
         assert_eq!(cloned.family(), "Number");
     }
 
+    fn celsius() -> MeasurementUnit {
+        MeasurementUnit::affine("C", "Temperature", "°C", "Celsius", 9, 2458.35)
+    }
+
+    fn fahrenheit() -> MeasurementUnit {
+        MeasurementUnit::affine("F", "Temperature", "°F", "Fahrenheit", 5, 2298.35)
+    }
+
+    #[test]
+    fn convert_celsius_to_fahrenheit() {
+        let (c, f) = (celsius(), fahrenheit());
+        assert!((convert(0.0, &c, &f).unwrap() - 32.0).abs() < 1e-9);
+        assert!((convert(100.0, &c, &f).unwrap() - 212.0).abs() < 1e-9);
+        assert!((convert(-40.0, &c, &f).unwrap() - -40.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn convert_celsius_fahrenheit_round_trip() {
+        let (c, f) = (celsius(), fahrenheit());
+        for &v in &[-273.15, -40.0, 0.0, 36.6, 100.0, 1234.5] {
+            let back = convert(convert(v, &c, &f).unwrap(), &f, &c).unwrap();
+            assert!((back - v).abs() < 1e-9, "{v} -> {back}");
+        }
+    }
+
+    #[test]
+    fn convert_affine_to_multiplicative() {
+        let c = celsius();
+        let k = MeasurementUnit::new("K", "Temperature", "K", "Kelvin", 9);
+        assert!((convert(25.0, &c, &k).unwrap() - 298.15).abs() < 1e-9);
+        assert!((convert(298.15, &k, &c).unwrap() - 25.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn convert_incompatible_fails() {
+        assert!(convert(1.0, &celsius(), &MeasurementUnit::number()).is_err());
+    }
+
+    #[test]
+    fn affine_flag() {
+        assert!(celsius().is_affine());
+        assert!(!MeasurementUnit::number().is_affine());
+        assert_ne!(
+            celsius(),
+            MeasurementUnit::new("C", "Temperature", "°C", "Celsius", 9)
+        );
+    }
+
     #[test]
     fn custom_unit_fields() {
         let unit = MeasurementUnit::new("sec", "Time", "s", "Second", 1_000_000_000);
@@ -0,0 +1,107 @@
+//! Inverse-variance pooling of centers from independent samples.
+//!
+//! [`MetaWeight`] weights each sample's [`center`] by `1 / se²`, with `se` from
+//! [`center_stderr`], so precise samples count more. The pooled center has
+//! standard error `1 / √Σ(1 / se²)` and Wald bounds `pooled ± z · se`.
+//!
+//! Like [`center_stderr`], this is asymptotic and exact only under the normal
+//! model: use it to combine summaries quickly, not as a distribution-free
+//! replacement for [`center_bounds`](crate::estimators::center_bounds). The
+//! samples are assumed to estimate the same center; heterogeneity between them
+//! is not modeled.
+
+use crate::assumptions::{AssumptionError, EstimatorError};
+use crate::bounds::Bounds;
+use crate::distributions::{Additive, Distribution};
+use crate::estimators::{center, center_stderr};
+use crate::measurement::Measurement;
+use crate::measurement_unit::{conversion_factor, convert, finer, MeasurementUnit};
+use crate::sample::Sample;
+
+/// Inverse-variance weights of several samples' centers and their pooled center.
+#[derive(Debug, Clone)]
+pub struct MetaWeight {
+    /// Weight of each sample, in input order; the weights sum to one.
+    pub weights: Vec<f64>,
+    /// Weighted average of the centers.
+    pub center: Measurement,
+    /// Asymptotic standard error of the pooled center.
+    pub stderr: Measurement,
+}
+
+impl MetaWeight {
+    /// Pools the centers of independent samples.
+    ///
+    /// The result is in the finest unit of the samples.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EstimatorError::Other`] if `samples` is empty or their units
+    /// are incompatible, and the errors of [`center`] and [`center_stderr`]
+    /// (e.g. a sample with zero spread).
+    ///
+    /// # Example
+    /// ```
+    /// use pragmastat::{MetaWeight, Sample};
+    ///
+    /// let a = Sample::new(vec![1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+    /// let b = Sample::new(vec![2.0, 4.0, 6.0, 8.0, 10.0]).unwrap();
+    /// let pooled = MetaWeight::new(&[&a, &b]).unwrap();
+    /// // Same size, half the spread: four times the weight
+    /// assert!((pooled.weights[0] - 0.8).abs() < 1e-12);
+    /// assert!((pooled.center.value - (0.8 * 3.0 + 0.2 * 6.0)).abs() < 1e-12);
+    /// ```
+    pub fn new(samples: &[&Sample]) -> Result<Self, EstimatorError> {
+        let first = samples
+            .first()
+            .ok_or_else(|| EstimatorError::Other("no samples to pool".to_string()))?;
+        let mut unit: &MeasurementUnit = first.unit();
+        for s in samples {
+            if !s.unit().is_compatible(unit) {
+                return Err(EstimatorError::Other(format!(
+                    "can't convert {} to {}",
+                    s.unit().full_name(),
+                    unit.full_name()
+                )));
+            }
+            unit = finer(unit, s.unit());
+        }
+
+        let mut centers = Vec::with_capacity(samples.len());
+        let mut weights = Vec::with_capacity(samples.len());
+        for s in samples {
+            let c = center(s)?;
+            let se = center_stderr(s)?;
+            centers.push(convert(c.value, &c.unit, unit)?);
+            weights.push((conversion_factor(&se.unit, unit) * se.value).powi(-2));
+        }
+        let total: f64 = weights.iter().sum();
+        for w in &mut weights {
+            *w /= total;
+        }
+        let pooled = weights.iter().zip(&centers).map(|(w, c)| w * c).sum();
+        Ok(Self {
+            weights,
+            center: Measurement::new(pooled, unit.clone()),
+            stderr: Measurement::new(total.sqrt().recip(), unit.clone()),
+        })
+    }
+
+    /// Wald bounds `center ± z · stderr` with `z = Φ⁻¹(1 - misrate / 2)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `domain(misrate)` error if `misrate` is not in `(0, 1]`.
+    pub fn bounds(&self, misrate: f64) -> Result<Bounds, EstimatorError> {
+        if misrate.is_nan() || misrate <= 0.0 || misrate > 1.0 {
+            return Err(AssumptionError::misrate_out_of_range(misrate).into());
+        }
+        let z = Additive::new(0.0, 1.0).quantile(1.0 - misrate / 2.0);
+        let half_width = z * self.stderr.value;
+        Ok(Bounds::new(
+            self.center.value - half_width,
+            self.center.value + half_width,
+            self.center.unit.clone(),
+        ))
+    }
+}
@@ -1,15 +1,12 @@
 //! Minimum achievable misrate functions
 
-use crate::assumptions::{AssumptionError, AssumptionId, Subject, Violation};
+use crate::assumptions::{AssumptionError, Subject};
 
 /// Computes the minimum achievable misrate for one-sample signed-rank based bounds.
 /// Returns 2^(1-n) which is the smallest possible misrate for a sample of size n.
 pub fn min_achievable_misrate_one_sample(n: usize) -> Result<f64, AssumptionError> {
     if n == 0 {
-        return Err(AssumptionError::new(Violation::new(
-            AssumptionId::Domain,
-            Subject::X,
-        )));
+        return Err(AssumptionError::sample_too_small(Subject::X, 0, 1));
     }
     Ok(0.5_f64.powi((n - 1) as i32))
 }
@@ -17,10 +14,10 @@ pub fn min_achievable_misrate_one_sample(n: usize) -> Result<f64, AssumptionErro
 /// Computes the minimum achievable misrate for two-sample Mann-Whitney based bounds.
 pub fn min_achievable_misrate_two_sample(n: usize, m: usize) -> Result<f64, AssumptionError> {
     if n == 0 {
-        return Err(AssumptionError::domain(Subject::X));
+        return Err(AssumptionError::sample_too_small(Subject::X, 0, 1));
     }
     if m == 0 {
-        return Err(AssumptionError::domain(Subject::Y));
+        return Err(AssumptionError::sample_too_small(Subject::Y, 0, 1));
     }
     Ok(2.0 / binomial_coefficient(n + m, n))
 }
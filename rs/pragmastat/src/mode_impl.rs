@@ -0,0 +1,85 @@
+//! Half-sample mode (Bickel & Frühwirth, 2006) of ascending input.
+//!
+//! Each step keeps the `h = ceil(n / 2)` consecutive values with the smallest
+//! range `x[i + h - 1] - x[i]`, until at most three values remain. The steps
+//! halve `n`, so the cost is O(n) after sorting.
+
+/// Returns the half-sample mode of a non-empty ascending slice.
+///
+/// Ties between equally narrow windows go to the middle one: with `k` tied
+/// windows (in index order), the one at position `(k - 1) / 2`. For an odd
+/// `k` this makes the result symmetric under `x -> -x`.
+///
+/// The last one to three values are resolved explicitly:
+/// - `n = 1`: the value;
+/// - `n = 2`: their midpoint;
+/// - `n = 3`: the midpoint of the closer pair, or the middle value if both
+///   gaps are equal.
+pub(crate) fn half_sample_mode(sorted: &[f64]) -> f64 {
+    debug_assert!(!sorted.is_empty());
+    let mut window = sorted;
+    while window.len() > 3 {
+        let h = window.len().div_ceil(2);
+        window = &window[narrowest_window(window, h)..][..h];
+    }
+    match *window {
+        [a] => a,
+        [a, b] => midpoint(a, b),
+        [a, b, c] => {
+            let (left, right) = (b - a, c - b);
+            if left < right {
+                midpoint(a, b)
+            } else if right < left {
+                midpoint(b, c)
+            } else {
+                b
+            }
+        }
+        _ => unreachable!("window has 1 to 3 values"),
+    }
+}
+
+/// Start of the narrowest run of `h` consecutive values, ties to the middle.
+fn narrowest_window(x: &[f64], h: usize) -> usize {
+    let widths = (0..=x.len() - h).map(|i| x[i + h - 1] - x[i]);
+    let min_width = widths.clone().fold(f64::INFINITY, f64::min);
+    let tied: Vec<usize> = widths
+        .enumerate()
+        .filter(|&(_, w)| w == min_width)
+        .map(|(i, _)| i)
+        .collect();
+    tied[(tied.len() - 1) / 2]
+}
+
+/// Midpoint without overflow at the ends of the `f64` range.
+fn midpoint(a: f64, b: f64) -> f64 {
+    0.5 * a + 0.5 * b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_samples_use_explicit_formulas() {
+        assert_eq!(half_sample_mode(&[4.0]), 4.0);
+        assert_eq!(half_sample_mode(&[1.0, 4.0]), 2.5);
+        assert_eq!(half_sample_mode(&[1.0, 2.0, 10.0]), 1.5);
+        assert_eq!(half_sample_mode(&[1.0, 9.0, 10.0]), 9.5);
+        assert_eq!(half_sample_mode(&[1.0, 2.0, 3.0]), 2.0);
+    }
+
+    #[test]
+    fn ties_go_to_the_middle_window() {
+        // All three windows of two values have width 1; the middle is [2, 3]
+        assert_eq!(narrowest_window(&[1.0, 2.0, 3.0, 4.0], 2), 1);
+        // Four tied windows: positions 0..=3, the lower middle is 1
+        assert_eq!(narrowest_window(&[1.0, 2.0, 3.0, 4.0, 5.0], 2), 1);
+    }
+
+    #[test]
+    fn infinite_widths_still_select_a_window() {
+        let x = [-f64::MAX, f64::MAX, f64::MAX, f64::MAX];
+        assert_eq!(half_sample_mode(&x), f64::MAX);
+    }
+}
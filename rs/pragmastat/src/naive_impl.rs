@@ -0,0 +1,59 @@
+//! Naive O(N log N) reference implementations (N = number of pairs).
+//!
+//! These materialize and sort every pairwise difference. They serve as the
+//! [`Algorithm::Naive`](crate::estimators::Algorithm::Naive) fallback and as
+//! oracles for the fast selection algorithms: each evaluates exactly the same
+//! floating-point expressions as its fast counterpart, so results agree
+//! bit-for-bit.
+//!
+//! Internal implementation - not part of public API.
+
+/// Median of all pairwise differences `x[i] - y[j]` (Type-7 interpolation).
+pub(crate) fn shift_naive(x: &[f64], y: &[f64]) -> f64 {
+    let mut diffs: Vec<f64> = Vec::with_capacity(x.len() * y.len());
+    for &xi in x {
+        for &yj in y {
+            diffs.push(xi - yj);
+        }
+    }
+    diffs.sort_unstable_by(|a, b| a.total_cmp(b));
+
+    // Same rank/weight arithmetic as shift_quantiles_impl at p = 0.5
+    let total = diffs.len() as i64;
+    let h = 1.0 + (total - 1) as f64 * 0.5;
+    let lower_rank = h.floor() as i64;
+    let upper_rank = h.ceil() as i64;
+    let weight = h - lower_rank as f64;
+    let lower = diffs[(lower_rank - 1) as usize];
+    let upper = diffs[(upper_rank - 1) as usize];
+    if weight == 0.0 {
+        lower
+    } else {
+        (1.0 - weight) * lower + weight * upper
+    }
+}
+
+/// Median of all pairwise absolute differences `|x[i] - x[j]|`, `i < j`.
+///
+/// `sorted` must be ascending, so every difference is `sorted[j] - sorted[i]`
+/// exactly as in spread_impl.
+pub(crate) fn spread_naive(sorted: &[f64]) -> f64 {
+    let n = sorted.len();
+    if n <= 1 {
+        return 0.0;
+    }
+    let mut diffs: Vec<f64> = Vec::with_capacity(n * (n - 1) / 2);
+    for i in 0..n {
+        for j in i + 1..n {
+            diffs.push(sorted[j] - sorted[i]);
+        }
+    }
+    diffs.sort_unstable_by(|a, b| a.total_cmp(b));
+
+    let total = diffs.len();
+    if total % 2 == 1 {
+        diffs[total / 2]
+    } else {
+        0.5 * diffs[total / 2 - 1] + 0.5 * diffs[total / 2]
+    }
+}
@@ -26,54 +26,252 @@ const MAX_ACCEPTABLE_BINOM_N: usize = 62;
 ///
 /// # Errors
 ///
-/// Returns an error if n == 0, m == 0, or misrate is outside [0, 1] or is NaN.
+/// Returns an error if n == 0, m == 0, or misrate is outside [0, 1] or is NaN,
+/// or is below the minimum achievable misrate `2 / C(n + m, n)`.
+///
+/// # Examples
+///
+/// ```
+/// use pragmastat::margins::pairwise_margin;
+///
+/// let margin = pairwise_margin(30, 30, 0.05).unwrap();
+/// assert_eq!(margin % 2, 0);
+/// assert!(margin < 30 * 30);
+/// assert!(pairwise_margin(2, 2, 0.01).is_err());
+/// ```
 pub fn pairwise_margin(n: usize, m: usize, misrate: f64) -> Result<u64, AssumptionError> {
-    if n == 0 {
-        return Err(AssumptionError::domain(Subject::X));
+    PairwiseMargin::new(n, m)?.margin(misrate)
+}
+
+/// Precomputed state for [`pairwise_margin`] at fixed sample sizes `n` and `m`.
+///
+/// Construction builds the table of Löffler's divisor-sum coefficients
+/// `σ(d) = Σ{k ≤ n, k | d} k − Σ{m < k ≤ m + n, k | d} k` for every
+/// `d ≤ n·m` with a single sieve pass, so each later lookup is O(1) and
+/// several misrates can be queried without recomputing it. The table is only
+/// built when the exact distribution is used (`n + m <= 400`); above that,
+/// margins come from the Edgeworth approximation and [`sigma`](Self::sigma)
+/// falls back to direct summation.
+///
+/// # Examples
+///
+/// ```
+/// use pragmastat::margins::{pairwise_margin, PairwiseMargin};
+///
+/// let table = PairwiseMargin::new(30, 20).unwrap();
+/// for misrate in [0.01, 0.05, 0.1] {
+///     assert_eq!(table.margin(misrate).unwrap(), pairwise_margin(30, 20, misrate).unwrap());
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PairwiseMargin {
+    n: usize,
+    m: usize,
+    sigma: Vec<f64>,
+}
+
+impl PairwiseMargin {
+    /// Precomputes the coefficient table for samples of sizes `n` and `m`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a domain error if `n == 0` or `m == 0`.
+    pub fn new(n: usize, m: usize) -> Result<Self, AssumptionError> {
+        if n == 0 {
+            return Err(AssumptionError::sample_too_small(Subject::X, 0, 1));
+        }
+        if m == 0 {
+            return Err(AssumptionError::sample_too_small(Subject::Y, 0, 1));
+        }
+
+        let sigma = if n + m <= MAX_EXACT_SIZE {
+            sigma_table(n, m)
+        } else {
+            Vec::new()
+        };
+        Ok(Self { n, m, sigma })
+    }
+
+    /// Size of the first sample.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Size of the second sample.
+    pub fn m(&self) -> usize {
+        self.m
     }
-    if m == 0 {
-        return Err(AssumptionError::domain(Subject::Y));
+
+    /// Löffler's coefficient `σ(d)`; `σ(0)` is defined as zero.
+    pub fn sigma(&self, d: usize) -> i64 {
+        match self.sigma.get(d) {
+            Some(&value) => value as i64,
+            None => sigma_direct(self.n, self.m, d),
+        }
     }
-    if misrate.is_nan() || !(0.0..=1.0).contains(&misrate) {
-        return Err(AssumptionError::domain(Subject::Misrate));
+
+    /// Exact null `P(U <= u)` of the Mann-Whitney statistic, summing the
+    /// Löffler counts up to `u`; `u` beyond `n * m` gives 1.
+    ///
+    /// Only available where the table is built (`n + m <= 400`).
+    pub(crate) fn exact_cdf(&self, u: usize) -> f64 {
+        debug_assert!(!self.sigma.is_empty(), "exact_cdf needs the sigma table");
+        let u = u.min(self.n * self.m);
+        let mut pmf = Vec::with_capacity(u + 1);
+        pmf.push(1.0);
+        while pmf.len() <= u {
+            pmf.push(loeffler_next(&pmf, &self.sigma));
+        }
+        pmf.iter().sum::<f64>() / arrangement_count(self.n, self.m)
     }
 
-    let min_misrate = crate::min_misrate::min_achievable_misrate_two_sample(n, m)?;
-    if misrate < min_misrate {
-        return Err(AssumptionError::domain(Subject::Misrate));
+    /// Margin for the given misrate; identical to [`pairwise_margin`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if misrate is outside [0, 1] or is NaN, or is below
+    /// the minimum achievable misrate `2 / C(n + m, n)`.
+    pub fn margin(&self, misrate: f64) -> Result<u64, AssumptionError> {
+        let (n, m) = (self.n, self.m);
+        if misrate.is_nan() || !(0.0..=1.0).contains(&misrate) {
+            return Err(AssumptionError::misrate_out_of_range(misrate));
+        }
+
+        let min_misrate = crate::min_misrate::min_achievable_misrate_two_sample(n, m)?;
+        if misrate < min_misrate {
+            return Err(AssumptionError::misrate_too_low(
+                misrate,
+                min_misrate,
+                n,
+                Some(m),
+            ));
+        }
+
+        if n + m <= MAX_EXACT_SIZE {
+            Ok(pairwise_margin_exact_raw(n, m, &self.sigma, misrate / 2.0) as u64 * 2)
+        } else {
+            Ok(pairwise_margin_approx_raw(n, m, misrate / 2.0) * 2)
+        }
     }
+}
 
-    if n + m <= MAX_EXACT_SIZE {
-        Ok(pairwise_margin_exact(n, m, misrate))
+/// Exact coverage of pairwise-difference bounds with the given margin.
+///
+/// The bounds are the pairwise differences of ranks `h + 1` and `N - h` among
+/// all `N = n · m`, with `h = margin / 2` capped at `(N - 1) / 2` as in
+/// `shift_bounds`. For samples from continuous distributions that differ only
+/// by a shift they contain that shift with probability `1 - 2 P(U <= h)`,
+/// where `U` is the Mann-Whitney statistic under the null.
+///
+/// The tail is exact while the smaller sample has at most 400 values (so
+/// `n = 1000` against `m <= 400`): it expands the Gaussian binomial
+/// coefficient `[n + m choose n]_q` in probability space, which does not
+/// overflow, in `O(min(n, m) · h)`. The expansion divides by `1 - q^j` and
+/// loses digits as the smaller sample grows, so above that size the tail
+/// comes from the Edgeworth expansion behind [`pairwise_margin`], accurate to
+/// about `1e-6` there.
+///
+/// # Panics
+///
+/// Panics if `n == 0` or `m == 0`.
+///
+/// # Examples
+///
+/// ```
+/// use pragmastat::margins::{pairwise_coverage, pairwise_margin};
+///
+/// let margin = pairwise_margin(5, 5, 0.1).unwrap();
+/// assert!((pairwise_coverage(5, 5, margin) - 214.0 / 252.0).abs() < 1e-12);
+/// assert!((pairwise_coverage(5, 5, 0) - 250.0 / 252.0).abs() < 1e-12);
+/// ```
+pub fn pairwise_coverage(n: usize, m: usize, margin: u64) -> f64 {
+    assert!(
+        n > 0 && m > 0,
+        "pairwise coverage needs two non-empty samples"
+    );
+    let total = n as u64 * m as u64;
+    let h = (margin / 2).min((total - 1) / 2);
+    let tail = if n.min(m) <= COVERAGE_MAX_EXACT_SIZE {
+        mann_whitney_cdf(n, m, h as usize)
     } else {
-        Ok(pairwise_margin_approx(n, m, misrate))
+        // edgeworth_cdf(u) approximates P(U < u)
+        edgeworth_cdf(n, m, h + 1)
+    };
+    (1.0 - 2.0 * tail).max(0.0)
+}
+
+/// Largest smaller sample for which [`pairwise_coverage`] expands the exact
+/// distribution; at this size the expansion still agrees with an independent
+/// evaluation to about `1e-13`.
+const COVERAGE_MAX_EXACT_SIZE: usize = 400;
+
+/// Null `P(U <= u)` of the Mann-Whitney statistic from the Gaussian binomial
+/// `[n + m choose n]_q = Π (1 - q^(m + j)) / (1 - q^j)`, `j = 1..=n`.
+///
+/// After factor `j` the first `u + 1` coefficients, scaled by `1 / C(m + j, j)`,
+/// are the exact probabilities for samples of sizes `j` and `m`.
+fn mann_whitney_cdf(n: usize, m: usize, u: usize) -> f64 {
+    let (n, m) = (n.min(m), n.max(m));
+    let mut p = vec![0.0; u + 1];
+    p[0] = 1.0;
+    for j in 1..=n {
+        // Divide by 1 - q^j
+        for v in j..=u {
+            p[v] += p[v - j];
+        }
+        // Multiply by 1 - q^(m + j) and rescale from C(m + j - 1, j - 1) to C(m + j, j)
+        let scale = j as f64 / (m + j) as f64;
+        for v in (0..=u).rev() {
+            let removed = if v >= m + j { p[v - m - j] } else { 0.0 };
+            p[v] = (p[v] - removed) * scale;
+        }
     }
+    p.iter().sum::<f64>().clamp(0.0, 1.0)
 }
 
-/// Uses the exact distribution based on Loeffler's recurrence
-fn pairwise_margin_exact(n: usize, m: usize, misrate: f64) -> u64 {
-    pairwise_margin_exact_raw(n, m, misrate / 2.0) as u64 * 2
+/// Sieves `σ(d)` for all `d` in `0..=n*m`
+fn sigma_table(n: usize, m: usize) -> Vec<f64> {
+    let size = n * m + 1;
+    let mut sigma = vec![0i64; size];
+    for k in 1..=n {
+        for d in (k..size).step_by(k) {
+            sigma[d] += k as i64;
+        }
+    }
+    for k in (m + 1)..=(m + n) {
+        for d in (k..size).step_by(k) {
+            sigma[d] -= k as i64;
+        }
+    }
+    sigma.into_iter().map(|value| value as f64).collect()
 }
 
-/// Uses Edgeworth approximation for large samples
-fn pairwise_margin_approx(n: usize, m: usize, misrate: f64) -> u64 {
-    pairwise_margin_approx_raw(n, m, misrate / 2.0) * 2
+/// Computes `σ(d)` by iterating over the candidate divisors
+fn sigma_direct(n: usize, m: usize, d: usize) -> i64 {
+    if d == 0 {
+        return 0;
+    }
+    let mut value = 0;
+    for k in 1..=n {
+        if d.is_multiple_of(k) {
+            value += k as i64;
+        }
+    }
+    for k in (m + 1)..=(m + n) {
+        if d.is_multiple_of(k) {
+            value -= k as i64;
+        }
+    }
+    value
 }
 
 /// Inversed implementation of Andreas Löffler's (1982)
 /// "Über eine Partition der nat. Zahlen und ihre Anwendung beim U-Test"
-fn pairwise_margin_exact_raw(n: usize, m: usize, p: f64) -> usize {
-    let total = if n + m < MAX_ACCEPTABLE_BINOM_N {
-        binomial_coefficient(n + m, m)
-    } else {
-        binomial_coefficient_float(n + m, m)
-    };
+fn pairwise_margin_exact_raw(n: usize, m: usize, sigma: &[f64], p: f64) -> usize {
+    let total = arrangement_count(n, m);
 
-    let capacity = n * m + 1;
-    let mut pmf = Vec::with_capacity(capacity);
+    let mut pmf = Vec::with_capacity(n * m + 1);
     pmf.push(1.0); // pmf[0] = 1
-    let mut sigma = Vec::with_capacity(capacity);
-    sigma.push(0.0); // sigma[0] is unused
 
     let mut u: usize = 0;
     let mut cdf = 1.0 / total;
@@ -82,31 +280,10 @@ fn pairwise_margin_exact_raw(n: usize, m: usize, p: f64) -> usize {
         return 0;
     }
 
-    loop {
+    while u + 1 < sigma.len() {
         u += 1;
 
-        // Ensure sigma has entry for u
-        if sigma.len() <= u {
-            let mut value = 0;
-            for d in 1..=n {
-                if u.is_multiple_of(d) && u >= d {
-                    value += d as i64;
-                }
-            }
-            for d in (m + 1)..=(m + n) {
-                if u.is_multiple_of(d) && u >= d {
-                    value -= d as i64;
-                }
-            }
-            sigma.push(value as f64);
-        }
-
-        // Compute pmf[u] using Loeffler recurrence
-        let mut sum = 0.0;
-        for i in 0..u {
-            sum = f64::mul_add(pmf[i], sigma[u - i], sum);
-        }
-        sum /= u as f64;
+        let sum = loeffler_next(&pmf, sigma);
         pmf.push(sum);
 
         cdf += sum / total;
@@ -121,6 +298,26 @@ fn pairwise_margin_exact_raw(n: usize, m: usize, p: f64) -> usize {
     pmf.len() - 1
 }
 
+/// Number of the `C(n + m, n)` equally likely arrangements under the null
+fn arrangement_count(n: usize, m: usize) -> f64 {
+    if n + m < MAX_ACCEPTABLE_BINOM_N {
+        binomial_coefficient(n + m, m)
+    } else {
+        binomial_coefficient_float(n + m, m)
+    }
+}
+
+/// Löffler's recurrence: the number of arrangements with `U = pmf.len()`,
+/// given the counts `pmf` for every smaller `U`
+fn loeffler_next(pmf: &[f64], sigma: &[f64]) -> f64 {
+    let u = pmf.len();
+    let mut sum = 0.0;
+    for i in 0..u {
+        sum = f64::mul_add(pmf[i], sigma[u - i], sum);
+    }
+    sum / u as f64
+}
+
 /// Inverse Edgeworth Approximation
 fn pairwise_margin_approx_raw(n: usize, m: usize, misrate: f64) -> u64 {
     let mut a: u64 = 0;
@@ -307,7 +504,7 @@ fn stirling_approx_log(x: f64) -> f64 {
 
 #[cfg(test)]
 mod tests {
-    use super::pairwise_margin;
+    use super::{edgeworth_cdf, mann_whitney_cdf, pairwise_margin, sigma_direct, PairwiseMargin};
     use serde::Deserialize;
     use std::fs;
     use std::path::PathBuf;
@@ -473,4 +670,60 @@ mod tests {
         assert_eq!(v.id, AssumptionId::Domain);
         assert_eq!(v.subject, Subject::Misrate);
     }
+
+    #[test]
+    fn cached_sigma_matches_direct() {
+        for (n, m) in [(1, 1), (5, 5), (7, 13), (40, 3), (25, 60)] {
+            let table = PairwiseMargin::new(n, m).unwrap();
+            for d in 0..=n * m + 10 {
+                assert_eq!(table.sigma(d), sigma_direct(n, m, d), "n={n}, m={m}, d={d}");
+            }
+        }
+    }
+
+    #[test]
+    fn cached_sigma_falls_back_above_exact_size() {
+        let table = PairwiseMargin::new(300, 150).unwrap();
+        for d in [1, 2, 151, 300, 450, 44_999, 45_000] {
+            assert_eq!(table.sigma(d), sigma_direct(300, 150, d), "d={d}");
+        }
+    }
+
+    #[test]
+    fn cached_margin_matches_free_function() {
+        for (n, m) in [(10, 10), (12, 30), (250, 200)] {
+            let table = PairwiseMargin::new(n, m).unwrap();
+            for misrate in [1e-3, 0.01, 0.05, 0.1, 0.5, 1.0] {
+                assert_eq!(
+                    table.margin(misrate).unwrap(),
+                    pairwise_margin(n, m, misrate).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn coverage_expansion_matches_loeffler() {
+        for (n, m) in [(1, 1), (3, 7), (10, 10), (12, 30), (60, 45), (120, 40)] {
+            let table = PairwiseMargin::new(n, m).unwrap();
+            for u in [0, 1, n * m / 4, n * m / 2, n * m] {
+                let expected = table.exact_cdf(u);
+                let actual = mann_whitney_cdf(n, m, u);
+                assert!(
+                    (actual - expected).abs() <= 1e-12 * expected,
+                    "n={n}, m={m}, u={u}: {actual} vs {expected}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn coverage_edgeworth_matches_expansion_at_switch() {
+        for misrate in [1e-3, 0.05] {
+            let h = pairwise_margin(400, 400, misrate).unwrap() / 2;
+            let exact = mann_whitney_cdf(400, 400, h as usize);
+            let approx = edgeworth_cdf(400, 400, h + 1);
+            assert!((exact - approx).abs() < 1e-8, "{exact} vs {approx}");
+        }
+    }
 }
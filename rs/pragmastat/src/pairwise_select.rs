@@ -0,0 +1,203 @@
+//! O(n) Center and Spread of already sorted values.
+//!
+//! The pairwise sums `x[i] + x[j]` and differences `x[j] - x[i]` of sorted
+//! values form matrices whose rows and columns are non-decreasing. The
+//! middle entries of such a matrix are selected in linear time, reading
+//! entries on demand, by recursing on the submatrix of every other row and
+//! column (Frederickson & Johnson, "Generalized selection and ranking: sorted
+//! matrices", 1984). Results are bit-identical to `center_impl` and
+//! `spread_impl` on the same sorted values.
+//!
+//! Internal implementation - not part of public API.
+
+/// Matrices this small are selected by sorting their entries.
+const BASE_DIM: usize = 8;
+
+/// Center (Hodges-Lehmann) of `sorted` in O(n).
+pub(crate) fn center_sorted(sorted: &[f64]) -> f64 {
+    let n = sorted.len();
+    assert!(n > 0, "center of an empty window");
+    if n == 1 {
+        return sorted[0];
+    }
+    if n == 2 {
+        return 0.5 * sorted[0] + 0.5 * sorted[1];
+    }
+
+    // Sums over i <= j: the off-diagonal ones appear twice in the full
+    // matrix, so the full ranks below bracket the triangle ranks
+    let total_pairs = n * (n + 1) / 2;
+    let rank_low = total_pairs.div_ceil(2);
+    let rank_high = (total_pairs + 2) / 2;
+    let sums = SortedMatrix::new(n, |i, j| sorted[i] + sorted[j]);
+    let (lo, hi) = sums.select((2 * rank_low).saturating_sub(n).max(1), 2 * rank_high - 1);
+    let (low, high) = sums.resolve(1, lo, hi, rank_low, rank_high, |i| i);
+
+    if rank_low < rank_high {
+        0.25 * high + 0.25 * low
+    } else {
+        low / 2.0
+    }
+}
+
+/// Spread (Shamos) of `sorted` in O(n).
+pub(crate) fn spread_sorted(sorted: &[f64]) -> f64 {
+    let n = sorted.len();
+    if n <= 1 {
+        return 0.0;
+    }
+    if n == 2 {
+        return sorted[1] - sorted[0];
+    }
+
+    // The full matrix holds every difference over i < j once with each sign,
+    // plus `n` zeros: the non-negative half starts at rank n + total_pairs
+    let total_pairs = n * (n - 1) / 2;
+    let rank_low = total_pairs.div_ceil(2);
+    let rank_high = (total_pairs + 2) / 2;
+    let differences = SortedMatrix::new(n, |i, j| sorted[j] - sorted[n - 1 - i]);
+    let offset = n + total_pairs;
+    let (low, high) = differences.select(offset + rank_low, offset + rank_high);
+
+    if rank_low < rank_high {
+        0.5 * low + 0.5 * high
+    } else {
+        low
+    }
+}
+
+/// An `n`×`n` matrix whose rows and columns are non-decreasing.
+struct SortedMatrix<F> {
+    n: usize,
+    at: F,
+}
+
+impl<F: Fn(usize, usize) -> f64> SortedMatrix<F> {
+    fn new(n: usize, at: F) -> Self {
+        Self { n, at }
+    }
+
+    /// Returns the entries of 1-based ranks `r1 <= r2`, in
+    /// O(n + (r2 - r1)) time.
+    fn select(&self, r1: usize, r2: usize) -> (f64, f64) {
+        debug_assert!(1 <= r1 && r1 <= r2 && r2 <= self.n * self.n);
+        self.select_in(1, r1, r2)
+    }
+
+    /// Dimension of the submatrix of every `step`-th row and column.
+    fn dim(&self, step: usize) -> usize {
+        (self.n - 1) / step + 1
+    }
+
+    fn entry(&self, step: usize, i: usize, j: usize) -> f64 {
+        (self.at)(i * step, j * step)
+    }
+
+    fn select_in(&self, step: usize, r1: usize, r2: usize) -> (f64, f64) {
+        let d = self.dim(step);
+        if d <= BASE_DIM {
+            let mut entries: Vec<f64> = (0..d)
+                .flat_map(|i| (0..d).map(move |j| self.entry(step, i, j)))
+                .collect();
+            entries.sort_unstable_by(f64::total_cmp);
+            return (entries[r1 - 1], entries[r2 - 1]);
+        }
+
+        // Each entry of the half-size matrix heads a 2x2 block of entries no
+        // smaller than it. With q_i entries <= x in row i (non-increasing in
+        // i), the full count c and the half count h satisfy
+        // 4h - (2d + 1) <= c <= 4h, so these half ranks bracket r1 and r2.
+        let half = self.dim(2 * step);
+        let lo_rank = r1.div_ceil(4);
+        let hi_rank = (r2 + 2 * d + 1).div_ceil(4);
+        let (lo, hi) = if hi_rank <= half * half {
+            self.select_in(2 * step, lo_rank, hi_rank)
+        } else {
+            let lo = self.select_in(2 * step, lo_rank, lo_rank).0;
+            (lo, self.entry(step, d - 1, d - 1))
+        };
+        self.resolve(step, lo, hi, r1, r2, |_| 0)
+    }
+
+    /// Returns the entries of ranks `r1 <= r2` among the entries at or right
+    /// of column `first(i)` in each row `i`, given that both lie in
+    /// `[lo, hi]`. Only the entries strictly between `lo` and `hi` are
+    /// collected.
+    fn resolve(
+        &self,
+        step: usize,
+        lo: f64,
+        hi: f64,
+        r1: usize,
+        r2: usize,
+        first: impl Fn(usize) -> usize,
+    ) -> (f64, f64) {
+        let d = self.dim(step);
+        let mut at_most_lo = 0;
+        let mut below_hi = 0;
+        let mut between = Vec::new();
+        // Row counts of entries <= lo and < hi only shrink down the rows
+        let (mut lo_col, mut hi_col) = (d, d);
+        for i in 0..d {
+            while lo_col > 0 && self.entry(step, i, lo_col - 1) > lo {
+                lo_col -= 1;
+            }
+            while hi_col > 0 && self.entry(step, i, hi_col - 1) >= hi {
+                hi_col -= 1;
+            }
+            let start = first(i);
+            at_most_lo += lo_col.saturating_sub(start);
+            below_hi += hi_col.saturating_sub(start);
+            between.extend((lo_col.max(start)..hi_col).map(|j| self.entry(step, i, j)));
+        }
+
+        let mut pick = |rank: usize| {
+            if rank <= at_most_lo {
+                lo
+            } else if rank > below_hi {
+                hi
+            } else {
+                let index = rank - at_most_lo - 1;
+                *between.select_nth_unstable_by(index, f64::total_cmp).1
+            }
+        };
+        (pick(r1), pick(r2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    #[test]
+    fn matches_monahan_selection() {
+        let mut rng = Rng::from_string("pairwise-select");
+        for n in (1..40).chain([63, 64, 65, 200, 513]) {
+            for ties in [false, true] {
+                let mut x: Vec<f64> = (0..n)
+                    .map(|_| {
+                        if ties {
+                            rng.uniform_i64(-3, 4) as f64
+                        } else {
+                            rng.uniform_f64() * 100.0 - 50.0
+                        }
+                    })
+                    .collect();
+                x.sort_unstable_by(f64::total_cmp);
+                let center = crate::center_impl::center_impl(&x, true).unwrap();
+                let spread = crate::spread_impl::spread_impl(&x, true).unwrap();
+                assert_eq!(
+                    center_sorted(&x).to_bits(),
+                    center.to_bits(),
+                    "center n={n}"
+                );
+                assert_eq!(
+                    spread_sorted(&x).to_bits(),
+                    spread.to_bits(),
+                    "spread n={n}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Deterministic chunked execution for the heavy inner loops.
+//!
+//! The row-scanning kernels of `spread_impl` and `shift_impl` are split into
+//! fixed-size row chunks. The partition depends only on the input size (never on
+//! the thread count), each chunk computes its own starting pointer, and the
+//! per-chunk results are combined in chunk order with exact operations (integer
+//! sums, min/max). The result is therefore bit-for-bit identical to a single
+//! serial pass. With the `rayon` feature the chunks run on the rayon thread pool;
+//! without it they run one after another.
+//!
+//! [`map_each`] runs independent per-item work (one estimator call per group)
+//! the same way and returns the results in item order.
+//!
+//! The replicate loop of `disparity_bounds_opts` runs through [`map_each`]:
+//! replicate `i` draws from its own generator derived from `(seed, i)` by
+//! SplitMix64, so its draws do not depend on which thread runs it. A single `spread_bounds`/`disparity_bounds` call is one shuffle of
+//! one sequential `Rng` stream; splitting that stream would change the
+//! published results, so it stays serial and only its `spread`/`shift`
+//! sub-computations use this module.
+//!
+//! Internal implementation - not part of public API.
+
+use std::ops::Range;
+
+/// Rows per chunk when running in parallel.
+const CHUNK_ROWS: usize = 4096;
+
+/// Inputs with fewer rows than this always run serially.
+const MIN_PARALLEL_ROWS: usize = 2 * CHUNK_ROWS;
+
+/// How a row-scanning kernel is executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Execution {
+    /// A single pass over all rows.
+    Serial,
+    /// Fixed-size row chunks, combined in order.
+    Chunked { chunk_rows: usize },
+}
+
+impl Execution {
+    /// Picks the execution mode for a kernel over `rows` rows.
+    ///
+    /// Chunking pays off only when the chunks can run concurrently, so without
+    /// the `rayon` feature this is always [`Execution::Serial`].
+    pub(crate) fn auto(rows: usize) -> Self {
+        if cfg!(feature = "rayon") && rows >= MIN_PARALLEL_ROWS {
+            Execution::Chunked {
+                chunk_rows: CHUNK_ROWS,
+            }
+        } else {
+            Execution::Serial
+        }
+    }
+}
+
+/// Applies `kernel` to row ranges covering `0..rows` and folds the results in
+/// row order with `combine`.
+pub(crate) fn map_rows<T, K, C>(rows: usize, exec: Execution, kernel: K, combine: C) -> T
+where
+    T: Send,
+    K: Fn(Range<usize>) -> T + Sync,
+    C: Fn(T, T) -> T,
+{
+    let chunk_rows = match exec {
+        Execution::Chunked { chunk_rows } if chunk_rows < rows => chunk_rows,
+        _ => return kernel(0..rows),
+    };
+    let ranges: Vec<Range<usize>> = (0..rows)
+        .step_by(chunk_rows)
+        .map(|start| start..(start + chunk_rows).min(rows))
+        .collect();
+    fold_in_order(run_chunks(ranges, kernel), combine)
+}
+
+/// Like [`map_rows`], but also hands each chunk the matching slice of `out`
+/// (one slot per row) to write per-row results into.
+pub(crate) fn map_rows_mut<U, T, K, C>(out: &mut [U], exec: Execution, kernel: K, combine: C) -> T
+where
+    U: Send,
+    T: Send,
+    K: Fn(usize, &mut [U]) -> T + Sync,
+    C: Fn(T, T) -> T,
+{
+    let chunk_rows = match exec {
+        Execution::Chunked { chunk_rows } if chunk_rows < out.len() => chunk_rows,
+        _ => return kernel(0, out),
+    };
+    let chunks: Vec<(usize, &mut [U])> = out
+        .chunks_mut(chunk_rows)
+        .enumerate()
+        .map(|(index, chunk)| (index * chunk_rows, chunk))
+        .collect();
+    fold_in_order(
+        run_chunks(chunks, |(start, chunk)| kernel(start, chunk)),
+        combine,
+    )
+}
+
+/// Applies `f` to every item and returns the results in item order. With the
+/// `rayon` feature the items run on the thread pool.
+pub(crate) fn map_each<I, T, F>(items: &[I], f: F) -> Vec<T>
+where
+    I: Sync,
+    T: Send,
+    F: Fn(&I) -> T + Sync,
+{
+    run_chunks(items.iter().collect(), f)
+}
+
+#[cfg(feature = "rayon")]
+fn run_chunks<I, T, K>(items: Vec<I>, kernel: K) -> Vec<T>
+where
+    I: Send,
+    T: Send,
+    K: Fn(I) -> T + Sync,
+{
+    use rayon::prelude::*;
+    // `collect` on an indexed parallel iterator preserves item order.
+    items.into_par_iter().map(&kernel).collect()
+}
+
+#[cfg(not(feature = "rayon"))]
+fn run_chunks<I, T, K>(items: Vec<I>, kernel: K) -> Vec<T>
+where
+    K: Fn(I) -> T,
+{
+    items.into_iter().map(kernel).collect()
+}
+
+fn fold_in_order<T>(results: Vec<T>, combine: impl Fn(T, T) -> T) -> T {
+    results
+        .into_iter()
+        .reduce(combine)
+        .expect("at least one chunk")
+}
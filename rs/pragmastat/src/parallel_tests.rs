@@ -0,0 +1,105 @@
+//! Chunked execution must reproduce the serial kernels bit-for-bit.
+//!
+//! Small chunk sizes put many chunk boundaries inside modest inputs. With the
+//! `rayon` feature the chunks run concurrently; without it they run in order,
+//! which still exercises the partitioning and the ordered reduction.
+
+use crate::parallel::Execution;
+use crate::rng::Rng;
+use crate::shift_impl::shift_quantiles_impl_with;
+use crate::spread_impl::spread_impl_with;
+
+const CHUNKS: [usize; 4] = [1, 7, 64, 1000];
+
+fn draw(rng: &mut Rng, n: usize, ties: bool) -> Vec<f64> {
+    (0..n)
+        .map(|_| {
+            let v = rng.uniform_f64_range(-100.0, 100.0);
+            if ties {
+                v.round()
+            } else {
+                v
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn chunked_spread_matches_serial() {
+    for seed in [
+        "parallel-spread-1",
+        "parallel-spread-2",
+        "parallel-spread-3",
+    ] {
+        let mut rng = Rng::from_string(seed);
+        for (n, ties) in [(3, false), (50, false), (501, true), (2000, false)] {
+            let x = draw(&mut rng, n, ties);
+            let serial = spread_impl_with(&x, false, Execution::Serial).unwrap();
+            for chunk_rows in CHUNKS {
+                let chunked =
+                    spread_impl_with(&x, false, Execution::Chunked { chunk_rows }).unwrap();
+                assert_eq!(
+                    serial.to_bits(),
+                    chunked.to_bits(),
+                    "{seed}: n={n}, chunk={chunk_rows}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn chunked_shift_matches_serial() {
+    let p = [0.0, 0.025, 0.5, 0.975, 1.0];
+    for seed in ["parallel-shift-1", "parallel-shift-2", "parallel-shift-3"] {
+        let mut rng = Rng::from_string(seed);
+        for (n, m, ties) in [
+            (1, 5, false),
+            (40, 3, false),
+            (700, 300, true),
+            (2000, 1500, false),
+        ] {
+            let x = draw(&mut rng, n, ties);
+            let y = draw(&mut rng, m, ties);
+            let serial = shift_quantiles_impl_with(&x, &y, &p, false, Execution::Serial).unwrap();
+            for chunk_rows in CHUNKS {
+                let chunked =
+                    shift_quantiles_impl_with(&x, &y, &p, false, Execution::Chunked { chunk_rows })
+                        .unwrap();
+                let serial_bits: Vec<u64> = serial.iter().map(|v| v.to_bits()).collect();
+                let chunked_bits: Vec<u64> = chunked.iter().map(|v| v.to_bits()).collect();
+                assert_eq!(
+                    serial_bits, chunked_bits,
+                    "{seed}: n={n}, m={m}, chunk={chunk_rows}"
+                );
+            }
+        }
+    }
+}
+
+/// Large enough for [`Execution::auto`] to chunk under the `rayon` feature, so
+/// running this with and without the feature compares both paths to serial.
+#[test]
+fn auto_shift_matches_serial_on_large_input() {
+    let mut rng = Rng::from_string("parallel-shift-large");
+    let x = draw(&mut rng, 20_000, false);
+    let y = draw(&mut rng, 3_000, true);
+    let p = [0.001, 0.5, 0.999];
+    assert_eq!(
+        Execution::auto(x.len()) != Execution::Serial,
+        cfg!(feature = "rayon")
+    );
+    let serial = shift_quantiles_impl_with(&x, &y, &p, false, Execution::Serial).unwrap();
+    let auto = crate::shift_impl::shift_quantiles_impl(&x, &y, &p, false).unwrap();
+    let serial_bits: Vec<u64> = serial.iter().map(|v| v.to_bits()).collect();
+    let auto_bits: Vec<u64> = auto.iter().map(|v| v.to_bits()).collect();
+    assert_eq!(serial_bits, auto_bits);
+}
+
+#[test]
+fn auto_is_serial_for_small_inputs() {
+    assert_eq!(Execution::auto(100), Execution::Serial);
+    if !cfg!(feature = "rayon") {
+        assert_eq!(Execution::auto(1_000_000), Execution::Serial);
+    }
+}
@@ -0,0 +1,248 @@
+//! Deterministic Monte Carlo permutation tests.
+//!
+//! For designs not covered by the closed-form margins, these functions
+//! approximate the two-sided p-value of "no effect" by re-randomizing the data
+//! with the shared [`Rng`], so every Pragmastat port reproduces the same
+//! p-value from the same seed.
+//!
+//! # RNG call sequence
+//!
+//! The stream is `Rng::from_string(seed)`. Each iteration consumes:
+//!
+//! - [`shift_pvalue`]: one [`Rng::shuffle`] of the *original* pooled vector
+//!   `x ++ y` (Fisher-Yates, `n + m - 1` calls to `uniform_i64`). The first
+//!   `n` shuffled values form `x*`, the rest `y*`.
+//! - [`paired_pvalue`]: one [`Rng::uniform_bool`] per difference
+//!   `d[i] = x[i] - y[i]`, in index order; `true` negates `d[i]`.
+//!
+//! An iteration counts as *extreme* when `|T*| >= |T|`, where `T` is the
+//! observed statistic (`shift(x, y)`, or `center(d)` for paired data). The
+//! p-value is `(extreme + 1) / (iterations + 1)`, which is never zero and is
+//! a valid (slightly conservative) p-value for any number of iterations.
+
+use crate::assumptions::{check_validity, EstimatorError, Subject};
+use crate::distributions::{Additive, Distribution};
+use crate::estimators::raw;
+use crate::rng::Rng;
+
+/// Default number of permutations for [`PermutationOptions`].
+pub const DEFAULT_ITERATIONS: usize = 10_000;
+
+/// Early stopping rule for permutation tests.
+///
+/// After `min_iterations`, the test stops as soon as the Wilson score interval
+/// (at confidence `1 - misrate`) around the running extreme fraction lies
+/// entirely above or below `threshold`, i.e. once more iterations could not
+/// plausibly change the decision `p < threshold`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EarlyStop {
+    /// Decision threshold for the p-value (e.g. 0.05).
+    pub threshold: f64,
+    /// Misrate of the Monte Carlo interval around the p-value.
+    pub misrate: f64,
+    /// Iterations to run before the first check.
+    pub min_iterations: usize,
+}
+
+impl EarlyStop {
+    /// Stops once the p-value is clearly above or below `threshold`, with a
+    /// 1e-3 Monte Carlo misrate and at least 100 iterations.
+    pub fn new(threshold: f64) -> Self {
+        Self {
+            threshold,
+            misrate: 1e-3,
+            min_iterations: 100,
+        }
+    }
+}
+
+/// Options for [`shift_pvalue_opts`] and [`paired_pvalue_opts`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermutationOptions {
+    /// Maximum number of permutations (must be positive).
+    pub iterations: usize,
+    /// Optional early stopping rule; `None` always runs every iteration.
+    pub early_stop: Option<EarlyStop>,
+}
+
+impl Default for PermutationOptions {
+    fn default() -> Self {
+        Self {
+            iterations: DEFAULT_ITERATIONS,
+            early_stop: None,
+        }
+    }
+}
+
+/// Outcome of a permutation test.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PermutationResult {
+    /// Two-sided p-value, `(extreme + 1) / (iterations + 1)`.
+    pub pvalue: f64,
+    /// Number of permutations actually run.
+    pub iterations: usize,
+    /// True if the early stopping rule ended the test before `iterations`.
+    pub stopped_early: bool,
+}
+
+/// Two-sided permutation p-value for `shift(x, y) = 0`.
+///
+/// Equivalent to [`shift_pvalue_opts`] without early stopping.
+///
+/// # Errors
+///
+/// Returns the validity errors of [`shift`](crate::estimators::shift), and
+/// [`EstimatorError::Other`] if `iterations` is zero.
+pub fn shift_pvalue(
+    x: &[f64],
+    y: &[f64],
+    iterations: usize,
+    seed: &str,
+) -> Result<f64, EstimatorError> {
+    let opts = PermutationOptions {
+        iterations,
+        early_stop: None,
+    };
+    Ok(shift_pvalue_opts(x, y, seed, &opts)?.pvalue)
+}
+
+/// Two-sided permutation p-value for `shift(x, y) = 0`, with options.
+///
+/// Group labels are permuted by shuffling the pooled values; see the
+/// [module documentation](self) for the exact RNG call sequence.
+pub fn shift_pvalue_opts(
+    x: &[f64],
+    y: &[f64],
+    seed: &str,
+    opts: &PermutationOptions,
+) -> Result<PermutationResult, EstimatorError> {
+    let observed = raw::shift(x, y, false)?.abs();
+    let n = x.len();
+    let pooled: Vec<f64> = x.iter().chain(y).copied().collect();
+    let mut permuted = pooled.clone();
+    let mut rng = Rng::from_string(seed);
+    run(opts, || {
+        permuted.copy_from_slice(&pooled);
+        rng.shuffle_mut(&mut permuted);
+        let (px, py) = permuted.split_at(n);
+        Ok(raw::shift(px, py, false)?.abs() >= observed)
+    })
+}
+
+/// Two-sided sign-flip p-value for `center(x - y) = 0` on paired data.
+///
+/// Equivalent to [`paired_pvalue_opts`] without early stopping.
+///
+/// # Errors
+///
+/// Returns [`EstimatorError::Other`] if `x` and `y` differ in length or
+/// `iterations` is zero, and the usual validity errors.
+pub fn paired_pvalue(
+    x: &[f64],
+    y: &[f64],
+    iterations: usize,
+    seed: &str,
+) -> Result<f64, EstimatorError> {
+    let opts = PermutationOptions {
+        iterations,
+        early_stop: None,
+    };
+    Ok(paired_pvalue_opts(x, y, seed, &opts)?.pvalue)
+}
+
+/// Two-sided sign-flip p-value for `center(x - y) = 0`, with options.
+///
+/// Each iteration negates every paired difference independently with
+/// probability 1/2; see the [module documentation](self) for the exact RNG
+/// call sequence.
+pub fn paired_pvalue_opts(
+    x: &[f64],
+    y: &[f64],
+    seed: &str,
+    opts: &PermutationOptions,
+) -> Result<PermutationResult, EstimatorError> {
+    if x.len() != y.len() {
+        return Err(EstimatorError::Other(format!(
+            "paired samples must have the same length, got {} and {}",
+            x.len(),
+            y.len()
+        )));
+    }
+    check_validity(x, Subject::X)?;
+    check_validity(y, Subject::Y)?;
+    let diffs: Vec<f64> = x.iter().zip(y).map(|(a, b)| a - b).collect();
+    let observed = raw::center(&diffs, false)?.abs();
+    let mut flipped = diffs.clone();
+    let mut rng = Rng::from_string(seed);
+    run(opts, || {
+        for (f, &d) in flipped.iter_mut().zip(&diffs) {
+            *f = if rng.uniform_bool() { -d } else { d };
+        }
+        Ok(raw::center(&flipped, false)?.abs() >= observed)
+    })
+}
+
+/// Runs up to `opts.iterations` calls of `is_extreme`, applying early stopping.
+fn run(
+    opts: &PermutationOptions,
+    mut is_extreme: impl FnMut() -> Result<bool, EstimatorError>,
+) -> Result<PermutationResult, EstimatorError> {
+    if opts.iterations == 0 {
+        return Err(EstimatorError::Other(
+            "iterations must be positive".to_string(),
+        ));
+    }
+    let z = match &opts.early_stop {
+        Some(stop) => {
+            if !(stop.threshold > 0.0 && stop.threshold < 1.0) {
+                return Err(EstimatorError::Other(format!(
+                    "early stop threshold must be in (0, 1), got {}",
+                    stop.threshold
+                )));
+            }
+            if !(stop.misrate > 0.0 && stop.misrate < 1.0) {
+                return Err(EstimatorError::Other(format!(
+                    "early stop misrate must be in (0, 1), got {}",
+                    stop.misrate
+                )));
+            }
+            Some(Additive::new(0.0, 1.0).quantile(1.0 - stop.misrate / 2.0))
+        }
+        None => None,
+    };
+
+    let mut extreme = 0usize;
+    let mut done = 0usize;
+    let mut stopped_early = false;
+    while done < opts.iterations {
+        if is_extreme()? {
+            extreme += 1;
+        }
+        done += 1;
+        if let (Some(stop), Some(z)) = (&opts.early_stop, z) {
+            if done >= stop.min_iterations && done < opts.iterations {
+                let (lower, upper) = wilson_interval(extreme, done, z);
+                if upper < stop.threshold || lower > stop.threshold {
+                    stopped_early = true;
+                    break;
+                }
+            }
+        }
+    }
+    Ok(PermutationResult {
+        pvalue: (extreme + 1) as f64 / (done + 1) as f64,
+        iterations: done,
+        stopped_early,
+    })
+}
+
+/// Wilson score interval for `successes` out of `trials` at normal quantile `z`.
+fn wilson_interval(successes: usize, trials: usize, z: f64) -> (f64, f64) {
+    let n = trials as f64;
+    let p = successes as f64 / n;
+    let z2 = z * z;
+    let denominator = 1.0 + z2 / n;
+    let middle = (p + z2 / (2.0 * n)) / denominator;
+    let half_width = z * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt() / denominator;
+    (middle - half_width, middle + half_width)
+}
@@ -0,0 +1,473 @@
+//! Sample-size planning for [`shift_bounds`](crate::estimators::shift_bounds).
+//!
+//! [`expected_shift_bounds_width`] predicts the width of the shift bounds from
+//! the spreads of both populations, [`required_n_for_width`] inverts it, and
+//! [`simulate_shift_bounds_width`] checks the prediction against a seeded
+//! simulation. [`precision_profile`] summarizes what a design can resolve
+//! before any data are collected, and [`simulate_detection_power`] checks its
+//! minimum detectable shift.
+//!
+//! The prediction is asymptotic. The bounds are the pairwise differences
+//! `x_i - y_j` at ranks `nm/2 ∓ z·σ_U`, where `σ_U = √(nm(n+m+1)/12)` is the
+//! standard deviation of the Mann-Whitney statistic and `z = Φ⁻¹(1 -
+//! misrate/2)`. Near the true shift the differences have density `h(0)`, so
+//!
+//! ```text
+//! width ≈ 2 · z · σ_U / (n · m · h(0))
+//! ```
+//!
+//! Under the normal model (both samples normal, standard deviations `σx` and
+//! `σy`), `h(0) = 1 / √(2π(σx² + σy²))` and each `Spread = √2 · Φ⁻¹(0.75) · σ`.
+//! For other shapes the width scales the same way in `n` and `m` but the
+//! constant differs; use the simulation to calibrate it.
+//!
+//! The estimated shift is asymptotically normal around the true shift with
+//! standard error `width / (2z)`, so the bounds exclude zero with probability
+//! [`DETECTION_POWER`] once the true shift reaches
+//!
+//! ```text
+//! min_detectable_shift ≈ (z + Φ⁻¹(DETECTION_POWER)) · width / (2z)
+//! ```
+
+use std::fmt;
+
+use crate::assumptions::EstimatorError;
+use crate::distributions::{Additive, Distribution};
+use crate::estimators::{min_misrate_shift, raw};
+use crate::rng::Rng;
+
+/// Probability with which the bounds must exclude zero for a shift to count as
+/// detectable in [`PrecisionProfile::min_detectable_shift`].
+pub const DETECTION_POWER: f64 = 0.8;
+
+/// Ratio of Spread to the standard deviation under the normal model,
+/// `√2 · Φ⁻¹(0.75)`.
+const NORMAL_SPREAD_TO_SIGMA: f64 = 0.9538725524089398;
+
+/// Predicted width of `shift_bounds(x, y, misrate)` for samples of sizes `n`
+/// and `m` whose populations have spreads `spread_x` and `spread_y`.
+///
+/// Exact asymptotically under the normal model; see the
+/// [module documentation](self). Returns `f64::INFINITY` if `misrate` is below
+/// [`min_misrate_shift(n, m)`](min_misrate_shift), since `shift_bounds`
+/// rejects such a request.
+///
+/// # Panics
+///
+/// Panics if `n` or `m` is zero, if a spread is not positive and finite, or if
+/// `misrate` is NaN or outside (0, 1).
+pub fn expected_shift_bounds_width(
+    spread_x: f64,
+    spread_y: f64,
+    n: usize,
+    m: usize,
+    misrate: f64,
+) -> f64 {
+    assert!(n > 0 && m > 0, "n and m must be positive");
+    assert!(
+        spread_x.is_finite() && spread_x > 0.0 && spread_y.is_finite() && spread_y > 0.0,
+        "spreads must be positive and finite"
+    );
+    assert!(
+        misrate > 0.0 && misrate < 1.0,
+        "misrate must be in (0, 1), got {misrate}"
+    );
+    if misrate < min_misrate_shift(n, m) {
+        return f64::INFINITY;
+    }
+    let (n, m) = (n as f64, m as f64);
+    let z = Additive::new(0.0, 1.0).quantile(1.0 - misrate / 2.0);
+    let sigma_x = spread_x / NORMAL_SPREAD_TO_SIGMA;
+    let sigma_y = spread_y / NORMAL_SPREAD_TO_SIGMA;
+    let inv_density = (2.0 * std::f64::consts::PI * (sigma_x * sigma_x + sigma_y * sigma_y)).sqrt();
+    let sigma_u = (n * m * (n + m + 1.0) / 12.0).sqrt();
+    2.0 * z * sigma_u / (n * m) * inv_density
+}
+
+/// Smallest per-group size `n` (with `m = n`) for which
+/// [`expected_shift_bounds_width`] is at most `target_width`, both groups
+/// having population spread `spread`.
+///
+/// # Panics
+///
+/// Panics if `target_width` or `spread` is not positive and finite, or if
+/// `misrate` is NaN or outside (0, 1).
+pub fn required_n_for_width(target_width: f64, spread: f64, misrate: f64) -> usize {
+    assert!(
+        target_width.is_finite() && target_width > 0.0,
+        "target_width must be positive and finite"
+    );
+    let fits =
+        |n: usize| expected_shift_bounds_width(spread, spread, n, n, misrate) <= target_width;
+
+    // The width decreases in n: double until it fits, then bisect.
+    let mut high = 1;
+    while !fits(high) {
+        high *= 2;
+    }
+    let mut low = high / 2;
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        if fits(mid) {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    high
+}
+
+/// Mean width of `shift_bounds(x, y, misrate)` over `iterations` simulated
+/// experiments, with `x` (size `n`) and `y` (size `m`) both drawn from
+/// `distribution`.
+///
+/// Each iteration draws `n` values for `x`, then `m` values for `y`, from one
+/// stream seeded by `seed`, so the estimate is reproducible.
+///
+/// # Errors
+///
+/// Returns [`EstimatorError::Other`] if `iterations` is zero, and the errors of
+/// [`shift_bounds`](crate::estimators::shift_bounds) (e.g. a misrate below
+/// [`min_misrate_shift(n, m)`](min_misrate_shift)).
+pub fn simulate_shift_bounds_width(
+    distribution: &dyn Distribution,
+    n: usize,
+    m: usize,
+    misrate: f64,
+    iterations: usize,
+    seed: &str,
+) -> Result<f64, EstimatorError> {
+    if iterations == 0 {
+        return Err(EstimatorError::Other(
+            "iterations must be positive".to_string(),
+        ));
+    }
+    let mut rng = Rng::from_string(seed);
+    let mut x = vec![0.0; n];
+    let mut y = vec![0.0; m];
+    let mut total = 0.0;
+    for _ in 0..iterations {
+        distribution.sample_into(&mut rng, &mut x);
+        distribution.sample_into(&mut rng, &mut y);
+        let bounds = raw::shift_bounds(&x, &y, misrate, false)?;
+        total += bounds.upper - bounds.lower;
+    }
+    Ok(total / iterations as f64)
+}
+
+/// What a two-sample design can resolve, from [`precision_profile`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct PrecisionProfile {
+    /// Size of the first sample.
+    pub n: usize,
+    /// Size of the second sample.
+    pub m: usize,
+    /// Expected spread of both populations.
+    pub spread: f64,
+    /// Requested misrate of the shift bounds.
+    pub misrate: f64,
+    /// Predicted width of the shift bounds ([`expected_shift_bounds_width`]).
+    pub expected_width: f64,
+    /// Smallest misrate the shift bounds accept at these sizes
+    /// ([`min_misrate_shift`]).
+    pub min_misrate: f64,
+    /// Smallest true shift for which the bounds exclude zero with probability
+    /// [`DETECTION_POWER`].
+    pub min_detectable_shift: f64,
+}
+
+impl PrecisionProfile {
+    /// Returns true if the shift bounds accept the requested misrate at these sizes.
+    ///
+    /// Otherwise the width and the detectable shift are infinite.
+    pub fn is_achievable(&self) -> bool {
+        self.misrate >= self.min_misrate
+    }
+}
+
+impl fmt::Display for PrecisionProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "n = {}, m = {}, misrate = {}: ",
+            self.n, self.m, self.misrate
+        )?;
+        if !self.is_achievable() {
+            return write!(
+                f,
+                "unachievable (min misrate {})",
+                significant(self.min_misrate)
+            );
+        }
+        write!(
+            f,
+            "width ≈ {}, detectable shift ≈ {} ({:.0}% power), min misrate {}",
+            significant(self.expected_width),
+            significant(self.min_detectable_shift),
+            DETECTION_POWER * 100.0,
+            significant(self.min_misrate)
+        )
+    }
+}
+
+/// Formats `x` with three significant digits (all integer digits are kept, and
+/// very small or large values use scientific notation).
+fn significant(x: f64) -> String {
+    if x == 0.0 || !x.is_finite() {
+        return x.to_string();
+    }
+    let magnitude = x.abs().log10().floor() as i32;
+    if !(-4..6).contains(&magnitude) {
+        return format!("{x:.2e}");
+    }
+    format!("{:.*}", (2 - magnitude).max(0) as usize, x)
+}
+
+/// Precision of `shift_bounds(x, y, misrate)` for samples of sizes `n` and `m`
+/// whose populations both have spread about `spread_estimate`.
+///
+/// Asymptotic under the normal model; see the [module documentation](self).
+/// Use [`simulate_detection_power`] to check the detectable shift for a
+/// specific design.
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`expected_shift_bounds_width`].
+///
+/// # Example
+/// ```
+/// use pragmastat::planning::precision_profile;
+///
+/// let profile = precision_profile(12, 12, 5.0, 0.005);
+/// assert!(profile.is_achievable());
+/// assert!(profile.min_detectable_shift > profile.expected_width / 2.0);
+/// ```
+pub fn precision_profile(
+    n: usize,
+    m: usize,
+    spread_estimate: f64,
+    misrate: f64,
+) -> PrecisionProfile {
+    let expected_width =
+        expected_shift_bounds_width(spread_estimate, spread_estimate, n, m, misrate);
+    let standard = Additive::new(0.0, 1.0);
+    let z = standard.quantile(1.0 - misrate / 2.0);
+    let z_power = standard.quantile(DETECTION_POWER);
+    PrecisionProfile {
+        n,
+        m,
+        spread: spread_estimate,
+        misrate,
+        expected_width,
+        min_misrate: min_misrate_shift(n, m),
+        min_detectable_shift: (z + z_power) * expected_width / (2.0 * z),
+    }
+}
+
+/// Fraction of `iterations` simulated experiments in which
+/// `shift_bounds(x, y, misrate)` excludes zero, with `x` (size `n`) and `y`
+/// (size `m`) normal with spread `spread` and `x` shifted up by `shift`.
+///
+/// Seeded like [`simulate_shift_bounds_width`], so the estimate is
+/// reproducible. At `shift = precision_profile(..).min_detectable_shift` it
+/// should be close to [`DETECTION_POWER`].
+///
+/// # Errors
+///
+/// Returns [`EstimatorError::Other`] if `iterations` is zero or `spread` is not
+/// positive and finite, and the errors of
+/// [`shift_bounds`](crate::estimators::shift_bounds).
+pub fn simulate_detection_power(
+    n: usize,
+    m: usize,
+    spread: f64,
+    shift: f64,
+    misrate: f64,
+    iterations: usize,
+    seed: &str,
+) -> Result<f64, EstimatorError> {
+    if iterations == 0 {
+        return Err(EstimatorError::Other(
+            "iterations must be positive".to_string(),
+        ));
+    }
+    if !(spread.is_finite() && spread > 0.0) {
+        return Err(EstimatorError::Other(
+            "spread must be positive and finite".to_string(),
+        ));
+    }
+    let sigma = spread / NORMAL_SPREAD_TO_SIGMA;
+    let (dist_x, dist_y) = (Additive::new(shift, sigma), Additive::new(0.0, sigma));
+    let mut rng = Rng::from_string(seed);
+    let mut x = vec![0.0; n];
+    let mut y = vec![0.0; m];
+    let mut detected = 0;
+    for _ in 0..iterations {
+        dist_x.sample_into(&mut rng, &mut x);
+        dist_y.sample_into(&mut rng, &mut y);
+        let bounds = raw::shift_bounds(&x, &y, misrate, false)?;
+        if bounds.lower > 0.0 || bounds.upper < 0.0 {
+            detected += 1;
+        }
+    }
+    Ok(detected as f64 / iterations as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn width_decreases_in_n_and_m() {
+        let mut previous = f64::INFINITY;
+        for n in [5, 10, 20, 50, 100, 1000] {
+            let width = expected_shift_bounds_width(1.0, 1.0, n, n, 0.05);
+            assert!(width < previous, "n = {n}");
+            previous = width;
+        }
+        let unbalanced = expected_shift_bounds_width(1.0, 1.0, 20, 40, 0.05);
+        assert!(unbalanced < expected_shift_bounds_width(1.0, 1.0, 20, 20, 0.05));
+        assert!(unbalanced > expected_shift_bounds_width(1.0, 1.0, 40, 40, 0.05));
+    }
+
+    #[test]
+    fn width_scales_with_spread_and_misrate() {
+        let base = expected_shift_bounds_width(1.0, 1.0, 30, 30, 0.05);
+        let wide = expected_shift_bounds_width(2.0, 2.0, 30, 30, 0.05);
+        assert!((wide / base - 2.0).abs() < 1e-12);
+        assert!(expected_shift_bounds_width(1.0, 1.0, 30, 30, 1e-3) > base);
+    }
+
+    #[test]
+    fn unreachable_misrate_is_infinite() {
+        // min_misrate_shift(2, 2) = 2 / C(4, 2) = 1/3
+        assert_eq!(
+            expected_shift_bounds_width(1.0, 1.0, 2, 2, 0.05),
+            f64::INFINITY
+        );
+    }
+
+    #[test]
+    fn required_n_is_the_smallest_fit() {
+        for target in [0.1, 0.5, 1.0, 3.0] {
+            let n = required_n_for_width(target, 1.0, 0.01);
+            assert!(expected_shift_bounds_width(1.0, 1.0, n, n, 0.01) <= target);
+            if n > 1 {
+                assert!(expected_shift_bounds_width(1.0, 1.0, n - 1, n - 1, 0.01) > target);
+            }
+        }
+        assert!(required_n_for_width(0.1, 1.0, 0.01) > required_n_for_width(0.5, 1.0, 0.01));
+    }
+
+    #[test]
+    fn formula_matches_simulation_for_additive() {
+        let dist = Additive::new(0.0, 1.0);
+        let spread = NORMAL_SPREAD_TO_SIGMA;
+        for (n, m) in [(30, 30), (40, 60)] {
+            let predicted = expected_shift_bounds_width(spread, spread, n, m, 0.05);
+            let simulated =
+                simulate_shift_bounds_width(&dist, n, m, 0.05, 300, "planning-test").unwrap();
+            let relative = (simulated - predicted).abs() / predicted;
+            assert!(
+                relative < 0.05,
+                "n = {n}, m = {m}: predicted {predicted}, simulated {simulated}"
+            );
+        }
+    }
+
+    #[test]
+    fn profile_improves_with_n() {
+        let mut previous = precision_profile(5, 5, 5.0, 0.05);
+        for n in [6, 8, 12, 20, 50, 200] {
+            let profile = precision_profile(n, n, 5.0, 0.05);
+            assert!(profile.expected_width < previous.expected_width, "n = {n}");
+            assert!(
+                profile.min_detectable_shift < previous.min_detectable_shift,
+                "n = {n}"
+            );
+            assert!(profile.min_misrate < previous.min_misrate, "n = {n}");
+            previous = profile;
+        }
+    }
+
+    #[test]
+    fn profile_agrees_with_min_misrate_shift() {
+        for (n, m) in [(2, 2), (3, 5), (12, 12), (40, 7)] {
+            let profile = precision_profile(n, m, 1.0, 0.005);
+            assert_eq!(profile.min_misrate, min_misrate_shift(n, m));
+            assert_eq!(profile.is_achievable(), 0.005 >= min_misrate_shift(n, m));
+            if profile.is_achievable() {
+                assert_eq!(
+                    profile.expected_width,
+                    expected_shift_bounds_width(1.0, 1.0, n, m, 0.005)
+                );
+                assert!(profile.min_detectable_shift.is_finite());
+            } else {
+                assert_eq!(profile.expected_width, f64::INFINITY);
+                assert_eq!(profile.min_detectable_shift, f64::INFINITY);
+            }
+        }
+    }
+
+    #[test]
+    fn detectable_shift_matches_simulated_power() {
+        let profile = precision_profile(40, 40, 2.0, 0.05);
+        let power = simulate_detection_power(
+            40,
+            40,
+            2.0,
+            profile.min_detectable_shift,
+            0.05,
+            1000,
+            "planning-power",
+        )
+        .unwrap();
+        assert!((power - DETECTION_POWER).abs() < 0.05, "power {power}");
+        let null =
+            simulate_detection_power(40, 40, 2.0, 0.0, 0.05, 1000, "planning-power").unwrap();
+        assert!(null < 0.1, "false detections {null}");
+        assert!(simulate_detection_power(40, 40, 0.0, 1.0, 0.05, 10, "seed").is_err());
+    }
+
+    #[test]
+    fn profile_display() {
+        let profile = precision_profile(12, 12, 5.0, 0.005);
+        assert_eq!(
+            profile.to_string(),
+            format!(
+                "n = 12, m = 12, misrate = 0.005: width ≈ {}, detectable shift ≈ {} \
+                 (80% power), min misrate 7.40e-7",
+                significant(profile.expected_width),
+                significant(profile.min_detectable_shift)
+            )
+        );
+        assert_eq!(
+            precision_profile(2, 2, 5.0, 0.05).to_string(),
+            "n = 2, m = 2, misrate = 0.05: unachievable (min misrate 0.333)"
+        );
+        assert_eq!(significant(9.7123), "9.71");
+        assert_eq!(significant(1234.5), "1234");
+        assert_eq!(significant(0.012345), "0.0123");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn profile_serde_round_trip() {
+        let profile = precision_profile(12, 12, 5.0, 0.005);
+        let json = serde_json::to_string(&profile).unwrap();
+        assert!(json.contains("\"minDetectableShift\""), "{json}");
+        let back: PrecisionProfile = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, profile);
+    }
+
+    #[test]
+    fn simulation_is_deterministic() {
+        let dist = Additive::new(10.0, 2.0);
+        let a = simulate_shift_bounds_width(&dist, 10, 12, 0.1, 20, "seed").unwrap();
+        let b = simulate_shift_bounds_width(&dist, 10, 12, 0.1, 20, "seed").unwrap();
+        assert_eq!(a, b);
+        assert!(simulate_shift_bounds_width(&dist, 10, 12, 0.1, 0, "seed").is_err());
+    }
+}
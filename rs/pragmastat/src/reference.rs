@@ -0,0 +1,926 @@
+//! Reader, writer and verifier for the cross-language reference tests.
+//!
+//! The repository's `tests/` directory holds JSON fixtures that every
+//! Pragmastat port must reproduce. This module (enabled by the
+//! `reference-tests` feature) exposes their schemas as typed structs, loads a
+//! fixture directory with [`load_dir`], and checks a case against this crate
+//! with [`verify`].
+//!
+//! Supported directories are `rng`, `shuffle`, `sample`, `resample`,
+//! `permutation` and every `distributions/<name>` directory. The schema is
+//! chosen by the directory name; inside `rng` it is chosen by the file-name
+//! prefix (`uniform-seed-`, `normal-seed-`, `next-u64-seed-`, ...).
+//!
+//! # Comparison rules
+//!
+//! - Integer, boolean and raw `u64` outputs must match exactly.
+//! - Values that are plain arithmetic on the raw stream (`uniform_f64`, string
+//!   seeds, shuffle, sample, resample, permutation p-values) must match within
+//!   `1e-15`.
+//! - Values that go through `ln`, `exp`, `sqrt` or trigonometric functions
+//!   (normal draws, uniform ranges, distributions) must match within `1e-12`,
+//!   since those functions may differ in the last bits across platforms.
+//! - `f32` values must match within `1e-7`.
+//!
+//! Floating-point values within 4 units in the last place also match, so large
+//! values (e.g., heavy-tailed draws) are not held to an absolute tolerance
+//! finer than their precision.
+//!
+//! # File format
+//!
+//! New fixtures are written by [`to_json`]: pretty-printed JSON whose floats
+//! are spelled by [`shortest_roundtrip`](crate::format::shortest_roundtrip),
+//! so a port that regenerates a fixture can compare it byte for byte.
+//! Fixtures that predate this spelling keep serde_json's (`1.0` rather than
+//! `1`): the generators go through [`write_fixture`], which leaves a file
+//! untouched while its values are unchanged.
+//!
+//! # Example
+//! ```no_run
+//! use pragmastat::reference::{load_dir, verify};
+//!
+//! for case in load_dir("tests/distributions/additive".as_ref()).unwrap() {
+//!     let result = verify(&case);
+//!     assert!(result.passed(), "{result}");
+//! }
+//! ```
+
+use crate::distributions::{
+    Additive, Ar1, Binomial, BivariateAdditive, ChiSquared, Distribution, Exp, Gamma,
+    GaussianCopula, Multiplic, Poisson, Power, StudentT, Uniform, UniformInt,
+};
+use crate::{format, permutation, Rng};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::ser::{Formatter, PrettyFormatter};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const RAW_TOLERANCE: f64 = 1e-15;
+const MATH_TOLERANCE: f64 = 1e-12;
+const F32_TOLERANCE: f32 = 1e-7;
+const MAX_ULPS: u64 = 4;
+
+/// A fixture: the input passed to the implementation and the expected output.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestCase<I, O> {
+    pub input: I,
+    pub output: O,
+}
+
+/// Input of the fixtures that draw `count` values from `Rng::from_seed(seed)`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SeedInput {
+    pub seed: i64,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StringSeedInput {
+    pub seed: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UniformIntInput {
+    pub seed: i64,
+    pub min: i64,
+    pub max: i64,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UniformI32Input {
+    pub seed: i64,
+    pub min: i32,
+    pub max: i32,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UniformRangeInput {
+    pub seed: i64,
+    pub min: f64,
+    pub max: f64,
+    pub count: usize,
+}
+
+/// Input of `split-seed-*`: `k` substreams, `count` raw outputs from each.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SplitInput {
+    pub seed: i64,
+    pub k: usize,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShuffleInput {
+    pub seed: i64,
+    pub x: Vec<f64>,
+}
+
+/// Input of the `sample` and `resample` fixtures.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SampleInput {
+    pub seed: i64,
+    pub x: Vec<f64>,
+    pub k: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PermutationInput {
+    pub seed: String,
+    pub x: Vec<f64>,
+    pub y: Vec<f64>,
+    pub iterations: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UniformDistInput {
+    pub seed: i64,
+    pub min: f64,
+    pub max: f64,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdditiveDistInput {
+    pub seed: i64,
+    pub mean: f64,
+    pub std_dev: f64,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiplicDistInput {
+    pub seed: i64,
+    pub log_mean: f64,
+    pub log_std_dev: f64,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExpDistInput {
+    pub seed: i64,
+    pub rate: f64,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PowerDistInput {
+    pub seed: i64,
+    pub min: f64,
+    pub shape: f64,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GammaDistInput {
+    pub seed: i64,
+    pub shape: f64,
+    pub rate: f64,
+    pub count: usize,
+}
+
+/// Input of the `chisquared` and `studentt` fixtures.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DfDistInput {
+    pub seed: i64,
+    pub df: f64,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PoissonDistInput {
+    pub seed: i64,
+    pub lambda: f64,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BinomialDistInput {
+    pub seed: i64,
+    pub n: u64,
+    pub p: f64,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BivariateAdditiveDistInput {
+    pub seed: i64,
+    pub mean_x: f64,
+    pub mean_y: f64,
+    pub sd_x: f64,
+    pub sd_y: f64,
+    pub rho: f64,
+    pub count: usize,
+}
+
+/// Input of `ar1`: an AR(1) series with `Additive(mean, stdDev)` innovations.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ar1DistInput {
+    pub seed: i64,
+    pub mean: f64,
+    pub std_dev: f64,
+    pub phi: f64,
+    pub count: usize,
+}
+
+/// Input of `gaussian-copula`: an `Exp(rate)` x marginal and a
+/// `Uniform(min, max)` y marginal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GaussianCopulaDistInput {
+    pub seed: i64,
+    pub rho: f64,
+    pub rate: f64,
+    pub min: f64,
+    pub max: f64,
+    pub count: usize,
+}
+
+/// Output of the bivariate fixtures: `x[i]` and `y[i]` form one pair.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PairOutput {
+    pub x: Vec<f64>,
+    pub y: Vec<f64>,
+}
+
+/// `rng/uniform-seed-*` and `rng/normal-seed-*`.
+pub type UniformTestCase = TestCase<SeedInput, Vec<f64>>;
+pub type UniformF32TestCase = TestCase<SeedInput, Vec<f32>>;
+pub type UniformBoolTestCase = TestCase<SeedInput, Vec<bool>>;
+/// Raw `next_u64` outputs as decimal strings (`next-u64-*`, `jump-*`, `long-jump-*`).
+pub type NextU64TestCase = TestCase<SeedInput, Vec<String>>;
+pub type StringSeedTestCase = TestCase<StringSeedInput, Vec<f64>>;
+pub type UniformIntTestCase = TestCase<UniformIntInput, Vec<i64>>;
+pub type UniformI32TestCase = TestCase<UniformI32Input, Vec<i32>>;
+pub type UniformRangeTestCase = TestCase<UniformRangeInput, Vec<f64>>;
+pub type SplitTestCase = TestCase<SplitInput, Vec<Vec<String>>>;
+pub type ShuffleTestCase = TestCase<ShuffleInput, Vec<f64>>;
+pub type SampleTestCase = TestCase<SampleInput, Vec<f64>>;
+pub type PermutationTestCase = TestCase<PermutationInput, f64>;
+pub type UniformDistTestCase = TestCase<UniformDistInput, Vec<f64>>;
+pub type AdditiveDistTestCase = TestCase<AdditiveDistInput, Vec<f64>>;
+pub type MultiplicDistTestCase = TestCase<MultiplicDistInput, Vec<f64>>;
+pub type ExpDistTestCase = TestCase<ExpDistInput, Vec<f64>>;
+pub type PowerDistTestCase = TestCase<PowerDistInput, Vec<f64>>;
+pub type GammaDistTestCase = TestCase<GammaDistInput, Vec<f64>>;
+pub type DfDistTestCase = TestCase<DfDistInput, Vec<f64>>;
+pub type PoissonDistTestCase = TestCase<PoissonDistInput, Vec<f64>>;
+pub type BinomialDistTestCase = TestCase<BinomialDistInput, Vec<f64>>;
+pub type BivariateAdditiveDistTestCase = TestCase<BivariateAdditiveDistInput, PairOutput>;
+pub type AdditiveAntitheticDistTestCase = TestCase<AdditiveDistInput, PairOutput>;
+pub type GaussianCopulaDistTestCase = TestCase<GaussianCopulaDistInput, PairOutput>;
+pub type Ar1DistTestCase = TestCase<Ar1DistInput, Vec<f64>>;
+
+/// A parsed fixture, tagged with the functionality it exercises.
+///
+/// Serializes as the bare fixture, so [`ReferenceCase::to_json`] reproduces
+/// the file format.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum CaseData {
+    RngUniform(UniformTestCase),
+    RngNormal(UniformTestCase),
+    RngStringSeed(StringSeedTestCase),
+    RngUniformInt(UniformIntTestCase),
+    RngUniformI32(UniformI32TestCase),
+    RngUniformRange(UniformRangeTestCase),
+    RngUniformF32(UniformF32TestCase),
+    RngUniformBool(UniformBoolTestCase),
+    RngNextU64(NextU64TestCase),
+    RngJump(NextU64TestCase),
+    RngLongJump(NextU64TestCase),
+    RngSplit(SplitTestCase),
+    Shuffle(ShuffleTestCase),
+    Sample(SampleTestCase),
+    Resample(SampleTestCase),
+    ShiftPermutation(PermutationTestCase),
+    PairedPermutation(PermutationTestCase),
+    UniformDist(UniformDistTestCase),
+    /// Same schema and values as [`CaseData::RngUniformInt`].
+    UniformIntDist(UniformIntTestCase),
+    AdditiveDist(AdditiveDistTestCase),
+    MultiplicDist(MultiplicDistTestCase),
+    ExpDist(ExpDistTestCase),
+    PowerDist(PowerDistTestCase),
+    GammaDist(GammaDistTestCase),
+    ChiSquaredDist(DfDistTestCase),
+    StudentTDist(DfDistTestCase),
+    PoissonDist(PoissonDistTestCase),
+    BinomialDist(BinomialDistTestCase),
+    BivariateAdditiveDist(BivariateAdditiveDistTestCase),
+    AdditiveAntitheticDist(AdditiveAntitheticDistTestCase),
+    GaussianCopulaDist(GaussianCopulaDistTestCase),
+    Ar1Dist(Ar1DistTestCase),
+}
+
+/// A fixture together with the file it was loaded from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceCase {
+    pub path: PathBuf,
+    pub data: CaseData,
+}
+
+impl ReferenceCase {
+    /// Serializes the case in the fixture file format (see [`to_json`]).
+    pub fn to_json(&self) -> String {
+        to_json(&self.data)
+    }
+
+    /// Writes the case to `path` in the fixture file format.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_json())
+    }
+}
+
+/// Serializes `data` in the fixture file format: JSON pretty-printed with two
+/// spaces, no trailing newline, and floats spelled by
+/// [`shortest_roundtrip`](crate::format::shortest_roundtrip).
+///
+/// # Panics
+///
+/// Panics if `data` cannot be represented as JSON (e.g. a map with non-string
+/// keys).
+pub fn to_json<T: Serialize + ?Sized>(data: &T) -> String {
+    let mut out = Vec::new();
+    let mut serializer =
+        serde_json::Serializer::with_formatter(&mut out, CanonicalFormatter::default());
+    data.serialize(&mut serializer)
+        .expect("fixtures always serialize");
+    String::from_utf8(out).expect("JSON is UTF-8")
+}
+
+/// Whether the fixture text `existing` holds the same values as `data`,
+/// however its floats are spelled.
+pub fn fixture_matches<T: Serialize + ?Sized>(existing: &str, data: &T) -> bool {
+    // Both sides are parsed from text so that f32 values compare by their
+    // spelled digits, as a reader sees them.
+    match (
+        serde_json::from_str::<serde_json::Value>(existing),
+        serde_json::from_str::<serde_json::Value>(&to_json(data)),
+    ) {
+        (Ok(old), Ok(new)) => to_json(&old) == to_json(&new),
+        _ => false,
+    }
+}
+
+/// Writes `data` to `path` with [`to_json`] unless the file already holds the
+/// same values (see [`fixture_matches`]), so regenerating does not respell
+/// existing fixtures. Returns whether the file was written.
+///
+/// # Errors
+///
+/// Returns the I/O error if the file cannot be written.
+pub fn write_fixture<T: Serialize + ?Sized>(path: &Path, data: &T) -> io::Result<bool> {
+    if let Ok(existing) = fs::read_to_string(path) {
+        if fixture_matches(&existing, data) {
+            return Ok(false);
+        }
+    }
+    fs::write(path, to_json(data))?;
+    Ok(true)
+}
+
+/// serde_json's pretty printer with canonical float spelling.
+#[derive(Default)]
+struct CanonicalFormatter(PrettyFormatter<'static>);
+
+impl Formatter for CanonicalFormatter {
+    fn write_f32<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: f32) -> io::Result<()> {
+        writer.write_all(format::shortest_roundtrip_f32(value).as_bytes())
+    }
+
+    fn write_f64<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: f64) -> io::Result<()> {
+        writer.write_all(format::shortest_roundtrip(value).as_bytes())
+    }
+
+    fn begin_array<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.0.begin_array(writer)
+    }
+
+    fn end_array<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.0.end_array(writer)
+    }
+
+    fn begin_array_value<W: ?Sized + io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> io::Result<()> {
+        self.0.begin_array_value(writer, first)
+    }
+
+    fn end_array_value<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.0.end_array_value(writer)
+    }
+
+    fn begin_object<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.0.begin_object(writer)
+    }
+
+    fn end_object<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.0.end_object(writer)
+    }
+
+    fn begin_object_key<W: ?Sized + io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> io::Result<()> {
+        self.0.begin_object_key(writer, first)
+    }
+
+    fn begin_object_value<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.0.begin_object_value(writer)
+    }
+
+    fn end_object_value<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.0.end_object_value(writer)
+    }
+}
+
+/// Error returned when a fixture cannot be loaded.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The file or directory could not be read.
+    Io { path: PathBuf, source: io::Error },
+    /// The file does not match the schema of its directory.
+    Json {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    /// The directory name (or, in `rng`, the file-name prefix) is not a known schema.
+    UnknownSchema { path: PathBuf },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io { path, source } => write!(f, "{}: {source}", path.display()),
+            LoadError::Json { path, source } => write!(f, "{}: {source}", path.display()),
+            LoadError::UnknownSchema { path } => {
+                write!(f, "{}: unknown reference test schema", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LoadError::Io { source, .. } => Some(source),
+            LoadError::Json { source, .. } => Some(source),
+            LoadError::UnknownSchema { .. } => None,
+        }
+    }
+}
+
+/// Loads every `*.json` file of a fixture directory, in file-name order.
+pub fn load_dir(path: &Path) -> Result<Vec<ReferenceCase>, LoadError> {
+    let io_error = |source| LoadError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    let mut files = Vec::new();
+    for entry in fs::read_dir(path).map_err(io_error)? {
+        let file = entry.map_err(io_error)?.path();
+        if file.extension().is_some_and(|ext| ext == "json") {
+            files.push(file);
+        }
+    }
+    files.sort();
+    files.iter().map(|file| load_file(file)).collect()
+}
+
+/// Loads one fixture, choosing its schema by the name of its directory.
+pub fn load_file(path: &Path) -> Result<ReferenceCase, LoadError> {
+    let name = |p: Option<&Path>| {
+        p.and_then(Path::file_name)
+            .and_then(|s| s.to_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+    let dir = name(path.parent());
+    let file = name(Some(path));
+    let content = fs::read_to_string(path).map_err(|source| LoadError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    match parse(&dir, &file, &content) {
+        Ok(Some(data)) => Ok(ReferenceCase {
+            path: path.to_path_buf(),
+            data,
+        }),
+        Ok(None) => Err(LoadError::UnknownSchema {
+            path: path.to_path_buf(),
+        }),
+        Err(source) => Err(LoadError::Json {
+            path: path.to_path_buf(),
+            source,
+        }),
+    }
+}
+
+fn de<T: DeserializeOwned>(content: &str) -> serde_json::Result<T> {
+    serde_json::from_str(content)
+}
+
+fn parse(dir: &str, file: &str, content: &str) -> serde_json::Result<Option<CaseData>> {
+    let data = match dir {
+        "rng" => return parse_rng(file, content),
+        "shuffle" => CaseData::Shuffle(de(content)?),
+        "sample" => CaseData::Sample(de(content)?),
+        "resample" => CaseData::Resample(de(content)?),
+        "permutation" if file.starts_with("paired-") => CaseData::PairedPermutation(de(content)?),
+        "permutation" => CaseData::ShiftPermutation(de(content)?),
+        "uniform" => CaseData::UniformDist(de(content)?),
+        "uniform-int" => CaseData::UniformIntDist(de(content)?),
+        "additive" => CaseData::AdditiveDist(de(content)?),
+        "multiplic" => CaseData::MultiplicDist(de(content)?),
+        "exp" => CaseData::ExpDist(de(content)?),
+        "power" => CaseData::PowerDist(de(content)?),
+        "gamma" => CaseData::GammaDist(de(content)?),
+        "chisquared" => CaseData::ChiSquaredDist(de(content)?),
+        "studentt" => CaseData::StudentTDist(de(content)?),
+        "poisson" => CaseData::PoissonDist(de(content)?),
+        "binomial" => CaseData::BinomialDist(de(content)?),
+        "bivariate-additive" => CaseData::BivariateAdditiveDist(de(content)?),
+        "additive-antithetic" => CaseData::AdditiveAntitheticDist(de(content)?),
+        "gaussian-copula" => CaseData::GaussianCopulaDist(de(content)?),
+        "ar1" => CaseData::Ar1Dist(de(content)?),
+        _ => return Ok(None),
+    };
+    Ok(Some(data))
+}
+
+/// File-name prefixes of the `rng` directory, one per schema.
+const RNG_PREFIXES: [&str; 12] = [
+    "uniform-seed-",
+    "normal-seed-",
+    "uniform-string-",
+    "uniform-int-",
+    "uniform-i32-",
+    "uniform-range-",
+    "uniform-f32-",
+    "uniform-bool-seed-",
+    "next-u64-seed-",
+    "jump-seed-",
+    "long-jump-seed-",
+    "split-seed-",
+];
+
+fn parse_rng(file: &str, content: &str) -> serde_json::Result<Option<CaseData>> {
+    let prefix = RNG_PREFIXES.into_iter().find(|p| file.starts_with(p));
+    let data = match prefix {
+        Some("uniform-seed-") => CaseData::RngUniform(de(content)?),
+        Some("normal-seed-") => CaseData::RngNormal(de(content)?),
+        Some("uniform-string-") => CaseData::RngStringSeed(de(content)?),
+        Some("uniform-int-") => CaseData::RngUniformInt(de(content)?),
+        Some("uniform-i32-") => CaseData::RngUniformI32(de(content)?),
+        Some("uniform-range-") => CaseData::RngUniformRange(de(content)?),
+        Some("uniform-f32-") => CaseData::RngUniformF32(de(content)?),
+        Some("uniform-bool-seed-") => CaseData::RngUniformBool(de(content)?),
+        Some("next-u64-seed-") => CaseData::RngNextU64(de(content)?),
+        Some("jump-seed-") => CaseData::RngJump(de(content)?),
+        Some("long-jump-seed-") => CaseData::RngLongJump(de(content)?),
+        Some("split-seed-") => CaseData::RngSplit(de(content)?),
+        _ => return Ok(None),
+    };
+    Ok(Some(data))
+}
+
+/// One value that differs from the fixture.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch {
+    /// Where the value sits in the output, e.g. `output[3]` or `y.len`.
+    pub location: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// Outcome of [`verify`]: the fixture passed if there are no mismatches.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerificationResult {
+    pub path: PathBuf,
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl VerificationResult {
+    /// True if every value matched.
+    pub fn passed(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+impl fmt::Display for VerificationResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.passed() {
+            return write!(f, "{}: passed", self.path.display());
+        }
+        write!(
+            f,
+            "{}: {} mismatches",
+            self.path.display(),
+            self.mismatches.len()
+        )?;
+        for m in &self.mismatches {
+            write!(
+                f,
+                "\n  {}: expected {}, got {}",
+                m.location, m.expected, m.actual
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs the crate functionality a case exercises and compares the result
+/// with the fixture, using the rules in the module documentation.
+///
+/// # Panics
+/// Panics where the exercised function does, e.g. for distribution
+/// parameters its constructor rejects.
+pub fn verify(case: &ReferenceCase) -> VerificationResult {
+    let mut check = Checker::default();
+    match &case.data {
+        CaseData::RngUniform(c) => {
+            let mut rng = Rng::from_seed(c.input.seed);
+            let actual: Vec<f64> = (0..c.input.count).map(|_| rng.uniform_f64()).collect();
+            check.floats("output", &actual, &c.output, RAW_TOLERANCE);
+        }
+        CaseData::RngNormal(c) => {
+            let mut rng = Rng::from_seed(c.input.seed);
+            let actual: Vec<f64> = (0..c.input.count).map(|_| rng.normal()).collect();
+            check.floats("output", &actual, &c.output, MATH_TOLERANCE);
+        }
+        CaseData::RngStringSeed(c) => {
+            let mut rng = Rng::from_string(&c.input.seed);
+            let actual: Vec<f64> = (0..c.input.count).map(|_| rng.uniform_f64()).collect();
+            check.floats("output", &actual, &c.output, RAW_TOLERANCE);
+        }
+        CaseData::RngUniformInt(c) => {
+            let mut rng = Rng::from_seed(c.input.seed);
+            let actual: Vec<i64> = (0..c.input.count)
+                .map(|_| rng.uniform_i64(c.input.min, c.input.max))
+                .collect();
+            check.exact("output", &actual, &c.output);
+        }
+        CaseData::RngUniformI32(c) => {
+            let mut rng = Rng::from_seed(c.input.seed);
+            let actual: Vec<i32> = (0..c.input.count)
+                .map(|_| rng.uniform_i32(c.input.min, c.input.max))
+                .collect();
+            check.exact("output", &actual, &c.output);
+        }
+        CaseData::RngUniformRange(c) => {
+            let mut rng = Rng::from_seed(c.input.seed);
+            let actual: Vec<f64> = (0..c.input.count)
+                .map(|_| rng.uniform_f64_range(c.input.min, c.input.max))
+                .collect();
+            check.floats("output", &actual, &c.output, MATH_TOLERANCE);
+        }
+        CaseData::RngUniformF32(c) => {
+            let mut rng = Rng::from_seed(c.input.seed);
+            let actual: Vec<f32> = (0..c.input.count).map(|_| rng.uniform_f32()).collect();
+            check.floats_f32("output", &actual, &c.output);
+        }
+        CaseData::RngUniformBool(c) => {
+            let mut rng = Rng::from_seed(c.input.seed);
+            let actual: Vec<bool> = (0..c.input.count).map(|_| rng.uniform_bool()).collect();
+            check.exact("output", &actual, &c.output);
+        }
+        CaseData::RngNextU64(c) => check.raw_u64(c, |_| {}),
+        CaseData::RngJump(c) => check.raw_u64(c, Rng::jump),
+        CaseData::RngLongJump(c) => check.raw_u64(c, Rng::long_jump),
+        CaseData::RngSplit(c) => {
+            let mut rng = Rng::from_seed(c.input.seed);
+            let substreams = rng.split(c.input.k);
+            check.len("output", substreams.len(), c.output.len());
+            for (i, (mut substream, expected)) in substreams.into_iter().zip(&c.output).enumerate()
+            {
+                let actual: Vec<String> = (0..c.input.count)
+                    .map(|_| substream.next_u64().to_string())
+                    .collect();
+                check.exact(&format!("output[{i}]"), &actual, expected);
+            }
+        }
+        CaseData::Shuffle(c) => {
+            let actual = Rng::from_seed(c.input.seed).shuffle(&c.input.x);
+            check.floats("output", &actual, &c.output, RAW_TOLERANCE);
+        }
+        CaseData::Sample(c) => {
+            let actual = Rng::from_seed(c.input.seed).sample(&c.input.x, c.input.k);
+            check.floats("output", &actual, &c.output, RAW_TOLERANCE);
+        }
+        CaseData::Resample(c) => {
+            let actual = Rng::from_seed(c.input.seed).resample(&c.input.x, c.input.k);
+            check.floats("output", &actual, &c.output, RAW_TOLERANCE);
+        }
+        CaseData::ShiftPermutation(c) => {
+            let i = &c.input;
+            let actual = permutation::shift_pvalue(&i.x, &i.y, i.iterations, &i.seed);
+            check.pvalue(actual, c.output);
+        }
+        CaseData::PairedPermutation(c) => {
+            let i = &c.input;
+            let actual = permutation::paired_pvalue(&i.x, &i.y, i.iterations, &i.seed);
+            check.pvalue(actual, c.output);
+        }
+        CaseData::UniformDist(c) => {
+            let dist = Uniform::new(c.input.min, c.input.max);
+            check.draws(&dist, c.input.seed, c.input.count, &c.output);
+        }
+        CaseData::UniformIntDist(c) => {
+            let mut rng = Rng::from_seed(c.input.seed);
+            let dist = UniformInt::new(c.input.min, c.input.max);
+            let actual: Vec<i64> = (0..c.input.count)
+                .map(|_| dist.sample(&mut rng) as i64)
+                .collect();
+            check.exact("output", &actual, &c.output);
+        }
+        CaseData::AdditiveDist(c) => {
+            let dist = Additive::new(c.input.mean, c.input.std_dev);
+            check.draws(&dist, c.input.seed, c.input.count, &c.output);
+        }
+        CaseData::MultiplicDist(c) => {
+            let dist = Multiplic::new(c.input.log_mean, c.input.log_std_dev);
+            check.draws(&dist, c.input.seed, c.input.count, &c.output);
+        }
+        CaseData::ExpDist(c) => {
+            let dist = Exp::new(c.input.rate);
+            check.draws(&dist, c.input.seed, c.input.count, &c.output);
+        }
+        CaseData::PowerDist(c) => {
+            let dist = Power::new(c.input.min, c.input.shape);
+            check.draws(&dist, c.input.seed, c.input.count, &c.output);
+        }
+        CaseData::GammaDist(c) => {
+            let dist = Gamma::new(c.input.shape, c.input.rate);
+            check.draws(&dist, c.input.seed, c.input.count, &c.output);
+        }
+        CaseData::ChiSquaredDist(c) => {
+            let dist = ChiSquared::new(c.input.df);
+            check.draws(&dist, c.input.seed, c.input.count, &c.output);
+        }
+        CaseData::StudentTDist(c) => {
+            let dist = StudentT::new(c.input.df);
+            check.draws(&dist, c.input.seed, c.input.count, &c.output);
+        }
+        CaseData::PoissonDist(c) => {
+            let dist = Poisson::new(c.input.lambda);
+            check.draws(&dist, c.input.seed, c.input.count, &c.output);
+        }
+        CaseData::BinomialDist(c) => {
+            let dist = Binomial::new(c.input.n, c.input.p);
+            check.draws(&dist, c.input.seed, c.input.count, &c.output);
+        }
+        CaseData::BivariateAdditiveDist(c) => {
+            let i = &c.input;
+            let dist = BivariateAdditive::new(i.mean_x, i.mean_y, i.sd_x, i.sd_y, i.rho)
+                .expect("valid BivariateAdditive parameters");
+            let actual = dist.sample_pairs(&mut Rng::from_seed(i.seed), i.count);
+            check.pairs(actual, &c.output);
+        }
+        CaseData::AdditiveAntitheticDist(c) => {
+            let mut rng = Rng::from_seed(c.input.seed);
+            let dist = Additive::new(c.input.mean, c.input.std_dev);
+            let actual = (0..c.input.count)
+                .map(|_| dist.sample_antithetic(&mut rng))
+                .unzip();
+            check.pairs(actual, &c.output);
+        }
+        CaseData::GaussianCopulaDist(c) => {
+            let i = &c.input;
+            let dist = GaussianCopula::new(
+                Box::new(Exp::new(i.rate)),
+                Box::new(Uniform::new(i.min, i.max)),
+                i.rho,
+            )
+            .expect("valid GaussianCopula parameters");
+            let actual = dist.sample_pairs(&mut Rng::from_seed(i.seed), i.count);
+            check.pairs(actual, &c.output);
+        }
+        CaseData::Ar1Dist(c) => {
+            let i = &c.input;
+            let dist = Ar1::new(Box::new(Additive::new(i.mean, i.std_dev)), i.phi)
+                .expect("valid Ar1 parameters");
+            let actual = dist.sample_series(&mut Rng::from_seed(i.seed), i.count);
+            check.floats("output", &actual, &c.output, MATH_TOLERANCE);
+        }
+    }
+    VerificationResult {
+        path: case.path.clone(),
+        mismatches: check.mismatches,
+    }
+}
+
+/// Distance in units in the last place; `u64::MAX` across signs or for NaN.
+fn ulps(a: u64, b: u64, same_sign: bool) -> u64 {
+    if same_sign {
+        a.abs_diff(b)
+    } else {
+        u64::MAX
+    }
+}
+
+fn close(actual: f64, expected: f64, tolerance: f64) -> bool {
+    let same_sign = actual.is_sign_negative() == expected.is_sign_negative();
+    actual == expected
+        || (actual - expected).abs() <= tolerance
+        || ulps(actual.to_bits(), expected.to_bits(), same_sign) <= MAX_ULPS
+}
+
+fn close_f32(actual: f32, expected: f32) -> bool {
+    let same_sign = actual.is_sign_negative() == expected.is_sign_negative();
+    actual == expected
+        || (actual - expected).abs() <= F32_TOLERANCE
+        || ulps(
+            actual.to_bits() as u64,
+            expected.to_bits() as u64,
+            same_sign,
+        ) <= MAX_ULPS
+}
+
+#[derive(Default)]
+struct Checker {
+    mismatches: Vec<Mismatch>,
+}
+
+impl Checker {
+    fn push(&mut self, location: String, expected: impl fmt::Display, actual: impl fmt::Display) {
+        self.mismatches.push(Mismatch {
+            location,
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+        });
+    }
+
+    fn len(&mut self, label: &str, actual: usize, expected: usize) {
+        if actual != expected {
+            self.push(format!("{label}.len"), expected, actual);
+        }
+    }
+
+    fn compare<T: fmt::Display>(
+        &mut self,
+        label: &str,
+        actual: &[T],
+        expected: &[T],
+        matches: impl Fn(&T, &T) -> bool,
+    ) {
+        self.len(label, actual.len(), expected.len());
+        for (i, (a, e)) in actual.iter().zip(expected).enumerate() {
+            if !matches(a, e) {
+                self.push(format!("{label}[{i}]"), e, a);
+            }
+        }
+    }
+
+    fn exact<T: PartialEq + fmt::Display>(&mut self, label: &str, actual: &[T], expected: &[T]) {
+        self.compare(label, actual, expected, |a, e| a == e);
+    }
+
+    fn floats(&mut self, label: &str, actual: &[f64], expected: &[f64], tolerance: f64) {
+        self.compare(label, actual, expected, |a, e| close(*a, *e, tolerance));
+    }
+
+    fn floats_f32(&mut self, label: &str, actual: &[f32], expected: &[f32]) {
+        self.compare(label, actual, expected, |a, e| close_f32(*a, *e));
+    }
+
+    fn raw_u64(&mut self, case: &NextU64TestCase, advance: fn(&mut Rng)) {
+        let mut rng = Rng::from_seed(case.input.seed);
+        advance(&mut rng);
+        let actual: Vec<String> = (0..case.input.count)
+            .map(|_| rng.next_u64().to_string())
+            .collect();
+        self.exact("output", &actual, &case.output);
+    }
+
+    fn pvalue<E: fmt::Display>(&mut self, actual: Result<f64, E>, expected: f64) {
+        match actual {
+            Ok(p) if close(p, expected, RAW_TOLERANCE) => {}
+            Ok(p) => self.push("output".to_string(), expected, p),
+            Err(e) => self.push("output".to_string(), expected, format!("error: {e}")),
+        }
+    }
+
+    fn draws<D: Distribution>(&mut self, dist: &D, seed: i64, count: usize, expected: &[f64]) {
+        let mut rng = Rng::from_seed(seed);
+        let actual: Vec<f64> = (0..count).map(|_| dist.sample(&mut rng)).collect();
+        self.floats("output", &actual, expected, MATH_TOLERANCE);
+    }
+
+    fn pairs(&mut self, actual: (Vec<f64>, Vec<f64>), expected: &PairOutput) {
+        self.floats("x", &actual.0, &expected.x, MATH_TOLERANCE);
+        self.floats("y", &actual.1, &expected.y, MATH_TOLERANCE);
+    }
+}
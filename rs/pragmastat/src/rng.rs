@@ -4,6 +4,7 @@
 //! produces identical sequences across all Pragmastat language implementations.
 
 use crate::fnv1a::fnv1a_hash;
+use crate::splitmix64::SplitMix64;
 use crate::xoshiro256::Xoshiro256PlusPlus;
 
 /// A deterministic random number generator.
@@ -29,6 +30,10 @@ use crate::xoshiro256::Xoshiro256PlusPlus;
 /// let mut rng = Rng::from_string("demo-sample");
 /// let sampled = rng.sample(&data, 3);
 /// ```
+///
+/// Cloning an `Rng` forks the stream: the clone continues from the same state
+/// and produces the same sequence as the original.
+#[derive(Clone)]
 pub struct Rng {
     inner: Xoshiro256PlusPlus,
 }
@@ -39,6 +44,24 @@ impl Default for Rng {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Rng {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.state().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Rng {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let state = <[u64; 4]>::deserialize(deserializer)?;
+        if state == [0; 4] {
+            return Err(serde::de::Error::custom("state must not be all zeros"));
+        }
+        Ok(Self::from_state(state))
+    }
+}
+
 impl Rng {
     /// Create a new Rng with system entropy
     ///
@@ -97,6 +120,184 @@ impl Rng {
         }
     }
 
+    /// Create the Rng for item `index` of a batch seeded with `base_seed`
+    ///
+    /// The item's integer seed is output `index` of a SplitMix64 stream seeded
+    /// with the FNV-1a hash of `base_seed`, passed to [`Rng::from_seed`]. It
+    /// depends only on `(base_seed, index)`, so a batch can be processed in any
+    /// order or on any number of threads and every item still sees the same
+    /// stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pragmastat::Rng;
+    ///
+    /// let forward: Vec<f64> = (0..4)
+    ///     .map(|i| Rng::from_string_indexed("batch", i).uniform_f64())
+    ///     .collect();
+    /// let backward: Vec<f64> = (0..4)
+    ///     .rev()
+    ///     .map(|i| Rng::from_string_indexed("batch", i).uniform_f64())
+    ///     .collect();
+    /// assert!(forward.iter().eq(backward.iter().rev()));
+    /// ```
+    pub fn from_string_indexed(base_seed: &str, index: u64) -> Self {
+        Self::from_seed(SplitMix64::nth(fnv1a_hash(base_seed), index) as i64)
+    }
+
+    /// Restore an Rng from a state captured by [`Rng::state`]
+    ///
+    /// The restored generator continues exactly where the saved one left off,
+    /// which makes it suitable for checkpointing long simulations.
+    ///
+    /// **Warning:** this bypasses the SplitMix64 seeding used by
+    /// [`Rng::from_seed`] and [`Rng::from_string`]. Pass only states obtained
+    /// from `state()`; hand-made states (e.g. small integers) are poorly mixed
+    /// and do not correspond to any seed, so other Pragmastat implementations
+    /// cannot reproduce them from a seed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `state` is all zeros, which xoshiro256++ can never leave.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pragmastat::Rng;
+    ///
+    /// let mut rng = Rng::from_string("long-simulation");
+    /// rng.uniform_f64();
+    /// let checkpoint = rng.state();
+    ///
+    /// let mut resumed = Rng::from_state(checkpoint);
+    /// assert_eq!(resumed.uniform_f64(), rng.uniform_f64());
+    /// ```
+    pub fn from_state(state: [u64; 4]) -> Self {
+        assert!(state != [0; 4], "state must not be all zeros");
+        Self {
+            inner: Xoshiro256PlusPlus::from_state(state),
+        }
+    }
+
+    /// Current xoshiro256++ state, for use with [`Rng::from_state`]
+    ///
+    /// With the `serde` feature, `Rng` itself serializes as this array.
+    pub fn state(&self) -> [u64; 4] {
+        self.inner.state()
+    }
+
+    // ========================================================================
+    // Raw Bit Methods
+    // ========================================================================
+
+    /// Generate the next raw 64-bit output of the underlying xoshiro256++.
+    ///
+    /// Every other method is built on this stream: `uniform_f64`, for example,
+    /// consumes exactly one `next_u64` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pragmastat::Rng;
+    ///
+    /// let mut rng = Rng::from_seed(1729);
+    /// let bits = rng.next_u64();
+    /// assert_eq!(Rng::from_seed(1729).next_u64(), bits);
+    /// ```
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        self.inner.next_u64()
+    }
+
+    /// Advance the stream by 2^128 steps.
+    ///
+    /// Applies the reference xoshiro256++ jump polynomial. Calling `jump` on
+    /// clones yields non-overlapping substreams: fork a clone, jump the
+    /// original, and repeat, giving each worker 2^128 values of its own
+    /// ([`Rng::split`] does the same with [`Rng::long_jump`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pragmastat::Rng;
+    ///
+    /// let mut rng = Rng::from_string("experiment");
+    /// let workers: Vec<Rng> = (0..4)
+    ///     .map(|_| {
+    ///         let worker = rng.clone();
+    ///         rng.jump();
+    ///         worker
+    ///     })
+    ///     .collect();
+    /// assert_eq!(workers.len(), 4);
+    /// ```
+    pub fn jump(&mut self) {
+        self.inner.jump();
+    }
+
+    /// Advance the stream by 2^192 steps.
+    ///
+    /// Like [`Rng::jump`] but one level up: use `long_jump` (or [`Rng::split`])
+    /// to separate top-level streams (e.g. per machine) that each split further
+    /// via `jump`.
+    pub fn long_jump(&mut self) {
+        self.inner.long_jump();
+    }
+
+    /// Split off `k` non-overlapping substreams spaced by [`Rng::long_jump`].
+    ///
+    /// The first returned generator continues from the current state, the
+    /// second starts 2^192 steps later, and so on. `self` is left just past the
+    /// last substream (long-jumped `k` times), so it can be split again without
+    /// overlap. Each substream has 2^192 values to itself before it would run
+    /// into the next, and can itself be divided further with [`Rng::jump`].
+    /// The spacing is part of the cross-language contract
+    /// (`tests/rng/split-seed-*.json`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pragmastat::Rng;
+    ///
+    /// let mut rng = Rng::from_string("experiment");
+    /// let mut workers = rng.split(4);
+    /// let first = workers[0].uniform_f64();
+    /// assert_eq!(first, Rng::from_string("experiment").uniform_f64());
+    /// ```
+    pub fn split(&mut self, k: usize) -> Vec<Rng> {
+        (0..k)
+            .map(|_| {
+                let substream = self.clone();
+                self.long_jump();
+                substream
+            })
+            .collect()
+    }
+
+    /// Fill `dst` with random bytes.
+    ///
+    /// Bytes are taken from successive [`Rng::next_u64`] values in
+    /// little-endian order, 8 per value; a trailing partial chunk uses the low
+    /// bytes of one more value (the rest are discarded). So `dst.len()` bytes
+    /// consume `ceil(dst.len() / 8)` values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pragmastat::Rng;
+    ///
+    /// let mut rng = Rng::from_string("demo-bytes");
+    /// let mut key = [0u8; 16];
+    /// rng.fill_bytes(&mut key);
+    /// ```
+    pub fn fill_bytes(&mut self, dst: &mut [u8]) {
+        for chunk in dst.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
     // ========================================================================
     // Floating Point Methods
     // ========================================================================
@@ -144,6 +345,38 @@ impl Rng {
         self.inner.uniform_f64_range(min, max)
     }
 
+    /// Fill `out` with uniform random f64 values in [0, 1)
+    ///
+    /// Produces exactly the values of `out.len()` successive
+    /// [`Rng::uniform_f64`] calls, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pragmastat::Rng;
+    ///
+    /// let mut rng = Rng::from_string("demo-uniform");
+    /// let mut buffer = [0.0; 1000];
+    /// rng.fill_uniform(&mut buffer);
+    /// assert!(buffer.iter().all(|v| (0.0..1.0).contains(v)));
+    /// ```
+    pub fn fill_uniform(&mut self, out: &mut [f64]) {
+        for value in out.iter_mut() {
+            *value = self.inner.uniform_f64();
+        }
+    }
+
+    /// Fill `out` with uniform random f64 values in [min, max)
+    ///
+    /// Produces exactly the values of `out.len()` successive
+    /// [`Rng::uniform_f64_range`] calls. For a degenerate range
+    /// (`min >= max`) every element is `min` and no values are consumed.
+    pub fn fill_uniform_range(&mut self, out: &mut [f64], min: f64, max: f64) {
+        for value in out.iter_mut() {
+            *value = self.inner.uniform_f64_range(min, max);
+        }
+    }
+
     /// Generate a uniform random f32 in [0, 1)
     ///
     /// Uses 24 bits for f32 mantissa precision.
@@ -305,14 +538,75 @@ impl Rng {
         self.inner.uniform_bool()
     }
 
+    // ========================================================================
+    // Gaussian Methods
+    // ========================================================================
+
+    /// Generate a standard normal random f64 (mean 0, standard deviation 1)
+    ///
+    /// Uses the Box-Muller transform and consumes exactly two `uniform_f64`
+    /// values; only the cosine branch is used. A zero first uniform is replaced
+    /// by the smallest positive subnormal to avoid `ln(0)`. The sequence is
+    /// identical to sampling [`Additive::new(0.0, 1.0)`](crate::distributions::Additive).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pragmastat::Rng;
+    ///
+    /// let mut rng = Rng::from_string("demo-normal");
+    /// let z = rng.normal();
+    /// assert!(z.is_finite());
+    /// ```
+    pub fn normal(&mut self) -> f64 {
+        let u1 = self.uniform_f64();
+        let u2 = self.uniform_f64();
+
+        // Avoid log(0) - use smallest positive subnormal for cross-language consistency
+        let u1 = if u1 == 0.0 {
+            crate::distributions::SMALLEST_POSITIVE_SUBNORMAL
+        } else {
+            u1
+        };
+
+        let r = (-2.0 * u1.ln()).sqrt();
+        let theta = 2.0 * std::f64::consts::PI * u2;
+        r * theta.cos()
+    }
+
+    /// Generate a normal random f64 with the given mean and standard deviation
+    ///
+    /// Computes `mean + normal() * std_dev`, matching
+    /// [`Additive::new(mean, std_dev)`](crate::distributions::Additive) draw for
+    /// draw. Like the uniform range methods, it does not validate `std_dev`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pragmastat::Rng;
+    ///
+    /// let mut rng = Rng::from_string("demo-normal");
+    /// let value = rng.normal_range(100.0, 15.0);
+    /// assert!(value.is_finite());
+    /// ```
+    #[inline]
+    pub fn normal_range(&mut self, mean: f64, std_dev: f64) -> f64 {
+        mean + self.normal() * std_dev
+    }
+
     // ========================================================================
     // Collection Methods
     // ========================================================================
 
     /// Sample k elements from the input slice without replacement
     ///
-    /// Uses selection sampling to maintain order of first appearance.
-    /// Returns up to `k` elements; if `k >= x.len()`, returns all elements.
+    /// Uses selection sampling (Knuth's Algorithm S): elements are visited in
+    /// input order and each is kept with probability `remaining / available`,
+    /// consuming one `uniform_f64` per visited element. The result is therefore
+    /// always in original-index order (no separate ordered variant is needed),
+    /// and `sample` stops drawing as soon as `k` elements are chosen.
+    /// Returns up to `k` elements; if `k >= x.len()`, returns all elements
+    /// without consuming any values.
     ///
     /// # Panics
     ///
@@ -434,12 +728,197 @@ impl Rng {
             x.swap(i, j);
         }
     }
+
+    /// Draw an index with probability proportional to `weights[i]`
+    ///
+    /// One-off convenience for `WeightedIndex::new(weights).sample(self)`: it
+    /// builds the alias table on every call, which costs O(n). For repeated
+    /// draws from the same weights, build a [`WeightedIndex`] once instead.
+    ///
+    /// # Panics
+    ///
+    /// Same as [`WeightedIndex::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pragmastat::Rng;
+    ///
+    /// let mut rng = Rng::from_string("demo-weighted");
+    /// let i = rng.weighted_index(&[1.0, 0.0, 3.0]);
+    /// assert!(i == 0 || i == 2);
+    /// ```
+    pub fn weighted_index(&mut self, weights: &[f64]) -> usize {
+        WeightedIndex::new(weights).sample(self)
+    }
+}
+
+/// Alias table for O(1) draws of a categorical distribution
+///
+/// Built once from non-negative weights with Vose's alias method; each
+/// [`sample`](WeightedIndex::sample) then consumes exactly one `uniform_usize`
+/// and one `uniform_f64` draw.
+///
+/// # Construction
+///
+/// For `n` weights with sum `W`, each weight is scaled to `p[i] = w[i] * n / W`.
+/// Indices with `p[i] < 1` go on a `small` stack and the rest on a `large`
+/// stack, each pushed in ascending index order. While both stacks are
+/// non-empty, pop `s` from `small` and `l` from `large`, set `prob[s] = p[s]`
+/// and `alias[s] = l`, then update `p[l] = (p[l] + p[s]) - 1` and push `l` back
+/// on `small` if `p[l] < 1`, otherwise on `large`. Indices left on either stack
+/// get `prob = 1` (and alias themselves).
+///
+/// To draw, take `i = uniform_usize(0, n)` and `u = uniform_f64()`, and return
+/// `i` if `u < prob[i]`, otherwise `alias[i]`. Ports that follow these steps in
+/// this order produce the same indices for the same seed.
+///
+/// # Examples
+///
+/// ```
+/// use pragmastat::rng::WeightedIndex;
+/// use pragmastat::Rng;
+///
+/// let dice = WeightedIndex::new(&[1.0, 1.0, 1.0, 1.0, 1.0, 5.0]);
+/// let mut rng = Rng::from_string("demo-weighted");
+/// let rolls: Vec<usize> = (0..10).map(|_| dice.sample(&mut rng)).collect();
+/// assert!(rolls.iter().all(|&i| i < 6));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedIndex {
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl WeightedIndex {
+    /// Build the alias table for `weights`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weights` is empty, contains a negative or non-finite value,
+    /// or sums to zero or to infinity.
+    pub fn new(weights: &[f64]) -> Self {
+        assert!(!weights.is_empty(), "Cannot sample from empty weights");
+        assert!(
+            weights.iter().all(|w| w.is_finite() && *w >= 0.0),
+            "Weights must be finite and non-negative"
+        );
+        let total: f64 = weights.iter().sum();
+        assert!(
+            total > 0.0 && total.is_finite(),
+            "Weights must have a positive finite sum"
+        );
+
+        let n = weights.len();
+        let mut p: Vec<f64> = weights.iter().map(|w| w * n as f64 / total).collect();
+        let mut prob = vec![1.0; n];
+        let mut alias: Vec<usize> = (0..n).collect();
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) = (0..n).partition(|&i| p[i] < 1.0);
+
+        while let (Some(&s), Some(&l)) = (small.last(), large.last()) {
+            small.pop();
+            large.pop();
+            prob[s] = p[s];
+            alias[s] = l;
+            p[l] = (p[l] + p[s]) - 1.0;
+            if p[l] < 1.0 {
+                small.push(l);
+            } else {
+                large.push(l);
+            }
+        }
+
+        Self { prob, alias }
+    }
+
+    /// Number of categories
+    pub fn len(&self) -> usize {
+        self.prob.len()
+    }
+
+    /// Always false: a table has at least one category
+    pub fn is_empty(&self) -> bool {
+        self.prob.is_empty()
+    }
+
+    /// Draw an index in `[0, len())` with probability proportional to its weight
+    pub fn sample(&self, rng: &mut Rng) -> usize {
+        let i = rng.uniform_usize(0, self.prob.len());
+        if rng.uniform_f64() < self.prob[i] {
+            i
+        } else {
+            self.alias[i]
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn weighted_index_frequencies_match_weights() {
+        let weights = [1.0, 2.0, 3.0, 0.0, 4.0, 10.0];
+        let table = WeightedIndex::new(&weights);
+        let mut rng = Rng::from_string("weighted-index");
+        let draws = 100_000;
+        let mut counts = [0usize; 6];
+        for _ in 0..draws {
+            counts[table.sample(&mut rng)] += 1;
+        }
+
+        assert_eq!(counts[3], 0, "zero weight must never be drawn");
+        let total: f64 = weights.iter().sum();
+        let chi2: f64 = weights
+            .iter()
+            .zip(counts)
+            .filter(|(w, _)| **w > 0.0)
+            .map(|(w, c)| {
+                let expected = draws as f64 * w / total;
+                (c as f64 - expected).powi(2) / expected
+            })
+            .sum();
+        // 4 degrees of freedom; P(chi2 > 18.47) = 0.001
+        assert!(chi2 < 18.47, "chi2 = {chi2}, counts = {counts:?}");
+    }
+
+    #[test]
+    fn weighted_index_is_deterministic() {
+        let weights = [0.5, 0.25, 0.125, 0.125];
+        let table = WeightedIndex::new(&weights);
+        let mut rng1 = Rng::from_string("weighted-index");
+        let mut rng2 = Rng::from_string("weighted-index");
+        let mut rng3 = Rng::from_string("weighted-index");
+        for _ in 0..100 {
+            let i = table.sample(&mut rng1);
+            assert_eq!(rng2.weighted_index(&weights), i);
+            // Scaling the weights does not change the table
+            assert_eq!(rng3.weighted_index(&[4.0, 2.0, 1.0, 1.0]), i);
+        }
+    }
+
+    #[test]
+    fn weighted_index_construction() {
+        // p = [0.5, 1.5]: 0 is small, 1 is large; 0 aliases 1
+        let table = WeightedIndex::new(&[1.0, 3.0]);
+        assert_eq!(table.prob, vec![0.5, 1.0]);
+        assert_eq!(table.alias, vec![1, 1]);
+        assert_eq!(table.len(), 2);
+        assert!(!table.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "non-negative")]
+    fn weighted_index_rejects_negative_weight() {
+        WeightedIndex::new(&[1.0, -1.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "positive finite sum")]
+    fn weighted_index_rejects_zero_sum() {
+        WeightedIndex::new(&[0.0, 0.0]);
+    }
+
     #[test]
     fn from_seed_deterministic() {
         let mut rng1 = Rng::from_seed(1729);
@@ -450,6 +929,141 @@ mod tests {
         }
     }
 
+    #[test]
+    fn fill_uniform_matches_per_call_sequence() {
+        let n = 997; // not a power of two
+
+        let mut expected_rng = Rng::from_seed(1729);
+        let expected: Vec<f64> = (0..n).map(|_| expected_rng.uniform_f64()).collect();
+        let mut rng = Rng::from_seed(1729);
+        let mut actual = vec![0.0; n];
+        rng.fill_uniform(&mut actual);
+        assert_eq!(
+            actual.iter().map(|v| v.to_bits()).collect::<Vec<_>>(),
+            expected.iter().map(|v| v.to_bits()).collect::<Vec<_>>()
+        );
+        assert_eq!(rng.next_u64(), expected_rng.next_u64());
+
+        let mut expected_rng = Rng::from_seed(-42);
+        let expected: Vec<f64> = (0..n)
+            .map(|_| expected_rng.uniform_f64_range(-5.0, 7.5))
+            .collect();
+        let mut rng = Rng::from_seed(-42);
+        let mut actual = vec![0.0; n];
+        rng.fill_uniform_range(&mut actual, -5.0, 7.5);
+        assert_eq!(
+            actual.iter().map(|v| v.to_bits()).collect::<Vec<_>>(),
+            expected.iter().map(|v| v.to_bits()).collect::<Vec<_>>()
+        );
+        assert_eq!(rng.next_u64(), expected_rng.next_u64());
+    }
+
+    #[test]
+    fn fill_uniform_range_degenerate_consumes_nothing() {
+        let mut rng = Rng::from_seed(1);
+        let mut out = [0.0; 5];
+        rng.fill_uniform_range(&mut out, 3.0, 3.0);
+        assert_eq!(out, [3.0; 5]);
+        assert_eq!(rng.next_u64(), Rng::from_seed(1).next_u64());
+    }
+
+    #[test]
+    fn restored_state_continues_the_stream() {
+        let data: Vec<f64> = (0..20).map(f64::from).collect();
+        let mut uninterrupted = Rng::from_string("checkpoint");
+        let mut rng = Rng::from_string("checkpoint");
+
+        for _ in 0..3 {
+            assert_eq!(rng.uniform_f64(), uninterrupted.uniform_f64());
+            assert_eq!(rng.shuffle(&data), uninterrupted.shuffle(&data));
+            assert_eq!(rng.resample(&data, 7), uninterrupted.resample(&data, 7));
+
+            // Checkpoint and resume mid-stream
+            let state = rng.state();
+            rng = Rng::from_state(state);
+            assert_eq!(rng.state(), uninterrupted.state());
+        }
+        for _ in 0..100 {
+            assert_eq!(rng.next_u64(), uninterrupted.next_u64());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "state must not be all zeros")]
+    fn from_state_rejects_zero_state() {
+        Rng::from_state([0; 4]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip() {
+        let mut rng = Rng::from_seed(1729);
+        rng.uniform_f64();
+        let json = serde_json::to_string(&rng).unwrap();
+        let mut restored: Rng = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.next_u64(), rng.next_u64());
+        assert!(serde_json::from_str::<Rng>("[0,0,0,0]").is_err());
+    }
+
+    #[test]
+    fn sample_preserves_input_order() {
+        let data: Vec<usize> = (0..50).collect();
+        for seed in 0..20 {
+            let mut rng = Rng::from_seed(seed);
+            let sampled = rng.sample(&data, 10);
+            assert_eq!(sampled.len(), 10);
+            assert!(sampled.windows(2).all(|w| w[0] < w[1]), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn split_is_spaced_by_long_jumps() {
+        let mut rng = Rng::from_seed(1729);
+        let mut expected = Rng::from_seed(1729);
+        let mut substreams = rng.split(3);
+        assert_eq!(substreams.len(), 3);
+        for substream in &mut substreams {
+            assert_eq!(substream.next_u64(), expected.clone().next_u64());
+            expected.long_jump();
+        }
+        // The parent continues past the last substream
+        assert_eq!(rng.next_u64(), expected.next_u64());
+        assert!(Rng::from_seed(1).split(0).is_empty());
+    }
+
+    #[test]
+    fn split_substreams_are_disjoint_and_deterministic() {
+        let outputs = |seed: &str| -> Vec<Vec<u64>> {
+            Rng::from_string(seed)
+                .split(8)
+                .into_iter()
+                .map(|mut r| (0..1_000).map(|_| r.next_u64()).collect())
+                .collect()
+        };
+        let children = outputs("split-disjoint");
+        assert_eq!(children, outputs("split-disjoint"));
+
+        let all: Vec<u64> = children.concat();
+        let distinct: std::collections::HashSet<u64> = all.iter().copied().collect();
+        assert_eq!(distinct.len(), all.len());
+    }
+
+    #[test]
+    fn normal_matches_additive() {
+        use crate::distributions::{Additive, Distribution};
+
+        let standard = Additive::new(0.0, 1.0);
+        let scaled = Additive::new(100.0, 15.0);
+        for seed in [0, 1, 1729, -42] {
+            let mut dist_rng = Rng::from_seed(seed);
+            let mut rng = Rng::from_seed(seed);
+            for _ in 0..100 {
+                assert_eq!(standard.sample(&mut dist_rng), rng.normal());
+                assert_eq!(scaled.sample(&mut dist_rng), rng.normal_range(100.0, 15.0));
+            }
+        }
+    }
+
     #[test]
     fn from_string_deterministic() {
         let mut rng1 = Rng::from_string("test");
@@ -460,13 +1074,78 @@ mod tests {
         }
     }
 
+    #[test]
+    fn next_u64_is_the_shared_stream() {
+        let mut raw = Rng::from_seed(1729);
+        let mut rng = Rng::from_seed(1729);
+        for _ in 0..100 {
+            let bits = raw.next_u64();
+            let expected = (bits >> 11) as f64 * (1.0 / (1u64 << 53) as f64);
+            assert_eq!(rng.uniform_f64(), expected);
+        }
+    }
+
+    #[test]
+    fn jump_matches_reference_implementation() {
+        // Values from the reference C code (prng.di.unimi.it) seeded via SplitMix64(1729)
+        let mut rng = Rng::from_seed(1729);
+        rng.jump();
+        let jumped: Vec<u64> = (0..3).map(|_| rng.next_u64()).collect();
+        assert_eq!(
+            jumped,
+            [
+                10290885188275915551,
+                15372776359467284671,
+                16743874170170988071
+            ]
+        );
+
+        let mut rng = Rng::from_seed(1729);
+        rng.long_jump();
+        let jumped: Vec<u64> = (0..3).map(|_| rng.next_u64()).collect();
+        assert_eq!(
+            jumped,
+            [
+                4029072759932873860,
+                11746433753208272519,
+                11847395456483168277
+            ]
+        );
+    }
+
+    #[test]
+    fn jumped_stream_is_disjoint_from_parent() {
+        let mut parent = Rng::from_seed(42);
+        let mut child = parent.clone();
+        child.jump();
+        let parent_values: std::collections::HashSet<u64> =
+            (0..10_000).map(|_| parent.next_u64()).collect();
+        assert!((0..10_000).all(|_| !parent_values.contains(&child.next_u64())));
+    }
+
+    #[test]
+    fn fill_bytes_little_endian_chunks() {
+        let mut raw = Rng::from_seed(42);
+        let first = raw.next_u64().to_le_bytes();
+        let second = raw.next_u64().to_le_bytes();
+        let after = raw.next_u64();
+
+        let mut rng = Rng::from_seed(42);
+        let mut bytes = [0u8; 11];
+        rng.fill_bytes(&mut bytes);
+        assert_eq!(bytes[..8], first);
+        assert_eq!(bytes[8..], second[..3]);
+        // The partial chunk consumed a whole value
+        assert_eq!(rng.next_u64(), after);
+    }
+
     #[test]
     fn uniform_in_range() {
         let mut rng = Rng::from_string("test-uniform");
 
         for _ in 0..10000 {
             let v = rng.uniform_f64();
-            assert!(v >= 0.0 && v < 1.0);
+            assert!((0.0..1.0).contains(&v));
         }
     }
 
@@ -476,7 +1155,7 @@ mod tests {
 
         for _ in 0..10000 {
             let v = rng.uniform_f64_range(-10.0, 10.0);
-            assert!(v >= -10.0 && v < 10.0);
+            assert!((-10.0..10.0).contains(&v));
         }
     }
 
@@ -486,7 +1165,7 @@ mod tests {
 
         for _ in 0..10000 {
             let v = rng.uniform_f32();
-            assert!(v >= 0.0 && v < 1.0);
+            assert!((0.0..1.0).contains(&v));
         }
     }
 
@@ -496,7 +1175,7 @@ mod tests {
 
         for _ in 0..10000 {
             let v = rng.uniform_f32_range(-10.0, 10.0);
-            assert!(v >= -10.0 && v < 10.0);
+            assert!((-10.0..10.0).contains(&v));
         }
     }
 
@@ -506,7 +1185,7 @@ mod tests {
 
         for _ in 0..10000 {
             let v = rng.uniform_i64(0, 100);
-            assert!(v >= 0 && v < 100);
+            assert!((0..100).contains(&v));
         }
     }
 
@@ -516,7 +1195,7 @@ mod tests {
 
         for _ in 0..10000 {
             let v = rng.uniform_i32(-500, 500);
-            assert!(v >= -500 && v < 500);
+            assert!((-500..500).contains(&v));
         }
     }
 
@@ -526,7 +1205,7 @@ mod tests {
 
         for _ in 0..10000 {
             let v = rng.uniform_i16(-100, 100);
-            assert!(v >= -100 && v < 100);
+            assert!((-100..100).contains(&v));
         }
     }
 
@@ -536,7 +1215,7 @@ mod tests {
 
         for _ in 0..10000 {
             let v = rng.uniform_i8(-50, 50);
-            assert!(v >= -50 && v < 50);
+            assert!((-50..50).contains(&v));
         }
     }
 
@@ -546,7 +1225,7 @@ mod tests {
 
         for _ in 0..10000 {
             let v = rng.uniform_u64(10, 1000);
-            assert!(v >= 10 && v < 1000);
+            assert!((10..1000).contains(&v));
         }
     }
 
@@ -556,7 +1235,7 @@ mod tests {
 
         for _ in 0..10000 {
             let v = rng.uniform_u32(10, 1000);
-            assert!(v >= 10 && v < 1000);
+            assert!((10..1000).contains(&v));
         }
     }
 
@@ -566,7 +1245,7 @@ mod tests {
 
         for _ in 0..10000 {
             let v = rng.uniform_u16(10, 100);
-            assert!(v >= 10 && v < 100);
+            assert!((10..100).contains(&v));
         }
     }
 
@@ -576,7 +1255,7 @@ mod tests {
 
         for _ in 0..10000 {
             let v = rng.uniform_u8(10, 100);
-            assert!(v >= 10 && v < 100);
+            assert!((10..100).contains(&v));
         }
     }
 
@@ -2,12 +2,20 @@
 //!
 //! [`Sample`] is the primary input type for all estimators in the metrology layer.
 //! It validates inputs at construction time (no empty, NaN, or infinite values)
-//! and lazily computes sorted values on demand.
+//! and lazily computes sorted values on demand. The sorted values are cached, so
+//! calling several estimators on the same sample (e.g., [`Sample::center`],
+//! [`Sample::spread`], [`Sample::median`]) sorts only once.
 
-use crate::assumptions::{AssumptionError, EstimatorError, Subject};
-use crate::measurement_unit::{
-    conversion_factor, finer, is_compatible, MeasurementUnit, UnitMismatchError,
+use crate::assumptions::{
+    check_all, tie_diagnostic, AssumptionError, Diagnostic, EstimatorError, Subject,
 };
+use crate::bounds::Bounds;
+use crate::config::{EstimatorConfig, NonFinitePolicy};
+use crate::distributions::Distribution;
+use crate::experiment::Experiment;
+use crate::measurement::Measurement;
+use crate::measurement_unit::{convert, finer, is_compatible, MeasurementUnit, UnitMismatchError};
+use crate::Rng;
 use std::ops::Mul;
 use std::sync::OnceLock;
 
@@ -24,6 +32,7 @@ pub struct Sample {
     total_weight: f64,
     weighted_size: f64,
     sorted_values: OnceLock<Vec<f64>>,
+    config: EstimatorConfig,
 }
 
 impl Sample {
@@ -45,6 +54,39 @@ impl Sample {
         Self::build(values, None, unit)
     }
 
+    /// Creates an unweighted sample with a specified unit and attaches
+    /// `config`, whose [`NonFinitePolicy`] decides what happens to NaN and
+    /// infinite values.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EstimatorError`] if `values` is empty, contains NaN/infinite
+    /// values under [`NonFinitePolicy::Reject`], or has no finite values under
+    /// [`NonFinitePolicy::Drop`].
+    ///
+    /// # Example
+    /// ```
+    /// use pragmastat::{EstimatorConfig, MeasurementUnit, NonFinitePolicy, Sample};
+    ///
+    /// let config = EstimatorConfig::builder()
+    ///     .non_finite(NonFinitePolicy::Drop)
+    ///     .build()
+    ///     .unwrap();
+    /// let values = vec![1.0, f64::NAN, 2.0, f64::INFINITY, 3.0];
+    /// let x = Sample::with_unit_and_config(values, MeasurementUnit::number(), config).unwrap();
+    /// assert_eq!(x.values(), &[1.0, 2.0, 3.0]);
+    /// ```
+    pub fn with_unit_and_config(
+        mut values: Vec<f64>,
+        unit: MeasurementUnit,
+        config: EstimatorConfig,
+    ) -> Result<Self, EstimatorError> {
+        if config.non_finite() == NonFinitePolicy::Drop {
+            values.retain(|v| v.is_finite());
+        }
+        Ok(Self::build(values, None, unit)?.with_config(config))
+    }
+
     /// Creates a weighted sample with a specified unit.
     ///
     /// # Errors
@@ -62,6 +104,149 @@ impl Sample {
         Self::build(values, Some(weights), unit)
     }
 
+    /// Creates an unweighted sample from an iterator of values with a specified unit.
+    ///
+    /// This is the fallible counterpart of `collect::<Sample>()`, which always
+    /// uses the number unit and panics on invalid input.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EstimatorError`] if the iterator is empty or yields NaN/infinite values.
+    pub fn from_iter(
+        iter: impl IntoIterator<Item = f64>,
+        unit: MeasurementUnit,
+    ) -> Result<Self, EstimatorError> {
+        Self::with_unit(iter.into_iter().collect(), unit)
+    }
+
+    /// Creates an unweighted sample from integers (e.g., `u64` nanosecond counts).
+    ///
+    /// Accepts any primitive integer type up to 64 bits. Every integer with
+    /// `|v| <= 2^53` converts to `f64` exactly; larger magnitudes are rejected
+    /// rather than silently rounded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EstimatorError`] if `values` is empty or any `|v| > 2^53`; the
+    /// error detail lists the offending indices.
+    pub fn from_ints<T: Copy + Into<i128>>(
+        values: &[T],
+        unit: MeasurementUnit,
+    ) -> Result<Self, EstimatorError> {
+        const MAX_EXACT: i128 = 1 << 53;
+        let inexact: Vec<usize> = values
+            .iter()
+            .enumerate()
+            .filter(|&(_, &v)| v.into().abs() > MAX_EXACT)
+            .map(|(i, _)| i)
+            .collect();
+        if !inexact.is_empty() {
+            return Err(EstimatorError::from(AssumptionError::inexact_integers(
+                Subject::X,
+                inexact,
+            )));
+        }
+        let converted = values.iter().map(|&v| v.into() as f64).collect();
+        Self::with_unit(converted, unit)
+    }
+
+    /// Draws a deterministic sample of `n` values from `dist`, with the number
+    /// unit.
+    ///
+    /// The values are `dist.samples(&mut Rng::from_string(seed), n)`, so the
+    /// same seed gives the same sample on every platform and in every
+    /// Pragmastat port.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero or a draw is not finite.
+    ///
+    /// # Example
+    /// ```
+    /// use pragmastat::{Additive, Sample};
+    ///
+    /// let x = Sample::synthetic(&Additive::new(100.0, 10.0), 50, "example-seed");
+    /// assert_eq!(x.size(), 50);
+    /// ```
+    pub fn synthetic<D: Distribution + ?Sized>(dist: &D, n: usize, seed: &str) -> Self {
+        Self::synthetic_with_unit(dist, n, seed, MeasurementUnit::number())
+    }
+
+    /// [`Sample::synthetic`] with a specified unit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero or a draw is not finite.
+    pub fn synthetic_with_unit<D: Distribution + ?Sized>(
+        dist: &D,
+        n: usize,
+        seed: &str,
+        unit: MeasurementUnit,
+    ) -> Self {
+        Self::synthetic_from(dist, n, &mut Rng::from_string(seed), unit)
+    }
+
+    /// Draws two independent deterministic samples: `n` values from `dist_x`
+    /// and `m` values from `dist_y`, both with the number unit.
+    ///
+    /// `x` draws from the stream `Rng::from_string("{seed}-x")` and `y` from
+    /// `Rng::from_string("{seed}-y")` (the streams `"x"` and `"y"` of
+    /// [`Experiment::new(seed)`](crate::experiment::Experiment)). Each sample
+    /// therefore depends only on the seed and its own size: changing `m` leaves
+    /// `x` unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` or `m` is zero or a draw is not finite.
+    ///
+    /// # Example
+    /// ```
+    /// use pragmastat::{Additive, Sample};
+    ///
+    /// let (x, y) = Sample::synthetic_pair(
+    ///     &Additive::new(110.0, 10.0),
+    ///     &Additive::new(100.0, 10.0),
+    ///     40,
+    ///     30,
+    ///     "example-seed",
+    /// );
+    /// assert_eq!((x.size(), y.size()), (40, 30));
+    /// ```
+    pub fn synthetic_pair<DX: Distribution + ?Sized, DY: Distribution + ?Sized>(
+        dist_x: &DX,
+        dist_y: &DY,
+        n: usize,
+        m: usize,
+        seed: &str,
+    ) -> (Self, Self) {
+        let experiment = Experiment::new(seed);
+        (
+            Self::synthetic_from(
+                dist_x,
+                n,
+                &mut experiment.rng("x"),
+                MeasurementUnit::number(),
+            ),
+            Self::synthetic_from(
+                dist_y,
+                m,
+                &mut experiment.rng("y"),
+                MeasurementUnit::number(),
+            ),
+        )
+    }
+
+    fn synthetic_from<D: Distribution + ?Sized>(
+        dist: &D,
+        n: usize,
+        rng: &mut Rng,
+        unit: MeasurementUnit,
+    ) -> Self {
+        assert!(n > 0, "synthetic sample size must be positive");
+        Self::with_unit(dist.samples(rng, n), unit)
+            .unwrap_or_else(|e| panic!("synthetic sample is invalid: {e}"))
+    }
+
     fn build(
         values: Vec<f64>,
         weights: Option<Vec<f64>>,
@@ -70,12 +255,16 @@ impl Sample {
         // Construction can't know argument position, so validity errors here always
         // report subject "x" (matches the sample-construction fixtures across languages).
         if values.is_empty() {
-            return Err(EstimatorError::from(AssumptionError::validity(Subject::X)));
+            return Err(EstimatorError::from(AssumptionError::empty_sample(
+                Subject::X,
+            )));
         }
-        for &v in &values {
-            if !v.is_finite() {
-                return Err(EstimatorError::from(AssumptionError::validity(Subject::X)));
-            }
+        let non_finite = non_finite_indices(&values);
+        if let Some(&first) = non_finite.first() {
+            return Err(EstimatorError::from(
+                AssumptionError::non_finite_values(Subject::X, non_finite)
+                    .with_offender(first, values[first]),
+            ));
         }
 
         let (is_weighted, total_weight, weighted_size, stored_weights) = match weights {
@@ -119,6 +308,7 @@ impl Sample {
             total_weight,
             weighted_size,
             sorted_values: OnceLock::new(),
+            config: EstimatorConfig::default(),
         })
     }
 
@@ -161,8 +351,292 @@ impl Sample {
         })
     }
 
+    /// Estimates the center of this sample; see [`crate::estimators::center`].
+    ///
+    /// Reuses the cached sorted values, so repeated estimator calls on the same
+    /// sample sort at most once.
+    ///
+    /// # Example
+    /// ```
+    /// use pragmastat::{Additive, Sample};
+    ///
+    /// let x = Sample::synthetic(&Additive::new(100.0, 10.0), 50, "example-seed");
+    /// assert!((x.center().unwrap().value - 100.0).abs() < 5.0);
+    /// ```
+    pub fn center(&self) -> Result<Measurement, EstimatorError> {
+        crate::estimators::center(self)
+    }
+
+    /// Estimates the spread of this sample; see [`crate::estimators::spread`].
+    ///
+    /// Reuses the cached sorted values.
+    ///
+    /// # Example
+    /// ```
+    /// use pragmastat::{Additive, Sample};
+    ///
+    /// // Spread of Additive(μ, σ) tends to ≈ 0.954σ
+    /// let x = Sample::synthetic(&Additive::new(100.0, 10.0), 200, "example-seed");
+    /// assert!((x.spread().unwrap().value - 9.54).abs() < 1.5);
+    /// ```
+    pub fn spread(&self) -> Result<Measurement, EstimatorError> {
+        crate::estimators::spread(self)
+    }
+
+    /// Estimates the volatility of this sample; see
+    /// [`crate::estimators::volatility`].
+    pub fn volatility(&self) -> Result<Measurement, EstimatorError> {
+        crate::estimators::volatility(self)
+    }
+
+    /// Estimates the precision of this sample; see
+    /// [`crate::estimators::precision`].
+    pub fn precision(&self) -> Result<Measurement, EstimatorError> {
+        crate::estimators::precision(self)
+    }
+
+    /// Estimates the median absolute deviation of this sample; see
+    /// [`crate::estimators::mad`].
+    pub fn mad(&self, scale: crate::estimators::MadScale) -> Result<Measurement, EstimatorError> {
+        crate::estimators::mad(self, scale)
+    }
+
+    /// Estimates the interquartile range of this sample; see
+    /// [`crate::estimators::iqr`].
+    pub fn iqr(&self) -> Result<Measurement, EstimatorError> {
+        crate::estimators::iqr(self)
+    }
+
+    /// Estimates the half-sample mode of this sample; see
+    /// [`crate::estimators::mode_hsm`].
+    pub fn mode(&self) -> Result<Measurement, EstimatorError> {
+        crate::estimators::mode_hsm(self)
+    }
+
+    /// Attaches `config`, which the bounds methods of this sample then use in
+    /// place of the crate defaults; its [`NonFinitePolicy`] also applies to
+    /// later [`extend`](Self::extend) calls.
+    ///
+    /// The config is carried over by [`convert_to`](Self::convert_to),
+    /// [`merge`](Self::merge) (from `self`), and scalar multiplication. For
+    /// two-sample bounds, the config of the receiver (`x`) applies.
+    pub fn with_config(mut self, config: EstimatorConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// The config used by the bounds methods; [`EstimatorConfig::default`]
+    /// unless set with [`with_config`](Self::with_config).
+    pub fn config(&self) -> &EstimatorConfig {
+        &self.config
+    }
+
+    /// Center bounds at the configured misrate; see
+    /// [`crate::estimators::center_bounds_with_config`].
+    ///
+    /// # Example
+    /// ```
+    /// use pragmastat::{Additive, Sample};
+    ///
+    /// let x = Sample::synthetic(&Additive::new(100.0, 10.0), 50, "example-seed");
+    /// let bounds = x.center_bounds().unwrap();
+    /// assert!(bounds.lower < 100.0 && 100.0 < bounds.upper);
+    /// ```
+    pub fn center_bounds(&self) -> Result<Bounds, EstimatorError> {
+        crate::estimators::center_bounds_with_config(self, &self.config)
+    }
+
+    /// Spread bounds at the configured misrate and seed; see
+    /// [`crate::estimators::spread_bounds_with_config`].
+    pub fn spread_bounds(&self) -> Result<Bounds, EstimatorError> {
+        crate::estimators::spread_bounds_with_config(self, &self.config)
+    }
+
+    /// Shift bounds against `y` at the configured misrate; see
+    /// [`crate::estimators::shift_bounds_with_config`].
+    ///
+    /// # Example
+    /// ```
+    /// use pragmastat::{Additive, Sample};
+    ///
+    /// let (x, y) = Sample::synthetic_pair(
+    ///     &Additive::new(110.0, 10.0),
+    ///     &Additive::new(100.0, 10.0),
+    ///     40,
+    ///     30,
+    ///     "example-seed",
+    /// );
+    /// let bounds = x.shift_bounds(&y).unwrap();
+    /// assert!(bounds.lower < 10.0 && 10.0 < bounds.upper);
+    /// ```
+    pub fn shift_bounds(&self, y: &Sample) -> Result<Bounds, EstimatorError> {
+        crate::estimators::shift_bounds_with_config(self, y, &self.config)
+    }
+
+    /// Ratio bounds against `y` at the configured misrate; see
+    /// [`crate::estimators::ratio_bounds_with_config`].
+    pub fn ratio_bounds(&self, y: &Sample) -> Result<Bounds, EstimatorError> {
+        crate::estimators::ratio_bounds_with_config(self, y, &self.config)
+    }
+
+    /// Disparity bounds against `y` with the configured misrate and seed; see [`crate::estimators::disparity_bounds_with_config`].
+    pub fn disparity_bounds(&self, y: &Sample) -> Result<Bounds, EstimatorError> {
+        crate::estimators::disparity_bounds_with_config(self, y, &self.config)
+    }
+
+    /// Dominance bounds against `y` at the configured misrate; see
+    /// [`crate::estimators::dominance_bounds_with_config`].
+    pub fn dominance_bounds(&self, y: &Sample) -> Result<Bounds, EstimatorError> {
+        crate::estimators::dominance_bounds_with_config(self, y, &self.config)
+    }
+
+    /// Flags values outside `center ± k * spread`; see
+    /// [`crate::estimators::flag_outliers`].
+    ///
+    /// The fences are reported in this sample's unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EstimatorError::Other`] if the sample is weighted or `k` is
+    /// invalid.
+    pub fn outliers(
+        &self,
+        k: f64,
+    ) -> Result<crate::estimators::OutlierReport<Measurement>, EstimatorError> {
+        check_non_weighted("x", self)?;
+        let report = crate::estimators::flag_outliers(self.values(), k)?;
+        Ok(crate::estimators::OutlierReport {
+            indices: report.indices,
+            lower_fence: Measurement::new(report.lower_fence, self.unit.clone()),
+            upper_fence: Measurement::new(report.upper_fence, self.unit.clone()),
+            below: report.below,
+            above: report.above,
+        })
+    }
+
+    /// Runs every sample assumption check ahead of time and returns all
+    /// violations, in canonical priority order, instead of failing on the first,
+    /// followed by a [`Diagnostic::Ties`] warning if the sample is heavily tied.
+    ///
+    /// Covers the [`check_all`] checks, including the `domain` check against
+    /// [`min_sample_size`](crate::estimators::min_sample_size) and custom ones
+    /// added with
+    /// [`register_assumption`](crate::assumptions::register_assumption). Use
+    /// [`Diagnostic::blocks`] and [`Diagnostic::degrades`] to see which
+    /// estimators each finding concerns. See [`audit_pair`] for two samples.
+    pub fn audit(&self) -> Vec<Diagnostic> {
+        self.audit_as(Subject::X)
+    }
+
+    fn audit_as(&self, subject: Subject) -> Vec<Diagnostic> {
+        check_all(self.values(), subject)
+            .into_iter()
+            .map(Diagnostic::Violation)
+            .chain(tie_diagnostic(self.sorted_values(), subject))
+            .collect()
+    }
+
+    /// Returns the sample median (the average of the two middle values for an
+    /// even size), computed from the cached sorted values.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EstimatorError::Other`] if the sample is weighted.
+    pub fn median(&self) -> Result<Measurement, EstimatorError> {
+        check_non_weighted("x", self)?;
+        let sorted = self.sorted_values();
+        let n = sorted.len();
+        let value = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            0.5 * sorted[n / 2 - 1] + 0.5 * sorted[n / 2]
+        };
+        Ok(Measurement::new(value, self.unit.clone()))
+    }
+
+    /// Returns a new sample without the `floor(n * fraction)` smallest and the
+    /// as many largest values.
+    ///
+    /// The kept values come from the cached sorted values, so the new sample is
+    /// in ascending order and starts with its sorted cache filled. The unit and
+    /// config are kept. Since `fraction < 0.5`, at least one value remains.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fraction` is NaN or outside `[0, 0.5)`, or if the sample is
+    /// weighted.
+    ///
+    /// # Example
+    /// ```
+    /// use pragmastat::Sample;
+    ///
+    /// let x = Sample::new(vec![100.0, 1.0, 3.0, 2.0, 5.0, 4.0, -50.0]).unwrap();
+    /// let trimmed = x.trim(0.2); // floor(7 * 0.2) = 1 value off each end
+    /// assert_eq!(trimmed.values(), &[1.0, 2.0, 3.0, 4.0, 5.0]);
+    /// ```
+    pub fn trim(&self, fraction: f64) -> Sample {
+        assert!(
+            (0.0..0.5).contains(&fraction),
+            "fraction must be in [0, 0.5), got {fraction}"
+        );
+        assert!(!self.is_weighted, "trim does not support weighted samples");
+        let sorted = self.sorted_values();
+        let k = (sorted.len() as f64 * fraction).floor() as usize;
+        let kept = sorted[k..sorted.len() - k].to_vec();
+        let trimmed = Self {
+            values: kept.clone(),
+            weights: None,
+            unit: self.unit.clone(),
+            is_weighted: false,
+            total_weight: 1.0,
+            weighted_size: kept.len() as f64,
+            sorted_values: OnceLock::new(),
+            config: self.config.clone(),
+        };
+        // Freshly built, so the cache is empty and this cannot fail.
+        let _ = trimmed.sorted_values.set(kept);
+        trimmed
+    }
+
+    /// Bins the values into `bins` uniform-width bins spanning `min..=max`.
+    ///
+    /// Returns `(center, count)` pairs in ascending order. Bin `i` covers
+    /// `[min + i*w, min + (i+1)*w)` with `w = (max - min) / bins`; the last bin
+    /// also includes `max`. If all values are equal, a single bin centered at
+    /// that value is returned. Counts ignore weights. Runs in one pass over the
+    /// cached sorted values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bins` is zero.
+    pub fn histogram(&self, bins: usize) -> Vec<(f64, usize)> {
+        assert!(bins > 0, "bins must be positive");
+        let sorted = self.sorted_values();
+        let min = sorted[0];
+        let max = sorted[sorted.len() - 1];
+        if min == max {
+            return vec![(min, sorted.len())];
+        }
+        let width = (max - min) / bins as f64;
+        let mut counts = vec![0usize; bins];
+        let mut bin = 0;
+        for &v in sorted {
+            while bin + 1 < bins && v >= min + (bin + 1) as f64 * width {
+                bin += 1;
+            }
+            counts[bin] += 1;
+        }
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| (min + (i as f64 + 0.5) * width, count))
+            .collect()
+    }
+
     /// Converts this sample to a different (compatible) unit.
     ///
+    /// Values are converted as absolute readings, so affine offsets are applied.
+    ///
     /// # Errors
     ///
     /// Returns an error if the target unit is in a different family.
@@ -170,11 +644,14 @@ impl Sample {
         if !is_compatible(&self.unit, target) {
             return Err(UnitMismatchError::new(&self.unit, target));
         }
-        if self.unit.id() == target.id() && self.unit.base_units() == target.base_units() {
+        if same_scale(&self.unit, target) {
             return Ok(self.clone());
         }
-        let factor = conversion_factor(&self.unit, target);
-        let converted: Vec<f64> = self.values.iter().map(|&v| v * factor).collect();
+        let converted = self
+            .values
+            .iter()
+            .map(|&v| convert(v, &self.unit, target))
+            .collect::<Result<Vec<f64>, _>>()?;
         Ok(Self {
             values: converted,
             weights: self.weights.clone(),
@@ -183,8 +660,179 @@ impl Sample {
             total_weight: self.total_weight,
             weighted_size: self.weighted_size,
             sorted_values: OnceLock::new(),
+            config: self.config.clone(),
         })
     }
+
+    /// Combines this sample with `other`: `self`'s values first, then `other`'s.
+    ///
+    /// The merged sample keeps `self`'s unit and config. If `other` uses a
+    /// different but compatible unit (e.g., seconds into milliseconds), its
+    /// values are converted into `self`'s unit first, as
+    /// [`convert_to`](Self::convert_to) would. If both samples already have
+    /// cached sorted values in the same unit, the merged sample's cache is
+    /// filled with a linear merge instead of a full re-sort. Weights are
+    /// concatenated; the values of an unweighted side get weight 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the units are in different families.
+    pub fn merge(&self, other: &Sample) -> Result<Sample, EstimatorError> {
+        let merged = Self::concat(self, std::slice::from_ref(other))?;
+        if same_scale(&self.unit, &other.unit) {
+            if let (Some(a), Some(b)) = (self.sorted_values.get(), other.sorted_values.get()) {
+                // Freshly built, so the cache is empty and this cannot fail.
+                let _ = merged.sorted_values.set(merge_sorted(a, b));
+            }
+        }
+        Ok(merged)
+    }
+
+    /// Combines `samples` in order, like repeated [`merge`](Self::merge) into
+    /// the first one, but copying the values only once.
+    ///
+    /// The result has the unit and config of `samples[0]`; the others are
+    /// converted into that unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `validity` error if `samples` is empty, and an error if any
+    /// unit is in a different family from the first.
+    pub fn merge_all(samples: &[Sample]) -> Result<Sample, EstimatorError> {
+        let (first, rest) = samples
+            .split_first()
+            .ok_or_else(|| EstimatorError::from(AssumptionError::validity(Subject::X)))?;
+        Self::concat(first, rest)
+    }
+
+    /// Concatenates `first` and `rest`, converting `rest` into `first`'s unit.
+    fn concat(first: &Sample, rest: &[Sample]) -> Result<Sample, EstimatorError> {
+        let converted = rest
+            .iter()
+            .map(|s| {
+                if same_scale(&first.unit, &s.unit) {
+                    Ok(std::borrow::Cow::Borrowed(s))
+                } else {
+                    s.convert_to(&first.unit).map(std::borrow::Cow::Owned)
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let parts = || std::iter::once(first).chain(converted.iter().map(|s| s.as_ref()));
+
+        let mut values = Vec::with_capacity(parts().map(Sample::size).sum());
+        for s in parts() {
+            values.extend_from_slice(&s.values);
+        }
+        let weights = if parts().any(|s| s.is_weighted) {
+            let mut w = Vec::with_capacity(values.len());
+            for s in parts() {
+                match &s.weights {
+                    Some(sw) => w.extend_from_slice(sw),
+                    None => w.resize(w.len() + s.size(), 1.0),
+                }
+            }
+            Some(w)
+        } else {
+            None
+        };
+        Ok(Sample::build(values, weights, first.unit.clone())?.with_config(first.config.clone()))
+    }
+
+    /// Appends `values` to this unweighted sample.
+    ///
+    /// A cached sorted view is updated incrementally (the new values are sorted
+    /// and merged in linearly) rather than discarded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (leaving the sample unchanged) if the sample is
+    /// weighted, or if `values` contains NaN/infinite values and the sample's
+    /// config uses [`NonFinitePolicy::Reject`]. Under [`NonFinitePolicy::Drop`]
+    /// those values are skipped.
+    pub fn extend(&mut self, values: &[f64]) -> Result<(), EstimatorError> {
+        check_non_weighted("extend", self)?;
+        let non_finite = non_finite_indices(values);
+        if !non_finite.is_empty() && self.config.non_finite() == NonFinitePolicy::Drop {
+            let finite: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+            return self.extend(&finite);
+        }
+        if !non_finite.is_empty() {
+            return Err(EstimatorError::from(AssumptionError::non_finite_values(
+                Subject::X,
+                non_finite,
+            )));
+        }
+        if let Some(cached) = self.sorted_values.take() {
+            let mut added = values.to_vec();
+            added.sort_unstable_by(|a, b| a.total_cmp(b));
+            let _ = self.sorted_values.set(merge_sorted(&cached, &added));
+        }
+        self.values.extend_from_slice(values);
+        self.weighted_size = self.values.len() as f64;
+        Ok(())
+    }
+}
+
+/// Returns the positions of NaN/infinite values.
+fn non_finite_indices(values: &[f64]) -> Vec<usize> {
+    values
+        .iter()
+        .enumerate()
+        .filter(|(_, v)| !v.is_finite())
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Merges two ascending slices into one ascending vector.
+fn merge_sorted(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let mut result = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if b[j].total_cmp(&a[i]).is_lt() {
+            result.push(b[j]);
+            j += 1;
+        } else {
+            result.push(a[i]);
+            i += 1;
+        }
+    }
+    result.extend_from_slice(&a[i..]);
+    result.extend_from_slice(&b[j..]);
+    result
+}
+
+/// Collects values into an unweighted sample with the number unit.
+///
+/// # Panics
+///
+/// Panics if the iterator is empty or yields NaN/infinite values. Use
+/// [`Sample::from_iter`] to handle these cases as errors.
+impl FromIterator<f64> for Sample {
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Self {
+        match Sample::new(iter.into_iter().collect()) {
+            Ok(sample) => sample,
+            Err(e) => panic!("cannot collect into Sample: {e}"),
+        }
+    }
+}
+
+/// Copies a slice into an unweighted sample with the number unit.
+impl TryFrom<&[f64]> for Sample {
+    type Error = EstimatorError;
+
+    fn try_from(values: &[f64]) -> Result<Self, Self::Error> {
+        Sample::new(values.to_vec())
+    }
+}
+
+/// Iterates over the values in their original order.
+impl<'a> IntoIterator for &'a Sample {
+    type Item = &'a f64;
+    type IntoIter = std::slice::Iter<'a, f64>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.iter()
+    }
 }
 
 /// Multiplies all values in the sample by a scalar.
@@ -193,8 +841,27 @@ impl Mul<f64> for &Sample {
 
     fn mul(self, rhs: f64) -> Self::Output {
         let scaled: Vec<f64> = self.values.iter().map(|&v| v * rhs).collect();
-        Sample::build(scaled, self.weights.clone(), self.unit.clone())
+        Ok(
+            Sample::build(scaled, self.weights.clone(), self.unit.clone())?
+                .with_config(self.config.clone()),
+        )
+    }
+}
+
+/// Runs every assumption check relevant to two-sample estimators on `x` and
+/// `y` and returns all findings: those of [`Sample::audit`] for `x`, then
+/// for `y`, then a [`Diagnostic::UnitMismatch`] if the units are not
+/// convertible.
+pub fn audit_pair(x: &Sample, y: &Sample) -> Vec<Diagnostic> {
+    let mut findings = x.audit_as(Subject::X);
+    findings.extend(y.audit_as(Subject::Y));
+    if !is_compatible(x.unit(), y.unit()) {
+        findings.push(Diagnostic::UnitMismatch(UnitMismatchError::new(
+            x.unit(),
+            y.unit(),
+        )));
     }
+    findings
 }
 
 // =============================================================================
@@ -211,6 +878,22 @@ pub(crate) fn check_non_weighted(name: &str, s: &Sample) -> Result<(), Estimator
     Ok(())
 }
 
+/// Returns an error if the sample uses an affine unit (ratios depend on the zero point).
+pub(crate) fn check_non_affine(name: &str, s: &Sample) -> Result<(), EstimatorError> {
+    if s.unit().is_affine() {
+        return Err(EstimatorError::Other(format!(
+            "affine unit {} is not supported for {name}: ratios depend on the zero point",
+            s.unit().full_name()
+        )));
+    }
+    Ok(())
+}
+
+/// Returns true if both units share id, scale, and offset (no conversion needed).
+fn same_scale(a: &MeasurementUnit, b: &MeasurementUnit) -> bool {
+    a.id() == b.id() && a.base_units() == b.base_units() && a.offset() == b.offset()
+}
+
 /// Returns an error if two samples have incompatible units.
 pub(crate) fn check_compatible_units(a: &Sample, b: &Sample) -> Result<(), EstimatorError> {
     if !is_compatible(a.unit(), b.unit()) {
@@ -237,7 +920,7 @@ pub(crate) fn prepare_pair<'a>(
 ) -> Result<(std::borrow::Cow<'a, Sample>, std::borrow::Cow<'a, Sample>), EstimatorError> {
     use std::borrow::Cow;
     check_compatible_units(a, b)?;
-    if a.unit().id() == b.unit().id() && a.unit().base_units() == b.unit().base_units() {
+    if same_scale(a.unit(), b.unit()) {
         return Ok((Cow::Borrowed(a), Cow::Borrowed(b)));
     }
     let target = finer(a.unit(), b.unit());
@@ -253,6 +936,7 @@ pub(crate) fn prepare_pair<'a>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::assumptions::ViolationDetail;
 
     // Sample must stay shareable across threads (e.g. Arc<Sample>, rayon &Sample).
     // The lazily-cached sorted view uses OnceLock (Sync), not OnceCell (!Sync) — this
@@ -327,6 +1011,457 @@ mod tests {
         assert_eq!(result.values(), &[2.0, 4.0, 6.0]);
     }
 
+    #[test]
+    fn cached_estimators_match_free_functions() {
+        let s = Sample::new(vec![5.0, 1.0, 4.0, 2.0, 9.0, 3.0]).unwrap();
+        let raw = s.values();
+        assert_eq!(
+            s.center().unwrap().value,
+            crate::estimators::raw::center(raw, false).unwrap()
+        );
+        assert_eq!(
+            s.spread().unwrap().value,
+            crate::estimators::raw::spread(raw, false).unwrap()
+        );
+        assert_eq!(s.median().unwrap().value, 3.5);
+        assert_eq!(
+            Sample::new(vec![3.0, 1.0, 2.0])
+                .unwrap()
+                .median()
+                .unwrap()
+                .value,
+            2.0
+        );
+    }
+
+    #[test]
+    fn sorted_values_are_computed_once() {
+        let s = Sample::new((0..1000).rev().map(f64::from).collect()).unwrap();
+        let first = s.sorted_values().as_ptr();
+        s.center().unwrap();
+        s.spread().unwrap();
+        s.median().unwrap();
+        crate::estimators::center(&s).unwrap();
+        // The same allocation is returned on every call: no re-sort happened.
+        assert!(std::ptr::eq(first, s.sorted_values().as_ptr()));
+    }
+
+    #[test]
+    fn median_rejects_weighted() {
+        let s =
+            Sample::weighted(vec![1.0, 2.0], vec![1.0, 1.0], MeasurementUnit::number()).unwrap();
+        assert!(s.median().is_err());
+    }
+
+    #[test]
+    fn merge_matches_fresh_concatenation() {
+        let a = Sample::new(vec![5.0, 1.0, 9.0, 3.0]).unwrap();
+        let b = Sample::new(vec![4.0, 2.0, 8.0]).unwrap();
+        let fresh = Sample::new(vec![5.0, 1.0, 9.0, 3.0, 4.0, 2.0, 8.0]).unwrap();
+
+        // Without caches: the merged sample sorts lazily.
+        let merged = a.merge(&b).unwrap();
+        assert!(merged.sorted_values.get().is_none());
+        assert_eq!(merged.values(), fresh.values());
+        assert_eq!(merged.sorted_values(), fresh.sorted_values());
+
+        // With both caches: the merged cache is filled by a linear merge.
+        a.sorted_values();
+        b.sorted_values();
+        let merged = a.merge(&b).unwrap();
+        assert_eq!(merged.sorted_values.get().unwrap(), fresh.sorted_values());
+        assert_eq!(
+            merged.center().unwrap().value,
+            fresh.center().unwrap().value
+        );
+        assert_eq!(
+            merged.spread().unwrap().value,
+            fresh.spread().unwrap().value
+        );
+    }
+
+    #[test]
+    fn merge_concatenates_weights() {
+        let a =
+            Sample::weighted(vec![1.0, 2.0], vec![2.0, 3.0], MeasurementUnit::number()).unwrap();
+        let b = Sample::new(vec![3.0]).unwrap();
+        let merged = a.merge(&b).unwrap();
+        assert!(merged.is_weighted());
+        assert!((merged.total_weight() - 6.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn merge_rejects_different_units() {
+        let a = Sample::new(vec![1.0, 2.0]).unwrap();
+        let b = Sample::with_unit(vec![1.0, 2.0], MeasurementUnit::ratio()).unwrap();
+        assert!(a.merge(&b).is_err());
+    }
+
+    #[test]
+    fn merge_converts_compatible_units() {
+        let ms = MeasurementUnit::new("ms", "Time", "ms", "Millisecond", 1_000_000);
+        let s = MeasurementUnit::new("s", "Time", "s", "Second", 1_000_000_000);
+        let a = Sample::with_unit(vec![1500.0, 200.0, 900.0], ms.clone()).unwrap();
+        let b = Sample::with_unit(vec![2.0, 0.5], s).unwrap();
+        a.sorted_values();
+        b.sorted_values();
+
+        let merged = a.merge(&b).unwrap();
+        assert_eq!(merged.unit(), &ms);
+        assert_eq!(merged.values(), &[1500.0, 200.0, 900.0, 2000.0, 500.0]);
+        // The seconds cache does not carry over; the merged view is re-sorted.
+        assert!(merged.sorted_values.get().is_none());
+
+        let manual =
+            Sample::with_unit(vec![1500.0, 200.0, 900.0, 2000.0, 500.0], ms.clone()).unwrap();
+        assert_eq!(
+            merged.center().unwrap().value,
+            manual.center().unwrap().value
+        );
+    }
+
+    #[test]
+    fn merge_all_is_order_stable() {
+        let ms = MeasurementUnit::new("ms", "Time", "ms", "Millisecond", 1_000_000);
+        let ns = MeasurementUnit::new("ns", "Time", "ns", "Nanosecond", 1);
+        let parts = [
+            Sample::with_unit(vec![3.0, 1.0], ms.clone()).unwrap(),
+            Sample::weighted(vec![2_000_000.0], vec![2.0], ns).unwrap(),
+            Sample::with_unit(vec![5.0, 4.0], ms.clone()).unwrap(),
+        ];
+        let merged = Sample::merge_all(&parts).unwrap();
+        assert_eq!(merged.unit(), &ms);
+        assert_eq!(merged.values(), &[3.0, 1.0, 2.0, 5.0, 4.0]);
+        assert_eq!(
+            merged.weights.as_deref().unwrap(),
+            &[1.0, 1.0, 2.0, 1.0, 1.0]
+        );
+
+        let pairwise = parts[0].merge(&parts[1]).unwrap().merge(&parts[2]).unwrap();
+        assert_eq!(pairwise.values(), merged.values());
+
+        assert!(Sample::merge_all(&[]).is_err());
+        let ratio = Sample::with_unit(vec![1.0], MeasurementUnit::ratio()).unwrap();
+        assert!(Sample::merge_all(&[parts[0].clone(), ratio]).is_err());
+    }
+
+    #[test]
+    fn extend_updates_cache_incrementally() {
+        let mut s = Sample::new(vec![5.0, 1.0, 9.0]).unwrap();
+        s.sorted_values();
+        s.extend(&[4.0, -2.0, 7.0]).unwrap();
+        let fresh = Sample::new(vec![5.0, 1.0, 9.0, 4.0, -2.0, 7.0]).unwrap();
+        assert_eq!(s.sorted_values.get().unwrap(), fresh.sorted_values());
+        assert_eq!(s.values(), fresh.values());
+        assert_eq!(s.weighted_size(), 6.0);
+        assert_eq!(s.center().unwrap().value, fresh.center().unwrap().value);
+
+        let mut uncached = Sample::new(vec![3.0, 1.0]).unwrap();
+        uncached.extend(&[2.0]).unwrap();
+        assert_eq!(uncached.sorted_values(), &[1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn extend_rejects_invalid_values_atomically() {
+        let mut s = Sample::new(vec![1.0, 2.0]).unwrap();
+        s.sorted_values();
+        assert!(s.extend(&[3.0, f64::NAN]).is_err());
+        assert_eq!(s.values(), &[1.0, 2.0]);
+        assert_eq!(s.sorted_values(), &[1.0, 2.0]);
+        let mut w = Sample::weighted(vec![1.0], vec![1.0], MeasurementUnit::number()).unwrap();
+        assert!(w.extend(&[2.0]).is_err());
+    }
+
+    #[test]
+    fn histogram_counts_and_boundaries() {
+        let s = Sample::new(vec![0.0, 1.0, 2.5, 2.5, 4.9, 5.0, 7.5, 10.0]).unwrap();
+        let hist = s.histogram(4);
+        let centers: Vec<f64> = hist.iter().map(|&(c, _)| c).collect();
+        let counts: Vec<usize> = hist.iter().map(|&(_, n)| n).collect();
+        assert_eq!(centers, vec![1.25, 3.75, 6.25, 8.75]);
+        // Boundaries at 2.5, 5.0, 7.5: lower edges are inclusive, max joins the last bin.
+        assert_eq!(counts, vec![2, 3, 1, 2]);
+        assert_eq!(counts.iter().sum::<usize>(), s.size());
+    }
+
+    #[test]
+    fn histogram_counts_sum_to_size() {
+        let s: Sample = (0..1000)
+            .map(|i| ((i * 7919) % 1000) as f64 / 3.0)
+            .collect();
+        for bins in [1, 2, 7, 10, 64, 2000] {
+            let hist = s.histogram(bins);
+            assert_eq!(hist.len(), bins);
+            assert_eq!(hist.iter().map(|&(_, n)| n).sum::<usize>(), 1000);
+        }
+    }
+
+    #[test]
+    fn histogram_all_equal_is_single_bin() {
+        let s = Sample::new(vec![4.0; 5]).unwrap();
+        assert_eq!(s.histogram(10), vec![(4.0, 5)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "bins must be positive")]
+    fn histogram_zero_bins_panics() {
+        Sample::new(vec![1.0, 2.0]).unwrap().histogram(0);
+    }
+
+    fn assert_pinned(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-12, "{actual:?}");
+        }
+    }
+
+    #[test]
+    fn synthetic_values_are_pinned() {
+        use crate::distributions::Additive;
+        let x = Sample::synthetic(&Additive::new(100.0, 10.0), 5, "example-seed");
+        assert_pinned(
+            x.values(),
+            &[
+                111.20489739274093,
+                117.4573826636846,
+                107.94236451795375,
+                101.0677863126864,
+                111.40086193421709,
+            ],
+        );
+        assert_eq!(x.unit(), &MeasurementUnit::number());
+        let expected = Additive::new(100.0, 10.0).samples(&mut Rng::from_string("example-seed"), 5);
+        assert_eq!(x.values(), expected.as_slice());
+    }
+
+    #[test]
+    fn synthetic_pair_streams_are_pinned() {
+        use crate::distributions::{Additive, Exp};
+        let (x, y) = Sample::synthetic_pair(
+            &Additive::new(110.0, 10.0),
+            &Exp::new(1.0),
+            3,
+            3,
+            "example-seed",
+        );
+        assert_pinned(
+            x.values(),
+            &[110.41838728396274, 102.12722645051318, 89.81357786912],
+        );
+        assert_pinned(
+            y.values(),
+            &[0.7825193338983778, 0.5744986269251753, 0.3136596329556639],
+        );
+        // Each stream depends only on the seed and its own size
+        let (x_longer_y, _) = Sample::synthetic_pair(
+            &Additive::new(110.0, 10.0),
+            &Exp::new(1.0),
+            3,
+            50,
+            "example-seed",
+        );
+        assert_eq!(x.values(), x_longer_y.values());
+        let direct = Exp::new(1.0).samples(&mut Rng::from_string("example-seed-y"), 3);
+        assert_eq!(y.values(), direct.as_slice());
+    }
+
+    #[test]
+    fn synthetic_with_unit_keeps_the_unit() {
+        let ms = MeasurementUnit::new("ms", "Time", "ms", "Millisecond", 1_000_000);
+        let x = Sample::synthetic_with_unit(
+            &crate::distributions::Exp::new(1.0),
+            10,
+            "unit",
+            ms.clone(),
+        );
+        assert_eq!(x.unit(), &ms);
+    }
+
+    #[test]
+    #[should_panic(expected = "synthetic sample size must be positive")]
+    fn synthetic_rejects_empty() {
+        Sample::synthetic(&crate::distributions::Exp::new(1.0), 0, "empty");
+    }
+
+    #[test]
+    fn trim_zero_is_the_sorted_original() {
+        let ms = MeasurementUnit::new("ms", "Time", "ms", "Millisecond", 1_000_000);
+        let s = Sample::with_unit(vec![3.0, -1.0, 2.0, 8.0], ms).unwrap();
+        let t = s.trim(0.0);
+        assert_eq!(t.sorted_values(), s.sorted_values());
+        assert_eq!(t.unit(), s.unit());
+        assert_eq!(t.center().unwrap().value, s.center().unwrap().value);
+        assert_eq!(t.spread().unwrap().value, s.spread().unwrap().value);
+    }
+
+    #[test]
+    fn trim_removes_floor_fraction_from_each_end() {
+        let s = Sample::new((0..20).rev().map(f64::from).collect()).unwrap();
+        for (fraction, k) in [(0.01, 0), (0.05, 1), (0.1, 2), (0.24, 4), (0.49, 9)] {
+            let t = s.trim(fraction);
+            assert_eq!(t.size(), 20 - 2 * k, "fraction={fraction}");
+            let expected: Vec<f64> = (k..20 - k).map(|v| v as f64).collect();
+            assert_eq!(t.values(), expected.as_slice());
+            assert_eq!(t.sorted_values.get(), Some(&expected));
+        }
+        assert_eq!(Sample::new(vec![7.0]).unwrap().trim(0.49).values(), &[7.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "fraction must be in [0, 0.5)")]
+    fn trim_half_panics() {
+        Sample::new(vec![1.0, 2.0]).unwrap().trim(0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "fraction must be in [0, 0.5)")]
+    fn trim_nan_panics() {
+        Sample::new(vec![1.0, 2.0]).unwrap().trim(f64::NAN);
+    }
+
+    #[test]
+    fn collect_from_iterator_chain() {
+        let data = [4.0, -1.0, 2.0, 8.0, -3.0, 6.0];
+        let s: Sample = data
+            .iter()
+            .copied()
+            .filter(|&v| v > 0.0)
+            .map(|v| v / 2.0)
+            .collect();
+        assert_eq!(s.values(), &[2.0, 1.0, 4.0, 3.0]);
+        assert_eq!(s.sorted_values(), &[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(s.unit().id(), "number");
+        assert!(!s.is_weighted());
+        assert_eq!(s.weighted_size(), 4.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot collect into Sample")]
+    fn collect_empty_panics() {
+        let _: Sample = std::iter::empty().collect();
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot collect into Sample")]
+    fn collect_nan_panics() {
+        let _: Sample = [1.0, f64::NAN].into_iter().collect();
+    }
+
+    #[test]
+    fn iterate_by_reference() {
+        let s = Sample::new(vec![3.0, 1.0, 2.0]).unwrap();
+        let mut sum = 0.0;
+        for &v in &s {
+            sum += v;
+        }
+        assert_eq!(sum, 6.0);
+        let round_trip: Sample = s.into_iter().copied().collect();
+        assert_eq!(round_trip.values(), s.values());
+    }
+
+    fn non_finite_detail(err: EstimatorError) -> Vec<usize> {
+        let EstimatorError::Assumption(e) = err else {
+            panic!("expected assumption error");
+        };
+        assert_eq!(e.violation().to_string(), "validity(x)");
+        match e.detail() {
+            Some(ViolationDetail::NonFiniteValues { indices }) => indices.clone(),
+            other => panic!("unexpected detail: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn new_lists_non_finite_indices() {
+        let err = Sample::new(vec![1.0, f64::NAN, 2.0, f64::INFINITY, f64::NEG_INFINITY]);
+        assert_eq!(non_finite_detail(err.unwrap_err()), vec![1, 3, 4]);
+    }
+
+    #[test]
+    fn extend_lists_non_finite_indices() {
+        let mut s = Sample::new(vec![1.0]).unwrap();
+        let err = s.extend(&[2.0, f64::NAN]).unwrap_err();
+        assert_eq!(non_finite_detail(err), vec![1]);
+    }
+
+    fn dropping() -> EstimatorConfig {
+        EstimatorConfig::builder()
+            .non_finite(NonFinitePolicy::Drop)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn drop_policy_filters_non_finite_values() {
+        let values = vec![f64::NEG_INFINITY, 1.0, f64::NAN, -0.0, f64::INFINITY];
+        let number = MeasurementUnit::number();
+        let mut s =
+            Sample::with_unit_and_config(values.clone(), number.clone(), dropping()).unwrap();
+        assert_eq!(s.values(), &[1.0, -0.0]);
+        assert!(s.values()[1].is_sign_negative());
+        assert_eq!(s.config().non_finite(), NonFinitePolicy::Drop);
+        s.extend(&[f64::NAN, 3.0]).unwrap();
+        assert_eq!(s.values(), &[1.0, -0.0, 3.0]);
+        assert_eq!(s.merge(&s).unwrap().config(), &dropping());
+        assert_eq!((&s * 2.0).unwrap().config(), &dropping());
+
+        let err = Sample::with_unit_and_config(values, number.clone(), EstimatorConfig::default());
+        assert_eq!(non_finite_detail(err.unwrap_err()), vec![0, 2, 4]);
+        let err = Sample::with_unit_and_config(vec![f64::NAN], number, dropping()).unwrap_err();
+        assert!(err.to_string().contains("validity(x)"), "{err}");
+    }
+
+    #[test]
+    fn from_iter_with_unit() {
+        let ms = MeasurementUnit::new("ms", "Time", "ms", "Millisecond", 1_000_000);
+        let lines = ["1.5", "2.5", "0.5"];
+        let s = Sample::from_iter(lines.iter().map(|l| l.parse::<f64>().unwrap()), ms).unwrap();
+        assert_eq!(s.values(), &[1.5, 2.5, 0.5]);
+        assert_eq!(s.unit().id(), "ms");
+        let err = Sample::from_iter([0.0, f64::NAN], MeasurementUnit::number()).unwrap_err();
+        assert_eq!(non_finite_detail(err), vec![1]);
+        assert!(Sample::from_iter(std::iter::empty(), MeasurementUnit::number()).is_err());
+    }
+
+    #[test]
+    fn from_ints_converts_exactly() {
+        let ns: [u64; 3] = [1_200, 900, 1 << 53];
+        let s = Sample::from_ints(&ns, MeasurementUnit::number()).unwrap();
+        assert_eq!(s.values(), &[1_200.0, 900.0, 9_007_199_254_740_992.0]);
+        let signed: [i32; 2] = [-5, 7];
+        let s = Sample::from_ints(&signed, MeasurementUnit::number()).unwrap();
+        assert_eq!(s.values(), &[-5.0, 7.0]);
+    }
+
+    #[test]
+    fn from_ints_rejects_inexact_values() {
+        let values: [i64; 4] = [1, (1 << 53) + 1, -(1 << 53) - 1, i64::MIN];
+        let EstimatorError::Assumption(e) =
+            Sample::from_ints(&values, MeasurementUnit::number()).unwrap_err()
+        else {
+            panic!("expected assumption error");
+        };
+        assert_eq!(
+            e.detail(),
+            Some(&ViolationDetail::InexactIntegers {
+                indices: vec![1, 2, 3]
+            })
+        );
+        assert!(Sample::from_ints::<u64>(&[], MeasurementUnit::number()).is_err());
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let data = [3.0, 1.0, 2.0];
+        let s = Sample::try_from(&data[..]).unwrap();
+        assert_eq!(s.values(), &data);
+        assert_eq!(s.unit().id(), "number");
+        let bad = [1.0, f64::NAN];
+        assert_eq!(
+            non_finite_detail(Sample::try_from(&bad[..]).unwrap_err()),
+            vec![1]
+        );
+    }
+
     #[test]
     fn weighted_sample() {
         let s = Sample::weighted(
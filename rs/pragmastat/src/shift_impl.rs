@@ -1,6 +1,9 @@
 use std::collections::BTreeSet;
+use std::ops::Range;
 
-use crate::assumptions::{log, Subject};
+use crate::assumptions::{log_of, Subject};
+use crate::float::Float;
+use crate::parallel::{map_rows, Execution};
 
 /// Computes quantiles of all pairwise differences {x[i] - y[j]}.
 /// Time complexity: O((m+n) log precision) per unique rank.
@@ -11,11 +14,21 @@ use crate::assumptions::{log, Subject};
 /// * `y` - Second sample (will be sorted if assume_sorted is false)
 /// * `p` - Slice of probabilities in [0, 1]
 /// * `assume_sorted` - If true, assumes inputs are already sorted
-pub(crate) fn shift_quantiles_impl(
-    x: &[f64],
-    y: &[f64],
+pub(crate) fn shift_quantiles_impl<T: Float>(
+    x: &[T],
+    y: &[T],
     p: &[f64],
     assume_sorted: bool,
+) -> Result<Vec<f64>, &'static str> {
+    shift_quantiles_impl_with(x, y, p, assume_sorted, Execution::auto(x.len()))
+}
+
+pub(crate) fn shift_quantiles_impl_with<T: Float>(
+    x: &[T],
+    y: &[T],
+    p: &[f64],
+    assume_sorted: bool,
+    exec: Execution,
 ) -> Result<Vec<f64>, &'static str> {
     if x.is_empty() || y.is_empty() {
         return Err("Input slices cannot be empty");
@@ -29,13 +42,13 @@ pub(crate) fn shift_quantiles_impl(
     }
 
     // Validate for NaN/infinite values
-    if x.iter().any(|v| !v.is_finite()) || y.iter().any(|v| !v.is_finite()) {
+    if x.iter().any(|v| !v.to_f64().is_finite()) || y.iter().any(|v| !v.to_f64().is_finite()) {
         return Err("Input contains NaN or infinite values");
     }
 
     let mut x_sorted_buf;
     let mut y_sorted_buf;
-    let (xs, ys): (&[f64], &[f64]) = if assume_sorted {
+    let (xs, ys): (&[T], &[T]) = if assume_sorted {
         (x, y)
     } else {
         x_sorted_buf = x.to_vec();
@@ -50,64 +63,65 @@ pub(crate) fn shift_quantiles_impl(
     let total = (m as i64) * (n as i64);
 
     // Collect all required ranks using Type-7 quantile interpolation
-    struct InterpolationParams {
-        lower_rank: i64,
-        upper_rank: i64,
-        weight: f64,
-    }
-
-    let mut params = Vec::with_capacity(p.len());
-    let mut required_ranks = BTreeSet::new();
-
-    for &pk in p {
-        let h = 1.0 + (total - 1) as f64 * pk;
-        let mut lower_rank = h.floor() as i64;
-        let mut upper_rank = h.ceil() as i64;
-        let weight = h - lower_rank as f64;
-
-        if lower_rank < 1 {
-            lower_rank = 1;
-        }
-        if upper_rank > total {
-            upper_rank = total;
-        }
-
-        params.push(InterpolationParams {
-            lower_rank,
-            upper_rank,
-            weight,
-        });
-        required_ranks.insert(lower_rank);
-        required_ranks.insert(upper_rank);
-    }
+    let params: Vec<QuantileRanks> = p.iter().map(|&pk| quantile_ranks(total, pk)).collect();
+    let required_ranks: BTreeSet<i64> = params
+        .iter()
+        .flat_map(|r| [r.lower_rank, r.upper_rank])
+        .collect();
 
     // Compute values for all required ranks
     let mut rank_values: Vec<(i64, f64)> = Vec::with_capacity(required_ranks.len());
     for rank in required_ranks {
-        rank_values.push((rank, select_kth_pairwise_diff(xs, ys, rank)?));
+        rank_values.push((rank, select_kth_pairwise_diff(xs, ys, rank, exec)?));
     }
 
     // Interpolate to get final results
     let find_rank = |r: i64| -> f64 { rank_values.iter().find(|(rank, _)| *rank == r).unwrap().1 };
     let result: Vec<f64> = params
         .iter()
-        .map(|param| {
-            let lower = find_rank(param.lower_rank);
-            let upper = find_rank(param.upper_rank);
-            if param.weight == 0.0 {
-                lower
-            } else {
-                (1.0 - param.weight) * lower + param.weight * upper
-            }
-        })
+        .map(|param| param.interpolate(find_rank(param.lower_rank), find_rank(param.upper_rank)))
         .collect();
 
     Ok(result)
 }
 
+/// Ranks and weight of the Type-7 quantile `p` among `total` ordered values.
+pub(crate) struct QuantileRanks {
+    pub(crate) lower_rank: i64,
+    pub(crate) upper_rank: i64,
+    weight: f64,
+}
+
+impl QuantileRanks {
+    /// The quantile from the values at the two ranks.
+    pub(crate) fn interpolate(&self, lower: f64, upper: f64) -> f64 {
+        if self.weight == 0.0 {
+            lower
+        } else {
+            (1.0 - self.weight) * lower + self.weight * upper
+        }
+    }
+}
+
+pub(crate) fn quantile_ranks(total: i64, p: f64) -> QuantileRanks {
+    let h = 1.0 + (total - 1) as f64 * p;
+    let lower_rank = h.floor() as i64;
+    let upper_rank = h.ceil() as i64;
+    QuantileRanks {
+        lower_rank: lower_rank.max(1),
+        upper_rank: upper_rank.min(total),
+        weight: h - lower_rank as f64,
+    }
+}
+
 /// Binary search to find the k-th smallest pairwise difference x[i] - y[j]
 /// without materializing all m*n differences.
-pub(crate) fn select_kth_pairwise_diff(x: &[f64], y: &[f64], k: i64) -> Result<f64, &'static str> {
+pub(crate) fn select_kth_pairwise_diff<T: Float>(
+    x: &[T],
+    y: &[T],
+    k: i64,
+    exec: Execution,
+) -> Result<f64, &'static str> {
     let m = x.len();
     let n = y.len();
     let total = (m as i64) * (n as i64);
@@ -117,13 +131,34 @@ pub(crate) fn select_kth_pairwise_diff(x: &[f64], y: &[f64], k: i64) -> Result<f
     }
 
     // Initial search bounds: [min_diff, max_diff]
-    let mut search_min = x[0] - y[n - 1];
-    let mut search_max = x[m - 1] - y[0];
+    let search_min = x[0].to_f64() - y[n - 1].to_f64();
+    let search_max = x[m - 1].to_f64() - y[0].to_f64();
 
-    if search_min.is_nan() || search_max.is_nan() {
-        return Err("NaN in input values");
+    select_kth_by_count(k, search_min, search_max, |threshold| {
+        Ok(count_pairs_le(x, y, threshold, exec))
+    })
+}
+
+/// The binary search of [`select_kth_pairwise_diff`] over any source of pair
+/// counts: `count(threshold)` returns how many differences are `<= threshold`
+/// and the closest differences on either side (infinite if there are none).
+/// `min_diff` and `max_diff` are the smallest and largest differences.
+///
+/// The result depends only on these aggregates, so every way of computing
+/// them (one pass, row chunks, chunks read from disk) gives the same value.
+pub(crate) fn select_kth_by_count<E: From<&'static str>>(
+    k: i64,
+    min_diff: f64,
+    max_diff: f64,
+    mut count: impl FnMut(f64) -> Result<(i64, f64, f64), E>,
+) -> Result<f64, E> {
+    if min_diff.is_nan() || max_diff.is_nan() {
+        return Err("NaN in input values".into());
     }
 
+    let mut search_min = min_diff;
+    let mut search_max = max_diff;
+
     const MAX_ITERATIONS: usize = 128; // Sufficient for double precision
     let mut prev_min = f64::NEG_INFINITY;
     let mut prev_max = f64::INFINITY;
@@ -134,7 +169,15 @@ pub(crate) fn select_kth_pairwise_diff(x: &[f64], y: &[f64], k: i64) -> Result<f
         }
 
         let mid = midpoint(search_min, search_max);
-        let (count_le, closest_below, closest_above) = count_and_neighbors(x, y, mid);
+        let (count_le, mut closest_below, mut closest_above) = count(mid)?;
+
+        // Fallback to actual min/max if no boundaries found
+        if closest_below == f64::NEG_INFINITY {
+            closest_below = min_diff;
+        }
+        if closest_above == f64::INFINITY {
+            closest_above = max_diff;
+        }
 
         // If we found the exact value
         if closest_below == closest_above {
@@ -161,24 +204,46 @@ pub(crate) fn select_kth_pairwise_diff(x: &[f64], y: &[f64], k: i64) -> Result<f
         }
     }
 
-    Err("Convergence failure (pathological input)")
+    Err("Convergence failure (pathological input)".into())
 }
 
 /// Counts how many pairs x[i] - y[j] <= threshold using a two-pointer algorithm.
-/// Also tracks the closest actual differences on either side of threshold.
+/// Also tracks the closest actual differences on either side of threshold
+/// (infinite if there are none).
 /// Returns (count_less_or_equal, closest_below, closest_above).
-fn count_and_neighbors(x: &[f64], y: &[f64], threshold: f64) -> (i64, f64, f64) {
-    let m = x.len();
+pub(crate) fn count_pairs_le<T: Float>(
+    x: &[T],
+    y: &[T],
+    threshold: f64,
+    exec: Execution,
+) -> (i64, f64, f64) {
+    map_rows(
+        x.len(),
+        exec,
+        |rows| count_rows(x, y, threshold, rows),
+        |(c1, b1, a1), (c2, b2, a2)| (c1 + c2, b1.max(b2), a1.min(a2)),
+    )
+}
+
+/// Two-pointer scan of `count_and_neighbors` over the `x` rows in `rows`.
+fn count_rows<T: Float>(x: &[T], y: &[T], threshold: f64, rows: Range<usize>) -> (i64, f64, f64) {
     let n = y.len();
     let mut count: i64 = 0;
     let mut max_below = f64::NEG_INFINITY;
     let mut min_above = f64::INFINITY;
 
-    // Two-pointer algorithm: for each x[i], find the largest j where x[i] - y[j] > threshold
-    let mut j = 0;
-    for &xi in x.iter() {
+    // Two-pointer algorithm: for each x[i], find the largest j where x[i] - y[j] > threshold.
+    // A later chunk finds its start by binary search, which on sorted input lands
+    // exactly where the serial scan from row 0 would be.
+    let mut j = if rows.start == 0 {
+        0
+    } else {
+        let x0 = x[rows.start].to_f64();
+        y.partition_point(|&yj| x0 - yj.to_f64() > threshold)
+    };
+    for xi in x[rows].iter().map(|v| v.to_f64()) {
         // Move j forward while xi - y[j] > threshold
-        while j < n && xi - y[j] > threshold {
+        while j < n && xi - y[j].to_f64() > threshold {
             j += 1;
         }
 
@@ -187,28 +252,20 @@ fn count_and_neighbors(x: &[f64], y: &[f64], threshold: f64) -> (i64, f64, f64)
 
         // Track boundaries
         if j < n {
-            let diff = xi - y[j];
+            let diff = xi - y[j].to_f64();
             if diff > max_below {
                 max_below = diff;
             }
         }
 
         if j > 0 {
-            let diff = xi - y[j - 1];
+            let diff = xi - y[j - 1].to_f64();
             if diff < min_above {
                 min_above = diff;
             }
         }
     }
 
-    // Fallback to actual min/max if no boundaries found
-    if max_below.is_infinite() && max_below.is_sign_negative() {
-        max_below = x[0] - y[n - 1];
-    }
-    if min_above.is_infinite() && min_above.is_sign_positive() {
-        min_above = x[m - 1] - y[0];
-    }
-
     (count, max_below, min_above)
 }
 
@@ -226,9 +283,9 @@ fn midpoint(a: f64, b: f64) -> f64 {
 /// * `y` - Second sample (must be positive; will be sorted if assume_sorted is false)
 /// * `p` - Slice of probabilities in [0, 1]
 /// * `assume_sorted` - If true, assumes inputs are already sorted
-pub(crate) fn ratio_quantiles_impl(
-    x: &[f64],
-    y: &[f64],
+pub(crate) fn ratio_quantiles_impl<T: Float>(
+    x: &[T],
+    y: &[T],
     p: &[f64],
     assume_sorted: bool,
 ) -> Result<Vec<f64>, &'static str> {
@@ -237,8 +294,8 @@ pub(crate) fn ratio_quantiles_impl(
     }
 
     // Log-transform both samples (includes positivity check)
-    let log_x = log(x, Subject::X).map_err(|_| "x must contain only positive values")?;
-    let log_y = log(y, Subject::Y).map_err(|_| "y must contain only positive values")?;
+    let log_x = log_of(x, Subject::X).map_err(|_| "x must contain only positive values")?;
+    let log_y = log_of(y, Subject::Y).map_err(|_| "y must contain only positive values")?;
 
     // Delegate to shift_quantiles_impl in log-space
     let log_result = shift_quantiles_impl(&log_x, &log_y, p, assume_sorted)?;
@@ -5,25 +5,68 @@
 
 use crate::assumptions::{AssumptionError, Subject};
 use crate::rng::Rng;
+use std::sync::OnceLock;
+
+/// SignMargin computes the conservative (non-randomized) margin for one-sample
+/// sign-test bounds: the largest even margin `2r` such that
+/// `P(Binomial(n, 0.5) <= r) <= misrate / 2`. Always computed exactly.
+///
+/// # Arguments
+///
+/// * `n` - Number of signs (must be positive)
+/// * `misrate` - Misclassification rate (must be in [0, 1])
+///
+/// # Errors
+///
+/// Returns a domain error if `n == 0`, or if `misrate` is NaN, outside [0, 1],
+/// or below the minimum achievable misrate `2^(1-n)`.
+///
+/// # Examples
+///
+/// ```
+/// use pragmastat::margins::sign_margin;
+///
+/// assert_eq!(sign_margin(10, 0.1).unwrap(), 2);
+/// assert!(sign_margin(10, 1e-6).is_err());
+/// ```
+pub fn sign_margin(n: usize, misrate: f64) -> Result<usize, AssumptionError> {
+    validate(n, misrate)?;
+    let target = misrate / 2.0;
+    let (r_low, log_cdf, _) = binom_cdf_split(n, target);
+    // binom_cdf_split reports r_low = 0 even when CDF(0) exceeds the target.
+    if log_cdf > target.ln() {
+        return Ok(0);
+    }
+    Ok(r_low * 2)
+}
 
 /// Randomized version of SignMargin.
 /// Randomizes the cutoff between adjacent ranks to match the requested misrate.
+///
+/// Returns either [`sign_margin`] or the next even margin, choosing the larger
+/// one with the probability that makes the expected misrate equal the requested
+/// one. Consumes exactly one `uniform_f64` draw from `rng` on success.
+///
+/// # Errors
+///
+/// Same as [`sign_margin`].
+///
+/// # Examples
+///
+/// ```
+/// use pragmastat::margins::{sign_margin, sign_margin_randomized};
+/// use pragmastat::Rng;
+///
+/// let mut rng = Rng::from_string("demo-sign-margin");
+/// let margin = sign_margin_randomized(10, 0.1, &mut rng).unwrap();
+/// assert!(margin == 2 || margin == 4);
+/// ```
 pub fn sign_margin_randomized(
     n: usize,
     misrate: f64,
     rng: &mut Rng,
 ) -> Result<usize, AssumptionError> {
-    if n == 0 {
-        return Err(AssumptionError::domain(Subject::X));
-    }
-    if misrate.is_nan() || !(0.0..=1.0).contains(&misrate) {
-        return Err(AssumptionError::domain(Subject::Misrate));
-    }
-
-    let min_misrate = crate::min_misrate::min_achievable_misrate_one_sample(n)?;
-    if misrate < min_misrate {
-        return Err(AssumptionError::domain(Subject::Misrate));
-    }
+    validate(n, misrate)?;
 
     let target = misrate / 2.0;
     if target <= 0.0 {
@@ -55,6 +98,62 @@ pub fn sign_margin_randomized(
     Ok(r * 2)
 }
 
+/// Exact coverage of sign-test bounds with the given margin.
+///
+/// The bounds are the order statistics `r + 1` and `n - r` of `n` independent
+/// values, with `r = margin / 2` capped at `(n - 1) / 2` as in `spread_bounds`.
+/// For any continuous distribution they contain its median with probability
+/// `1 - 2 P(Binomial(n, 0.5) <= r)`; the tail is summed in log space, so large
+/// `n` neither overflows nor underflows.
+///
+/// `spread_bounds` takes `n = x.len() / 2` disjoint pairs and randomizes the
+/// margin between [`sign_margin`] and the next even value, so its coverage is
+/// the corresponding mixture of two of these values.
+///
+/// # Panics
+///
+/// Panics if `n == 0`.
+///
+/// # Examples
+///
+/// ```
+/// use pragmastat::margins::{sign_coverage, sign_margin};
+///
+/// let margin = sign_margin(10, 0.1).unwrap();
+/// assert!((sign_coverage(10, margin) - 1002.0 / 1024.0).abs() < 1e-12);
+/// assert!(sign_coverage(10, margin) >= 0.9);
+/// ```
+pub fn sign_coverage(n: usize, margin: usize) -> f64 {
+    assert!(n > 0, "sign coverage needs at least one sign");
+    let r = (margin / 2).min((n - 1) / 2);
+    let log_cdf = if n <= SIGN_MAX_CACHED_SIZE {
+        binom_log_tables()[n][r].1
+    } else {
+        binom_log_cdf_direct(n, r)
+    };
+    (1.0 - 2.0 * log_cdf.exp()).max(0.0)
+}
+
+fn validate(n: usize, misrate: f64) -> Result<(), AssumptionError> {
+    if n == 0 {
+        return Err(AssumptionError::sample_too_small(Subject::X, 0, 1));
+    }
+    if misrate.is_nan() || !(0.0..=1.0).contains(&misrate) {
+        return Err(AssumptionError::misrate_out_of_range(misrate));
+    }
+
+    let min_misrate = crate::min_misrate::min_achievable_misrate_one_sample(n)?;
+    if misrate < min_misrate {
+        return Err(AssumptionError::misrate_too_low(
+            misrate,
+            min_misrate,
+            n,
+            None,
+        ));
+    }
+    Ok(())
+}
+
 /// Small helper for log-sum-exp in base-e.
 fn log_add_exp(a: f64, b: f64) -> f64 {
     if a.is_infinite() && a.is_sign_negative() {
@@ -80,6 +179,9 @@ fn log_sub_exp(a: f64, b: f64) -> f64 {
     }
 }
 
+/// Largest n whose Binomial(n, 0.5) log-CDF is tabulated by [`binom_cdf_split`].
+const SIGN_MAX_CACHED_SIZE: usize = 64;
+
 /// Returns (r_low, log_cdf, log_pmf_high) where:
 /// - r_low is the largest r such that CDF(r) <= target
 /// - log_cdf = log(CDF(r_low))
@@ -87,7 +189,28 @@ fn log_sub_exp(a: f64, b: f64) -> f64 {
 ///
 /// Special case: when CDF(0) > target, returns (0, log(PMF(0)), log(PMF(0)))
 /// because r_low = 0 and the "next" PMF is also PMF(0).
+///
+/// For n <= [`SIGN_MAX_CACHED_SIZE`] the log-PMF and log-CDF come from tables
+/// built once by the same recurrence, so the result matches
+/// [`binom_cdf_split_direct`] bit for bit.
 fn binom_cdf_split(n: usize, target: f64) -> (usize, f64, f64) {
+    if n > SIGN_MAX_CACHED_SIZE {
+        return binom_cdf_split_direct(n, target);
+    }
+    let table = &binom_log_tables()[n];
+    let log_target = target.ln();
+    // log_add_exp never decreases its larger argument, so the log-CDF is
+    // non-decreasing and the first entry above the target can be bisected.
+    let k = table.partition_point(|&(_, log_cdf)| log_cdf <= log_target);
+    match k {
+        0 => (0, table[0].1, table[0].0),
+        k if k > n => (n, table[n].1, f64::NEG_INFINITY),
+        k => (k - 1, table[k - 1].1, table[k].0),
+    }
+}
+
+/// Linear scan behind [`binom_cdf_split`], used directly for large n.
+fn binom_cdf_split_direct(n: usize, target: f64) -> (usize, f64, f64) {
     let log_target = target.ln();
 
     // pmf(0) = 2^-n
@@ -101,8 +224,7 @@ fn binom_cdf_split(n: usize, target: f64) -> (usize, f64, f64) {
     }
 
     for k in 1..=n {
-        let log_pmf_next = log_pmf + ((n - k + 1) as f64).ln() - (k as f64).ln();
-        let log_cdf_next = log_add_exp(log_cdf, log_pmf_next);
+        let (log_pmf_next, log_cdf_next) = binom_log_step(n, k, log_pmf, log_cdf);
 
         if log_cdf_next > log_target {
             return (r_low, log_cdf, log_pmf_next);
@@ -115,3 +237,70 @@ fn binom_cdf_split(n: usize, target: f64) -> (usize, f64, f64) {
 
     (r_low, log_cdf, f64::NEG_INFINITY)
 }
+
+/// `log CDF(r)` of Binomial(n, 0.5) by the recurrence behind the tables.
+fn binom_log_cdf_direct(n: usize, r: usize) -> f64 {
+    let log_pmf = -(n as f64) * std::f64::consts::LN_2;
+    (1..=r)
+        .fold((log_pmf, log_pmf), |(log_pmf, log_cdf), k| {
+            binom_log_step(n, k, log_pmf, log_cdf)
+        })
+        .1
+}
+
+/// Advances `(log PMF(k - 1), log CDF(k - 1))` of Binomial(n, 0.5) to `k`.
+fn binom_log_step(n: usize, k: usize, log_pmf: f64, log_cdf: f64) -> (f64, f64) {
+    let log_pmf_next = log_pmf + ((n - k + 1) as f64).ln() - (k as f64).ln();
+    (log_pmf_next, log_add_exp(log_cdf, log_pmf_next))
+}
+
+/// `(log PMF(k), log CDF(k))` of Binomial(n, 0.5) for `k = 0..=n`, for every
+/// `n <= SIGN_MAX_CACHED_SIZE`. Built once on first use.
+fn binom_log_tables() -> &'static [Vec<(f64, f64)>] {
+    static TABLES: OnceLock<Vec<Vec<(f64, f64)>>> = OnceLock::new();
+    TABLES.get_or_init(|| {
+        (0..=SIGN_MAX_CACHED_SIZE)
+            .map(|n| {
+                let log_pmf = -(n as f64) * std::f64::consts::LN_2;
+                let mut row = vec![(log_pmf, log_pmf)];
+                for k in 1..=n {
+                    let (log_pmf, log_cdf) = row[k - 1];
+                    row.push(binom_log_step(n, k, log_pmf, log_cdf));
+                }
+                row
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cached_split_matches_direct() {
+        let misrates = [
+            1e-12, 1e-9, 1e-6, 1e-4, 1e-3, 0.005, 0.01, 0.02, 0.05, 0.1, 0.2, 0.25, 0.5, 0.9, 1.0,
+        ];
+        for n in 1..=SIGN_MAX_CACHED_SIZE {
+            for &misrate in &misrates {
+                let target = misrate / 2.0;
+                let (r, log_cdf, log_pmf) = binom_cdf_split(n, target);
+                let (r_direct, log_cdf_direct, log_pmf_direct) = binom_cdf_split_direct(n, target);
+                assert_eq!(r, r_direct, "n = {n}, misrate = {misrate}");
+                assert_eq!(log_cdf.to_bits(), log_cdf_direct.to_bits());
+                assert_eq!(log_pmf.to_bits(), log_pmf_direct.to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn cached_log_cdf_matches_direct() {
+        for n in 1..=SIGN_MAX_CACHED_SIZE {
+            for r in 0..=n {
+                let direct = binom_log_cdf_direct(n, r);
+                assert_eq!(binom_log_tables()[n][r].1.to_bits(), direct.to_bits());
+            }
+        }
+    }
+}
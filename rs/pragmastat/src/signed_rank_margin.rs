@@ -5,6 +5,7 @@
 use crate::assumptions::AssumptionError;
 use crate::gauss_cdf::gauss_cdf;
 use crate::min_misrate::min_achievable_misrate_one_sample;
+use std::sync::OnceLock;
 
 /// Maximum n for exact computation. Limited to 63 because 2^n must fit in a 64-bit integer.
 const SIGNED_RANK_MAX_EXACT_SIZE: usize = 63;
@@ -18,95 +19,224 @@ const SIGNED_RANK_MAX_EXACT_SIZE: usize = 63;
 /// * `n` - Sample size (must be positive)
 /// * `misrate` - Misclassification rate (must be in [0, 1])
 ///
+/// Uses the exact distribution for n <= 63 and an Edgeworth approximation
+/// for larger samples. The exact distributions are tabulated once per process,
+/// so repeated calls with small n cost a binary search.
+///
 /// # Returns
 ///
 /// Integer margin, or an error if inputs are invalid.
+///
+/// # Errors
+///
+/// Returns an error if n == 0, or misrate is outside [0, 1] or is NaN, or is
+/// below the minimum achievable misrate `2^(1-n)`.
+///
+/// # Examples
+///
+/// ```
+/// use pragmastat::margins::signed_rank_margin;
+///
+/// let margin = signed_rank_margin(20, 0.05).unwrap();
+/// assert!(margin <= 20 * 21 / 2);
+/// assert!(signed_rank_margin(3, 0.1).is_err());
+/// ```
 pub fn signed_rank_margin(n: usize, misrate: f64) -> Result<usize, AssumptionError> {
+    validate(n, misrate)?;
+    if n <= SIGNED_RANK_MAX_EXACT_SIZE {
+        Ok(signed_rank_margin_exact(n, misrate))
+    } else {
+        signed_rank_margin_approx(n, misrate)
+    }
+}
+
+/// [`signed_rank_margin`] from the plain normal approximation of the
+/// signed-rank distribution at every `n`: the Edgeworth expansion without its
+/// kurtosis term. Used by `BoundsMethod::Asymptotic`.
+pub(crate) fn signed_rank_margin_normal(n: usize, misrate: f64) -> Result<usize, AssumptionError> {
+    validate(n, misrate)?;
+    signed_rank_margin_bisect(n, misrate / 2.0, signed_rank_normal_cdf)
+        .checked_mul(2)
+        .ok_or_else(|| AssumptionError::domain(crate::assumptions::Subject::X))
+}
+
+fn validate(n: usize, misrate: f64) -> Result<(), AssumptionError> {
     if n == 0 {
-        return Err(AssumptionError::domain(crate::assumptions::Subject::X));
+        return Err(AssumptionError::sample_too_small(
+            crate::assumptions::Subject::X,
+            0,
+            1,
+        ));
     }
     if misrate.is_nan() || !(0.0..=1.0).contains(&misrate) {
-        return Err(AssumptionError::domain(
-            crate::assumptions::Subject::Misrate,
-        ));
+        return Err(AssumptionError::misrate_out_of_range(misrate));
     }
 
     let min_misrate = min_achievable_misrate_one_sample(n)?;
     if misrate < min_misrate {
-        return Err(AssumptionError::domain(
-            crate::assumptions::Subject::Misrate,
+        return Err(AssumptionError::misrate_too_low(
+            misrate,
+            min_misrate,
+            n,
+            None,
         ));
     }
-
-    if n <= SIGNED_RANK_MAX_EXACT_SIZE {
-        Ok(signed_rank_margin_exact(n, misrate))
-    } else {
-        signed_rank_margin_approx(n, misrate)
-    }
+    Ok(())
 }
 
 /// Computes one-sided margin using exact Wilcoxon signed-rank distribution.
-/// Uses dynamic programming to compute the CDF.
 fn signed_rank_margin_exact(n: usize, misrate: f64) -> usize {
     signed_rank_margin_exact_raw(n, misrate / 2.0) * 2
 }
 
+/// Smallest `w` with `P(W <= w) >= p`, looked up in the cached cumulative counts.
+///
+/// The CDF is evaluated as `cumulative / 2^n` exactly as a linear scan would, and
+/// it is non-decreasing in `w`, so the binary search finds the same `w`.
 fn signed_rank_margin_exact_raw(n: usize, p: f64) -> usize {
-    let total = 1_u64 << n;
-    let max_w = n * (n + 1) / 2;
+    let cumulative = &signed_rank_cumulative_tables()[n];
+    let total = (1_u64 << n) as f64;
+    let w = cumulative.partition_point(|&c| (c as f64 / total) < p);
+    w.min(cumulative.len() - 1)
+}
 
-    let mut count = vec![0_u64; max_w + 1];
-    count[0] = 1;
+/// Exact null `P(W <= w)` of the signed-rank statistic for `n` values,
+/// from the cached counts; `w` beyond the maximum gives 1.
+///
+/// # Panics
+/// Panics if `n > SIGNED_RANK_MAX_EXACT_SIZE`.
+pub(crate) fn signed_rank_exact_cdf(n: usize, w: usize) -> f64 {
+    let cumulative = &signed_rank_cumulative_tables()[n];
+    let count = cumulative[w.min(cumulative.len() - 1)];
+    count as f64 / (1_u64 << n) as f64
+}
 
-    for i in 1..=n {
-        let max_wi = i * (i + 1) / 2;
-        let max_wi = max_wi.min(max_w);
-        for w in (i..=max_wi).rev() {
-            count[w] += count[w - i];
-        }
-    }
+/// Exact coverage of signed-rank bounds with the given margin.
+///
+/// The bounds are the Walsh averages of ranks `h + 1` and `T - h` among all
+/// `T = n (n + 1) / 2`, with `h = margin / 2` capped at `(T - 1) / 2` as in
+/// `center_bounds`. For any continuous distribution symmetric around its
+/// center they contain that center with probability `1 - 2 P(W <= h)`, where
+/// `W` is the Wilcoxon signed-rank statistic.
+///
+/// The tail comes from the cached exact counts for `n <= 63` and from the
+/// same recurrence in probability space above, which neither overflows nor
+/// underflows at `n = 1000`; the cost is `O(min(n, h) · h)`.
+///
+/// # Panics
+///
+/// Panics if `n == 0`.
+///
+/// # Examples
+///
+/// ```
+/// use pragmastat::margins::{signed_rank_coverage, signed_rank_margin};
+///
+/// let margin = signed_rank_margin(10, 0.05).unwrap();
+/// let coverage = signed_rank_coverage(10, margin);
+/// assert!((coverage - 958.0 / 1024.0).abs() < 1e-12);
+/// assert!(signed_rank_coverage(10, margin + 2) < coverage);
+/// ```
+pub fn signed_rank_coverage(n: usize, margin: usize) -> f64 {
+    assert!(n > 0, "signed-rank coverage needs at least one value");
+    let total = n * (n + 1) / 2;
+    let h = (margin / 2).min((total - 1) / 2);
+    let tail = if n <= SIGNED_RANK_MAX_EXACT_SIZE {
+        signed_rank_exact_cdf(n, h)
+    } else {
+        signed_rank_cdf_scaled(n, h)
+    };
+    (1.0 - 2.0 * tail).max(0.0)
+}
 
-    let mut cumulative: u64 = 0;
-    for (w, &c) in count.iter().enumerate().take(max_w + 1) {
-        cumulative += c;
-        let cdf = cumulative as f64 / total as f64;
-        if cdf >= p {
-            return w;
+/// `P(W <= w)` for `n` values by the counting recurrence, with every step
+/// halved so that the entries stay probabilities: adding rank `i` maps
+/// `p[v]` to `(p[v] + p[v - i]) / 2`. Only `v <= w` is tracked, and ranks
+/// above `w` merely halve every entry.
+fn signed_rank_cdf_scaled(n: usize, w: usize) -> f64 {
+    let mut p = vec![0.0; w + 1];
+    p[0] = 1.0;
+    let active = n.min(w);
+    for i in 1..=active {
+        for v in (i..=w).rev() {
+            p[v] = 0.5 * (p[v] + p[v - i]);
+        }
+        for value in &mut p[..i] {
+            *value *= 0.5;
         }
     }
+    p.iter().sum::<f64>() * 0.5_f64.powf((n - active) as f64)
+}
 
-    max_w
+/// Cumulative counts of the Wilcoxon signed-rank statistic for every
+/// `n <= SIGNED_RANK_MAX_EXACT_SIZE`: entry `[n][w]` is the number of the `2^n`
+/// sign assignments with `W <= w`. Built once on first use by a single dynamic
+/// programming pass, since the counts after adding rank `i` are those for `n = i`.
+fn signed_rank_cumulative_tables() -> &'static [Vec<u64>] {
+    static TABLES: OnceLock<Vec<Vec<u64>>> = OnceLock::new();
+    TABLES.get_or_init(|| {
+        let max_w = SIGNED_RANK_MAX_EXACT_SIZE * (SIGNED_RANK_MAX_EXACT_SIZE + 1) / 2;
+        let mut count = vec![0_u64; max_w + 1];
+        count[0] = 1;
+
+        let mut tables = Vec::with_capacity(SIGNED_RANK_MAX_EXACT_SIZE + 1);
+        tables.push(vec![1]);
+        for i in 1..=SIGNED_RANK_MAX_EXACT_SIZE {
+            let max_wi = i * (i + 1) / 2;
+            for w in (i..=max_wi).rev() {
+                count[w] += count[w - i];
+            }
+            let cumulative = count[..=max_wi]
+                .iter()
+                .scan(0_u64, |acc, &c| {
+                    *acc += c;
+                    Some(*acc)
+                })
+                .collect();
+            tables.push(cumulative);
+        }
+        tables
+    })
 }
 
 /// Computes one-sided margin using Edgeworth approximation for large n.
 fn signed_rank_margin_approx(n: usize, misrate: f64) -> Result<usize, AssumptionError> {
-    let raw = signed_rank_margin_approx_raw(n, misrate / 2.0);
+    let raw = signed_rank_margin_bisect(n, misrate / 2.0, signed_rank_edgeworth_cdf);
     raw.checked_mul(2)
         .ok_or_else(|| AssumptionError::domain(crate::assumptions::Subject::X))
 }
 
-fn signed_rank_margin_approx_raw(n: usize, misrate: f64) -> usize {
+/// Inverts an approximate signed-rank CDF by bisection.
+fn signed_rank_margin_bisect(n: usize, misrate: f64, cdf: fn(usize, usize) -> f64) -> usize {
     let max_w = n * (n + 1) / 2;
     let mut a: usize = 0;
     let mut b = max_w;
 
     while a < b - 1 {
         let c = usize::midpoint(a, b);
-        let cdf = signed_rank_edgeworth_cdf(n, c);
-        if cdf < misrate {
+        if cdf(n, c) < misrate {
             a = c;
         } else {
             b = c;
         }
     }
 
-    if signed_rank_edgeworth_cdf(n, b) < misrate {
+    if cdf(n, b) < misrate {
         b
     } else {
         a
     }
 }
 
+/// Normal approximation of the Wilcoxon signed-rank distribution CDF, with
+/// the same continuity correction as [`signed_rank_edgeworth_cdf`].
+fn signed_rank_normal_cdf(n: usize, w: usize) -> f64 {
+    let n_f64 = n as f64;
+    let mu = n_f64 * (n_f64 + 1.0) / 4.0;
+    let sigma = (n_f64 * (n_f64 + 1.0) * (2.0 * n_f64 + 1.0) / 24.0).sqrt();
+    gauss_cdf((w as f64 - mu + 0.5) / sigma)
+}
+
 /// Edgeworth expansion for Wilcoxon signed-rank distribution CDF.
 fn signed_rank_edgeworth_cdf(n: usize, w: usize) -> f64 {
     let n_f64 = n as f64;
@@ -257,6 +387,64 @@ mod tests {
         assert_eq!(v.subject, Subject::Misrate);
     }
 
+    /// The uncached dynamic programming and linear CDF scan.
+    fn exact_raw_direct(n: usize, p: f64) -> usize {
+        let total = 1_u64 << n;
+        let max_w = n * (n + 1) / 2;
+
+        let mut count = vec![0_u64; max_w + 1];
+        count[0] = 1;
+        for i in 1..=n {
+            for w in (i..=i * (i + 1) / 2).rev() {
+                count[w] += count[w - i];
+            }
+        }
+
+        let mut cumulative: u64 = 0;
+        for (w, &c) in count.iter().enumerate() {
+            cumulative += c;
+            if cumulative as f64 / total as f64 >= p {
+                return w;
+            }
+        }
+        max_w
+    }
+
+    #[test]
+    fn cached_matches_direct() {
+        let misrates = [
+            1e-12, 1e-9, 1e-6, 1e-4, 1e-3, 0.005, 0.01, 0.02, 0.05, 0.1, 0.2, 0.25, 0.5, 0.9, 1.0,
+        ];
+        for n in 1..=super::SIGNED_RANK_MAX_EXACT_SIZE {
+            for &misrate in &misrates {
+                let p = misrate / 2.0;
+                assert_eq!(
+                    super::signed_rank_margin_exact_raw(n, p),
+                    exact_raw_direct(n, p),
+                    "n = {n}, misrate = {misrate}"
+                );
+                if let Ok(margin) = signed_rank_margin(n, misrate) {
+                    assert_eq!(margin, exact_raw_direct(n, p) * 2);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn scaled_cdf_matches_cached_counts() {
+        for n in [1, 2, 5, 17, 40, super::SIGNED_RANK_MAX_EXACT_SIZE] {
+            let max_w = n * (n + 1) / 2;
+            for w in [0, 1, n, max_w / 3, max_w / 2, max_w] {
+                let expected = super::signed_rank_exact_cdf(n, w);
+                let actual = super::signed_rank_cdf_scaled(n, w);
+                assert!(
+                    (actual - expected).abs() <= 1e-13 * expected,
+                    "n = {n}, w = {w}: {actual} vs {expected}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn misrate_below_min() {
         let result = signed_rank_margin(5, 1e-20);
@@ -1,6 +1,9 @@
 //! SplitMix64 PRNG for seed expansion
 //! Reference: https://prng.di.unimi.it/splitmix64.c
 
+/// Increment of the SplitMix64 state per output.
+const GAMMA: u64 = 0x9e3779b97f4a7c15;
+
 pub(crate) struct SplitMix64 {
     state: u64,
 }
@@ -10,8 +13,14 @@ impl SplitMix64 {
         Self { state: seed }
     }
 
+    /// Output `index` (zero-based) of the stream seeded with `seed`, without
+    /// generating the earlier ones.
+    pub fn nth(seed: u64, index: u64) -> u64 {
+        Self::new(seed.wrapping_add(index.wrapping_mul(GAMMA))).next()
+    }
+
     pub fn next(&mut self) -> u64 {
-        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        self.state = self.state.wrapping_add(GAMMA);
         let mut z = self.state;
         z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
         z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
@@ -42,4 +51,15 @@ mod tests {
 
         assert_ne!(rng1.next(), rng2.next());
     }
+
+    #[test]
+    fn nth_matches_sequence() {
+        let mut rng = SplitMix64::new(1729);
+        for index in 0..100 {
+            assert_eq!(SplitMix64::nth(1729, index), rng.next());
+        }
+        let mut rng = SplitMix64::new(u64::MAX);
+        rng.next();
+        assert_eq!(SplitMix64::nth(u64::MAX, 1), rng.next());
+    }
 }
@@ -2,20 +2,33 @@
 /// Based on Monahan's selection algorithm adapted for pairwise differences.
 ///
 /// Internal implementation - not part of public API.
+use crate::float::Float;
 use crate::fnv1a::hash_f64_slice;
+use crate::parallel::{map_rows_mut, Execution};
 use crate::rng::Rng;
 
-pub(crate) fn spread_impl(values: &[f64], assume_sorted: bool) -> Result<f64, &'static str> {
+pub(crate) fn spread_impl<T: Float>(
+    values: &[T],
+    assume_sorted: bool,
+) -> Result<f64, &'static str> {
+    spread_impl_with(values, assume_sorted, Execution::auto(values.len()))
+}
+
+pub(crate) fn spread_impl_with<T: Float>(
+    values: &[T],
+    assume_sorted: bool,
+    exec: Execution,
+) -> Result<f64, &'static str> {
     let n = values.len();
     if n <= 1 {
         return Ok(0.0);
     }
     if n == 2 {
-        return Ok((values[1] - values[0]).abs());
+        return Ok((values[1].to_f64() - values[0].to_f64()).abs());
     }
 
     // Validate for NaN/infinite values
-    if values.iter().any(|v| !v.is_finite()) {
+    if values.iter().any(|v| !v.to_f64().is_finite()) {
         return Err("Input contains NaN or infinite values");
     }
 
@@ -24,7 +37,7 @@ pub(crate) fn spread_impl(values: &[f64], assume_sorted: bool) -> Result<f64, &'
     }
 
     let owned_sorted;
-    let a: &[f64] = if assume_sorted {
+    let a: &[T] = if assume_sorted {
         values
     } else {
         owned_sorted = {
@@ -55,7 +68,7 @@ pub(crate) fn spread_impl(values: &[f64], assume_sorted: bool) -> Result<f64, &'
     let mut row_counts: Vec<u32> = vec![0; n];
 
     // Initial pivot: a central gap
-    let mut pivot = a[n / 2] - a[(n - 1) / 2];
+    let mut pivot = a[n / 2].to_f64() - a[(n - 1) / 2].to_f64();
     let mut prev_count_below = -1i64;
 
     let mut rng = Rng::from_seed(hash_f64_slice(values));
@@ -76,34 +89,12 @@ pub(crate) fn spread_impl(values: &[f64], assume_sorted: bool) -> Result<f64, &'
 
     for _ in 0..max_iterations {
         // === PARTITION: count how many differences are < pivot ===
-        let mut count_below: u64 = 0;
-        let mut largest_below = f64::NEG_INFINITY;
-        let mut smallest_at_or_above = f64::INFINITY;
-
-        let mut j = 1; // global two-pointer (non-decreasing across rows)
-        for i in 0..n - 1 {
-            if j < i + 1 {
-                j = i + 1;
-            }
-            while j < n && a[j] - a[i] < pivot {
-                j += 1;
-            }
-
-            let cnt_row = j.saturating_sub(i + 1);
-            row_counts[i] = cnt_row as u32;
-            count_below += cnt_row as u64;
-
-            // boundary elements for this row
-            if cnt_row > 0 {
-                let cand_below = a[j - 1] - a[i];
-                largest_below = largest_below.max(cand_below);
-            }
-
-            if j < n {
-                let cand_at_or_above = a[j] - a[i];
-                smallest_at_or_above = smallest_at_or_above.min(cand_at_or_above);
-            }
-        }
+        let (count_below, largest_below, smallest_at_or_above) = map_rows_mut(
+            &mut row_counts[..n - 1],
+            exec,
+            |first_row, counts| partition_rows(a, pivot, first_row, counts),
+            |(c1, lb1, sa1), (c2, lb2, sa2)| (c1 + c2, lb1.max(lb2), sa1.min(sa2)),
+        );
 
         // === TARGET CHECK ===
         let at_target = count_below == k_low || count_below == k_high - 1;
@@ -139,8 +130,8 @@ pub(crate) fn spread_impl(values: &[f64], assume_sorted: bool) -> Result<f64, &'
                     continue;
                 }
 
-                let row_min = a[li] - a[i];
-                let row_max = a[ri] - a[i];
+                let row_min = a[li].to_f64() - a[i].to_f64();
+                let row_max = a[ri].to_f64() - a[i].to_f64();
                 min_active = min_active.min(row_min);
                 max_active = max_active.max(row_max);
                 active += (ri - li + 1) as u64;
@@ -250,8 +241,8 @@ pub(crate) fn spread_impl(values: &[f64], assume_sorted: bool) -> Result<f64, &'
                 if left_bounds[i] > right_bounds[i] {
                     continue;
                 }
-                let lo = a[left_bounds[i] as usize] - a[i];
-                let hi = a[right_bounds[i] as usize] - a[i];
+                let lo = a[left_bounds[i] as usize].to_f64() - a[i].to_f64();
+                let hi = a[right_bounds[i] as usize].to_f64() - a[i].to_f64();
                 min_rem = min_rem.min(lo);
                 max_rem = max_rem.max(hi);
             }
@@ -300,13 +291,224 @@ pub(crate) fn spread_impl(values: &[f64], assume_sorted: bool) -> Result<f64, &'
             if col >= n {
                 return Err("spread_impl: pivot index out of range");
             }
-            pivot = a[col] - a[row];
+            pivot = a[col].to_f64() - a[row].to_f64();
         }
     }
 
     Err("Convergence failure (pathological input)")
 }
 
+/// Counts differences `a[j] - a[i] < pivot` (j > i) for rows
+/// `first_row..first_row + counts.len()`, storing per-row counts in `counts`.
+/// Returns (count_below, largest_below, smallest_at_or_above).
+fn partition_rows<T: Float>(
+    a: &[T],
+    pivot: f64,
+    first_row: usize,
+    counts: &mut [u32],
+) -> (u64, f64, f64) {
+    let n = a.len();
+    let mut count_below: u64 = 0;
+    let mut largest_below = f64::NEG_INFINITY;
+    let mut smallest_at_or_above = f64::INFINITY;
+
+    // Two-pointer, non-decreasing across rows. A later chunk finds its start by
+    // binary search, which on sorted input lands exactly where the serial scan
+    // from row 0 would be.
+    let mut j = if first_row == 0 {
+        1
+    } else {
+        let start = first_row + 1;
+        start + a[start..].partition_point(|&v| v.to_f64() - a[first_row].to_f64() < pivot)
+    };
+    for (offset, count) in counts.iter_mut().enumerate() {
+        let i = first_row + offset;
+        if j < i + 1 {
+            j = i + 1;
+        }
+        while j < n && a[j].to_f64() - a[i].to_f64() < pivot {
+            j += 1;
+        }
+
+        let cnt_row = j.saturating_sub(i + 1);
+        *count = cnt_row as u32;
+        count_below += cnt_row as u64;
+
+        // boundary elements for this row
+        if cnt_row > 0 {
+            let cand_below = a[j - 1].to_f64() - a[i].to_f64();
+            largest_below = largest_below.max(cand_below);
+        }
+
+        if j < n {
+            let cand_at_or_above = a[j].to_f64() - a[i].to_f64();
+            smallest_at_or_above = smallest_at_or_above.min(cand_at_or_above);
+        }
+    }
+
+    (count_below, largest_below, smallest_at_or_above)
+}
+
+/// Counts pairwise differences `a[j] - a[i]` (i < j) that are `<= t`, for
+/// ascending `a` and `t >= 0`. Two pointers, O(n).
+pub(crate) fn count_pairwise_diffs_at_most(a: &[f64], t: f64) -> u64 {
+    let mut i = 0;
+    let mut count = 0u64;
+    for j in 0..a.len() {
+        while a[j] - a[i] > t {
+            i += 1;
+        }
+        count += (j - i) as u64;
+    }
+    count
+}
+
+/// Selects the `k`-th smallest (1-based) pairwise difference `a[j] - a[i]`
+/// (i < j) of ascending `a`.
+#[cfg(test)]
+pub(crate) fn select_kth_pairwise_diff_sorted(a: &[f64], k: u64) -> f64 {
+    select_kth_pairwise_diff_between(a, k, 0.0, a[a.len() - 1] - a[0])
+}
+
+/// Selects the `k`-th smallest (1-based) pairwise difference `a[j] - a[i]`
+/// (i < j) of ascending `a`, starting from the bracket `(lo, hi]` when it
+/// contains the answer and from the full range otherwise.
+///
+/// Narrows the bracket with O(n) counts. Each step takes a secant step on the
+/// count through the two latest probes; once a probe lands close to `k`, a
+/// second probe just past `k` on the other side closes the bracket. A step
+/// whose secant guess leaves the bracket bisects it instead, on the bit
+/// patterns of the non-negative `f64`s (which order like the values), which
+/// bounds the worst case. Once at most `n` differences remain in the bracket,
+/// they are collected and selected directly; a bracket that cannot get that
+/// small spans two adjacent floats, so the answer is its upper end. Counts are
+/// smooth near the answer for continuous data, so a few steps usually suffice.
+pub(crate) fn select_kth_pairwise_diff_between(a: &[f64], k: u64, lo: f64, hi: f64) -> f64 {
+    let n = a.len();
+    let count = |t: f64| count_pairwise_diffs_at_most(a, t);
+    let max = a[n - 1] - a[0];
+    // Invariant: count(lo) < k <= count(hi), with 0 <= lo < hi
+    let (mut lo, mut lo_count) = match (lo > 0.0 && lo < max).then(|| count(lo)) {
+        Some(c) if c < k => (lo, c),
+        _ => match count(0.0) {
+            c if c >= k => return 0.0,
+            c => (0.0, c),
+        },
+    };
+    let (mut hi, mut hi_count) = match (hi > lo && hi < max).then(|| count(hi)) {
+        Some(c) if c >= k => (hi, c),
+        _ => (max, count(max)),
+    };
+    let target = k as f64 - 0.5;
+    let (mut prev, mut last) = ((lo, lo_count as f64), (hi, hi_count as f64));
+    let mut steps = 0;
+    while hi_count - lo_count > n as u64 && hi.to_bits() - lo.to_bits() > 1 {
+        steps += 1;
+        let slope = (last.1 - prev.1) / (last.0 - prev.0);
+        let guess = last.0 + (target - last.1) / slope;
+        let mut probes = [f64::NAN; 2];
+        if guess > lo && guess < hi && steps <= 64 {
+            probes[0] = guess;
+        } else {
+            probes[0] = f64::from_bits(lo.to_bits() + (hi.to_bits() - lo.to_bits()) / 2);
+        }
+        for i in 0..2 {
+            let t = probes[i];
+            if !(t > lo && t < hi) {
+                break;
+            }
+            let c = count(t);
+            (prev, last) = (last, (t, c as f64));
+            if c >= k {
+                (hi, hi_count) = (t, c);
+            } else {
+                (lo, lo_count) = (t, c);
+            }
+            let miss = c as f64 - target;
+            if i == 0 && miss.abs() < (hi_count - lo_count) as f64 / 4.0 {
+                // Close the bracket just past k on the other side
+                let step = (miss.abs() + n as f64 / 4.0) / slope;
+                probes[1] = if c >= k { t - step } else { t + step };
+            }
+        }
+    }
+    if hi_count - lo_count > n as u64 {
+        // Adjacent floats: every difference in (lo, hi] is hi (a heavy tie)
+        return hi;
+    }
+    // Differences in (lo, hi]: for row j, those with i in [i_hi, i_lo)
+    let mut candidates = Vec::with_capacity((hi_count - lo_count) as usize);
+    let (mut i_lo, mut i_hi) = (0, 0);
+    for j in 0..n {
+        while a[j] - a[i_lo] > lo {
+            i_lo += 1;
+        }
+        while a[j] - a[i_hi] > hi {
+            i_hi += 1;
+        }
+        candidates.extend((i_hi..i_lo).map(|i| a[j] - a[i]));
+    }
+    if candidates.is_empty() {
+        return hi;
+    }
+    let index = ((k - lo_count - 1) as usize).min(candidates.len() - 1);
+    let (_, kth, _) = candidates.select_nth_unstable_by(index, |x, y| x.total_cmp(y));
+    *kth
+}
+
+/// Sorted differences of `size` random pairs of distinct positions of `a`,
+/// for bracketing quantiles of all pairwise differences before an exact
+/// [`select_kth_pairwise_diff_between`]. The seed is fixed; it only affects
+/// how fast the selection converges, never its result.
+pub(crate) fn pilot_pairwise_diffs(a: &[f64], size: usize) -> Vec<f64> {
+    let n = a.len();
+    let mut rng = Rng::from_string("pilot-pairwise-diffs");
+    let mut pilot: Vec<f64> = (0..size)
+        .map(|_| {
+            let i = rng.uniform_usize(0, n);
+            let j = (i + rng.uniform_usize(1, n)) % n;
+            (a[i] - a[j]).abs()
+        })
+        .collect();
+    pilot.sort_unstable_by(|x, y| x.total_cmp(y));
+    pilot
+}
+
+/// Bracket for the `p`-quantile of all pairwise differences from a sorted
+/// pilot, wide enough (about eight standard errors) to usually contain it.
+pub(crate) fn pilot_bracket(pilot: &[f64], p: f64) -> (f64, f64) {
+    let s = pilot.len() as f64;
+    let delta = 4.0 * (0.25 / s).sqrt() + 2.0 / s;
+    let at = |q: f64| pilot[((q.clamp(0.0, 1.0) * (s - 1.0)).round()) as usize];
+    (at(p - delta), at(p + delta))
+}
+
+/// Sample variance over `i` of the fraction of the other values within `t`
+/// of `a[i]`, for ascending `a` with at least two values. Two pointers, O(n).
+///
+/// This estimates `ζ₁ = Var(P(|X − X'| ≤ t | X))`, the variance of the first
+/// Hoeffding projection of the kernel `|x₁ − x₂| ≤ t`.
+pub(crate) fn neighbor_fraction_variance(a: &[f64], t: f64) -> f64 {
+    let n = a.len();
+    let (mut l, mut u) = (0, 0);
+    let (mut mean, mut m2) = (0.0, 0.0);
+    for i in 0..n {
+        while a[i] - a[l] > t {
+            l += 1;
+        }
+        u = u.max(i);
+        while u + 1 < n && a[u + 1] - a[i] <= t {
+            u += 1;
+        }
+        // Welford update with the fraction of j != i within t
+        let h = (u - l) as f64 / (n - 1) as f64;
+        let delta = h - mean;
+        mean += delta / (i + 1) as f64;
+        m2 += delta * (h - mean);
+    }
+    m2 / (n - 1) as f64
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -342,4 +544,65 @@ mod tests {
             "expected convergence-failure error"
         );
     }
+
+    fn all_pairwise_diffs(a: &[f64]) -> Vec<f64> {
+        let mut d: Vec<f64> = (0..a.len())
+            .flat_map(|i| (i + 1..a.len()).map(move |j| a[j] - a[i]))
+            .collect();
+        d.sort_by(f64::total_cmp);
+        d
+    }
+
+    #[test]
+    fn kth_pairwise_diff_matches_materialized() {
+        let mut rng = Rng::from_string("spread-impl-kth");
+        for n in [2, 3, 10, 57, 200] {
+            // Rounded values give ties and zero differences
+            let mut a: Vec<f64> = (0..n).map(|_| (rng.normal() * 4.0).round()).collect();
+            if n > 10 {
+                a.extend((0..n).map(|_| rng.normal() * 1e6));
+            }
+            a.sort_by(f64::total_cmp);
+            check_all_ranks(&a);
+        }
+        // Heavy ties: most differences equal one of three values
+        let mut a: Vec<f64> = (0..600).map(|i| f64::from(i % 3)).collect();
+        a.sort_by(f64::total_cmp);
+        check_all_ranks(&a);
+    }
+
+    /// Compares the selection with all materialized differences, at up to
+    /// about 300 ranks including the last.
+    fn check_all_ranks(a: &[f64]) {
+        let expected = all_pairwise_diffs(a);
+        let step = (expected.len() / 300).max(1);
+        let last = expected.len() as u64;
+        for k in (1..=last).step_by(step).chain([last]) {
+            assert_eq!(
+                select_kth_pairwise_diff_sorted(a, k),
+                expected[k as usize - 1],
+                "n = {}, k = {k}",
+                a.len()
+            );
+        }
+    }
+
+    #[test]
+    fn neighbor_fraction_variance_matches_direct() {
+        let mut rng = Rng::from_string("spread-impl-neighbors");
+        let mut a: Vec<f64> = (0..50).map(|_| rng.normal().round()).collect();
+        a.sort_by(f64::total_cmp);
+        let n = a.len();
+        for t in [0.0, 0.5, 1.0, 2.0] {
+            let h: Vec<f64> = (0..n)
+                .map(|i| {
+                    let within = (0..n).filter(|&j| j != i && (a[j] - a[i]).abs() <= t);
+                    within.count() as f64 / (n - 1) as f64
+                })
+                .collect();
+            let mean = h.iter().sum::<f64>() / n as f64;
+            let variance = h.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+            assert!((neighbor_fraction_variance(&a, t) - variance).abs() < 1e-12);
+        }
+    }
 }
@@ -1,8 +1,27 @@
 //! Unit registry for looking up measurement units by ID.
 
-use crate::measurement_unit::MeasurementUnit;
+use crate::measurement_unit::{is_compatible, MeasurementUnit};
 use std::collections::HashMap;
 
+/// SI decimal prefixes: (name, id symbol, display symbol, power of ten).
+const SI_PREFIXES: &[(&str, &str, &str, u32)] = &[
+    ("nano", "n", "n", 0),
+    ("micro", "u", "µ", 3),
+    ("milli", "m", "m", 6),
+    ("", "", "", 9),
+    ("kilo", "k", "k", 12),
+    ("mega", "M", "M", 15),
+    ("giga", "G", "G", 18),
+];
+
+/// IEC binary prefixes: (name, symbol, power of 1024).
+const BINARY_PREFIXES: &[(&str, &str, u32)] = &[
+    ("", "", 0),
+    ("kibi", "Ki", 1),
+    ("mebi", "Mi", 2),
+    ("gibi", "Gi", 3),
+];
+
 /// Stores measurement units and enables lookup by ID.
 pub struct UnitRegistry {
     units: HashMap<String, MeasurementUnit>,
@@ -33,14 +52,101 @@ impl UnitRegistry {
             .ok_or_else(|| format!("unknown unit id: '{id}'"))
     }
 
+    /// Returns all registered units compatible with `unit` (including `unit`
+    /// itself if registered), ordered from finest to coarsest (the order used by
+    /// [`finer`](crate::finer)).
+    ///
+    /// Units with equal granularity are ordered by ID so the result is deterministic.
+    pub fn compatible_units(&self, unit: &MeasurementUnit) -> Vec<&MeasurementUnit> {
+        let mut result: Vec<&MeasurementUnit> = self
+            .units
+            .values()
+            .filter(|u| is_compatible(u, unit))
+            .collect();
+        result.sort_by(|a, b| {
+            a.base_units()
+                .cmp(&b.base_units())
+                .then_with(|| a.id().cmp(b.id()))
+        });
+        result
+    }
+
+    /// Registers a unit family with SI decimal prefixes.
+    ///
+    /// Creates seven units in family `base`: nano, micro, milli, the base unit,
+    /// kilo, mega, and giga. Unit IDs are the prefix symbol followed by
+    /// `base_symbol`, with `u` standing in for micro (e.g., for `("second", "s")`:
+    /// `ns`, `us`, `ms`, `s`, `ks`, `Ms`, `Gs`). Abbreviations use `µ` for micro;
+    /// full names are the prefix name followed by `base` (e.g., `millisecond`).
+    /// The nano unit is the family's base (`base_units = 1`).
+    ///
+    /// Returns an error (and registers nothing) if any generated ID already exists.
+    pub fn register_si_family(&mut self, base: &str, base_symbol: &str) -> Result<(), String> {
+        let units = SI_PREFIXES
+            .iter()
+            .map(|&(name, id_symbol, symbol, power)| {
+                MeasurementUnit::new(
+                    format!("{id_symbol}{base_symbol}"),
+                    base,
+                    format!("{symbol}{base_symbol}"),
+                    format!("{name}{base}"),
+                    10_i64.pow(power),
+                )
+            })
+            .collect();
+        self.register_all(units)
+    }
+
+    /// Registers a unit family with IEC binary (1024-based) prefixes.
+    ///
+    /// Creates four units in family `base`: the base unit, kibi, mebi, and gibi.
+    /// Unit IDs and abbreviations are the prefix symbol followed by `base_symbol`
+    /// (e.g., for `("byte", "B")`: `B`, `KiB`, `MiB`, `GiB`); full names are the
+    /// prefix name followed by `base` (e.g., `kibibyte`).
+    ///
+    /// Returns an error (and registers nothing) if any generated ID already exists.
+    pub fn register_binary_family(&mut self, base: &str, base_symbol: &str) -> Result<(), String> {
+        let units = BINARY_PREFIXES
+            .iter()
+            .map(|&(name, symbol, power)| {
+                let id = format!("{symbol}{base_symbol}");
+                MeasurementUnit::new(
+                    id.clone(),
+                    base,
+                    id,
+                    format!("{name}{base}"),
+                    1024_i64.pow(power),
+                )
+            })
+            .collect();
+        self.register_all(units)
+    }
+
+    /// Registers all units, or none if any ID is already taken.
+    fn register_all(&mut self, units: Vec<MeasurementUnit>) -> Result<(), String> {
+        if let Some(taken) = units.iter().find(|u| self.units.contains_key(u.id())) {
+            return Err(format!(
+                "unit with id '{}' is already registered",
+                taken.id()
+            ));
+        }
+        for unit in units {
+            self.register(unit)?;
+        }
+        Ok(())
+    }
+
     /// Returns a registry pre-populated with the standard units
-    /// (number, ratio, disparity).
+    /// (number, percent, ppm, ratio, disparity, probability).
     pub fn standard() -> Self {
         let mut r = Self::new();
         // Standard units are guaranteed unique; unwrap is safe.
         r.register(MeasurementUnit::number()).unwrap();
+        r.register(MeasurementUnit::percent()).unwrap();
+        r.register(MeasurementUnit::parts_per_million()).unwrap();
         r.register(MeasurementUnit::ratio()).unwrap();
         r.register(MeasurementUnit::disparity()).unwrap();
+        r.register(MeasurementUnit::probability()).unwrap();
         r
     }
 }
@@ -54,6 +160,7 @@ impl Default for UnitRegistry {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::measurement_unit::conversion_factor;
 
     #[test]
     fn register_and_resolve() {
@@ -78,11 +185,97 @@ mod tests {
     }
 
     #[test]
-    fn standard_contains_all_three() {
+    fn standard_contains_builtin_units() {
         let r = UnitRegistry::standard();
         assert!(r.resolve("number").is_ok());
+        assert!(r.resolve("percent").is_ok());
+        assert!(r.resolve("ppm").is_ok());
         assert!(r.resolve("ratio").is_ok());
         assert!(r.resolve("disparity").is_ok());
+        assert!(r.resolve("probability").is_ok());
+    }
+
+    #[test]
+    fn si_family_kilo_to_milli() {
+        let mut r = UnitRegistry::new();
+        r.register_si_family("meter", "m").unwrap();
+        let km = r.resolve("km").unwrap();
+        let mm = r.resolve("mm").unwrap();
+        assert_eq!(km.family(), "meter");
+        assert_eq!(km.full_name(), "kilometer");
+        assert!((conversion_factor(km, mm) - 1_000_000.0).abs() < 1e-9);
+        assert!((conversion_factor(mm, km) - 1e-6).abs() < 1e-18);
+    }
+
+    #[test]
+    fn si_family_generated_units() {
+        let mut r = UnitRegistry::new();
+        r.register_si_family("second", "s").unwrap();
+        for id in ["ns", "us", "ms", "s", "ks", "Ms", "Gs"] {
+            assert_eq!(r.resolve(id).unwrap().family(), "second");
+        }
+        let us = r.resolve("us").unwrap();
+        assert_eq!(us.abbreviation(), "µs");
+        assert_eq!(us.full_name(), "microsecond");
+        assert_eq!(r.resolve("s").unwrap().full_name(), "second");
+        assert!(
+            (conversion_factor(r.resolve("Gs").unwrap(), r.resolve("ns").unwrap()) - 1e18).abs()
+                < 1.0
+        );
+    }
+
+    #[test]
+    fn binary_family_generated_units() {
+        let mut r = UnitRegistry::new();
+        r.register_binary_family("byte", "B").unwrap();
+        let gib = r.resolve("GiB").unwrap();
+        let kib = r.resolve("KiB").unwrap();
+        assert_eq!(gib.full_name(), "gibibyte");
+        assert!((conversion_factor(gib, kib) - 1_048_576.0).abs() < 1e-9);
+        assert!((conversion_factor(kib, r.resolve("B").unwrap()) - 1024.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn family_registration_is_atomic() {
+        let mut r = UnitRegistry::new();
+        r.register(MeasurementUnit::new(
+            "ms",
+            "Time",
+            "ms",
+            "Millisecond",
+            1_000_000,
+        ))
+        .unwrap();
+        assert!(r.register_si_family("second", "s").is_err());
+        assert!(r.resolve("ns").is_err());
+    }
+
+    #[test]
+    fn compatible_units_stay_within_family() {
+        let mut r = UnitRegistry::new();
+        r.register_si_family("second", "s").unwrap();
+        r.register_binary_family("byte", "B").unwrap();
+
+        let ms = r.resolve("ms").unwrap().clone();
+        let ids: Vec<&str> = r.compatible_units(&ms).iter().map(|u| u.id()).collect();
+        assert_eq!(ids, vec!["ns", "us", "ms", "s", "ks", "Ms", "Gs"]);
+
+        let kib = r.resolve("KiB").unwrap().clone();
+        let ids: Vec<&str> = r.compatible_units(&kib).iter().map(|u| u.id()).collect();
+        assert_eq!(ids, vec!["B", "KiB", "MiB", "GiB"]);
+
+        assert!(r.compatible_units(&MeasurementUnit::number()).is_empty());
+    }
+
+    #[test]
+    fn standard_fractions_are_compatible() {
+        let r = UnitRegistry::standard();
+        let ids: Vec<&str> = r
+            .compatible_units(&MeasurementUnit::percent())
+            .iter()
+            .map(|u| u.id())
+            .collect();
+        assert_eq!(ids, vec!["ppm", "percent", "number"]);
     }
 
     #[test]
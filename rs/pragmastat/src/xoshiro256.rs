@@ -1,11 +1,29 @@
 //! xoshiro256++ PRNG
 //! Reference: https://prng.di.unimi.it/xoshiro256plusplus.c
 //!
-//! This is the jump-free version of the algorithm. It passes BigCrush
-//! and is used by .NET 6+, Julia, and Rust's rand crate.
+//! Passes BigCrush and is used by .NET 6+, Julia, and Rust's rand crate.
+//! Includes the reference jump (2^128 steps) and long-jump (2^192 steps)
+//! functions for splitting the stream into non-overlapping substreams.
 
 use crate::splitmix64::SplitMix64;
 
+/// Jump polynomial for 2^128 steps (from the reference implementation).
+const JUMP: [u64; 4] = [
+    0x180e_c6d3_3cfd_0aba,
+    0xd5a6_1266_f0c9_392c,
+    0xa958_2618_e03f_c9aa,
+    0x39ab_dc45_29b1_661c,
+];
+
+/// Jump polynomial for 2^192 steps (from the reference implementation).
+const LONG_JUMP: [u64; 4] = [
+    0x76e1_5d3e_fefd_cbbf,
+    0xc500_4e44_1c52_2fb3,
+    0x7771_0069_854e_e241,
+    0x3910_9bb0_2acb_e635,
+];
+
+#[derive(Clone)]
 pub(crate) struct Xoshiro256PlusPlus {
     state: [u64; 4],
 }
@@ -20,6 +38,16 @@ impl Xoshiro256PlusPlus {
         }
     }
 
+    /// Create a generator directly from a raw state (no SplitMix64 expansion)
+    pub fn from_state(state: [u64; 4]) -> Self {
+        Self { state }
+    }
+
+    /// Raw internal state
+    pub fn state(&self) -> [u64; 4] {
+        self.state
+    }
+
     /// Generate the next 64-bit random value
     #[inline]
     pub fn next_u64(&mut self) -> u64 {
@@ -40,6 +68,31 @@ impl Xoshiro256PlusPlus {
         result
     }
 
+    /// Advance the state by 2^128 steps
+    pub fn jump(&mut self) {
+        self.apply_jump(&JUMP);
+    }
+
+    /// Advance the state by 2^192 steps
+    pub fn long_jump(&mut self) {
+        self.apply_jump(&LONG_JUMP);
+    }
+
+    fn apply_jump(&mut self, polynomial: &[u64; 4]) {
+        let mut state = [0u64; 4];
+        for &word in polynomial {
+            for bit in 0..64 {
+                if word & (1u64 << bit) != 0 {
+                    for (s, v) in state.iter_mut().zip(&self.state) {
+                        *s ^= v;
+                    }
+                }
+                self.next_u64();
+            }
+        }
+        self.state = state;
+    }
+
     /// Generate a uniform f64 in [0, 1)
     /// Uses the upper 53 bits for maximum precision
     #[inline]
@@ -209,7 +262,7 @@ mod tests {
 
         for _ in 0..1000 {
             let v = rng.uniform_f64();
-            assert!(v >= 0.0 && v < 1.0);
+            assert!((0.0..1.0).contains(&v));
         }
     }
 
@@ -219,7 +272,7 @@ mod tests {
 
         for _ in 0..1000 {
             let v = rng.uniform_f64_range(-5.0, 5.0);
-            assert!(v >= -5.0 && v < 5.0);
+            assert!((-5.0..5.0).contains(&v));
         }
     }
 
@@ -229,7 +282,7 @@ mod tests {
 
         for _ in 0..1000 {
             let v = rng.uniform_f32();
-            assert!(v >= 0.0 && v < 1.0);
+            assert!((0.0..1.0).contains(&v));
         }
     }
 
@@ -239,7 +292,7 @@ mod tests {
 
         for _ in 0..1000 {
             let v = rng.uniform_f32_range(-5.0, 5.0);
-            assert!(v >= -5.0 && v < 5.0);
+            assert!((-5.0..5.0).contains(&v));
         }
     }
 
@@ -249,7 +302,7 @@ mod tests {
 
         for _ in 0..1000 {
             let v = rng.uniform_i64(10, 20);
-            assert!(v >= 10 && v < 20);
+            assert!((10..20).contains(&v));
         }
     }
 
@@ -259,7 +312,7 @@ mod tests {
 
         for _ in 0..1000 {
             let v = rng.uniform_i64(-10, 10);
-            assert!(v >= -10 && v < 10);
+            assert!((-10..10).contains(&v));
         }
     }
 
@@ -269,7 +322,7 @@ mod tests {
 
         for _ in 0..1000 {
             let v = rng.uniform_i32(-100, 100);
-            assert!(v >= -100 && v < 100);
+            assert!((-100..100).contains(&v));
         }
     }
 
@@ -279,7 +332,7 @@ mod tests {
 
         for _ in 0..1000 {
             let v = rng.uniform_i16(-100, 100);
-            assert!(v >= -100 && v < 100);
+            assert!((-100..100).contains(&v));
         }
     }
 
@@ -289,7 +342,7 @@ mod tests {
 
         for _ in 0..1000 {
             let v = rng.uniform_i8(-50, 50);
-            assert!(v >= -50 && v < 50);
+            assert!((-50..50).contains(&v));
         }
     }
 
@@ -299,7 +352,7 @@ mod tests {
 
         for _ in 0..1000 {
             let v = rng.uniform_u64(10, 100);
-            assert!(v >= 10 && v < 100);
+            assert!((10..100).contains(&v));
         }
     }
 
@@ -309,7 +362,7 @@ mod tests {
 
         for _ in 0..1000 {
             let v = rng.uniform_u32(10, 100);
-            assert!(v >= 10 && v < 100);
+            assert!((10..100).contains(&v));
         }
     }
 
@@ -319,7 +372,7 @@ mod tests {
 
         for _ in 0..1000 {
             let v = rng.uniform_u16(10, 100);
-            assert!(v >= 10 && v < 100);
+            assert!((10..100).contains(&v));
         }
     }
 
@@ -329,7 +382,7 @@ mod tests {
 
         for _ in 0..1000 {
             let v = rng.uniform_u8(10, 100);
-            assert!(v >= 10 && v < 100);
+            assert!((10..100).contains(&v));
         }
     }
 
@@ -0,0 +1,186 @@
+//! Oracle tests for the pairwise-difference algorithms.
+//!
+//! `Algorithm::FastSelect` must agree bit-for-bit with the `Algorithm::Naive`
+//! materialization on randomized inputs, including heavily tied ones (many
+//! identical pairwise differences), and `Algorithm::Auto` must agree with both.
+
+use pragmastat::estimators::raw;
+use pragmastat::{shift, shift_with, spread, spread_with, Algorithm, Rng, Sample};
+
+const ALGORITHMS: [Algorithm; 3] = [Algorithm::Auto, Algorithm::FastSelect, Algorithm::Naive];
+
+/// Draws `n` values; `levels > 0` rounds them onto that many distinct levels.
+fn draw(rng: &mut Rng, n: usize, levels: usize) -> Vec<f64> {
+    (0..n)
+        .map(|_| {
+            if levels > 0 {
+                rng.uniform_i64(0, levels as i64) as f64 * 0.5
+            } else {
+                rng.uniform_f64_range(-50.0, 50.0)
+            }
+        })
+        .collect()
+}
+
+const CASES: [(usize, usize); 8] = [
+    (1, 0),
+    (2, 0),
+    (7, 0),
+    (40, 0),
+    (173, 0),
+    (30, 3),
+    (120, 2),
+    (300, 5),
+];
+
+#[test]
+fn shift_fast_select_matches_naive() {
+    let mut rng = Rng::from_string("algorithm-shift");
+    for _ in 0..10 {
+        for &(n, levels) in &CASES {
+            for &(m, _) in &CASES[..5] {
+                let x = draw(&mut rng, n, levels);
+                let y = draw(&mut rng, m.max(1), levels);
+                let naive = raw::shift_with(&x, &y, Algorithm::Naive, false).unwrap();
+                for algorithm in ALGORITHMS {
+                    let actual = raw::shift_with(&x, &y, algorithm, false).unwrap();
+                    assert_eq!(
+                        actual.to_bits(),
+                        naive.to_bits(),
+                        "{algorithm:?}: n={n}, m={m}, levels={levels}"
+                    );
+                }
+                assert_eq!(
+                    raw::shift(&x, &y, false).unwrap().to_bits(),
+                    naive.to_bits()
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn shift_of_identical_samples_is_zero() {
+    let mut rng = Rng::from_string("algorithm-shift-identical");
+    for &(n, levels) in &CASES {
+        let x = draw(&mut rng, n, levels);
+        let copy = x.clone();
+        let naive = raw::shift_with(&x, &copy, Algorithm::Naive, false).unwrap();
+        assert_eq!(naive, 0.0, "n={n}, levels={levels}");
+        for algorithm in ALGORITHMS {
+            let same = raw::shift_with(&x, &x, algorithm, false).unwrap();
+            let equal = raw::shift_with(&x, &copy, algorithm, false).unwrap();
+            assert_eq!(same.to_bits(), naive.to_bits(), "{algorithm:?}: n={n}");
+            assert_eq!(equal.to_bits(), naive.to_bits(), "{algorithm:?}: n={n}");
+        }
+        let mut reversed = copy;
+        reversed.reverse();
+        assert_eq!(raw::shift(&x, &reversed, false).unwrap().to_bits(), 0);
+        let s = Sample::new(x).unwrap();
+        assert_eq!(shift(&s, &s).unwrap().value.to_bits(), 0);
+    }
+}
+
+#[test]
+fn shift_of_near_identical_samples_is_not_short_circuited() {
+    let mut rng = Rng::from_string("algorithm-shift-near-identical");
+    for &(n, levels) in &CASES[1..] {
+        let x = draw(&mut rng, n, levels);
+        for i in [0, n / 2, n - 1] {
+            let mut y = x.clone();
+            y[i] = y[i].next_up();
+            let naive = raw::shift_with(&x, &y, Algorithm::Naive, false).unwrap();
+            for algorithm in ALGORITHMS {
+                let actual = raw::shift_with(&x, &y, algorithm, false).unwrap();
+                assert_eq!(
+                    actual.to_bits(),
+                    naive.to_bits(),
+                    "{algorithm:?}: n={n}, i={i}"
+                );
+            }
+        }
+    }
+    // A one-ulp nudge of a two-point sample moves the median difference off zero
+    let x = [1.0, 2.0];
+    let y = [1.0, 2f64.next_up()];
+    assert!(raw::shift(&x, &y, false).unwrap() < 0.0);
+    assert_eq!(
+        raw::shift(&[1.0], &[1f64.next_down()], false).unwrap(),
+        f64::EPSILON / 2.0
+    );
+}
+
+#[test]
+fn spread_fast_select_matches_naive() {
+    let mut rng = Rng::from_string("algorithm-spread");
+    for _ in 0..20 {
+        for &(n, levels) in &CASES[1..] {
+            let x = draw(&mut rng, n, levels);
+            let naive = match raw::spread_with(&x, Algorithm::Naive, false) {
+                Ok(v) => v,
+                Err(_) => {
+                    // Tie-dominant draws fail sparity under every algorithm.
+                    for algorithm in ALGORITHMS {
+                        assert!(raw::spread_with(&x, algorithm, false).is_err());
+                    }
+                    continue;
+                }
+            };
+            for algorithm in ALGORITHMS {
+                let actual = raw::spread_with(&x, algorithm, false).unwrap();
+                assert_eq!(
+                    actual.to_bits(),
+                    naive.to_bits(),
+                    "{algorithm:?}: n={n}, levels={levels}"
+                );
+            }
+            assert_eq!(raw::spread(&x, false).unwrap().to_bits(), naive.to_bits());
+        }
+    }
+}
+
+#[test]
+fn naive_known_values() {
+    // Pairwise differences x - y: {-2, -1, -1, 0, 0, 1}; median = (-1 + 0) / 2
+    let shift = raw::shift_with(&[1.0, 2.0, 3.0], &[2.0, 3.0], Algorithm::Naive, false).unwrap();
+    assert_eq!(shift, -0.5);
+    // |x[i] - x[j]| over {1, 2, 4, 8}: {1, 2, 3, 4, 6, 7}; median = (3 + 4) / 2
+    let spread = raw::spread_with(&[8.0, 1.0, 4.0, 2.0], Algorithm::Naive, false).unwrap();
+    assert_eq!(spread, 3.5);
+}
+
+#[test]
+fn auto_handles_large_heavily_tied_input() {
+    let mut rng = Rng::from_string("algorithm-auto-tied");
+    let x = draw(&mut rng, 600, 4);
+    let y = draw(&mut rng, 700, 4);
+    let fast = raw::shift_with(&x, &y, Algorithm::FastSelect, false).unwrap();
+    let auto = raw::shift_with(&x, &y, Algorithm::Auto, false).unwrap();
+    assert_eq!(fast.to_bits(), auto.to_bits());
+}
+
+#[test]
+fn sample_api_matches_default_estimators() {
+    let mut rng = Rng::from_string("algorithm-sample");
+    let x = Sample::new(draw(&mut rng, 50, 0)).unwrap();
+    let y = Sample::new(draw(&mut rng, 60, 0)).unwrap();
+    for algorithm in ALGORITHMS {
+        assert_eq!(
+            shift_with(&x, &y, algorithm).unwrap().value,
+            shift(&x, &y).unwrap().value
+        );
+        assert_eq!(
+            spread_with(&x, algorithm).unwrap().value,
+            spread(&x).unwrap().value
+        );
+    }
+}
+
+#[test]
+fn validation_is_shared_across_algorithms() {
+    for algorithm in ALGORITHMS {
+        assert!(raw::shift_with(&[], &[1.0], algorithm, false).is_err());
+        assert!(raw::shift_with(&[f64::NAN], &[1.0], algorithm, false).is_err());
+        assert!(raw::spread_with(&[3.0; 5], algorithm, false).is_err());
+    }
+}
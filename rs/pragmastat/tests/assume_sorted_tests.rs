@@ -180,3 +180,42 @@ fn center_n2_midpoint_is_order_symmetric() {
     assert_eq!(reversed, -3.4);
     assert_eq!(forward, reversed); // bit-exact, not approx
 }
+
+// --- `*_sorted` entry points: same result as the sorting API, bit for bit ---
+
+#[test]
+fn center_sorted_equals_center_unsorted() {
+    let x = unsorted_x();
+    let got = raw::center_sorted(&sorted_copy(&x)).unwrap();
+    assert_eq!(got, raw::center(&x, false).unwrap());
+}
+
+#[test]
+fn spread_sorted_equals_spread_unsorted() {
+    let x = unsorted_x();
+    let got = raw::spread_sorted(&sorted_copy(&x)).unwrap();
+    assert_eq!(got, raw::spread(&x, false).unwrap());
+}
+
+#[test]
+fn shift_sorted_equals_shift_unsorted() {
+    let x = unsorted_x();
+    let y = unsorted_y();
+    let got = raw::shift_sorted(&sorted_copy(&x), &sorted_copy(&y)).unwrap();
+    assert_eq!(got, raw::shift(&x, &y, false).unwrap());
+}
+
+#[test]
+fn sorted_entry_points_validate_input() {
+    assert!(raw::center_sorted(&[]).is_err());
+    assert!(raw::spread_sorted(&[1.0, f64::NAN]).is_err());
+    assert!(raw::spread_sorted(&[2.0, 2.0, 2.0]).is_err()); // sparity
+    assert!(raw::shift_sorted(&[1.0], &[]).is_err());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "sorted ascending")]
+fn spread_sorted_rejects_unsorted_in_debug() {
+    let _ = raw::spread_sorted(&unsorted_x());
+}
@@ -0,0 +1,201 @@
+//! Tests for custom checks added with `register_assumption`.
+//!
+//! The registry is process-wide and tests run in parallel, so every check here
+//! only fires on inputs containing its own marker value.
+
+use pragmastat::estimators::raw;
+use pragmastat::{
+    center, check_all, register_assumption, AssumptionError, AssumptionId, EstimatorError,
+    EstimatorId, Sample, Severity, Subject, Violation,
+};
+
+fn ids(violations: &[Violation]) -> Vec<AssumptionId> {
+    violations.iter().map(|v| v.id).collect()
+}
+
+fn failed(result: Result<impl std::fmt::Debug, EstimatorError>) -> (AssumptionId, Subject) {
+    match result.unwrap_err() {
+        EstimatorError::Assumption(e) => (e.violation().id, e.violation().subject),
+        other => panic!("expected an assumption error, got {other:?}"),
+    }
+}
+
+#[test]
+fn custom_check_fires() {
+    let _check = register_assumption(AssumptionId::Domain, |x| {
+        x.contains(&-1234.0)
+            .then(|| Violation::new(AssumptionId::Domain, Subject::X))
+    });
+
+    let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+    assert!(check_all(&x, Subject::X).is_empty());
+
+    let marked = [1.0, 2.0, -1234.0, 4.0, 5.0];
+    let violations = check_all(&marked, Subject::Y);
+    // Sorted by canonical priority: domain before the built-in positivity
+    assert_eq!(
+        ids(&violations),
+        vec![AssumptionId::Domain, AssumptionId::Positivity]
+    );
+    assert!(violations.iter().all(|v| v.subject == Subject::Y));
+    assert_eq!(
+        AssumptionError::aggregate(&violations)
+            .unwrap()
+            .violation()
+            .id,
+        AssumptionId::Domain
+    );
+}
+
+#[test]
+fn custom_check_keeps_its_severity() {
+    let _check = register_assumption(AssumptionId::Sparity, |x| {
+        x.contains(&4321.0).then(|| {
+            Violation::new(AssumptionId::Sparity, Subject::X).with_severity(Severity::Warning)
+        })
+    });
+
+    let violations = check_all(&[1.0, 2.0, 4321.0], Subject::X);
+    assert_eq!(ids(&violations), vec![AssumptionId::Sparity]);
+    assert_eq!(violations[0].severity, Severity::Warning);
+}
+
+#[test]
+fn custom_check_runs_after_builtin_of_same_id() {
+    let _check = register_assumption(AssumptionId::Validity, |x| {
+        x.contains(&9876.0).then(|| {
+            Violation::new(AssumptionId::Validity, Subject::X).with_severity(Severity::Warning)
+        })
+    });
+
+    let violations = check_all(&[f64::NAN, 1.0, 9876.0], Subject::X);
+    assert_eq!(
+        ids(&violations),
+        vec![AssumptionId::Validity, AssumptionId::Validity]
+    );
+    assert_eq!(violations[0].severity, Severity::Error);
+    assert_eq!(violations[1].severity, Severity::Warning);
+}
+
+#[test]
+fn dropping_the_registration_unregisters_the_check() {
+    let marked = [1.0, 2.0, 5555.0];
+    let check = || {
+        register_assumption(AssumptionId::Domain, |x| {
+            x.contains(&5555.0)
+                .then(|| Violation::new(AssumptionId::Domain, Subject::X))
+        })
+    };
+
+    let registration = check();
+    assert_eq!(
+        ids(&check_all(&marked, Subject::X)),
+        vec![AssumptionId::Domain]
+    );
+    drop(registration);
+    assert!(check_all(&marked, Subject::X).is_empty());
+
+    // Registering twice runs the check twice; each registration removes only
+    // its own copy
+    let first = check();
+    let second = check();
+    assert_eq!(check_all(&marked, Subject::X).len(), 2);
+    drop(first);
+    assert_eq!(check_all(&marked, Subject::X).len(), 1);
+    second.keep();
+    assert_eq!(check_all(&marked, Subject::X).len(), 1);
+}
+
+#[test]
+fn min_sample_size_is_a_registered_builtin() {
+    let violations = check_all(&[7.0], Subject::Y);
+    assert_eq!(
+        ids(&violations),
+        vec![AssumptionId::Domain, AssumptionId::Sparity]
+    );
+    assert!(violations.iter().all(|v| v.subject == Subject::Y));
+}
+
+#[test]
+fn custom_check_rejects_estimator_input() {
+    let check = register_assumption(AssumptionId::Domain, |x| {
+        x.contains(&2468.0)
+            .then(|| Violation::new(AssumptionId::Domain, Subject::X))
+    });
+
+    let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let marked = [1.0, 2.0, 2468.0, 4.0, 5.0];
+    assert_eq!(
+        failed(raw::center(&marked, false)),
+        (AssumptionId::Domain, Subject::X)
+    );
+    assert_eq!(
+        failed(raw::shift(&x, &marked, false)),
+        (AssumptionId::Domain, Subject::Y)
+    );
+    assert_eq!(
+        failed(raw::center_bounds(&marked, 0.1, false)),
+        (AssumptionId::Domain, Subject::X)
+    );
+    let error = center(&Sample::new(marked.to_vec()).unwrap()).unwrap_err();
+    match error {
+        EstimatorError::Assumption(e) => assert_eq!(e.estimator(), Some(EstimatorId::Center)),
+        other => panic!("expected an assumption error, got {other:?}"),
+    }
+    // Validity is still checked first
+    assert_eq!(
+        failed(raw::shift(&marked, &[f64::NAN], false)),
+        (AssumptionId::Validity, Subject::Y)
+    );
+
+    drop(check);
+    assert!(raw::center(&marked, false).is_ok());
+}
+
+#[test]
+fn custom_check_only_concerns_its_estimators() {
+    let _check = register_assumption(AssumptionId::Positivity, |x| {
+        x.contains(&1357.0)
+            .then(|| Violation::new(AssumptionId::Positivity, Subject::X))
+    });
+
+    let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let marked = [1.0, 2.0, 1357.0, 4.0, 5.0];
+    assert_eq!(
+        failed(raw::ratio(&marked, &x, false)),
+        (AssumptionId::Positivity, Subject::X)
+    );
+    assert_eq!(
+        failed(raw::ratio_bounds(&x, &marked, 0.5, false)),
+        (AssumptionId::Positivity, Subject::Y)
+    );
+    assert!(raw::shift(&marked, &x, false).is_ok());
+    assert!(raw::center(&marked, false).is_ok());
+}
+
+#[test]
+fn custom_warning_does_not_reject_estimator_input() {
+    let _check = register_assumption(AssumptionId::Domain, |x| {
+        x.contains(&8642.0).then(|| {
+            Violation::new(AssumptionId::Domain, Subject::X).with_severity(Severity::Warning)
+        })
+    });
+
+    assert!(raw::center(&[1.0, 2.0, 8642.0], false).is_ok());
+}
+
+#[test]
+fn builtin_checks_reject_estimator_input_through_the_registry() {
+    assert_eq!(
+        failed(raw::center_bounds(&[7.0], 0.5, false)),
+        (AssumptionId::Domain, Subject::X)
+    );
+    assert_eq!(
+        failed(raw::spread_bounds(&[7.0], 0.5, false)),
+        (AssumptionId::Sparity, Subject::X)
+    );
+    assert_eq!(
+        failed(raw::ratio(&[1.0, 2.0], &[0.0, 1.0], false)),
+        (AssumptionId::Positivity, Subject::Y)
+    );
+}
@@ -0,0 +1,111 @@
+//! Tests for the `Sample::audit` and `audit_pair` pre-flight reports.
+
+use pragmastat::measurement_unit::MeasurementUnit;
+use pragmastat::{audit_pair, AssumptionId, Diagnostic, EstimatorId, Sample, Severity, Subject};
+
+fn ids(findings: &[Diagnostic]) -> Vec<(AssumptionId, Subject)> {
+    findings
+        .iter()
+        .map(|d| {
+            let v = d.violation().expect("an assumption violation");
+            (v.id, v.subject)
+        })
+        .collect()
+}
+
+#[test]
+fn clean_sample_passes() {
+    let x = Sample::new(vec![1.2, 3.4, 2.2, 5.9, 4.1]).unwrap();
+    assert!(x.audit().is_empty());
+}
+
+#[test]
+fn single_nonpositive_value_reports_every_violation() {
+    let x = Sample::new(vec![-1.0]).unwrap();
+    let violations = x.audit();
+    assert_eq!(
+        ids(&violations),
+        vec![
+            (AssumptionId::Domain, Subject::X),
+            (AssumptionId::Positivity, Subject::X),
+            (AssumptionId::Sparity, Subject::X),
+        ]
+    );
+
+    let too_small = violations[0].blocks();
+    assert!(too_small.contains(&EstimatorId::CenterBounds));
+    assert!(too_small.contains(&EstimatorId::Spread));
+    assert!(!too_small.contains(&EstimatorId::Center));
+    assert!(!too_small.contains(&EstimatorId::Shift));
+
+    assert_eq!(
+        violations[1].blocks(),
+        vec![EstimatorId::Ratio, EstimatorId::RatioBounds]
+    );
+    assert!(violations[2].blocks().contains(&EstimatorId::Disparity));
+    assert!(violations.iter().all(|v| v.degrades().is_empty()));
+}
+
+#[test]
+fn ties_only_degrade_bounds() {
+    let x = Sample::new(vec![1.0, 1.0, 1.0, 2.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+    let findings = x.audit();
+    assert_eq!(findings.len(), 1);
+    match &findings[0] {
+        Diagnostic::Ties { subject, report } => {
+            assert_eq!(*subject, Subject::X);
+            assert_eq!((report.n, report.distinct, report.max_tie_run), (8, 5, 3));
+        }
+        other => panic!("expected a ties warning, got {other:?}"),
+    }
+    assert_eq!(findings[0].severity(), Severity::Warning);
+    assert!(findings[0].blocks().is_empty());
+    let degraded = findings[0].degrades();
+    assert!(degraded.iter().all(|e| e.is_bounds()));
+    assert!(degraded.contains(&EstimatorId::CenterBounds));
+}
+
+#[test]
+fn pair_reports_both_subjects_and_units() {
+    let ms = MeasurementUnit::new("ms", "Time", "ms", "Millisecond", 1_000_000);
+    let x = Sample::new(vec![0.0, 2.0, 3.0]).unwrap();
+    let y = Sample::with_unit(vec![5.0], ms).unwrap();
+    let mut violations = audit_pair(&x, &y);
+    let mismatch = violations.pop().unwrap();
+    assert_eq!(
+        ids(&violations),
+        vec![
+            (AssumptionId::Positivity, Subject::X),
+            (AssumptionId::Domain, Subject::Y),
+            (AssumptionId::Sparity, Subject::Y),
+        ]
+    );
+
+    // Violations of y only concern two-sample estimators
+    let y_small = violations[1].blocks();
+    assert_eq!(
+        y_small,
+        vec![
+            EstimatorId::Disparity,
+            EstimatorId::SpreadRatio,
+            EstimatorId::AvgSpread,
+            EstimatorId::DisparityBounds,
+            EstimatorId::SpreadRatioBounds,
+            EstimatorId::AvgSpreadBounds,
+        ]
+    );
+
+    // Units are not an assumption: a mismatch gets a diagnostic of its own
+    assert!(matches!(mismatch, Diagnostic::UnitMismatch(_)));
+    assert_eq!(mismatch.severity(), Severity::Error);
+    assert_eq!(mismatch.to_string(), "can't convert Number to Millisecond");
+    let blocked = mismatch.blocks();
+    assert!(!blocked.is_empty() && blocked.iter().all(|e| e.is_two_sample()));
+}
+
+#[test]
+fn compatible_pair_has_no_unit_violation() {
+    let x = Sample::new(vec![1.0, 2.0, 3.5]).unwrap();
+    let y = Sample::new(vec![4.0, 6.5, 5.0]).unwrap();
+    assert!(audit_pair(&x, &y).is_empty());
+}
@@ -0,0 +1,77 @@
+//! Tests for the exact and asymptotic center bounds methods.
+
+use pragmastat::distributions::{Additive, Distribution, Exp};
+use pragmastat::estimators::raw;
+use pragmastat::measurement_unit::MeasurementUnit;
+use pragmastat::{center_bounds, center_bounds_with_method, BoundsMethod, Rng, Sample};
+
+#[test]
+fn exact_method_is_center_bounds() {
+    assert_eq!(BoundsMethod::default(), BoundsMethod::Exact);
+    let mut rng = Rng::from_string("center-bounds-method-exact");
+    for n in [2, 10, 63, 64, 200] {
+        let x = Additive::new(5.0, 2.0).samples(&mut rng, n);
+        for misrate in [0.01, 0.1, 0.5] {
+            let Ok(expected) = raw::center_bounds(&x, misrate, false) else {
+                continue;
+            };
+            let actual =
+                raw::center_bounds_with_method(&x, misrate, BoundsMethod::Exact, false).unwrap();
+            assert_eq!(actual, expected, "n={n}, misrate={misrate}");
+        }
+    }
+}
+
+#[test]
+fn asymptotic_matches_exact_for_large_n() {
+    let cases: [(&str, &dyn Distribution); 2] = [
+        ("additive", &Additive::new(10.0, 2.0)),
+        ("exp", &Exp::new(0.5)),
+    ];
+    for (name, distribution) in cases {
+        let mut rng = Rng::from_string(&format!("center-bounds-method-{name}"));
+        let x = distribution.samples(&mut rng, 2000);
+        for misrate in [1e-3, 0.01, 0.05] {
+            let exact = raw::center_bounds(&x, misrate, false).unwrap();
+            let asymptotic =
+                raw::center_bounds_with_method(&x, misrate, BoundsMethod::Asymptotic, false)
+                    .unwrap();
+            // The normal tails are heavier, so the asymptotic bounds are wider
+            assert!(
+                asymptotic.lower <= exact.lower && exact.upper <= asymptotic.upper,
+                "{name}, misrate={misrate}"
+            );
+            let exact_width = exact.upper - exact.lower;
+            let extra = (asymptotic.upper - asymptotic.lower) / exact_width - 1.0;
+            assert!(extra < 0.01, "{name}, misrate={misrate}: {extra}");
+        }
+    }
+}
+
+#[test]
+fn asymptotic_is_wider_for_small_n() {
+    let mut rng = Rng::from_string("center-bounds-method-small");
+    let x = Additive::new(0.0, 1.0).samples(&mut rng, 20);
+    let exact = raw::center_bounds(&x, 1e-3, false).unwrap();
+    let asymptotic =
+        raw::center_bounds_with_method(&x, 1e-3, BoundsMethod::Asymptotic, false).unwrap();
+    assert!(asymptotic.lower < exact.lower && exact.upper < asymptotic.upper);
+}
+
+#[test]
+fn sample_api_keeps_unit_and_errors() {
+    let ms = MeasurementUnit::new("ms", "Time", "ms", "Millisecond", 1_000_000);
+    let x = Sample::with_unit((1..=30).map(f64::from).collect(), ms.clone()).unwrap();
+    for method in [BoundsMethod::Exact, BoundsMethod::Asymptotic] {
+        let bounds = center_bounds_with_method(&x, 0.05, method).unwrap();
+        assert_eq!(bounds.unit, ms);
+        let err = center_bounds_with_method(&x, 1e-12, method).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            center_bounds(&x, 1e-12).unwrap_err().to_string()
+        );
+    }
+    let exact = center_bounds_with_method(&x, 0.05, BoundsMethod::Exact).unwrap();
+    let default = center_bounds(&x, 0.05).unwrap();
+    assert_eq!((exact.lower, exact.upper), (default.lower, default.upper));
+}
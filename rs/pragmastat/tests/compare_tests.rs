@@ -120,35 +120,26 @@ fn verify_error(
     expected: &ExpectedError,
     is_sample_creation: bool,
 ) {
-    match err {
-        EstimatorError::Assumption(ae) => {
-            let violation = ae.violation();
-            if violation.id.as_str() != expected.id {
-                failures.push(format!(
-                    "{file_name:?}: expected violation id \"{}\", got \"{}\"",
-                    expected.id,
-                    violation.id.as_str()
-                ));
-            }
-            // Sample::new always uses Subject::X, so when the expected subject is "y"
-            // and the error originates from sample construction, the subject check would
-            // always fail. Skip it in that case only.
-            let skip_subject = is_sample_creation && expected.subject == "y";
-            if !skip_subject && violation.subject.as_str() != expected.subject {
-                failures.push(format!(
-                    "{file_name:?}: expected violation subject \"{}\", got \"{}\"",
-                    expected.subject,
-                    violation.subject.as_str()
-                ));
-            }
+    // Non-assumption errors (e.g. "thresholds list cannot be empty") are accepted as-is.
+    if let EstimatorError::Assumption(ae) = err {
+        let violation = ae.violation();
+        if violation.id.as_str() != expected.id {
+            failures.push(format!(
+                "{file_name:?}: expected violation id \"{}\", got \"{}\"",
+                expected.id,
+                violation.id.as_str()
+            ));
         }
-        _ => {
-            let err_str = err.to_string();
-            if expected.id == "validity"
-                && (err_str.contains("empty") || err_str.contains("validity"))
-            {
-                return;
-            }
+        // Sample::new always uses Subject::X, so when the expected subject is "y"
+        // and the error originates from sample construction, the subject check would
+        // always fail. Skip it in that case only.
+        let skip_subject = is_sample_creation && expected.subject == "y";
+        if !skip_subject && violation.subject.as_str() != expected.subject {
+            failures.push(format!(
+                "{file_name:?}: expected violation subject \"{}\", got \"{}\"",
+                expected.subject,
+                violation.subject.as_str()
+            ));
         }
     }
 }
@@ -0,0 +1,150 @@
+//! Tests for `EstimatorConfig` validation and its propagation through
+//! `Sample::with_config`.
+
+use pragmastat::estimators::DisparityBoundsOptions;
+use pragmastat::*;
+
+fn x() -> Sample {
+    Sample::new((1..=40).map(|i| (i * i % 37) as f64 + 1.0).collect()).unwrap()
+}
+
+fn y() -> Sample {
+    Sample::new((1..=30).map(|i| (i * i % 29) as f64 + 3.0).collect()).unwrap()
+}
+
+fn lu(bounds: Result<Bounds, EstimatorError>) -> (f64, f64) {
+    let bounds = bounds.unwrap();
+    (bounds.lower, bounds.upper)
+}
+
+fn config() -> EstimatorConfig {
+    EstimatorConfig::builder()
+        .misrate(0.01)
+        .bootstrap_iterations(5)
+        .seed("config-tests")
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn default_config_uses_crate_defaults() {
+    let config = EstimatorConfig::default();
+    assert_eq!(config.misrate(), DEFAULT_MISRATE);
+    assert_eq!(config.bootstrap_iterations(), 1);
+    assert_eq!(config.seed(), None);
+    assert_eq!(config.non_finite(), NonFinitePolicy::Reject);
+    assert_eq!(EstimatorConfig::builder().build().unwrap(), config);
+    assert_eq!(x().config(), &config);
+}
+
+#[test]
+fn invalid_misrate_is_rejected_at_construction() {
+    for misrate in [0.0, -0.1, 0.5000001, 1.0, f64::NAN] {
+        let err = EstimatorConfig::builder()
+            .misrate(misrate)
+            .build()
+            .unwrap_err();
+        match err {
+            EstimatorError::Assumption(e) => {
+                assert_eq!(e.violation().id, AssumptionId::Domain);
+                assert_eq!(e.violation().subject, Subject::Misrate);
+            }
+            other => panic!("unexpected error for {misrate}: {other}"),
+        }
+    }
+    assert!(EstimatorConfig::builder().misrate(0.5).build().is_ok());
+}
+
+#[test]
+fn zero_iterations_are_rejected_at_construction() {
+    let result = EstimatorConfig::builder().bootstrap_iterations(0).build();
+    assert!(matches!(result, Err(EstimatorError::Other(_))));
+}
+
+#[test]
+fn sample_methods_use_attached_config() {
+    let config = config();
+    let (x, y) = (x().with_config(config.clone()), y());
+
+    assert_eq!(lu(x.center_bounds()), lu(center_bounds(&x, 0.01)));
+    assert_eq!(
+        lu(x.spread_bounds()),
+        lu(spread_bounds_with_seed(&x, 0.01, "config-tests"))
+    );
+    assert_eq!(lu(x.shift_bounds(&y)), lu(shift_bounds(&x, &y, 0.01)));
+    assert_eq!(lu(x.ratio_bounds(&y)), lu(ratio_bounds(&x, &y, 0.01)));
+    assert_eq!(
+        lu(x.dominance_bounds(&y)),
+        lu(dominance_bounds(&x, &y, 0.01))
+    );
+    assert_eq!(
+        lu(x.disparity_bounds(&y)),
+        lu(disparity_bounds_with_seed(&x, &y, 0.01, "config-tests"))
+    );
+}
+
+#[test]
+fn disparity_options_take_config_replicates() {
+    let config = config();
+    let opts = DisparityBoundsOptions::from(&config);
+    assert_eq!(
+        opts,
+        DisparityBoundsOptions {
+            iterations: 5,
+            seed: Some("config-tests".to_string()),
+            misrate: 0.01,
+        }
+    );
+    let (x, y) = (x(), y());
+    let result = disparity_bounds_opts(&x, &y, &opts).unwrap();
+    assert_eq!(result.iterations, 5);
+    assert_eq!(
+        (result.bounds.lower, result.bounds.upper),
+        lu(disparity_bounds_with_config(&x, &y, &config))
+    );
+}
+
+#[test]
+fn plain_sample_uses_default_misrate() {
+    let x = x();
+    assert_eq!(
+        lu(x.center_bounds()),
+        lu(center_bounds(&x, DEFAULT_MISRATE))
+    );
+    // The configured misrate (0.01) is looser than the default, so its bounds are narrower
+    let looser = x.clone().with_config(config()).center_bounds().unwrap();
+    let default = x.center_bounds().unwrap();
+    assert!(looser.lower >= default.lower && looser.upper <= default.upper);
+}
+
+#[test]
+fn config_survives_derived_samples() {
+    let config = config();
+    let x = x().with_config(config.clone());
+    assert_eq!((&x * 2.0).unwrap().config(), &config);
+    assert_eq!(x.merge(&y()).unwrap().config(), &config);
+    let ms = MeasurementUnit::new("ms", "Time", "ms", "Millisecond", 1_000_000);
+    let us = MeasurementUnit::new("us", "Time", "us", "Microsecond", 1_000);
+    let timed = Sample::with_unit(vec![1.0, 2.0, 4.0], ms)
+        .unwrap()
+        .with_config(config.clone());
+    assert_eq!(timed.convert_to(&us).unwrap().config(), &config);
+}
+
+#[test]
+fn with_config_functions_match_explicit_calls() {
+    let config = config();
+    let (x, y) = (x(), y());
+    assert_eq!(
+        lu(center_bounds_with_config(&x, &config)),
+        lu(center_bounds(&x, 0.01))
+    );
+    assert_eq!(
+        lu(shift_bounds_with_config(&x, &y, &config)),
+        lu(shift_bounds(&x, &y, 0.01))
+    );
+    assert_eq!(
+        lu(spread_bounds_with_config(&x, &config)),
+        lu(spread_bounds_with_seed(&x, 0.01, "config-tests"))
+    );
+}
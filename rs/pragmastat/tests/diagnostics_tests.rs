@@ -0,0 +1,186 @@
+//! Tests for the ties diagnostic, the `*_with_diagnostics` bounds functions, and
+//! the `check_all` pre-flight check.
+
+use pragmastat::assumptions::TIE_FRACTION_THRESHOLD;
+use pragmastat::{
+    center_bounds, center_bounds_with_diagnostics, check_all, check_ties,
+    shift_bounds_with_diagnostics, spread_bounds_with_diagnostics, AssumptionError, AssumptionId,
+    Diagnostic, EstimatorError, Sample, Severity, Subject, TieReport, Violation,
+};
+
+fn continuous() -> Vec<f64> {
+    (1..=20).map(|i| f64::from(i) * 1.37).collect()
+}
+
+/// 20 values: 10 of them in ties (five pairs), 10 distinct singletons.
+fn half_tied() -> Vec<f64> {
+    let mut v: Vec<f64> = (1..=5).flat_map(|i| [f64::from(i), f64::from(i)]).collect();
+    v.extend((6..=15).map(f64::from));
+    v
+}
+
+#[test]
+fn continuous_sample_has_no_ties() {
+    let report = check_ties(&continuous());
+    assert_eq!(
+        report,
+        TieReport {
+            n: 20,
+            distinct: 20,
+            max_tie_run: 1,
+            tie_fraction: 0.0,
+        }
+    );
+    assert!(!report.is_heavily_tied());
+}
+
+#[test]
+fn half_tied_sample() {
+    let report = check_ties(&half_tied());
+    assert_eq!(report.n, 20);
+    assert_eq!(report.distinct, 15);
+    assert_eq!(report.max_tie_run, 2);
+    assert_eq!(report.tie_fraction, 0.5);
+    assert!(report.tie_fraction > TIE_FRACTION_THRESHOLD);
+    assert!(report.is_heavily_tied());
+}
+
+#[test]
+fn fully_tied_sample() {
+    let report = check_ties(&[3.0; 7]);
+    assert_eq!(report.distinct, 1);
+    assert_eq!(report.max_tie_run, 7);
+    assert_eq!(report.tie_fraction, 1.0);
+}
+
+#[test]
+fn empty_sample_report() {
+    let report = check_ties(&[]);
+    assert_eq!((report.n, report.distinct, report.max_tie_run), (0, 0, 0));
+    assert_eq!(report.tie_fraction, 0.0);
+}
+
+#[test]
+fn clean_data_has_no_warnings_and_identical_bounds() {
+    let x = Sample::new(continuous()).unwrap();
+    let plain = center_bounds(&x, 0.01).unwrap();
+    let diag = center_bounds_with_diagnostics(&x, 0.01).unwrap();
+    assert!(diag.warnings.is_empty());
+    assert_eq!(diag.value.lower, plain.lower);
+    assert_eq!(diag.value.upper, plain.upper);
+}
+
+#[test]
+fn tied_data_reports_warning_per_subject() {
+    let x = Sample::new(continuous()).unwrap();
+    let y = Sample::new(half_tied()).unwrap();
+    let diag = shift_bounds_with_diagnostics(&x, &y, 0.01).unwrap();
+    assert_eq!(
+        diag.warnings,
+        vec![Diagnostic::Ties {
+            subject: Subject::Y,
+            report: check_ties(&half_tied()),
+        }]
+    );
+    assert_eq!(diag.warnings[0].to_string(), "ties(y)");
+
+    let diag = center_bounds_with_diagnostics(&y, 0.01).unwrap();
+    assert_eq!(diag.warnings.len(), 1);
+    assert_eq!(diag.warnings[0].subject(), Subject::X);
+    assert_eq!(diag.warnings[0].severity(), Severity::Warning);
+    assert!(diag.warnings[0].violation().is_none());
+}
+
+#[test]
+fn fully_tied_data_keeps_strict_errors() {
+    // Ties never replace the existing hard failures: a fully tied sample still
+    // fails sparity for spread bounds.
+    let x = Sample::new(vec![3.0; 10]).unwrap();
+    match spread_bounds_with_diagnostics(&x, 0.5).unwrap_err() {
+        EstimatorError::Assumption(e) => assert_eq!(e.violation().id, AssumptionId::Sparity),
+        EstimatorError::Other(msg) => panic!("unexpected error: {msg}"),
+    }
+}
+
+fn ids(violations: &[Violation]) -> Vec<AssumptionId> {
+    violations.iter().map(|v| v.id).collect()
+}
+
+#[test]
+fn check_all_clean_sample() {
+    assert!(check_all(&continuous(), Subject::X).is_empty());
+}
+
+#[test]
+fn check_all_reports_every_violation() {
+    // Non-finite, non-positive, and mostly tied at once
+    let x = [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, f64::NAN];
+    let violations = check_all(&x, Subject::Y);
+    assert_eq!(
+        ids(&violations),
+        vec![
+            AssumptionId::Validity,
+            AssumptionId::Positivity,
+            AssumptionId::Sparity,
+        ]
+    );
+    assert!(violations.iter().all(|v| v.subject == Subject::Y));
+    assert!(violations.iter().all(|v| v.severity == Severity::Error));
+}
+
+#[test]
+fn check_all_ignores_ties() {
+    // Ties are a diagnostic, not an assumption
+    assert!(check_all(&half_tied(), Subject::X).is_empty());
+}
+
+#[test]
+fn check_all_empty_and_single() {
+    // Too small for the two-value estimators: the registered min-size check
+    assert_eq!(
+        ids(&check_all(&[], Subject::X)),
+        vec![AssumptionId::Validity, AssumptionId::Domain]
+    );
+    assert_eq!(
+        ids(&check_all(&[5.0], Subject::X)),
+        vec![AssumptionId::Domain, AssumptionId::Sparity]
+    );
+}
+
+#[test]
+fn aggregate_surfaces_highest_severity() {
+    let degraded =
+        Violation::new(AssumptionId::Validity, Subject::X).with_severity(Severity::Warning);
+    let positivity = Violation::new(AssumptionId::Positivity, Subject::X);
+    let sparity = Violation::new(AssumptionId::Sparity, Subject::Y);
+
+    let error = AssumptionError::aggregate(&[degraded, positivity, sparity]).unwrap();
+    assert_eq!(error.violation(), positivity);
+    assert_eq!(error.severity(), Severity::Error);
+    assert_eq!(error.others(), &[degraded, sparity]);
+
+    let warning = AssumptionError::aggregate(&[degraded]).unwrap();
+    assert_eq!(warning.severity(), Severity::Warning);
+    assert!(warning.others().is_empty());
+
+    assert!(AssumptionError::aggregate(&[]).is_none());
+
+    let x = [-1.0, -1.0, -1.0, f64::INFINITY];
+    let error = AssumptionError::aggregate(&check_all(&x, Subject::X)).unwrap();
+    assert_eq!(error.violation().id, AssumptionId::Validity);
+    assert_eq!(
+        ids(error.others()),
+        vec![AssumptionId::Positivity, AssumptionId::Sparity]
+    );
+}
+
+#[test]
+fn severity_ordering() {
+    assert!(Severity::Warning < Severity::Error);
+    assert_eq!(AssumptionId::Domain.severity(), Severity::Error);
+    assert_eq!(AssumptionId::Sparity.severity(), Severity::Error);
+    let downgraded =
+        Violation::new(AssumptionId::Sparity, Subject::X).with_severity(Severity::Warning);
+    assert_eq!(downgraded.severity, Severity::Warning);
+    assert_eq!(Severity::Error.to_string(), "error");
+}
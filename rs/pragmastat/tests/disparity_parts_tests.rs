@@ -0,0 +1,98 @@
+//! Tests for the disparity decomposition into shift and average spread.
+
+use pragmastat::distributions::{Additive, Distribution, Exp};
+use pragmastat::estimators::raw;
+use pragmastat::measurement_unit::MeasurementUnit;
+use pragmastat::{
+    disparity, disparity_bounds_decomposed_with_seed, disparity_bounds_with_seed,
+    disparity_decomposed, shift, Rng, Sample,
+};
+
+const SEED: &str = "disparity-parts";
+
+fn draws(n: usize, m: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut rng = Rng::from_string(SEED);
+    let x = Additive::new(10.0, 2.0).samples(&mut rng, n);
+    let y = Exp::new(0.5).samples(&mut rng, m);
+    (x, y)
+}
+
+#[test]
+fn point_parts_match_standalone_estimators() {
+    for (n, m) in [(5, 5), (12, 30), (40, 17)] {
+        let (x, y) = draws(n, m);
+        let parts = raw::disparity_decomposed(&x, &y, false).unwrap();
+        assert_eq!(parts.disparity, parts.shift / parts.avg_spread);
+        assert_eq!(parts.disparity, raw::disparity(&x, &y, false).unwrap());
+        assert_eq!(parts.shift, raw::shift(&x, &y, false).unwrap());
+        let avg_spread = (n as f64 * raw::spread(&x, false).unwrap()
+            + m as f64 * raw::spread(&y, false).unwrap())
+            / (n + m) as f64;
+        assert_eq!(parts.avg_spread, avg_spread);
+    }
+}
+
+#[test]
+fn bounds_parts_are_coherent() {
+    let misrate = 0.1;
+    for (n, m) in [(14, 14), (20, 31), (50, 40)] {
+        let (x, y) = draws(n, m);
+        let parts =
+            raw::disparity_bounds_decomposed_with_seed(&x, &y, misrate, SEED, false).unwrap();
+
+        // Same draws as the plain disparity bounds
+        assert_eq!(
+            parts.disparity,
+            raw::disparity_bounds_with_seed(&x, &y, misrate, SEED, false).unwrap()
+        );
+
+        // The misrate is split between the components
+        assert!((parts.shift_misrate + parts.avg_spread_misrate - misrate).abs() < 1e-15);
+        assert_eq!(
+            parts.shift,
+            raw::shift_bounds(&x, &y, parts.shift_misrate, false).unwrap()
+        );
+
+        // Disparity bounds are the extreme quotients of the component bounds
+        let (s, a) = (parts.shift, parts.avg_spread);
+        assert!(a.lower > 0.0);
+        let quotients = [
+            s.lower / a.lower,
+            s.lower / a.upper,
+            s.upper / a.lower,
+            s.upper / a.upper,
+        ];
+        let lower = quotients.iter().copied().fold(f64::INFINITY, f64::min);
+        let upper = quotients.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        assert_eq!(
+            (parts.disparity.lower, parts.disparity.upper),
+            (lower, upper)
+        );
+    }
+}
+
+#[test]
+fn sample_parts_carry_units() {
+    let ms = MeasurementUnit::new("ms", "Time", "ms", "Millisecond", 1_000_000);
+    let ns = MeasurementUnit::new("ns", "Time", "ns", "Nanosecond", 1);
+    let (x, y) = draws(20, 20);
+    let x = Sample::with_unit(x, ms).unwrap();
+    let y = Sample::with_unit(y.iter().map(|v| v * 1e6).collect(), ns.clone()).unwrap();
+
+    let parts = disparity_decomposed(&x, &y).unwrap();
+    assert_eq!(parts.shift.unit, ns);
+    assert_eq!(parts.avg_spread.unit, ns);
+    assert_eq!(parts.disparity.unit, MeasurementUnit::disparity());
+    assert_eq!(parts.shift.value, shift(&x, &y).unwrap().value);
+    assert_eq!(parts.disparity.value, disparity(&x, &y).unwrap().value);
+
+    let bounds = disparity_bounds_decomposed_with_seed(&x, &y, 0.1, SEED).unwrap();
+    assert_eq!(bounds.shift.unit, ns);
+    assert_eq!(bounds.avg_spread.unit, ns);
+    let plain = disparity_bounds_with_seed(&x, &y, 0.1, SEED).unwrap();
+    assert_eq!(
+        (bounds.disparity.lower, bounds.disparity.upper),
+        (plain.lower, plain.upper)
+    );
+    assert_eq!(bounds.disparity.unit, plain.unit);
+}
@@ -0,0 +1,120 @@
+//! Tests for the dominance (probability of superiority) estimator and its bounds.
+//!
+//! The merge-counting implementation is checked against a brute-force O(n * m)
+//! oracle on random inputs, with and without ties.
+
+use pragmastat::distributions::{Additive, Distribution};
+use pragmastat::estimators::raw;
+use pragmastat::{dominance, dominance_bounds, AssumptionId, EstimatorError, Rng, Sample};
+
+fn brute_force(x: &[f64], y: &[f64]) -> f64 {
+    let mut score = 0.0;
+    for &xi in x {
+        for &yj in y {
+            if xi > yj {
+                score += 1.0;
+            } else if xi == yj {
+                score += 0.5;
+            }
+        }
+    }
+    score / (x.len() * y.len()) as f64
+}
+
+#[test]
+fn matches_brute_force_on_random_inputs() {
+    let mut rng = Rng::from_string("dominance-oracle");
+    let dist = Additive::new(0.0, 1.0);
+    for n in 1..=30 {
+        for m in [1, 2, 7, 30] {
+            let x = dist.samples(&mut rng, n);
+            let y: Vec<f64> = dist.samples(&mut rng, m).iter().map(|v| v + 0.3).collect();
+            assert_eq!(raw::dominance(&x, &y, false).unwrap(), brute_force(&x, &y));
+        }
+    }
+}
+
+#[test]
+fn matches_brute_force_with_ties() {
+    let mut rng = Rng::from_string("dominance-oracle-ties");
+    for _ in 0..200 {
+        let n = rng.uniform_i64(1, 25) as usize;
+        let m = rng.uniform_i64(1, 25) as usize;
+        let x: Vec<f64> = (0..n).map(|_| rng.uniform_i64(0, 5) as f64).collect();
+        let y: Vec<f64> = (0..m).map(|_| rng.uniform_i64(0, 5) as f64).collect();
+        let actual = raw::dominance(&x, &y, false).unwrap();
+        assert_eq!(actual, brute_force(&x, &y), "x={x:?} y={y:?}");
+        assert!((0.0..=1.0).contains(&actual));
+    }
+}
+
+#[test]
+fn extreme_and_tied_values() {
+    assert_eq!(raw::dominance(&[5.0; 4], &[5.0; 9], false).unwrap(), 0.5);
+    assert_eq!(
+        raw::dominance(&[2.0, 3.0], &[0.0, 1.0], false).unwrap(),
+        1.0
+    );
+    assert_eq!(
+        raw::dominance(&[0.0, 1.0], &[2.0, 3.0], false).unwrap(),
+        0.0
+    );
+    let x = [1.0, 4.0, 2.0, 8.0];
+    assert_eq!(raw::dominance(&x, &x, false).unwrap(), 0.5);
+}
+
+#[test]
+fn swapping_samples_complements() {
+    let x = [1.0, 3.0, 3.0, 7.0, 9.0];
+    let y = [2.0, 3.0, 8.0];
+    let forward = raw::dominance(&x, &y, false).unwrap();
+    let backward = raw::dominance(&y, &x, false).unwrap();
+    assert_eq!(forward + backward, 1.0);
+}
+
+#[test]
+fn bounds_contain_estimate_and_stay_in_unit_interval() {
+    let mut rng = Rng::from_string("dominance-bounds");
+    let dist = Additive::new(0.0, 1.0);
+    for shift in [-3.0, -0.5, 0.0, 0.5, 3.0] {
+        let x: Vec<f64> = dist
+            .samples(&mut rng, 30)
+            .iter()
+            .map(|v| v + shift)
+            .collect();
+        let y = dist.samples(&mut rng, 25);
+        let estimate = raw::dominance(&x, &y, false).unwrap();
+        let bounds = raw::dominance_bounds(&x, &y, 0.05, false).unwrap();
+        assert!(0.0 <= bounds.lower && bounds.upper <= 1.0, "{bounds:?}");
+        assert!(bounds.lower <= estimate && estimate <= bounds.upper);
+        let wider = raw::dominance_bounds(&x, &y, 0.001, false).unwrap();
+        assert!(wider.lower <= bounds.lower && bounds.upper <= wider.upper);
+    }
+}
+
+#[test]
+fn bounds_for_identical_samples_are_symmetric() {
+    let x: Vec<f64> = (1..=20).map(f64::from).collect();
+    let bounds = raw::dominance_bounds(&x, &x, 0.01, false).unwrap();
+    assert_eq!(bounds.lower + bounds.upper, 1.0);
+    assert!(bounds.lower > 0.0);
+}
+
+#[test]
+fn sample_api_returns_probability_unit() {
+    let x = Sample::new(vec![3.0, 4.0, 5.0, 6.0]).unwrap();
+    let y = Sample::new(vec![1.0, 2.0, 4.0]).unwrap();
+    let result = dominance(&x, &y).unwrap();
+    assert_eq!(result.value, 10.5 / 12.0);
+    assert_eq!(result.unit.id(), "probability");
+    let bounds = dominance_bounds(&x, &y, 0.5).unwrap();
+    assert_eq!(bounds.unit.id(), "probability");
+}
+
+#[test]
+fn bounds_reject_too_low_misrate() {
+    match raw::dominance_bounds(&[1.0, 2.0], &[3.0, 4.0], 1e-6, false).unwrap_err() {
+        EstimatorError::Assumption(e) => assert_eq!(e.violation().id, AssumptionId::Domain),
+        EstimatorError::Other(msg) => panic!("unexpected error: {msg}"),
+    }
+}
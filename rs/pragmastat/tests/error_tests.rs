@@ -1,7 +1,10 @@
 //! Tests for error handling and input validation
 
-use pragmastat::assumptions::{AssumptionId, EstimatorError, Subject};
+use pragmastat::assumptions::{
+    check_all, AssumptionError, AssumptionId, EstimatorError, Subject, Violation, ViolationDetail,
+};
 use pragmastat::estimators::raw;
+use pragmastat::{center_bounds, min_sample_size, EstimatorId, Sample};
 
 #[test]
 fn center_empty_input() {
@@ -193,3 +196,625 @@ fn shift_bounds_misrate_out_of_domain() {
         assert_eq!(subject, Subject::Misrate, "misrate {misrate}");
     }
 }
+
+// --- structured violation details ---
+
+fn detail_of(err: EstimatorError) -> ViolationDetail {
+    match err {
+        EstimatorError::Assumption(e) => {
+            e.detail().cloned().expect("violation detail is populated")
+        }
+        EstimatorError::Other(msg) => panic!("unexpected non-assumption error: {msg}"),
+    }
+}
+
+#[test]
+fn center_bounds_misrate_too_low_detail() {
+    let err = raw::center_bounds(&[1.0, 2.0, 3.0, 4.0, 5.0], 0.01, false).unwrap_err();
+    assert_eq!(
+        detail_of(err),
+        ViolationDetail::MisrateTooLow {
+            requested: 0.01,
+            minimum: 0.0625,
+            n: 5,
+            m: None,
+        }
+    );
+}
+
+#[test]
+fn center_bounds_sample_too_small_detail() {
+    let err = raw::center_bounds(&[1.0], 0.5, false).unwrap_err();
+    assert_eq!(
+        detail_of(err),
+        ViolationDetail::SampleTooSmall { n: 1, required: 2 }
+    );
+}
+
+#[test]
+fn center_bounds_single_element_reports_min_sample_size() {
+    let required = min_sample_size(EstimatorId::CenterBounds);
+    assert_eq!(required, 2);
+    let x = Sample::new(vec![1.0]).unwrap();
+    let err = center_bounds(&x, 0.5).unwrap_err();
+    assert_eq!(
+        detail_of(err),
+        ViolationDetail::SampleTooSmall { n: 1, required }
+    );
+}
+
+#[test]
+fn bounds_accept_min_sample_size() {
+    let x = [1.0, 2.0];
+    let center_n = min_sample_size(EstimatorId::CenterBounds);
+    assert!(raw::center_bounds(&x[..center_n], 1.0, false).is_ok());
+    let spread_n = min_sample_size(EstimatorId::SpreadBounds);
+    assert!(raw::spread_bounds(&x[..spread_n], 1.0, false).is_ok());
+    let shift_n = min_sample_size(EstimatorId::ShiftBounds);
+    assert!(raw::shift_bounds(&x[..shift_n], &x[..shift_n], 1.0, false).is_ok());
+    let spread_err = raw::spread_bounds(&x[..spread_n - 1], 1.0, false).unwrap_err();
+    assert_eq!(
+        detail_of(spread_err),
+        ViolationDetail::SampleTooSmall {
+            n: 1,
+            required: spread_n
+        }
+    );
+}
+
+#[test]
+fn shift_bounds_misrate_too_low_detail() {
+    let err = raw::shift_bounds(&[1.0, 2.0], &[3.0, 4.0, 5.0], 0.1, false).unwrap_err();
+    match detail_of(err) {
+        ViolationDetail::MisrateTooLow {
+            requested,
+            minimum,
+            n,
+            m,
+        } => {
+            assert_eq!(requested, 0.1);
+            assert!((minimum - 0.2).abs() < 1e-12);
+            assert_eq!((n, m), (2, Some(3)));
+        }
+        other => panic!("unexpected detail: {other:?}"),
+    }
+}
+
+#[test]
+fn misrate_out_of_range_detail() {
+    let err = raw::spread_bounds(&[1.0, 2.0, 3.0, 4.0], 1.5, false).unwrap_err();
+    assert_eq!(
+        detail_of(err),
+        ViolationDetail::MisrateOutOfRange { requested: 1.5 }
+    );
+}
+
+#[test]
+fn disparity_bounds_sample_too_small_detail_names_subject() {
+    let err = raw::disparity_bounds(&[1.0, 2.0, 3.0], &[4.0], 0.5, false).unwrap_err();
+    let EstimatorError::Assumption(e) = &err else {
+        panic!("expected assumption error");
+    };
+    assert_eq!(e.violation().subject, Subject::Y);
+    assert_eq!(
+        e.detail(),
+        Some(&ViolationDetail::SampleTooSmall { n: 1, required: 2 })
+    );
+}
+
+#[test]
+fn display_includes_detail_and_source_chains() {
+    use std::error::Error;
+    let err = raw::center_bounds(&[1.0, 2.0, 3.0], 0.01, false).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "domain(misrate): misrate 0.01 is below the minimum achievable 0.25 for n=3; \
+         raise the misrate to the minimum or collect more values"
+    );
+    let source = err.source().expect("assumption errors expose their source");
+    assert_eq!(source.to_string(), err.to_string());
+    assert!(EstimatorError::Other("x".to_string()).source().is_none());
+}
+
+fn positivity_of(err: EstimatorError) -> (Subject, ViolationDetail) {
+    let EstimatorError::Assumption(e) = &err else {
+        panic!("expected assumption error, got {err}");
+    };
+    assert_eq!(e.violation().id, AssumptionId::Positivity);
+    (e.violation().subject, e.detail().cloned().expect("detail"))
+}
+
+#[test]
+fn ratio_single_zero_detail() {
+    let (subject, detail) =
+        positivity_of(raw::ratio(&[1.0, 2.0], &[3.0, 0.0, 4.0], false).unwrap_err());
+    assert_eq!(subject, Subject::Y);
+    assert_eq!(
+        detail,
+        ViolationDetail::NonPositiveValues {
+            zeros: 1,
+            negatives: 0
+        }
+    );
+    assert_eq!(detail.to_string(), "1 zero value(s)");
+}
+
+#[test]
+fn ratio_single_negative_detail() {
+    let (subject, detail) =
+        positivity_of(raw::ratio(&[1.0, -2.0, 3.0], &[4.0], false).unwrap_err());
+    assert_eq!(subject, Subject::X);
+    assert_eq!(
+        detail,
+        ViolationDetail::NonPositiveValues {
+            zeros: 0,
+            negatives: 1
+        }
+    );
+}
+
+#[test]
+fn ratio_bounds_mixed_sign_detail() {
+    let x = [-3.0, -1.0, 0.0, 2.0, 5.0];
+    let y = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let (subject, detail) = positivity_of(raw::ratio_bounds(&x, &y, 0.1, false).unwrap_err());
+    assert_eq!(subject, Subject::X);
+    assert_eq!(
+        detail,
+        ViolationDetail::NonPositiveValues {
+            zeros: 1,
+            negatives: 2
+        }
+    );
+    assert_eq!(detail.to_string(), "1 zero and 2 negative value(s)");
+
+    // The Sample-level API reports the same structured error
+    let xs = Sample::new(x.to_vec()).unwrap();
+    let ys = Sample::new(y.to_vec()).unwrap();
+    assert_eq!(
+        positivity_of(pragmastat::ratio_bounds(&xs, &ys, 0.1).unwrap_err()).1,
+        detail
+    );
+}
+
+#[test]
+fn sample_ratio_rejects_nonpositive_denominator() {
+    let x = Sample::new(vec![1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+    for (y, zeros, negatives) in [
+        (vec![2.0, 0.0, 4.0, 6.0, 8.0], 1, 0),
+        (vec![2.0, 3.0, -4.0, 6.0, 8.0], 0, 1),
+    ] {
+        let y = Sample::new(y).unwrap();
+        let expected = (
+            Subject::Y,
+            ViolationDetail::NonPositiveValues { zeros, negatives },
+        );
+        assert_eq!(
+            positivity_of(pragmastat::ratio(&x, &y).unwrap_err()),
+            expected
+        );
+        assert_eq!(
+            positivity_of(pragmastat::ratio_bounds(&x, &y, 0.1).unwrap_err()),
+            expected
+        );
+    }
+}
+
+// --- zero average spread in disparity ---
+
+fn sparity_of(err: EstimatorError) -> (Subject, Option<ViolationDetail>) {
+    let EstimatorError::Assumption(e) = &err else {
+        panic!("expected assumption error, got {err}");
+    };
+    assert_eq!(e.violation().id, AssumptionId::Sparity);
+    (e.violation().subject, e.detail().cloned())
+}
+
+#[test]
+fn disparity_of_different_constant_samples_reports_zero_avg_spread() {
+    let x = [5.0; 10];
+    let y = [2.0; 10];
+    let expected = (
+        Subject::X,
+        Some(ViolationDetail::ZeroAvgSpread { shift: 3.0 }),
+    );
+    assert_eq!(
+        sparity_of(raw::disparity(&x, &y, false).unwrap_err()),
+        expected
+    );
+    assert_eq!(
+        sparity_of(raw::disparity_bounds_with_seed(&x, &y, 0.5, "zero", false).unwrap_err()),
+        expected
+    );
+
+    let xs = Sample::new(x.to_vec()).unwrap();
+    let ys = Sample::new(y.to_vec()).unwrap();
+    assert_eq!(
+        sparity_of(pragmastat::disparity(&xs, &ys).unwrap_err()),
+        expected
+    );
+    assert_eq!(
+        sparity_of(pragmastat::disparity_with_diagnostics(&xs, &ys).unwrap_err()),
+        expected
+    );
+    let err = pragmastat::disparity_bounds(&xs, &ys, 0.5).unwrap_err();
+    assert!(err.to_string().contains("use shift or shift_bounds"));
+    assert_eq!(sparity_of(err), expected);
+}
+
+#[test]
+fn disparity_of_equal_constant_samples_is_zero_with_a_warning() {
+    let x = [3.0; 10];
+    assert_eq!(raw::disparity(&x, &x, false).unwrap(), 0.0);
+    let b = raw::disparity_bounds_with_seed(&x, &x, 0.5, "zero", false).unwrap();
+    assert_eq!((b.lower, b.upper), (0.0, 0.0));
+
+    let xs = Sample::new(x.to_vec()).unwrap();
+    assert_eq!(pragmastat::disparity(&xs, &xs).unwrap().value, 0.0);
+    let b = pragmastat::disparity_bounds(&xs, &xs, 0.5).unwrap();
+    assert_eq!((b.lower, b.upper), (0.0, 0.0));
+
+    let zero_avg_spread = |warnings: &[pragmastat::Diagnostic]| {
+        warnings.iter().filter_map(|w| w.violation()).any(|w| {
+            w.id == AssumptionId::Sparity
+                && w.subject == Subject::X
+                && w.severity == pragmastat::Severity::Warning
+        })
+    };
+    let d = pragmastat::disparity_with_diagnostics(&xs, &xs).unwrap();
+    assert_eq!(d.value.value, 0.0);
+    assert!(zero_avg_spread(&d.warnings), "{:?}", d.warnings);
+    let b = pragmastat::disparity_bounds_with_diagnostics(&xs, &xs, 0.5).unwrap();
+    assert_eq!((b.value.lower, b.value.upper), (0.0, 0.0));
+    assert!(zero_avg_spread(&b.warnings), "{:?}", b.warnings);
+
+    let ys = Sample::new((1..=10).map(f64::from).collect()).unwrap();
+    let d = pragmastat::disparity_with_diagnostics(&ys, &ys).unwrap();
+    assert!(!zero_avg_spread(&d.warnings), "{:?}", d.warnings);
+}
+
+#[test]
+fn disparity_with_one_constant_sample_has_no_zero_avg_spread_detail() {
+    let x = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+    let c = [4.0; 10];
+    assert_eq!(
+        sparity_of(raw::disparity(&c, &x, false).unwrap_err()),
+        (Subject::X, None)
+    );
+    assert_eq!(
+        sparity_of(raw::disparity(&x, &c, false).unwrap_err()),
+        (Subject::Y, None)
+    );
+    assert_eq!(
+        sparity_of(raw::disparity_bounds_with_seed(&c, &x, 0.5, "zero", false).unwrap_err()),
+        (Subject::X, None)
+    );
+    assert_eq!(
+        sparity_of(raw::disparity_bounds_with_seed(&x, &c, 0.5, "zero", false).unwrap_err()),
+        (Subject::Y, None)
+    );
+}
+
+#[test]
+fn disparity_with_near_zero_spreads_succeeds() {
+    let x: Vec<f64> = (0..10).map(|i| 5.0 + i as f64 * 1e-12).collect();
+    let y: Vec<f64> = (0..10).map(|i| 2.0 + i as f64 * 1e-12).collect();
+    let d = raw::disparity(&x, &y, false).unwrap();
+    assert!(d.is_finite() && d > 1e11, "disparity {d}");
+    let b = raw::disparity_bounds_with_seed(&x, &y, 0.5, "zero", false).unwrap();
+    assert!(b.lower.is_finite() && b.upper.is_finite());
+    assert!(b.lower > 0.0 && b.lower <= b.upper);
+}
+
+// --- estimator names and std::error::Error ---
+
+#[test]
+fn sample_errors_name_the_estimator() {
+    let constant = Sample::new(vec![2.0; 10]).unwrap();
+    let x = Sample::new((1..=10).map(f64::from).collect()).unwrap();
+
+    let err = pragmastat::spread(&constant).unwrap_err();
+    assert_eq!(err.to_string(), "spread: sparity(x)");
+    let EstimatorError::Assumption(e) = &err else {
+        panic!("expected assumption error");
+    };
+    assert_eq!(e.estimator(), Some(EstimatorId::Spread));
+
+    assert_eq!(
+        center_bounds(&x, 0.001).unwrap_err().to_string(),
+        "center_bounds: domain(misrate): misrate 0.001 is below the minimum achievable \
+         0.001953125 for n=10; raise the misrate to the minimum or collect more values"
+    );
+    // The shift bounds inside disparity bounds do not leak their own name
+    assert_eq!(
+        pragmastat::disparity_bounds_with_seed(&x, &constant, 0.5, "name")
+            .unwrap_err()
+            .to_string(),
+        "disparity_bounds: sparity(y)"
+    );
+    assert_eq!(
+        pragmastat::ratio(&x, &Sample::new(vec![1.0, -1.0]).unwrap())
+            .unwrap_err()
+            .to_string(),
+        "ratio: positivity(y): 1 negative value(s) (first at index 1: -1); \
+         use shift for data that can be zero or negative"
+    );
+}
+
+#[test]
+fn raw_errors_carry_no_estimator_name() {
+    let err = raw::spread(&[2.0; 10], false).unwrap_err();
+    assert_eq!(err.to_string(), "sparity(x)");
+    let EstimatorError::Assumption(e) = err else {
+        panic!("expected assumption error");
+    };
+    assert_eq!(e.estimator(), None);
+    assert_eq!(
+        e.in_estimator(EstimatorId::Precision).to_string(),
+        "precision: sparity(x)"
+    );
+}
+
+#[test]
+fn errors_convert_to_boxed_std_errors() {
+    fn spread_of(values: Vec<f64>) -> Result<f64, Box<dyn std::error::Error + Send + Sync>> {
+        let x = Sample::new(values)?;
+        Ok(pragmastat::spread(&x)?.value)
+    }
+    assert_eq!(spread_of(vec![1.0, 3.0]).unwrap(), 2.0);
+    let err = spread_of(vec![4.0, 4.0, 4.0]).unwrap_err();
+    assert_eq!(err.to_string(), "spread: sparity(x)");
+    let err = err.downcast::<EstimatorError>().expect("an EstimatorError");
+    assert!(matches!(*err, EstimatorError::Assumption(_)));
+
+    let assumption: Box<dyn std::error::Error> = Box::new(AssumptionError::sparity(Subject::Y));
+    assert_eq!(assumption.to_string(), "sparity(y)");
+}
+
+fn violation_of(err: EstimatorError) -> Violation {
+    let EstimatorError::Assumption(e) = &err else {
+        panic!("expected assumption error, got {err}");
+    };
+    e.violation()
+}
+
+#[test]
+fn center_too_few_values_suggests_a_remedy() {
+    let v = violation_of(raw::center(&[], false).unwrap_err());
+    assert_eq!(v.id, AssumptionId::Validity);
+    assert_eq!((v.index, v.value), (None, None));
+    assert_eq!(v.remedy, Some("provide at least one value"));
+
+    let v = violation_of(center_bounds(&Sample::new(vec![4.0]).unwrap(), 0.5).unwrap_err());
+    assert_eq!(v.id, AssumptionId::Domain);
+    assert_eq!(v.subject, Subject::X);
+    assert_eq!(v.remedy, Some("collect more values"));
+}
+
+#[test]
+fn non_finite_violation_points_at_the_first_offender() {
+    let v = violation_of(raw::center(&[1.0, 2.0, f64::INFINITY, f64::NAN], false).unwrap_err());
+    assert_eq!(v.index, Some(2));
+    assert_eq!(v.value, Some(f64::INFINITY));
+    assert!(v.remedy.is_some());
+
+    let v = violation_of(Sample::new(vec![1.0, f64::NAN]).unwrap_err());
+    assert_eq!(v.index, Some(1));
+    assert!(v.value.unwrap().is_nan());
+}
+
+#[test]
+fn positivity_violation_points_at_the_first_offender() {
+    // Raw functions index the slice as given
+    let v = violation_of(raw::ratio(&[3.0, 2.0], &[4.0, 1.0, -2.0, 0.0], false).unwrap_err());
+    assert_eq!(v.subject, Subject::Y);
+    assert_eq!((v.index, v.value), (Some(2), Some(-2.0)));
+    assert_eq!(
+        v.remedy,
+        Some("use shift for data that can be zero or negative")
+    );
+
+    // Sample-level functions index the sample's own order, not the sorted one
+    let x = Sample::new(vec![5.0, 0.0, 7.0, -3.0]).unwrap();
+    let y = Sample::new(vec![1.0, 2.0]).unwrap();
+    for err in [
+        pragmastat::ratio(&x, &y).unwrap_err(),
+        pragmastat::ratio_bounds(&x, &y, 0.5).unwrap_err(),
+        pragmastat::ratio_with_bounds(&x, &y, 0.5).unwrap_err(),
+    ] {
+        let v = violation_of(err);
+        assert_eq!(v.subject, Subject::X);
+        assert_eq!((v.index, v.value), (Some(1), Some(0.0)));
+    }
+
+    let violations = check_all(&[2.0, -1.0, 3.0], Subject::Y);
+    assert_eq!(violations[0].id, AssumptionId::Positivity);
+    assert_eq!(
+        (violations[0].index, violations[0].value),
+        (Some(1), Some(-1.0))
+    );
+}
+
+type RawCall = Box<dyn Fn(&[f64], &[f64]) -> Result<(), EstimatorError>>;
+
+/// Every raw estimator as `(name, is_two_sample, f(x, y))`; one-sample
+/// estimators ignore `y`.
+fn raw_estimators() -> Vec<(&'static str, bool, RawCall)> {
+    use pragmastat::estimators::{Algorithm, BoundsMethod, MadScale};
+    let m = 0.2;
+    let s = "non-finite";
+    fn f32s(values: &[f64]) -> Vec<f32> {
+        values.iter().map(|&v| v as f32).collect()
+    }
+    macro_rules! one {
+        ($name:literal, |$x:ident| $call:expr) => {
+            (
+                $name,
+                false,
+                Box::new(move |$x: &[f64], _: &[f64]| $call.map(|_| ())) as RawCall,
+            )
+        };
+    }
+    macro_rules! two {
+        ($name:literal, |$x:ident, $y:ident| $call:expr) => {
+            (
+                $name,
+                true,
+                Box::new(move |$x: &[f64], $y: &[f64]| $call.map(|_| ())) as RawCall,
+            )
+        };
+    }
+    vec![
+        one!("center", |x| raw::center(x, false)),
+        one!("spread", |x| raw::spread(x, false)),
+        one!("center_bounds", |x| raw::center_bounds(x, m, false)),
+        one!("spread_bounds", |x| raw::spread_bounds_with_seed(
+            x, m, s, false
+        )),
+        one!("spread_with", |x| raw::spread_with(
+            x,
+            Algorithm::Naive,
+            false
+        )),
+        one!("volatility", |x| raw::volatility(x, false)),
+        one!("precision", |x| raw::precision(x, false)),
+        one!("mad", |x| raw::mad(x, MadScale::Raw, false)),
+        one!("iqr", |x| raw::iqr(x, false)),
+        one!("center_sorted", |x| raw::center_sorted(x)),
+        one!("spread_sorted", |x| raw::spread_sorted(x)),
+        one!("spread_bounds_asymptotic", |x| {
+            raw::spread_bounds_asymptotic(x, m, false)
+        }),
+        one!("mode_hsm", |x| raw::mode_hsm(x, false)),
+        one!("center_with_bounds", |x| raw::center_with_bounds(
+            x, m, false
+        )),
+        one!("spread_with_bounds", |x| raw::spread_with_bounds_with_seed(
+            x, m, s, false
+        )),
+        one!("center_stderr", |x| raw::center_stderr(x, false)),
+        one!("center_bounds_with_method", |x| {
+            raw::center_bounds_with_method(x, m, BoundsMethod::Asymptotic, false)
+        }),
+        two!("shift", |x, y| raw::shift(x, y, false)),
+        two!("ratio", |x, y| raw::ratio(x, y, false)),
+        two!("disparity", |x, y| raw::disparity(x, y, false)),
+        two!("shift_bounds", |x, y| raw::shift_bounds(x, y, m, false)),
+        two!("ratio_bounds", |x, y| raw::ratio_bounds(x, y, m, false)),
+        two!("disparity_bounds", |x, y| raw::disparity_bounds_with_seed(
+            x, y, m, s, false
+        )),
+        two!("shift_with", |x, y| raw::shift_with(
+            x,
+            y,
+            Algorithm::Naive,
+            false
+        )),
+        two!("dominance", |x, y| raw::dominance(x, y, false)),
+        two!("dominance_bounds", |x, y| raw::dominance_bounds(
+            x, y, m, false
+        )),
+        two!("shift_sorted", |x, y| raw::shift_sorted(x, y)),
+        one!("center_f32", |x| raw::center_f32(&f32s(x), false)),
+        one!("spread_f32", |x| raw::spread_f32(&f32s(x), false)),
+        two!("shift_f32", |x, y| raw::shift_f32(
+            &f32s(x),
+            &f32s(y),
+            false
+        )),
+        two!("ratio_f32", |x, y| raw::ratio_f32(
+            &f32s(x),
+            &f32s(y),
+            false
+        )),
+        two!("disparity_decomposed", |x, y| raw::disparity_decomposed(
+            x, y, false
+        )),
+        two!("disparity_bounds_decomposed", |x, y| {
+            raw::disparity_bounds_decomposed_with_seed(x, y, m, s, false)
+        }),
+        two!("shift_with_bounds", |x, y| raw::shift_with_bounds(
+            x, y, m, false
+        )),
+        two!("ratio_with_bounds", |x, y| raw::ratio_with_bounds(
+            x, y, m, false
+        )),
+        two!("disparity_with_bounds", |x, y| {
+            raw::disparity_with_bounds_with_seed(x, y, m, s, false)
+        }),
+        two!("spread_ratio", |x, y| raw::spread_ratio(x, y, false)),
+        two!("spread_ratio_bounds", |x, y| {
+            raw::spread_ratio_bounds_with_seed(x, y, m, s, false)
+        }),
+        two!("avg_spread", |x, y| raw::avg_spread(x, y, false)),
+        two!(
+            "avg_spread_bounds",
+            |x, y| raw::avg_spread_bounds_with_seed(x, y, m, s, false)
+        ),
+        two!("shift_stderr", |x, y| raw::shift_stderr(x, y, false)),
+        two!("theil_sen", |x, y| raw::theil_sen(x, y)),
+        two!("disparity_bounds_seeded_by_index", |x, y| {
+            raw::disparity_bounds_seeded_by_index(x, y, m, s, 0, false)
+        }),
+    ]
+}
+
+fn non_finite_indices_of(name: &str, err: EstimatorError) -> (Subject, Vec<usize>) {
+    let EstimatorError::Assumption(e) = &err else {
+        panic!("{name}: expected assumption error, got {err}");
+    };
+    assert_eq!(e.violation().id, AssumptionId::Validity, "{name}: {err}");
+    match e.detail() {
+        Some(ViolationDetail::NonFiniteValues { indices }) => {
+            (e.violation().subject, indices.clone())
+        }
+        other => panic!("{name}: unexpected detail {other:?}"),
+    }
+}
+
+#[test]
+fn every_raw_estimator_rejects_non_finite_values_with_indices() {
+    let clean: Vec<f64> = (1..=12).map(|i| f64::from(i * i % 13) + 1.0).collect();
+    for bad in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let mut dirty = clean.clone();
+        dirty[2] = bad;
+        dirty[7] = bad;
+        for (name, two_sample, f) in raw_estimators() {
+            let err = f(&dirty, &clean).expect_err(name);
+            let (subject, indices) = non_finite_indices_of(name, err);
+            assert_eq!(
+                (subject, indices),
+                (Subject::X, vec![2, 7]),
+                "{name}, {bad}"
+            );
+            if two_sample {
+                let err = f(&clean, &dirty).expect_err(name);
+                let (subject, indices) = non_finite_indices_of(name, err);
+                assert_eq!(
+                    (subject, indices),
+                    (Subject::Y, vec![2, 7]),
+                    "{name}, {bad}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn every_raw_estimator_accepts_negative_zero() {
+    // Ascending, so the `*_sorted` variants accept it too
+    let x = [
+        -2.0, -0.0, -0.0, 1.0, 2.5, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0,
+    ];
+    let y: Vec<f64> = (1..=12).map(f64::from).collect();
+    for (name, _, f) in raw_estimators() {
+        // Ratio-based estimators still reject zero via positivity, and some
+        // bounds need more values, but none may call `-0.0` invalid
+        if let Err(err) = f(&x, &y) {
+            let EstimatorError::Assumption(e) = &err else {
+                panic!("{name}: {err}");
+            };
+            assert_ne!(e.violation().id, AssumptionId::Validity, "{name}: {err}");
+        }
+    }
+}
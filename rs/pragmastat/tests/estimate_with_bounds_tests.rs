@@ -0,0 +1,156 @@
+//! Tests for estimates reported together with their bounds.
+
+use pragmastat::distributions::{Distribution, Multiplic};
+use pragmastat::estimators::raw;
+use pragmastat::measurement_unit::MeasurementUnit;
+use pragmastat::*;
+
+fn ms() -> MeasurementUnit {
+    MeasurementUnit::new("ms", "Time", "ms", "Millisecond", 1_000_000)
+}
+
+fn skewed(seed: &str, n: usize) -> Sample {
+    let mut rng = Rng::from_string(seed);
+    Sample::with_unit(Multiplic::new(1.0, 0.6).samples(&mut rng, n), ms()).unwrap()
+}
+
+fn assert_same(e: &EstimateWithBounds, estimate: Measurement, bounds: Bounds) {
+    assert_eq!(e.estimate, estimate.value);
+    assert_eq!(
+        (e.bounds.lower, e.bounds.upper),
+        (bounds.lower, bounds.upper)
+    );
+    assert_eq!(e.unit(), &estimate.unit);
+    assert_eq!(e.unit(), &bounds.unit);
+}
+
+#[test]
+fn matches_separate_calls() {
+    let x = skewed("ewb-x", 40);
+    let y = skewed("ewb-y", 30);
+    let misrate = 0.05;
+
+    let e = center_with_bounds(&x, misrate).unwrap();
+    assert_same(&e, center(&x).unwrap(), center_bounds(&x, misrate).unwrap());
+    let e = shift_with_bounds(&x, &y, misrate).unwrap();
+    assert_same(
+        &e,
+        shift(&x, &y).unwrap(),
+        shift_bounds(&x, &y, misrate).unwrap(),
+    );
+    let e = ratio_with_bounds(&x, &y, misrate).unwrap();
+    assert_same(
+        &e,
+        ratio(&x, &y).unwrap(),
+        ratio_bounds(&x, &y, misrate).unwrap(),
+    );
+    let e = spread_with_bounds_with_seed(&x, misrate, "ewb").unwrap();
+    let bounds = spread_bounds_with_seed(&x, misrate, "ewb").unwrap();
+    assert_same(&e, spread(&x).unwrap(), bounds);
+    let e = disparity_with_bounds_with_seed(&x, &y, misrate, "ewb").unwrap();
+    let bounds = disparity_bounds_with_seed(&x, &y, misrate, "ewb").unwrap();
+    assert_same(&e, disparity(&x, &y).unwrap(), bounds);
+}
+
+#[test]
+fn raw_matches_sample_level() {
+    let x = skewed("ewb-x", 25);
+    let y = skewed("ewb-y", 20);
+    let (xv, yv) = (x.values(), y.values());
+    let misrate = 0.1;
+
+    let pairs = [
+        (
+            raw::center_with_bounds(xv, misrate, false).unwrap(),
+            center_with_bounds(&x, misrate).unwrap(),
+        ),
+        (
+            raw::spread_with_bounds_with_seed(xv, misrate, "s", false).unwrap(),
+            spread_with_bounds_with_seed(&x, misrate, "s").unwrap(),
+        ),
+        (
+            raw::shift_with_bounds(xv, yv, misrate, false).unwrap(),
+            shift_with_bounds(&x, &y, misrate).unwrap(),
+        ),
+        (
+            raw::ratio_with_bounds(xv, yv, misrate, false).unwrap(),
+            ratio_with_bounds(&x, &y, misrate).unwrap(),
+        ),
+        (
+            raw::disparity_with_bounds_with_seed(xv, yv, misrate, "d", false).unwrap(),
+            disparity_with_bounds_with_seed(&x, &y, misrate, "d").unwrap(),
+        ),
+    ];
+    for (raw, sample) in pairs {
+        assert_eq!(raw.estimate, sample.estimate);
+        assert_eq!(raw.bounds.lower, sample.bounds.lower);
+        assert_eq!(raw.bounds.upper, sample.bounds.upper);
+        assert_eq!(raw.unit(), &MeasurementUnit::number());
+    }
+}
+
+#[test]
+fn symmetric_data_give_exactly_symmetric_bounds() {
+    let x = Sample::new((1..=12).map(f64::from).collect()).unwrap();
+    let e = center_with_bounds(&x, 0.05).unwrap();
+    assert_eq!(e.estimate, 6.5);
+    assert_eq!(e.margin_lower(), e.margin_upper());
+    assert!(e.is_symmetric(0.0));
+
+    let y = Sample::new((1..=12).map(|v| f64::from(v) + 100.0).collect()).unwrap();
+    let e = shift_with_bounds(&x, &y, 0.05).unwrap();
+    assert_eq!(e.estimate, -100.0);
+    assert!(e.is_symmetric(0.0));
+}
+
+#[test]
+fn randomized_spread_bounds_are_asymmetric() {
+    let x = skewed("ewb-spread", 60);
+    let e = spread_with_bounds_with_seed(&x, 0.05, "ewb-spread").unwrap();
+    assert!(e.margin_lower() > 0.0 && e.margin_upper() > 0.0, "{e:?}");
+    assert!(!e.is_symmetric(0.05 * e.estimate), "{e:?}");
+    // Both margins print, each with its own value
+    let text = e.to_string();
+    assert!(text.contains(" ms (+") && text.contains(" / −"), "{text}");
+}
+
+#[test]
+fn ratio_bounds_are_asymmetric_around_the_estimate() {
+    let x = skewed("ewb-x", 40);
+    let y = skewed("ewb-y", 30);
+    let e = ratio_with_bounds(&x, &y, 0.05).unwrap();
+    // Symmetric in log space: estimate / lower == upper / estimate
+    let log_lower = e.estimate.ln() - e.bounds.lower.ln();
+    let log_upper = e.bounds.upper.ln() - e.estimate.ln();
+    assert!((log_lower - log_upper).abs() < 0.1 * log_upper, "{e:?}");
+    assert!(e.margin_upper() > e.margin_lower());
+}
+
+#[test]
+fn display_carries_the_unit() {
+    let x = Sample::with_unit(
+        vec![3.1, 3.3, 3.35, 3.4, 3.42, 3.45, 3.5, 3.6, 3.8, 4.1],
+        ms(),
+    )
+    .unwrap();
+    let e = center_with_bounds(&x, 0.1).unwrap();
+    let text = e.to_string();
+    assert!(text.contains(" ms (+"), "{text}");
+    assert!(text.contains(" / −"), "{text}");
+
+    let r = ratio_with_bounds(&x, &x, 0.1).unwrap();
+    assert_eq!(r.unit(), &MeasurementUnit::ratio());
+    assert!(r.to_string().starts_with("1.0"), "{r}");
+}
+
+#[test]
+fn errors_name_the_bounds_estimator() {
+    let x = Sample::new(vec![1.0, 2.0, 3.0]).unwrap();
+    let err = center_with_bounds(&x, 1e-6).unwrap_err();
+    match err {
+        EstimatorError::Assumption(e) => {
+            assert_eq!(e.estimator(), Some(EstimatorId::CenterBounds))
+        }
+        other => panic!("expected an assumption error, got {other:?}"),
+    }
+}
@@ -0,0 +1,193 @@
+//! Tests for the external-memory shift.
+//!
+//! Samples are small enough to compute the in-memory shift too; every chunking
+//! must reproduce it bit-for-bit.
+
+use pragmastat::distributions::{Additive, Distribution, Exp};
+use pragmastat::estimators::external::{
+    shift_external, ReaderChunks, ShiftExternalOptions, SliceChunks,
+};
+use pragmastat::estimators::raw;
+use pragmastat::{AssumptionId, EstimatorError, Rng, Subject};
+use std::fs::{self, File};
+use std::io::{Cursor, Write};
+use std::path::PathBuf;
+
+fn sorted(mut values: Vec<f64>) -> Vec<f64> {
+    values.sort_by(f64::total_cmp);
+    values
+}
+
+fn le_bytes(values: &[f64]) -> Vec<u8> {
+    values.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+fn write_values(name: &str, values: &[f64]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "pragmastat-external-{name}-{}.bin",
+        std::process::id()
+    ));
+    File::create(&path)
+        .unwrap()
+        .write_all(&le_bytes(values))
+        .unwrap();
+    path
+}
+
+fn external(x: &[f64], chunk_x: usize, y: &[f64], chunk_y: usize) -> f64 {
+    shift_external(
+        SliceChunks::new(x, chunk_x),
+        SliceChunks::new(y, chunk_y),
+        &ShiftExternalOptions::default(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn slice_chunks_match_in_memory_shift() {
+    let mut rng = Rng::from_string("external-shift-slices");
+    for (n, m) in [(1, 1), (1, 9), (7, 4), (100, 37), (500, 800)] {
+        let x = sorted(Additive::new(1.0, 2.0).samples(&mut rng, n));
+        let y = sorted(Exp::new(1.0).samples(&mut rng, m));
+        let expected = raw::shift(&x, &y, true).unwrap();
+        for (cx, cy) in [(1, 1), (3, 5), (64, 17), (n, m), (10_000, 10_000)] {
+            assert_eq!(
+                external(&x, cx, &y, cy).to_bits(),
+                expected.to_bits(),
+                "n={n}, m={m}, chunks=({cx}, {cy})"
+            );
+        }
+    }
+}
+
+#[test]
+fn ties_match_in_memory_shift() {
+    let mut rng = Rng::from_string("external-shift-ties");
+    let x = sorted((0..300).map(|_| rng.uniform_i64(0, 10) as f64).collect());
+    let y = sorted((0..200).map(|_| rng.uniform_i64(0, 6) as f64).collect());
+    let expected = raw::shift(&x, &y, true).unwrap();
+    assert_eq!(external(&x, 32, &y, 7), expected);
+}
+
+#[test]
+fn sorted_runs_need_not_be_ordered_among_themselves() {
+    // Three sorted runs of 50 in arbitrary order, as an external sort leaves them
+    let mut rng = Rng::from_string("external-shift-runs");
+    let runs: Vec<Vec<f64>> = (0..3)
+        .map(|_| sorted(Additive::new(0.0, 1.0).samples(&mut rng, 50)))
+        .collect();
+    let x: Vec<f64> = runs.concat();
+    let y = sorted(Additive::new(0.5, 1.0).samples(&mut rng, 40));
+    let expected = raw::shift(&x, &y, false).unwrap();
+    assert_eq!(external(&x, 50, &y, 40), expected);
+}
+
+#[test]
+fn reader_chunks_match_in_memory_shift() {
+    let mut rng = Rng::from_string("external-shift-file");
+    let x = sorted(Additive::new(10.0, 3.0).samples(&mut rng, 1000));
+    let y = sorted(Additive::new(8.0, 1.0).samples(&mut rng, 333));
+    let path_x = write_values("x", &x);
+    let path_y = write_values("y", &y);
+    let from_files = shift_external(
+        ReaderChunks::open(&path_x, 256).unwrap(),
+        ReaderChunks::open(&path_y, 100).unwrap(),
+        &ShiftExternalOptions::default(),
+    );
+    fs::remove_file(&path_x).unwrap();
+    fs::remove_file(&path_y).unwrap();
+    assert_eq!(from_files.unwrap(), raw::shift(&x, &y, true).unwrap());
+
+    let from_cursors = shift_external(
+        ReaderChunks::new(Cursor::new(le_bytes(&x)), 1000).unwrap(),
+        ReaderChunks::new(Cursor::new(le_bytes(&y)), 1).unwrap(),
+        &ShiftExternalOptions::default(),
+    );
+    assert_eq!(from_cursors.unwrap(), raw::shift(&x, &y, true).unwrap());
+}
+
+#[test]
+fn quantile_option_matches_materialized_differences() {
+    let mut rng = Rng::from_string("external-shift-quantile");
+    let x = sorted(Additive::new(0.0, 1.0).samples(&mut rng, 30));
+    let y = sorted(Additive::new(0.0, 1.0).samples(&mut rng, 21));
+    let diffs = sorted(
+        x.iter()
+            .flat_map(|a| y.iter().map(move |b| a - b))
+            .collect(),
+    );
+    for p in [0.0, 0.1, 0.25, 0.9, 1.0] {
+        let h = (diffs.len() - 1) as f64 * p;
+        let (lo, hi) = (h.floor() as usize, h.ceil() as usize);
+        let expected = diffs[lo] + (h - lo as f64) * (diffs[hi] - diffs[lo]);
+        let opts = ShiftExternalOptions {
+            quantile: p,
+            ..ShiftExternalOptions::default()
+        };
+        let actual =
+            shift_external(SliceChunks::new(&x, 8), SliceChunks::new(&y, 5), &opts).unwrap();
+        assert!(
+            (actual - expected).abs() < 1e-12,
+            "p={p}: {actual} vs {expected}"
+        );
+    }
+}
+
+#[test]
+fn invalid_inputs_are_rejected() {
+    let opts = ShiftExternalOptions::default();
+    let ok = [1.0, 2.0, 3.0];
+
+    let err = shift_external(SliceChunks::new(&[], 4), SliceChunks::new(&ok, 4), &opts);
+    match err.unwrap_err() {
+        EstimatorError::Assumption(e) => {
+            assert_eq!(e.violation().id, AssumptionId::Validity);
+            assert_eq!(e.violation().subject, Subject::X);
+        }
+        other => panic!("unexpected {other:?}"),
+    }
+
+    let bad = [1.0, 2.0, 3.0, f64::INFINITY];
+    let err = shift_external(SliceChunks::new(&ok, 2), SliceChunks::new(&bad, 2), &opts);
+    match err.unwrap_err() {
+        EstimatorError::Assumption(e) => {
+            assert_eq!(e.violation().subject, Subject::Y);
+            assert_eq!(e.violation().index, Some(3));
+        }
+        other => panic!("unexpected {other:?}"),
+    }
+
+    let unsorted = [1.0, 3.0, 2.0];
+    assert!(matches!(
+        shift_external(
+            SliceChunks::new(&unsorted, 3),
+            SliceChunks::new(&ok, 3),
+            &opts
+        ),
+        Err(EstimatorError::Other(_))
+    ));
+    // Each chunk of one value is trivially ascending
+    assert_eq!(
+        shift_external(
+            SliceChunks::new(&unsorted, 1),
+            SliceChunks::new(&ok, 3),
+            &opts
+        )
+        .unwrap(),
+        raw::shift(&unsorted, &ok, false).unwrap()
+    );
+
+    let opts = ShiftExternalOptions {
+        quantile: 1.5,
+        ..ShiftExternalOptions::default()
+    };
+    assert!(matches!(
+        shift_external(SliceChunks::new(&ok, 3), SliceChunks::new(&ok, 3), &opts),
+        Err(EstimatorError::Other(_))
+    ));
+
+    assert!(matches!(
+        ReaderChunks::new(Cursor::new(vec![0u8; 12]), 4),
+        Err(EstimatorError::Other(_))
+    ));
+}
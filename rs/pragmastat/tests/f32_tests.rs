@@ -0,0 +1,136 @@
+//! Tests for the `f32` entry points of the raw API.
+//!
+//! On `f32` data the results must equal the `f64` estimators on the widened
+//! data bit for bit, and stay within the documented tolerance of the `f64`
+//! estimators on the original, unrounded data.
+
+use pragmastat::assumptions::{AssumptionId, EstimatorError, Subject};
+use pragmastat::distributions::{Additive, Distribution, Exp, Multiplic};
+use pragmastat::estimators::raw;
+use pragmastat::Rng;
+
+const SIZES: [usize; 5] = [1, 2, 3, 10, 101];
+
+/// Draws `n` values and returns them as `f64` together with their `f32` rounding.
+fn draw(distribution: &dyn Distribution, rng: &mut Rng, n: usize) -> (Vec<f64>, Vec<f32>) {
+    let x = distribution.samples(rng, n);
+    let x32 = x.iter().map(|&v| v as f32).collect();
+    (x, x32)
+}
+
+fn widen(x: &[f32]) -> Vec<f64> {
+    x.iter().map(|&v| f64::from(v)).collect()
+}
+
+fn max_abs(x: &[f64]) -> f64 {
+    x.iter().fold(0.0, |m, v| m.max(v.abs()))
+}
+
+fn sorted(x: &[f32]) -> Vec<f32> {
+    let mut v = x.to_vec();
+    v.sort_unstable_by(|a, b| a.total_cmp(b));
+    v
+}
+
+/// Allows for the f64 rounding of the estimators themselves.
+fn slack(scale: f64) -> f64 {
+    scale * (1.0 + 1e-9)
+}
+
+#[test]
+fn one_sample_matches_widened_f64_exactly() {
+    let mut rng = Rng::from_string("f32-one-sample");
+    for n in SIZES {
+        let (_, x32) = draw(&Additive::new(100.0, 10.0), &mut rng, n);
+        let x = widen(&x32);
+        let xs = sorted(&x32);
+        let center = raw::center(&x, false).unwrap();
+        assert_eq!(raw::center_f32(&x32, false).unwrap(), center, "n = {n}");
+        assert_eq!(raw::center_f32(&xs, true).unwrap(), center, "n = {n}");
+        if n > 1 {
+            let spread = raw::spread(&x, false).unwrap();
+            assert_eq!(raw::spread_f32(&x32, false).unwrap(), spread, "n = {n}");
+            assert_eq!(raw::spread_f32(&xs, true).unwrap(), spread, "n = {n}");
+        }
+    }
+}
+
+#[test]
+fn two_sample_matches_widened_f64_exactly() {
+    let mut rng = Rng::from_string("f32-two-sample");
+    for n in SIZES {
+        for m in SIZES {
+            let (_, x32) = draw(&Multiplic::new(0.0, 0.5), &mut rng, n);
+            let (_, y32) = draw(&Exp::new(1.0), &mut rng, m);
+            let (x, y) = (widen(&x32), widen(&y32));
+            assert_eq!(
+                raw::shift_f32(&x32, &y32, false).unwrap(),
+                raw::shift(&x, &y, false).unwrap(),
+                "n = {n}, m = {m}"
+            );
+            assert_eq!(
+                raw::ratio_f32(&sorted(&x32), &sorted(&y32), true).unwrap(),
+                raw::ratio(&x, &y, false).unwrap(),
+                "n = {n}, m = {m}"
+            );
+        }
+    }
+}
+
+#[test]
+fn within_documented_tolerance_of_unrounded_f64() {
+    let eps = f64::from(f32::EPSILON) / 2.0; // 2^-24
+    let mut rng = Rng::from_string("f32-tolerance");
+    for n in [10, 101, 1_000] {
+        let (x, x32) = draw(&Additive::new(1_000.0, 50.0), &mut rng, n);
+        let (y, y32) = draw(&Exp::new(0.01), &mut rng, n);
+        let (mx, my) = (max_abs(&x), max_abs(&y));
+
+        let center = raw::center(&x, false).unwrap();
+        let diff = (raw::center_f32(&x32, false).unwrap() - center).abs();
+        assert!(diff <= slack(eps * mx), "center, n = {n}: {diff}");
+
+        let spread = raw::spread(&x, false).unwrap();
+        let diff = (raw::spread_f32(&x32, false).unwrap() - spread).abs();
+        assert!(diff <= slack(2.0 * eps * mx), "spread, n = {n}: {diff}");
+
+        let shift = raw::shift(&x, &y, false).unwrap();
+        let diff = (raw::shift_f32(&x32, &y32, false).unwrap() - shift).abs();
+        assert!(diff <= slack(eps * (mx + my)), "shift, n = {n}: {diff}");
+
+        let ratio = raw::ratio(&x, &y, false).unwrap();
+        let diff = (raw::ratio_f32(&x32, &y32, false).unwrap() / ratio - 1.0).abs();
+        assert!(diff <= slack(2.0 * eps), "ratio, n = {n}: {diff}");
+    }
+}
+
+fn violation(err: EstimatorError) -> (AssumptionId, Subject) {
+    match err {
+        EstimatorError::Assumption(e) => (e.violation().id, e.violation().subject),
+        other => panic!("expected assumption error, got {other}"),
+    }
+}
+
+#[test]
+fn errors_match_f64_entry_points() {
+    assert_eq!(
+        violation(raw::center_f32(&[], false).unwrap_err()),
+        (AssumptionId::Validity, Subject::X)
+    );
+    assert_eq!(
+        violation(raw::spread_f32(&[1.0, f32::NAN], false).unwrap_err()),
+        (AssumptionId::Validity, Subject::X)
+    );
+    assert_eq!(
+        violation(raw::spread_f32(&[2.0, 2.0, 2.0], false).unwrap_err()),
+        (AssumptionId::Sparity, Subject::X)
+    );
+    assert_eq!(
+        violation(raw::shift_f32(&[1.0], &[f32::INFINITY], false).unwrap_err()),
+        (AssumptionId::Validity, Subject::Y)
+    );
+    assert_eq!(
+        violation(raw::ratio_f32(&[1.0, 2.0], &[0.0, 1.0], false).unwrap_err()),
+        (AssumptionId::Positivity, Subject::Y)
+    );
+}
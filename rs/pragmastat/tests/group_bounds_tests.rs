@@ -0,0 +1,42 @@
+//! Tests for bounds computed per group in one call.
+
+use pragmastat::distributions::{Additive, Distribution};
+use pragmastat::*;
+
+#[test]
+fn matches_center_bounds_per_group() {
+    let mut rng = Rng::from_string("group-center-bounds");
+    let groups: Vec<Vec<f64>> = [5, 30, 200]
+        .iter()
+        .map(|&n| Additive::new(10.0, 2.0).samples(&mut rng, n))
+        .collect();
+    let slices: Vec<&[f64]> = groups.iter().map(Vec::as_slice).collect();
+    let grouped = group_center_bounds(&slices, 0.1);
+    assert_eq!(grouped.len(), 3);
+    for (group, result) in groups.iter().zip(&grouped) {
+        let expected = center_bounds(&Sample::new(group.clone()).unwrap(), 0.1).unwrap();
+        let actual = result.as_ref().unwrap();
+        assert_eq!(
+            (actual.lower, actual.upper),
+            (expected.lower, expected.upper)
+        );
+        assert_eq!(actual.unit, expected.unit);
+    }
+}
+
+#[test]
+fn bad_groups_fail_on_their_own() {
+    let good = [3.0, 1.0, 4.0, 1.5, 5.0, 9.0, 2.6, 6.5];
+    let grouped = group_center_bounds(&[&[], &good, &[1.0], &[f64::NAN, 1.0], &good], 0.1);
+    assert_eq!(grouped.len(), 5);
+    let id = |i: usize| match &grouped[i] {
+        Err(EstimatorError::Assumption(e)) => e.violation().id,
+        other => panic!("group {i}: unexpected {other:?}"),
+    };
+    assert_eq!(id(0), AssumptionId::Validity);
+    assert_eq!(id(2), AssumptionId::Domain);
+    assert_eq!(id(3), AssumptionId::Validity);
+    let (a, b) = (grouped[1].as_ref().unwrap(), grouped[4].as_ref().unwrap());
+    assert_eq!((a.lower, a.upper), (b.lower, b.upper));
+    assert!(group_center_bounds(&[], 0.1).is_empty());
+}
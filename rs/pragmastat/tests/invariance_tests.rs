@@ -61,6 +61,10 @@ mod invariance_tests {
         v.iter().map(|&x| x * scalar).collect()
     }
 
+    fn to_f32(v: &[f64]) -> Vec<f32> {
+        v.iter().map(|&x| x as f32).collect()
+    }
+
     // Center invariance tests
 
     #[test]
@@ -83,10 +87,39 @@ mod invariance_tests {
     fn center_negate() {
         perform_test_one(
             |x| raw::center(&vec_mul_scalar(x, -1.0), false).unwrap(),
-            |x| -1.0 * raw::center(x, false).unwrap(),
+            |x| -raw::center(x, false).unwrap(),
         );
     }
 
+    #[test]
+    fn center_permutation_with_ties() {
+        // Few distinct values (signed zeros included), so most pairwise sums tie
+        let mut rng = Rng::from_string("center-ties");
+        for n in [3, 4, 10, 25, 64, 101] {
+            let x: Vec<f64> = (0..n)
+                .map(|i| match i % 5 {
+                    0 => -0.0,
+                    1 => 0.0,
+                    _ => rng.uniform_i64(-2, 3) as f64 * 0.1,
+                })
+                .collect();
+            let expected = raw::center(&x, false).unwrap();
+            let expected_f32 = raw::center_f32(&to_f32(&x), false).unwrap();
+            for _ in 0..20 {
+                let shuffled = rng.shuffle(&x);
+                let sample = Sample::new(shuffled.clone()).unwrap();
+                for actual in [
+                    raw::center(&shuffled, false).unwrap(),
+                    center(&sample).unwrap().value,
+                ] {
+                    assert_eq!(actual.to_bits(), expected.to_bits(), "n={n}");
+                }
+                let actual_f32 = raw::center_f32(&to_f32(&shuffled), false).unwrap();
+                assert_eq!(actual_f32.to_bits(), expected_f32.to_bits(), "n={n}");
+            }
+        }
+    }
+
     // Spread invariance tests
 
     #[test]
@@ -135,7 +168,7 @@ mod invariance_tests {
     fn shift_antisymmetry() {
         perform_test_two(
             |x, y| raw::shift(x, y, false).unwrap(),
-            |x, y| -1.0 * raw::shift(y, x, false).unwrap(),
+            |x, y| -raw::shift(y, x, false).unwrap(),
         );
     }
 
@@ -149,6 +182,23 @@ mod invariance_tests {
         );
     }
 
+    #[test]
+    fn ratio_is_exp_shift_of_logs() {
+        let ln = |v: &[f64]| v.iter().map(|x| x.ln()).collect::<Vec<_>>();
+        perform_test_two(
+            |x, y| raw::ratio(x, y, false).unwrap(),
+            |x, y| raw::shift(&ln(x), &ln(y), false).unwrap().exp(),
+        );
+    }
+
+    #[test]
+    fn ratio_antisymmetry() {
+        perform_test_two(
+            |x, y| raw::ratio(x, y, false).unwrap(),
+            |x, y| 1.0 / raw::ratio(y, x, false).unwrap(),
+        );
+    }
+
     // Disparity invariance tests
 
     #[test]
@@ -173,7 +223,7 @@ mod invariance_tests {
             |x, y| {
                 raw::disparity(&vec_mul_scalar(x, -2.0), &vec_mul_scalar(y, -2.0), false).unwrap()
             },
-            |x, y| -1.0 * raw::disparity(x, y, false).unwrap(),
+            |x, y| -raw::disparity(x, y, false).unwrap(),
         );
     }
 
@@ -181,7 +231,7 @@ mod invariance_tests {
     fn disparity_antisymmetry() {
         perform_test_two(
             |x, y| raw::disparity(x, y, false).unwrap(),
-            |x, y| -1.0 * raw::disparity(y, x, false).unwrap(),
+            |x, y| -raw::disparity(y, x, false).unwrap(),
         );
     }
 }
@@ -0,0 +1,97 @@
+//! Reference values for the MAD and IQR estimators, computed by hand.
+
+use pragmastat::estimators::raw;
+use pragmastat::estimators::MAD_CONSISTENCY_CONSTANT;
+use pragmastat::measurement_unit::MeasurementUnit;
+use pragmastat::{AssumptionId, EstimatorError, MadScale, Sample};
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-12,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn mad_odd_n() {
+    // median 3, deviations {0, 1, 1, 2, 97}
+    let x = [100.0, 2.0, 3.0, 1.0, 4.0];
+    assert_close(raw::mad(&x, MadScale::Raw, false).unwrap(), 1.0);
+}
+
+#[test]
+fn mad_even_n() {
+    // median 3.5, deviations {0.5, 0.5, 1.5, 1.5, 2.5, 2.5}
+    let x = [6.0, 1.0, 5.0, 2.0, 4.0, 3.0];
+    assert_close(raw::mad(&x, MadScale::Raw, false).unwrap(), 1.5);
+}
+
+#[test]
+fn mad_normal_scaling() {
+    let x = [6.0, 1.0, 5.0, 2.0, 4.0, 3.0];
+    assert_close(
+        raw::mad(&x, MadScale::Normal, false).unwrap(),
+        1.5 * 1.482602218505602,
+    );
+    assert_close(MAD_CONSISTENCY_CONSTANT, 1.482602218505602);
+}
+
+#[test]
+fn mad_of_tied_data_is_zero() {
+    let x = [1.0, 1.0, 1.0, 2.0];
+    assert_eq!(raw::mad(&x, MadScale::Raw, false).unwrap(), 0.0);
+}
+
+#[test]
+fn iqr_odd_n() {
+    // Q1 at position 1 -> 2, Q3 at position 3 -> 4
+    let x = [5.0, 1.0, 4.0, 2.0, 3.0];
+    assert_close(raw::iqr(&x, false).unwrap(), 2.0);
+}
+
+#[test]
+fn iqr_even_n() {
+    // Q1 at position 1.25 -> 2.25, Q3 at position 3.75 -> 4.75
+    let x = [6.0, 1.0, 5.0, 2.0, 4.0, 3.0];
+    assert_close(raw::iqr(&x, false).unwrap(), 2.5);
+}
+
+#[test]
+fn iqr_single_value_is_zero() {
+    assert_eq!(raw::iqr(&[7.0], false).unwrap(), 0.0);
+}
+
+#[test]
+fn invalid_input_is_rejected() {
+    for x in [vec![], vec![1.0, f64::NAN], vec![f64::INFINITY, 2.0]] {
+        for result in [raw::mad(&x, MadScale::Raw, false), raw::iqr(&x, false)] {
+            match result.unwrap_err() {
+                EstimatorError::Assumption(e) => {
+                    assert_eq!(e.violation().id, AssumptionId::Validity)
+                }
+                other => panic!("unexpected error: {other}"),
+            }
+        }
+    }
+}
+
+#[test]
+fn sample_methods_keep_unit() {
+    let ms = MeasurementUnit::new("ms", "Time", "ms", "Millisecond", 1_000_000);
+    let x = Sample::with_unit(vec![6.0, 1.0, 5.0, 2.0, 4.0, 3.0], ms.clone()).unwrap();
+
+    let mad = x.mad(MadScale::Raw).unwrap();
+    assert_close(mad.value, 1.5);
+    assert_eq!(mad.unit, ms);
+
+    let iqr = x.iqr().unwrap();
+    assert_close(iqr.value, 2.5);
+    assert_eq!(iqr.unit, ms);
+}
+
+#[test]
+fn sample_methods_reject_weighted() {
+    let x = Sample::weighted(vec![1.0, 2.0], vec![1.0, 1.0], MeasurementUnit::number()).unwrap();
+    assert!(x.mad(MadScale::Normal).is_err());
+    assert!(x.iqr().is_err());
+}
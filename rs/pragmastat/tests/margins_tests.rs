@@ -0,0 +1,260 @@
+//! Property tests for the public margin functions.
+//!
+//! Margins must be monotone: a larger misrate excludes at least as many
+//! extreme order statistics, and so does a larger sample at a fixed misrate.
+//! The size ranges straddle the exact/approximate switchovers
+//! (n = 63 for signed-rank, n + m = 400 for pairwise).
+//!
+//! The coverage functions are checked against tail sums computed here by
+//! enumeration or binomial coefficients, and against simulated bounds.
+
+use pragmastat::distributions::{Additive, Distribution};
+use pragmastat::estimators::raw;
+use pragmastat::experiment::{coverage, Experiment};
+use pragmastat::margins::{
+    pairwise_coverage, pairwise_margin, sign_coverage, sign_margin, sign_margin_randomized,
+    signed_rank_coverage, signed_rank_margin,
+};
+use pragmastat::{AssumptionId, Rng, Subject};
+
+const MISRATES: [f64; 7] = [1e-3, 0.005, 0.01, 0.05, 0.1, 0.25, 0.5];
+
+#[test]
+fn sign_margin_monotone_in_misrate_and_n() {
+    for n in 12..=80 {
+        let margins: Vec<usize> = MISRATES
+            .iter()
+            .map(|&m| sign_margin(n, m).unwrap())
+            .collect();
+        assert!(
+            margins.windows(2).all(|w| w[0] <= w[1]),
+            "n={n}: {margins:?}"
+        );
+    }
+    for &misrate in &MISRATES {
+        let margins: Vec<usize> = (12..=80)
+            .map(|n| sign_margin(n, misrate).unwrap())
+            .collect();
+        assert!(
+            margins.windows(2).all(|w| w[0] <= w[1]),
+            "misrate={misrate}"
+        );
+    }
+}
+
+#[test]
+fn sign_margin_randomized_brackets_conservative_margin() {
+    let mut rng = Rng::from_string("margins-randomized");
+    for n in 10..=40 {
+        let base = sign_margin(n, 0.1).unwrap();
+        for _ in 0..20 {
+            let margin = sign_margin_randomized(n, 0.1, &mut rng).unwrap();
+            assert!(
+                margin == base || margin == base + 2,
+                "n={n}: {margin} vs {base}"
+            );
+        }
+    }
+}
+
+#[test]
+fn signed_rank_margin_monotone_in_misrate_and_n() {
+    for n in 12..=80 {
+        let margins: Vec<usize> = MISRATES
+            .iter()
+            .map(|&m| signed_rank_margin(n, m).unwrap())
+            .collect();
+        assert!(
+            margins.windows(2).all(|w| w[0] <= w[1]),
+            "n={n}: {margins:?}"
+        );
+    }
+    for &misrate in &MISRATES {
+        let margins: Vec<usize> = (12..=80)
+            .map(|n| signed_rank_margin(n, misrate).unwrap())
+            .collect();
+        assert!(
+            margins.windows(2).all(|w| w[0] <= w[1]),
+            "misrate={misrate}"
+        );
+    }
+}
+
+#[test]
+fn pairwise_margin_monotone_in_misrate_and_n() {
+    for n in [5, 50, 379, 380, 381, 500] {
+        let margins: Vec<u64> = MISRATES
+            .iter()
+            .map(|&m| pairwise_margin(n, 20, m).unwrap())
+            .collect();
+        assert!(
+            margins.windows(2).all(|w| w[0] <= w[1]),
+            "n={n}: {margins:?}"
+        );
+    }
+    for &misrate in &MISRATES {
+        let margins: Vec<u64> = (376..=384)
+            .map(|n| pairwise_margin(n, 20, misrate).unwrap())
+            .collect();
+        assert!(
+            margins.windows(2).all(|w| w[0] <= w[1]),
+            "misrate={misrate}"
+        );
+    }
+}
+
+#[test]
+fn invalid_misrates_are_domain_errors() {
+    for misrate in [f64::NAN, -0.1, 1.1, 1e-12] {
+        for err in [
+            sign_margin(10, misrate).unwrap_err(),
+            signed_rank_margin(10, misrate).unwrap_err(),
+            pairwise_margin(10, 10, misrate).unwrap_err(),
+        ] {
+            assert_eq!(err.violation().id, AssumptionId::Domain);
+            assert_eq!(err.violation().subject, Subject::Misrate);
+        }
+    }
+    assert_eq!(
+        sign_margin(0, 0.5).unwrap_err().violation().subject,
+        Subject::X
+    );
+}
+
+/// `P(Binomial(n, 0.5) <= r)` from exact binomial coefficients.
+fn binomial_tail(n: usize, r: usize) -> f64 {
+    let mut c: u128 = 1;
+    let mut sum: u128 = 1;
+    for k in 1..=r {
+        c = c * (n - k + 1) as u128 / k as u128;
+        sum += c;
+    }
+    sum as f64 / 2f64.powi(n as i32)
+}
+
+/// `P(W <= h)` by enumerating all `2^n` sign assignments.
+fn signed_rank_tail(n: usize, h: usize) -> f64 {
+    let count = (0..1_u32 << n)
+        .filter(|mask| {
+            let w: usize = (0..n).filter(|i| mask >> i & 1 == 1).map(|i| i + 1).sum();
+            w <= h
+        })
+        .count();
+    count as f64 / (1_u32 << n) as f64
+}
+
+/// `P(U <= h)` by enumerating all placements of `n` values among `n + m`.
+fn mann_whitney_tail(n: usize, m: usize, h: usize) -> f64 {
+    let placements: Vec<u32> = (0..1_u32 << (n + m))
+        .filter(|mask| mask.count_ones() as usize == n)
+        .collect();
+    let count = placements
+        .iter()
+        .filter(|&&mask| {
+            let positions: usize = (0..n + m).filter(|i| mask >> i & 1 == 1).sum();
+            positions - n * (n - 1) / 2 <= h
+        })
+        .count();
+    count as f64 / placements.len() as f64
+}
+
+#[test]
+fn sign_coverage_matches_binomial_tail() {
+    for n in 1..=100 {
+        for r in 0..=(n - 1) / 2 {
+            let expected = 1.0 - 2.0 * binomial_tail(n, r);
+            let actual = sign_coverage(n, 2 * r);
+            assert!((actual - expected).abs() < 1e-12, "n={n}, r={r}");
+        }
+        // Margins past the middle collapse the bounds to the central pair
+        assert_eq!(sign_coverage(n, 2 * n), sign_coverage(n, n - 1));
+    }
+    assert_eq!(sign_coverage(7, 100), 0.0);
+}
+
+#[test]
+fn signed_rank_coverage_matches_enumeration() {
+    for n in 1..=14 {
+        let total = n * (n + 1) / 2;
+        for h in 0..=(total - 1) / 2 {
+            let expected = 1.0 - 2.0 * signed_rank_tail(n, h);
+            let actual = signed_rank_coverage(n, 2 * h);
+            assert!((actual - expected).abs() < 1e-12, "n={n}, h={h}");
+        }
+    }
+}
+
+#[test]
+fn pairwise_coverage_matches_enumeration() {
+    for n in 1..=7 {
+        for m in 1..=14 - n {
+            let total = n * m;
+            for h in 0..=(total - 1) / 2 {
+                let expected = 1.0 - 2.0 * mann_whitney_tail(n, m, h);
+                let actual = pairwise_coverage(n, m, 2 * h as u64);
+                assert!((actual - expected).abs() < 1e-12, "n={n}, m={m}, h={h}");
+            }
+        }
+    }
+}
+
+#[test]
+fn coverage_decreases_with_margin() {
+    fn assert_decreasing(coverages: &[f64], label: &str) {
+        assert!(
+            coverages.windows(2).all(|w| w[0] >= w[1]),
+            "{label}: {coverages:?}"
+        );
+        assert!(coverages.iter().all(|c| (0.0..=1.0).contains(c)), "{label}");
+    }
+    for n in [10, 64, 65, 1000] {
+        let margins: Vec<usize> = (0..=n).step_by(2).collect();
+        let coverages: Vec<f64> = margins.iter().map(|&k| sign_coverage(n, k)).collect();
+        assert_decreasing(&coverages, &format!("sign n={n}"));
+    }
+    for n in [10, 63, 64, 150] {
+        let total = n * (n + 1) / 2;
+        let coverages: Vec<f64> = (0..=total)
+            .step_by(2 * (total / 100).max(1))
+            .map(|k| signed_rank_coverage(n, k))
+            .collect();
+        assert_decreasing(&coverages, &format!("signed-rank n={n}"));
+    }
+    for (n, m) in [(10, 10), (30, 1000), (450, 500)] {
+        let total = (n * m) as u64;
+        let coverages: Vec<f64> = (0..=total)
+            .step_by(2 * (total as usize / 60).max(1))
+            .map(|k| pairwise_coverage(n, m, k))
+            .collect();
+        assert_decreasing(&coverages, &format!("pairwise n={n}, m={m}"));
+    }
+}
+
+#[test]
+fn coverage_of_chosen_margins_at_large_n() {
+    let sign = sign_coverage(1000, sign_margin(1000, 0.05).unwrap());
+    assert!((0.95..0.96).contains(&sign), "{sign}");
+    let signed_rank = signed_rank_coverage(1000, signed_rank_margin(1000, 0.05).unwrap());
+    assert!((signed_rank - 0.95).abs() < 1e-3, "{signed_rank}");
+    let pairwise = pairwise_coverage(1000, 30, pairwise_margin(1000, 30, 0.05).unwrap());
+    assert!((pairwise - 0.95).abs() < 1e-3, "{pairwise}");
+}
+
+#[test]
+fn bounds_cover_at_the_exact_rate() {
+    let (n, m, misrate) = (8, 6, 0.1);
+    let runs = Experiment::new("margins-coverage").replicate(20_000, |rng| {
+        let x = Additive::new(0.0, 1.0).samples(rng, n);
+        let y = Additive::new(0.0, 1.0).samples(rng, m);
+        (
+            raw::center_bounds(&x, misrate, false).unwrap(),
+            raw::shift_bounds(&x, &y, misrate, false).unwrap(),
+        )
+    });
+    let center = coverage(&runs, |r| r.0.lower <= 0.0 && 0.0 <= r.0.upper);
+    let expected = signed_rank_coverage(n, signed_rank_margin(n, misrate).unwrap());
+    assert!((center - expected).abs() < 0.01, "{center} vs {expected}");
+    let shift = coverage(&runs, |r| r.1.lower <= 0.0 && 0.0 <= r.1.upper);
+    let expected = pairwise_coverage(n, m, pairwise_margin(n, m, misrate).unwrap());
+    assert!((shift - expected).abs() < 0.01, "{shift} vs {expected}");
+}
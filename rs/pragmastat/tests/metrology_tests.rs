@@ -189,6 +189,110 @@ fn shift_bounds_sample_unit_is_finer() {
     assert_eq!(b.unit.id(), ms.id());
 }
 
+fn celsius() -> MeasurementUnit {
+    MeasurementUnit::affine("C", "Temperature", "°C", "Celsius", 9, 2458.35)
+}
+
+fn fahrenheit() -> MeasurementUnit {
+    MeasurementUnit::affine("F", "Temperature", "°F", "Fahrenheit", 5, 2298.35)
+}
+
+#[test]
+fn affine_sample_round_trip() {
+    let x = Sample::with_unit(vec![-40.0, 0.0, 37.0, 100.0], celsius()).unwrap();
+    let f = x.convert_to(&fahrenheit()).unwrap();
+    for (&actual, expected) in f.values().iter().zip([-40.0, 32.0, 98.6, 212.0]) {
+        assert!(approx_eq!(f64, actual, expected, epsilon = 1e-9));
+    }
+    let back = f.convert_to(&celsius()).unwrap();
+    for (&actual, &expected) in back.values().iter().zip(x.values()) {
+        assert!(approx_eq!(f64, actual, expected, epsilon = 1e-9));
+    }
+}
+
+#[test]
+fn affine_shift_is_offset_free() {
+    // 10..=15 °C vs 50..=59 °F (= 10..=15 °C): shift must be zero in either unit.
+    let x = Sample::with_unit((10..=15).map(f64::from).collect(), celsius()).unwrap();
+    let y = Sample::with_unit(
+        (10..=15).map(|v| f64::from(v) * 1.8 + 32.0).collect(),
+        fahrenheit(),
+    )
+    .unwrap();
+    let m = shift(&x, &y).unwrap();
+    assert!(approx_eq!(f64, m.value, 0.0, epsilon = 1e-9));
+    assert_eq!(m.unit.id(), "F");
+}
+
+#[test]
+fn affine_center_threshold_is_converted() {
+    let x = Sample::with_unit((1..=10).map(f64::from).collect(), celsius()).unwrap();
+    // 32 °F == 0 °C, which is below every value in x.
+    let threshold =
+        Threshold::new(Metric::Center, Measurement::new(32.0, fahrenheit()), 0.05).unwrap();
+    let projections = compare1(&x, &[threshold]).unwrap();
+    assert_eq!(projections[0].verdict(), ComparisonVerdict::Greater);
+}
+
+#[test]
+fn affine_ratio_rejected() {
+    let x = Sample::with_unit(vec![1.0, 2.0, 3.0, 4.0], celsius()).unwrap();
+    let y = Sample::with_unit(vec![2.0, 3.0, 4.0, 5.0], celsius()).unwrap();
+    assert!(matches!(ratio(&x, &y), Err(EstimatorError::Other(_))));
+    assert!(matches!(
+        ratio_bounds(&x, &y, 0.5),
+        Err(EstimatorError::Other(_))
+    ));
+    // Differences remain well-defined.
+    assert!(shift(&x, &y).is_ok());
+    assert!(disparity(&x, &y).is_ok());
+}
+
+#[test]
+fn percent_shift_is_in_percentage_points() {
+    // Cache hit rates: x is 2 percentage points above y, a relative change of ~2.2 %.
+    let y = Sample::with_unit(vec![89.0, 90.0, 90.0, 91.0], MeasurementUnit::percent()).unwrap();
+    let x = Sample::with_unit(vec![91.0, 92.0, 92.0, 93.0], MeasurementUnit::percent()).unwrap();
+    let s = shift(&x, &y).unwrap();
+    assert_eq!(s.value, 2.0);
+    assert_eq!(s.unit, MeasurementUnit::percent());
+    assert_eq!(s.to_string(), "2 %");
+
+    let r = ratio(&x, &y).unwrap();
+    assert_eq!(r.unit, MeasurementUnit::ratio());
+    assert!(approx_eq!(f64, r.value, 92.0 / 90.0, epsilon = 1e-12));
+
+    // A plain fraction converts to the finer percent unit
+    let fraction = Sample::new(vec![0.89, 0.90, 0.90, 0.91]).unwrap();
+    let s = shift(&x, &fraction).unwrap();
+    assert_eq!(s.unit, MeasurementUnit::percent());
+    assert!(approx_eq!(f64, s.value, 2.0, epsilon = 1e-9));
+}
+
+#[test]
+fn fraction_percent_ppm_sample_round_trip() {
+    let fraction = Sample::new(vec![0.5, 0.001]).unwrap();
+    let percent = fraction.convert_to(&MeasurementUnit::percent()).unwrap();
+    assert_eq!(percent.values(), &[50.0, 0.1]);
+    let ppm = percent
+        .convert_to(&MeasurementUnit::parts_per_million())
+        .unwrap();
+    assert_eq!(ppm.values(), &[500_000.0, 1_000.0]);
+    let back = ppm.convert_to(&MeasurementUnit::number()).unwrap();
+    assert_eq!(back.values(), fraction.values());
+}
+
+#[test]
+fn percent_and_milliseconds_do_not_mix() {
+    let ms = MeasurementUnit::new("ms", "Time", "ms", "Millisecond", 1_000_000);
+    let x = Sample::with_unit(vec![1.0, 2.0, 3.0], MeasurementUnit::percent()).unwrap();
+    let y = Sample::with_unit(vec![1.0, 2.0, 3.0], ms.clone()).unwrap();
+    let err = shift(&x, &y).unwrap_err();
+    assert_eq!(err.to_string(), "can't convert Percent to Millisecond");
+    assert!(ratio(&x, &y).is_err());
+    assert!(x.convert_to(&ms).is_err());
+}
+
 // =============================================================================
 // Unit propagation tests
 // =============================================================================
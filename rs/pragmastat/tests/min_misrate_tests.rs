@@ -0,0 +1,158 @@
+//! Misrate planning helpers must agree with what the bounds functions accept.
+//!
+//! For every size, the reported minimum misrate is accepted and anything
+//! strictly below it is rejected with a misrate domain error.
+
+use pragmastat::estimators::raw;
+use pragmastat::{
+    center_bounds, center_bounds_clamped, min_misrate_center, min_misrate_shift,
+    min_misrate_spread, required_sample_size_center, AssumptionId, EstimatorError, Sample,
+    Severity, Subject,
+};
+
+fn data(n: usize) -> Vec<f64> {
+    (0..n).map(|i| (i * i) as f64 + 1.0).collect()
+}
+
+fn is_misrate_error(err: EstimatorError) -> bool {
+    match err {
+        EstimatorError::Assumption(e) => {
+            e.violation().id == AssumptionId::Domain && e.violation().subject == Subject::Misrate
+        }
+        EstimatorError::Other(_) => false,
+    }
+}
+
+fn just_below(misrate: f64) -> f64 {
+    misrate * (1.0 - 1e-9)
+}
+
+#[test]
+fn center_boundaries() {
+    assert_eq!(min_misrate_center(0), f64::INFINITY);
+    assert_eq!(min_misrate_center(1), f64::INFINITY);
+    assert_eq!(min_misrate_center(2), 0.5);
+    assert!(raw::center_bounds(&data(1), 1.0, false).is_err());
+    for n in 2..=12 {
+        let x = data(n);
+        let min = min_misrate_center(n);
+        assert!(raw::center_bounds(&x, min, false).is_ok(), "n={n}");
+        let err = raw::center_bounds(&x, just_below(min), false).unwrap_err();
+        assert!(is_misrate_error(err), "n={n}");
+    }
+}
+
+#[test]
+fn center_achievable_misrate_for_small_n() {
+    let expected = [0.5, 0.25, 0.125, 0.0625, 0.03125];
+    for (n, &min) in (2..=6).zip(&expected) {
+        assert_eq!(min_misrate_center(n), min, "n={n}");
+    }
+    // The default misrate 1e-3 needs 11 values
+    assert!(min_misrate_center(10) > 1e-3 && min_misrate_center(11) <= 1e-3);
+}
+
+#[test]
+fn center_bounds_clamped_raises_unachievable_misrate() {
+    for n in 2..=8 {
+        let x = Sample::new(data(n)).unwrap();
+        let min = min_misrate_center(n);
+        let clamped = center_bounds_clamped(&x, min / 10.0).unwrap();
+        let at_min = center_bounds(&x, min).unwrap();
+        assert_eq!(
+            (clamped.value.lower, clamped.value.upper),
+            (at_min.lower, at_min.upper),
+            "n={n}"
+        );
+        // The minimum misrate gives the widest bounds: the extreme values
+        assert_eq!(at_min.lower, 1.0);
+        assert_eq!(at_min.upper, ((n - 1) * (n - 1)) as f64 + 1.0);
+        let warning = clamped.warnings.last().unwrap().violation().unwrap();
+        assert_eq!(
+            (warning.id, warning.subject, warning.severity),
+            (AssumptionId::Domain, Subject::Misrate, Severity::Warning)
+        );
+        assert_eq!(warning.value, Some(min / 10.0));
+    }
+}
+
+#[test]
+fn center_bounds_clamped_passes_achievable_and_invalid_misrates_through() {
+    let x = Sample::new(data(12)).unwrap();
+    let clamped = center_bounds_clamped(&x, 0.01).unwrap();
+    let plain = center_bounds(&x, 0.01).unwrap();
+    assert_eq!(
+        (clamped.value.lower, clamped.value.upper),
+        (plain.lower, plain.upper)
+    );
+    assert!(clamped.warnings.is_empty());
+    for misrate in [0.0, -0.1, f64::NAN, 1.5] {
+        let err = center_bounds_clamped(&x, misrate).unwrap_err();
+        assert!(is_misrate_error(err), "misrate={misrate}");
+    }
+    // Too small for any misrate
+    let single = Sample::new(vec![1.0]).unwrap();
+    assert!(center_bounds_clamped(&single, 0.5).is_err());
+}
+
+#[test]
+fn shift_boundaries() {
+    assert_eq!(min_misrate_shift(0, 5), f64::INFINITY);
+    assert_eq!(min_misrate_shift(5, 0), f64::INFINITY);
+    assert_eq!(min_misrate_shift(1, 1), 1.0);
+    for (n, m) in [(1, 1), (1, 2), (2, 2), (3, 5), (6, 4)] {
+        let (x, y) = (data(n), data(m));
+        let min = min_misrate_shift(n, m);
+        assert!(
+            raw::shift_bounds(&x, &y, min, false).is_ok(),
+            "n={n}, m={m}"
+        );
+        assert!(
+            raw::ratio_bounds(&x, &y, min, false).is_ok(),
+            "n={n}, m={m}"
+        );
+        let err = raw::shift_bounds(&x, &y, just_below(min), false).unwrap_err();
+        assert!(is_misrate_error(err), "n={n}, m={m}");
+    }
+}
+
+#[test]
+fn spread_boundaries() {
+    assert_eq!(min_misrate_spread(0), f64::INFINITY);
+    assert_eq!(min_misrate_spread(1), f64::INFINITY);
+    assert_eq!(min_misrate_spread(2), 1.0);
+    assert_eq!(min_misrate_spread(3), 1.0);
+    assert!(raw::spread_bounds_with_seed(&data(1), 1.0, "min-misrate", false).is_err());
+    for n in 2..=14 {
+        let x = data(n);
+        let min = min_misrate_spread(n);
+        assert!(
+            raw::spread_bounds_with_seed(&x, min, "min-misrate", false).is_ok(),
+            "n={n}"
+        );
+        if min < 1.0 {
+            let err = raw::spread_bounds_with_seed(&x, just_below(min), "min-misrate", false)
+                .unwrap_err();
+            assert!(is_misrate_error(err), "n={n}");
+        }
+    }
+}
+
+#[test]
+fn required_sample_size_center_inverts_min_misrate() {
+    assert_eq!(required_sample_size_center(1.0), 2);
+    assert_eq!(required_sample_size_center(0.5), 2);
+    assert_eq!(required_sample_size_center(0.4), 3);
+    assert_eq!(required_sample_size_center(1e-3), 11);
+    for misrate in [0.3, 0.1, 0.05, 0.01, 0.005, 1e-3, 1e-6] {
+        let n = required_sample_size_center(misrate);
+        assert!(min_misrate_center(n) <= misrate);
+        assert!(n == 2 || min_misrate_center(n - 1) > misrate);
+    }
+}
+
+#[test]
+#[should_panic(expected = "misrate must be in (0, 1]")]
+fn required_sample_size_center_rejects_zero() {
+    required_sample_size_center(0.0);
+}
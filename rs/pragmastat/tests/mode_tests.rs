@@ -0,0 +1,130 @@
+//! Tests for the half-sample mode estimator.
+
+use pragmastat::distributions::{Additive, Distribution, Mixture, Multiplic};
+use pragmastat::estimators::raw;
+use pragmastat::invariants::{location_equivariance, scale_equivariance};
+use pragmastat::measurement_unit::MeasurementUnit;
+use pragmastat::{center, mode_hsm, AssumptionId, EstimatorError, Rng, Sample};
+
+fn mode(x: &[f64]) -> f64 {
+    raw::mode_hsm(x, false).unwrap()
+}
+
+#[test]
+fn small_samples() {
+    assert_eq!(mode(&[7.0]), 7.0);
+    assert_eq!(mode(&[3.0, 1.0]), 2.0);
+    // Closer pair on the left, on the right, and equal gaps
+    assert_eq!(mode(&[10.0, 1.0, 2.0]), 1.5);
+    assert_eq!(mode(&[1.0, 10.0, 9.0]), 9.5);
+    assert_eq!(mode(&[3.0, 1.0, 2.0]), 2.0);
+}
+
+#[test]
+fn hand_worked_samples() {
+    // h = 3: [1, 2, 2] and [2, 2, 3] both have range 1, the first is kept
+    // (position (2 - 1) / 2 = 0); then the gaps 1 and 0 pick [2, 2]
+    assert_eq!(mode(&[3.0, 2.0, 10.0, 1.0, 2.0]), 2.0);
+
+    // h = 4: [1, 5, 6, 7] (range 6); h = 2: [5, 6] and [6, 7] tie, [5, 6] is kept
+    assert_eq!(mode(&[30.0, 1.0, 6.0, 31.0, 5.0, 20.0, 7.0]), 5.5);
+
+    // Three tied windows of width 1: the middle one, [2, 3]
+    assert_eq!(mode(&[1.0, 2.0, 3.0, 4.0]), 2.5);
+}
+
+#[test]
+fn skewed_latencies() {
+    // h = 4: [10, 11, 11, 12] and [11, 11, 12, 13] tie, the first is kept;
+    // h = 2: [11, 11] has range 0
+    let x = [300.0, 10.0, 11.0, 95.0, 12.0, 11.0, 40.0, 13.0];
+    assert_eq!(mode(&x), 11.0);
+    let sample = Sample::new(x.to_vec()).unwrap();
+    assert!(sample.mode().unwrap().value < sample.median().unwrap().value);
+    assert!(sample.median().unwrap().value < center(&sample).unwrap().value);
+}
+
+#[test]
+fn tied_values() {
+    assert_eq!(mode(&[4.0; 9]), 4.0);
+    assert_eq!(mode(&[1.0, 5.0, 5.0, 5.0, 9.0, 9.0]), 5.0);
+}
+
+#[test]
+fn shift_and_scale_are_exact_on_a_dyadic_grid() {
+    let x = [0.25, 1.5, 1.75, 2.0, 2.0, 3.5, 6.0, 6.25];
+    let m = mode(&x);
+    for c in [-8.0, 1.0, 1024.0] {
+        let shifted: Vec<f64> = x.iter().map(|v| v + c).collect();
+        assert_eq!(mode(&shifted), m + c);
+    }
+    for k in [0.5, 2.0, 64.0, -4.0] {
+        let scaled: Vec<f64> = x.iter().map(|v| v * k).collect();
+        assert_eq!(mode(&scaled), m * k);
+    }
+}
+
+#[test]
+fn equivariant_on_random_samples() {
+    let mut rng = Rng::from_string("mode-equivariance");
+    for n in [5, 17, 64, 301] {
+        let x = Multiplic::new(1.0, 0.8).samples(&mut rng, n);
+        let c = rng.uniform_f64_range(-100.0, 100.0);
+        let k = rng.uniform_f64_range(0.1, 10.0) * if n % 2 == 0 { -1.0 } else { 1.0 };
+        let result = location_equivariance(|v| raw::mode_hsm(v, false), &x, c).unwrap();
+        assert!(result.holds(), "n = {n}: {result}");
+        let result = scale_equivariance(|v| raw::mode_hsm(v, false), &x, k).unwrap();
+        assert!(result.holds(), "n = {n}: {result}");
+    }
+}
+
+#[test]
+fn finds_the_major_peak_of_a_bimodal_mixture() {
+    let dist = Mixture::new(
+        vec![
+            Box::new(Additive::new(0.0, 1.0)),
+            Box::new(Additive::new(10.0, 1.0)),
+        ],
+        vec![0.7, 0.3],
+    );
+    for seed in ["a", "b", "c", "d", "e"] {
+        let mut rng = Rng::from_string(seed);
+        let x = Sample::new(dist.samples(&mut rng, 2000)).unwrap();
+        let m = x.mode().unwrap().value;
+        assert!(m.abs() < 0.5, "seed {seed}: mode {m}");
+        // Center is pulled toward the minor peak
+        assert!(center(&x).unwrap().value > 2.0);
+    }
+}
+
+#[test]
+fn sits_below_the_median_of_a_skewed_distribution() {
+    // Multiplic(0, 0.5) has mode exp(-0.25) ~ 0.78 and median 1; the half-sample
+    // mode is biased toward the steep side, so only a coarse range is checked
+    let mut rng = Rng::from_string("mode-multiplic");
+    let x = Multiplic::new(0.0, 0.5).samples(&mut rng, 20_000);
+    let m = mode(&x);
+    assert!((0.5..0.9).contains(&m), "mode {m}");
+}
+
+#[test]
+fn sample_mode_carries_the_unit() {
+    let ms = MeasurementUnit::new("ms", "Time", "ms", "Millisecond", 1_000_000);
+    let x = Sample::with_unit(vec![30.0, 1.0, 6.0, 31.0, 5.0, 20.0, 7.0], ms.clone()).unwrap();
+    let m = mode_hsm(&x).unwrap();
+    assert_eq!(m.value, 5.5);
+    assert_eq!(m.unit, ms);
+    assert_eq!(x.mode().unwrap().value, 5.5);
+}
+
+#[test]
+fn rejects_invalid_input() {
+    for x in [vec![], vec![1.0, f64::NAN]] {
+        match raw::mode_hsm(&x, false) {
+            Err(EstimatorError::Assumption(e)) => {
+                assert_eq!(e.violation().id, AssumptionId::Validity)
+            }
+            other => panic!("expected a validity error, got {other:?}"),
+        }
+    }
+}
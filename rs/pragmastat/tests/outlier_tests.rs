@@ -0,0 +1,88 @@
+//! Tests for `flag_outliers` and `Sample::outliers`.
+
+use pragmastat::estimators::raw;
+use pragmastat::{flag_outliers, EstimatorError, MeasurementUnit, Sample, DEFAULT_OUTLIER_K};
+
+fn clean() -> Vec<f64> {
+    (1..=20).map(f64::from).collect()
+}
+
+#[test]
+fn clean_sample_flags_nothing() {
+    let report = flag_outliers(&clean(), DEFAULT_OUTLIER_K).unwrap();
+    assert!(report.indices.is_empty());
+    assert_eq!((report.below, report.above), (0, 0));
+    let center = raw::center(&clean(), false).unwrap();
+    let spread = raw::spread(&clean(), false).unwrap();
+    assert_eq!(report.lower_fence, center - 3.0 * spread);
+    assert_eq!(report.upper_fence, center + 3.0 * spread);
+}
+
+#[test]
+fn asymmetric_contamination() {
+    // Three high outliers and one low outlier, interleaved with clean values
+    let mut x = clean();
+    x.insert(3, 500.0);
+    x.insert(9, -300.0);
+    x.push(1000.0);
+    x.push(750.0);
+    let report = flag_outliers(&x, DEFAULT_OUTLIER_K).unwrap();
+    assert_eq!(report.indices, vec![3, 9, 22, 23]);
+    assert_eq!(report.below, 1);
+    assert_eq!(report.above, 3);
+    // Robust fences stay close to the clean ones
+    assert!(report.upper_fence < 100.0);
+    assert!(report.lower_fence > -100.0);
+}
+
+#[test]
+fn fence_boundaries_are_inclusive() {
+    // k = 0 collapses both fences to the center (3); the value equal to it stays
+    let report = flag_outliers(&[1.0, 2.0, 3.0, 4.0, 5.0], 0.0).unwrap();
+    assert_eq!((report.lower_fence, report.upper_fence), (3.0, 3.0));
+    assert_eq!(report.indices, vec![0, 1, 3, 4]);
+}
+
+#[test]
+fn degenerate_spread() {
+    let report = flag_outliers(&[4.0; 10], DEFAULT_OUTLIER_K).unwrap();
+    assert!(report.indices.is_empty());
+    assert_eq!((report.lower_fence, report.upper_fence), (4.0, 4.0));
+
+    // Tie-dominant sample: spread is 0, so only the differing values are flagged
+    let mut x = vec![4.0; 10];
+    x[2] = 4.5;
+    x[7] = 1.0;
+    let report = flag_outliers(&x, DEFAULT_OUTLIER_K).unwrap();
+    assert_eq!(report.indices, vec![2, 7]);
+    assert_eq!((report.below, report.above), (1, 1));
+}
+
+#[test]
+fn invalid_k_is_rejected() {
+    for k in [-1.0, f64::NAN, f64::INFINITY] {
+        assert!(matches!(
+            flag_outliers(&clean(), k),
+            Err(EstimatorError::Other(_))
+        ));
+    }
+    assert!(matches!(
+        flag_outliers(&[], 3.0),
+        Err(EstimatorError::Assumption(_))
+    ));
+}
+
+#[test]
+fn sample_outliers_reports_fences_in_sample_unit() {
+    let ms = MeasurementUnit::new("ms", "Time", "ms", "Millisecond", 1_000_000);
+    let mut values = clean();
+    values.push(400.0);
+    let x = Sample::with_unit(values.clone(), ms.clone()).unwrap();
+    let report = x.outliers(DEFAULT_OUTLIER_K).unwrap();
+    let raw_report = flag_outliers(&values, DEFAULT_OUTLIER_K).unwrap();
+    assert_eq!(report.indices, vec![20]);
+    assert_eq!(report.indices, raw_report.indices);
+    assert_eq!(report.lower_fence.value, raw_report.lower_fence);
+    assert_eq!(report.upper_fence.value, raw_report.upper_fence);
+    assert_eq!(report.upper_fence.unit, ms);
+}
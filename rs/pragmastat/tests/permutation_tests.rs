@@ -0,0 +1,142 @@
+//! Tests for `permutation::shift_pvalue` and `permutation::paired_pvalue`.
+
+use pragmastat::estimators::raw;
+use pragmastat::permutation::{
+    paired_pvalue, paired_pvalue_opts, shift_pvalue, shift_pvalue_opts, EarlyStop,
+    PermutationOptions,
+};
+use pragmastat::{Additive, Distribution, EstimatorError, Rng};
+
+fn draw(seed: &str, mean: f64, n: usize) -> Vec<f64> {
+    let mut rng = Rng::from_string(seed);
+    Additive::new(mean, 1.0).sample_n(&mut rng, n)
+}
+
+#[test]
+fn same_seed_same_pvalue() {
+    let x = draw("perm-x", 0.0, 15);
+    let y = draw("perm-y", 0.5, 12);
+    let a = shift_pvalue(&x, &y, 500, "seed").unwrap();
+    let b = shift_pvalue(&x, &y, 500, "seed").unwrap();
+    assert_eq!(a, b);
+    let c = paired_pvalue(&x[..12], &y, 500, "seed").unwrap();
+    let d = paired_pvalue(&x[..12], &y, 500, "seed").unwrap();
+    assert_eq!(c, d);
+}
+
+#[test]
+fn shift_pvalue_separates_null_and_effect() {
+    let x = draw("perm-null-x", 0.0, 30);
+    let same = draw("perm-null-y", 0.0, 30);
+    let shifted = draw("perm-alt-y", 3.0, 30);
+    assert!(shift_pvalue(&x, &same, 1000, "null").unwrap() > 0.05);
+    // No permutation beats a 3-sigma shift: p = 1 / (iterations + 1)
+    assert_eq!(
+        shift_pvalue(&x, &shifted, 1000, "alt").unwrap(),
+        1.0 / 1001.0
+    );
+}
+
+#[test]
+fn paired_pvalue_converges_to_exact_enumeration() {
+    let x = [5.0, 6.0, 9.0, 8.0, 12.0, 10.0];
+    let y = [1.0, 4.0, 3.0, 7.0, 5.0, 11.0];
+    let d: Vec<f64> = x.iter().zip(&y).map(|(a, b)| a - b).collect();
+    let observed = raw::center(&d, false).unwrap().abs();
+    let patterns = 1u32 << d.len();
+    let extreme = (0..patterns)
+        .filter(|mask| {
+            let flipped: Vec<f64> = d
+                .iter()
+                .enumerate()
+                .map(|(i, v)| if mask >> i & 1 == 1 { -v } else { *v })
+                .collect();
+            raw::center(&flipped, false).unwrap().abs() >= observed
+        })
+        .count();
+    let exact = extreme as f64 / patterns as f64;
+    let p = paired_pvalue(&x, &y, 20_000, "enumeration").unwrap();
+    assert!((p - exact).abs() < 0.01, "p = {p}, exact = {exact}");
+}
+
+#[test]
+fn early_stop_for_clear_effect() {
+    let x = draw("perm-early-x", 0.0, 20);
+    let y = draw("perm-early-y", 3.0, 20);
+    let opts = PermutationOptions {
+        iterations: 10_000,
+        early_stop: Some(EarlyStop::new(0.05)),
+    };
+    let result = shift_pvalue_opts(&x, &y, "early", &opts).unwrap();
+    assert!(result.stopped_early);
+    assert!(result.iterations < 10_000);
+    assert!(result.pvalue < 0.05);
+}
+
+#[test]
+fn early_stop_for_clear_null() {
+    let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let opts = PermutationOptions {
+        iterations: 10_000,
+        early_stop: Some(EarlyStop::new(0.05)),
+    };
+    let result = paired_pvalue_opts(&x, &x, "early", &opts).unwrap();
+    assert!(result.stopped_early);
+    assert_eq!(result.iterations, 100);
+    assert_eq!(result.pvalue, 1.0);
+}
+
+#[test]
+fn early_stop_prefix_matches_full_run() {
+    // Early stopping truncates the same stream; it never changes the draws.
+    let x = draw("perm-prefix-x", 0.0, 10);
+    let y = draw("perm-prefix-y", 2.0, 10);
+    let opts = PermutationOptions {
+        iterations: 10_000,
+        early_stop: Some(EarlyStop::new(0.05)),
+    };
+    let early = shift_pvalue_opts(&x, &y, "prefix", &opts).unwrap();
+    let full = shift_pvalue(&x, &y, early.iterations, "prefix").unwrap();
+    assert_eq!(early.pvalue, full);
+}
+
+#[test]
+fn without_early_stop_runs_every_iteration() {
+    let x = [1.0, 2.0, 3.0];
+    let y = [4.0, 5.0, 6.0];
+    let opts = PermutationOptions {
+        iterations: 300,
+        early_stop: None,
+    };
+    let result = shift_pvalue_opts(&x, &y, "full", &opts).unwrap();
+    assert!(!result.stopped_early);
+    assert_eq!(result.iterations, 300);
+}
+
+#[test]
+fn invalid_inputs() {
+    let x = [1.0, 2.0, 3.0];
+    assert!(matches!(
+        shift_pvalue(&x, &x, 0, "s"),
+        Err(EstimatorError::Other(_))
+    ));
+    assert!(matches!(
+        paired_pvalue(&x, &x[..2], 100, "s"),
+        Err(EstimatorError::Other(_))
+    ));
+    assert!(matches!(
+        shift_pvalue(&[], &x, 100, "s"),
+        Err(EstimatorError::Assumption(_))
+    ));
+    let opts = PermutationOptions {
+        iterations: 100,
+        early_stop: Some(EarlyStop {
+            misrate: 0.0,
+            ..EarlyStop::new(0.05)
+        }),
+    };
+    assert!(matches!(
+        shift_pvalue_opts(&x, &x, "s", &opts),
+        Err(EstimatorError::Other(_))
+    ));
+}
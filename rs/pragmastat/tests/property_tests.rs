@@ -0,0 +1,305 @@
+//! Randomized property tests built on the checkers in `pragmastat::invariants`.
+//!
+//! Cases are drawn from a seeded `Rng`, so every run checks the same inputs.
+//! When a property fails, the case is shrunk while the failure persists: elements
+//! are dropped one at a time, then elements and the scalars `c` and `k` are
+//! replaced by simpler values (zero, `±1`, truncated or halved), and the
+//! smallest failing case is reported.
+
+use pragmastat::assumptions::EstimatorError;
+use pragmastat::distributions::{Additive, Distribution, Exp, Multiplic, Uniform};
+use pragmastat::estimators::raw;
+use pragmastat::invariants::{
+    bounds_contain_estimate, location_equivariance, location_invariance, ratio_inversion,
+    scale_equivariance, sign_symmetry, InvariantResult,
+};
+use pragmastat::Rng;
+
+const CASES: usize = 300;
+const MAX_SIZE: usize = 30;
+const MISRATE: f64 = 0.1;
+
+#[derive(Debug, Clone)]
+struct Case {
+    x: Vec<f64>,
+    y: Vec<f64>,
+    c: f64,
+    k: f64,
+}
+
+/// Draws a sample of size `n` from a randomly chosen shape, including
+/// rounded values so that ties occur.
+fn draw_sample(rng: &mut Rng, n: usize) -> Vec<f64> {
+    match rng.uniform_i64(0, 5) {
+        0 => Uniform::new(-1.0, 1.0).samples(rng, n),
+        1 => Additive::new(1e3, 10.0).samples(rng, n),
+        2 => Exp::new(1.0).samples(rng, n),
+        3 => Multiplic::new(0.0, 2.0).samples(rng, n),
+        _ => Additive::new(0.0, 3.0)
+            .samples(rng, n)
+            .into_iter()
+            .map(f64::round)
+            .collect(),
+    }
+}
+
+fn draw_case(rng: &mut Rng) -> Case {
+    let n = rng.uniform_i64(1, MAX_SIZE as i64 + 1) as usize;
+    let m = rng.uniform_i64(1, MAX_SIZE as i64 + 1) as usize;
+    let x = draw_sample(rng, n);
+    let y = draw_sample(rng, m);
+    let c = rng.uniform_f64_range(-1e3, 1e3);
+    let k = rng.uniform_f64_range(0.01, 100.0) * if rng.uniform_bool() { 1.0 } else { -1.0 };
+    Case { x, y, c, k }
+}
+
+fn positive(v: &[f64]) -> Vec<f64> {
+    v.iter().map(|a| a.abs() + 1e-3).collect()
+}
+
+/// A property returns `None` when the estimator rejects the inputs.
+fn outcome(result: Result<InvariantResult, EstimatorError>) -> Option<InvariantResult> {
+    result.ok()
+}
+
+fn fails<P>(property: &P, case: &Case) -> Option<InvariantResult>
+where
+    P: Fn(&Case) -> Option<InvariantResult>,
+{
+    property(case).filter(|r| !r.holds())
+}
+
+impl Case {
+    fn sample_mut(&mut self, in_y: bool) -> &mut Vec<f64> {
+        if in_y {
+            &mut self.y
+        } else {
+            &mut self.x
+        }
+    }
+}
+
+/// Simpler values to try in place of `v`: zero, `±1`, and `v` truncated or
+/// halved toward zero. Only candidates that are strictly simpler (an integer
+/// before a fraction, then a smaller magnitude) are kept, so shrinking
+/// terminates.
+fn simpler(v: f64) -> Vec<f64> {
+    let complexity = |a: f64| (a.fract() != 0.0, a.abs());
+    [0.0, v.signum(), v.trunc(), (v / 2.0).trunc()]
+        .into_iter()
+        .filter(|&w| complexity(w) < complexity(v))
+        .collect()
+}
+
+/// Shrinks `case` while the property keeps failing: drops elements of `x` and
+/// `y`, then replaces elements and the scalars `c` and `k` by [`simpler`]
+/// values, until no change keeps the failure.
+fn shrink<P>(property: &P, mut case: Case, mut result: InvariantResult) -> (Case, InvariantResult)
+where
+    P: Fn(&Case) -> Option<InvariantResult>,
+{
+    let accept = |candidate: Case, case: &mut Case, result: &mut InvariantResult| match fails(
+        property, &candidate,
+    ) {
+        Some(r) => {
+            *case = candidate;
+            *result = r;
+            true
+        }
+        None => false,
+    };
+    loop {
+        let mut progress = false;
+        for in_y in [false, true] {
+            let mut i = 0;
+            while i < case.sample_mut(in_y).len() {
+                let mut candidate = case.clone();
+                candidate.sample_mut(in_y).remove(i);
+                if accept(candidate, &mut case, &mut result) {
+                    progress = true;
+                } else {
+                    i += 1;
+                }
+            }
+        }
+        for in_y in [false, true] {
+            for i in 0..case.sample_mut(in_y).len() {
+                for w in simpler(case.sample_mut(in_y)[i]) {
+                    let mut candidate = case.clone();
+                    candidate.sample_mut(in_y)[i] = w;
+                    if accept(candidate, &mut case, &mut result) {
+                        progress = true;
+                        break;
+                    }
+                }
+            }
+        }
+        for w in simpler(case.c) {
+            let candidate = Case {
+                c: w,
+                ..case.clone()
+            };
+            if accept(candidate, &mut case, &mut result) {
+                progress = true;
+                break;
+            }
+        }
+        for w in simpler(case.k) {
+            let candidate = Case {
+                k: w,
+                ..case.clone()
+            };
+            if accept(candidate, &mut case, &mut result) {
+                progress = true;
+                break;
+            }
+        }
+        if !progress {
+            return (case, result);
+        }
+    }
+}
+
+fn check<P>(name: &str, property: P)
+where
+    P: Fn(&Case) -> Option<InvariantResult>,
+{
+    let mut rng = Rng::from_string(name);
+    let mut checked = 0;
+    for _ in 0..CASES {
+        let case = draw_case(&mut rng);
+        let Some(result) = property(&case) else {
+            continue;
+        };
+        checked += 1;
+        if !result.holds() {
+            let (case, result) = shrink(&property, case, result);
+            panic!("{name}: {result}\nminimal case: {case:?}");
+        }
+    }
+    assert!(checked >= CASES / 2, "{name}: only {checked} cases checked");
+}
+
+#[test]
+fn center_is_location_and_scale_equivariant() {
+    check("center-location", |case| {
+        outcome(location_equivariance(
+            |v| raw::center(v, false),
+            &case.x,
+            case.c,
+        ))
+    });
+    check("center-scale", |case| {
+        outcome(scale_equivariance(
+            |v| raw::center(v, false),
+            &case.x,
+            case.k,
+        ))
+    });
+}
+
+#[test]
+fn spread_is_location_invariant_and_scale_equivariant() {
+    check("spread-location", |case| {
+        outcome(location_invariance(
+            |v| raw::spread(v, false),
+            &case.x,
+            case.c,
+        ))
+    });
+    // Spread scales with |k|
+    check("spread-scale", |case| {
+        outcome(scale_equivariance(
+            |v| raw::spread(v, false),
+            &case.x,
+            case.k.abs(),
+        ))
+    });
+}
+
+#[test]
+fn shift_is_sign_symmetric_and_location_equivariant() {
+    check("shift-sign", |case| {
+        outcome(sign_symmetry(
+            |x, y| raw::shift(x, y, false),
+            &case.x,
+            &case.y,
+        ))
+    });
+    check("shift-location", |case| {
+        outcome(location_equivariance(
+            |v| raw::shift(v, &case.y, false),
+            &case.x,
+            case.c,
+        ))
+    });
+}
+
+#[test]
+fn disparity_is_sign_symmetric() {
+    check("disparity-sign", |case| {
+        outcome(sign_symmetry(
+            |x, y| raw::disparity(x, y, false),
+            &case.x,
+            &case.y,
+        ))
+    });
+}
+
+#[test]
+fn ratio_inverts() {
+    check("ratio-inversion", |case| {
+        outcome(ratio_inversion(&positive(&case.x), &positive(&case.y)))
+    });
+}
+
+#[test]
+fn rank_based_bounds_contain_estimate() {
+    check("center-bounds", |case| {
+        let bounds = raw::center_bounds(&case.x, MISRATE, false).ok()?;
+        let estimate = raw::center(&case.x, false).ok()?;
+        Some(bounds_contain_estimate(bounds, estimate))
+    });
+    check("shift-bounds", |case| {
+        let bounds = raw::shift_bounds(&case.x, &case.y, MISRATE, false).ok()?;
+        let estimate = raw::shift(&case.x, &case.y, false).ok()?;
+        Some(bounds_contain_estimate(bounds, estimate))
+    });
+    check("ratio-bounds", |case| {
+        let (x, y) = (positive(&case.x), positive(&case.y));
+        let bounds = raw::ratio_bounds(&x, &y, MISRATE, false).ok()?;
+        let estimate = raw::ratio(&x, &y, false).ok()?;
+        Some(bounds_contain_estimate(bounds, estimate))
+    });
+}
+
+#[test]
+fn shrinking_finds_a_minimal_counterexample() {
+    // Deliberately broken: the sum is not location-equivariant once there is
+    // more than one value, but this "estimator" only uses it above three
+    let broken = |case: &Case| {
+        outcome(location_equivariance(
+            |v| {
+                let max = v.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                Ok(if v.len() > 3 { v.iter().sum() } else { max })
+            },
+            &case.x,
+            case.c,
+        ))
+    };
+    let case = Case {
+        x: vec![5.5, 1.0, 2.25, 3.0, 4.0, 6.0, 7.0],
+        y: vec![1.0, 2.0],
+        c: 2.5,
+        k: 7.25,
+    };
+    let result = fails(&broken, &case).expect("the case fails");
+    let (minimal, result) = shrink(&broken, case, result);
+    // Four values are needed and any shift breaks it, so c stops at 1; the
+    // values and the unused k and y shrink away entirely
+    assert_eq!(minimal.x, vec![0.0; 4]);
+    assert!(minimal.y.is_empty());
+    assert_eq!(minimal.c, 1.0);
+    assert_eq!(minimal.k, 0.0);
+    assert!(!result.holds());
+}
@@ -0,0 +1,173 @@
+//! Tests for the Wilcoxon signed-rank and Mann-Whitney tests.
+//!
+//! Expected values are R's `wilcox.test` (defaults: `exact = NULL`,
+//! `correct = TRUE`) and textbook worked examples.
+
+use pragmastat::tests::{mann_whitney_test, signed_rank_test, TestMethod, EXACT_MAX_SIZE};
+use pragmastat::{AssumptionId, EstimatorError, Subject};
+
+/// Relative tolerance for exact p-values.
+const EXACT: f64 = 1e-12;
+/// Relative tolerance for normal p-values, limited by `gauss_cdf` (ACM 209).
+const APPROX: f64 = 1e-6;
+
+fn assert_close(actual: f64, expected: f64, relative: f64) {
+    let tolerance = relative * expected.abs();
+    assert!(
+        (actual - expected).abs() <= tolerance,
+        "expected {expected}, got {actual}"
+    );
+}
+
+// Hollander & Wolfe (1973), p. 69: permeability constants of the human
+// chorioamnion at term (x) and at 12 to 26 weeks of gestation (y).
+// R: W = 35, p-value = 0.2544.
+#[test]
+fn mann_whitney_hollander_wolfe() {
+    let x = [0.80, 0.83, 1.89, 1.04, 1.45, 1.38, 1.91, 1.64, 0.73, 1.46];
+    let y = [1.15, 0.88, 0.90, 0.74, 1.21];
+    let r = mann_whitney_test(&x, &y).unwrap();
+    assert_eq!(r.statistic, 35.0);
+    assert_eq!(r.method, TestMethod::Exact);
+    assert_close(r.p_value, 0.2544122544122544, EXACT);
+}
+
+// R's `?wilcox.test` example on `airquality`: Ozone in May vs August, NAs
+// removed. The data have ties, so R warns and uses the normal approximation.
+// R: W = 127.5, p-value = 0.0001208.
+#[test]
+fn mann_whitney_airquality_with_ties() {
+    let may = [
+        41.0, 36.0, 12.0, 18.0, 28.0, 23.0, 19.0, 8.0, 7.0, 16.0, 11.0, 14.0, 18.0, 14.0, 34.0,
+        6.0, 30.0, 11.0, 1.0, 11.0, 4.0, 32.0, 23.0, 45.0, 115.0, 37.0,
+    ];
+    let aug = [
+        39.0, 9.0, 16.0, 78.0, 35.0, 66.0, 122.0, 89.0, 110.0, 44.0, 28.0, 65.0, 22.0, 59.0, 23.0,
+        31.0, 44.0, 21.0, 9.0, 45.0, 168.0, 73.0, 76.0, 118.0, 84.0, 85.0,
+    ];
+    let r = mann_whitney_test(&may, &aug).unwrap();
+    assert_eq!(r.statistic, 127.5);
+    assert_eq!(r.method, TestMethod::NormalApproximation);
+    assert!((r.p_value - 0.0001208).abs() < 5e-8, "got {}", r.p_value);
+}
+
+// Hollander & Wolfe (1973), p. 29: Hamilton depression scale factor IV
+// before (x) and after (y) the first tranquilizer visit, tested on x - y.
+// R: V = 40, p-value = 0.03906.
+#[test]
+#[allow(clippy::approx_constant)] // 3.14 is a score, not pi
+fn signed_rank_hollander_wolfe_depression() {
+    let x = [1.83, 0.50, 1.62, 2.48, 1.68, 1.88, 1.55, 3.06, 1.30];
+    let y = [0.878, 0.647, 0.598, 2.05, 1.06, 1.29, 1.06, 3.14, 1.29];
+    let d: Vec<f64> = x.iter().zip(&y).map(|(a, b)| a - b).collect();
+    let r = signed_rank_test(&d, 0.0).unwrap();
+    assert_eq!(r.statistic, 40.0);
+    assert_eq!(r.method, TestMethod::Exact);
+    assert_close(r.p_value, 0.0390625, EXACT);
+}
+
+#[test]
+fn signed_rank_is_symmetric_in_the_sign() {
+    let d = [0.952, -0.147, 1.022, 0.43, 0.62, 0.59, 0.49, -0.08, 0.01];
+    let positive = signed_rank_test(&d, 0.0).unwrap();
+    let negated: Vec<f64> = d.iter().map(|v| -v).collect();
+    let negative = signed_rank_test(&negated, 0.0).unwrap();
+    assert_eq!(positive.statistic + negative.statistic, 45.0);
+    assert_eq!(positive.p_value, negative.p_value);
+}
+
+// Ties among |x - mu0| and values equal to mu0: zeros are dropped, the rest
+// get mid-ranks and the normal approximation.
+// R: wilcox.test(c(1.5, 2, 2, 3, -1, 4, 5, 5, -2, 6, 0)) gives V = 50,
+// p-value = 0.02446.
+#[test]
+fn signed_rank_ties_and_zeros() {
+    let x = [1.5, 2.0, 2.0, 3.0, -1.0, 4.0, 5.0, 5.0, -2.0, 6.0, 0.0];
+    let r = signed_rank_test(&x, 0.0).unwrap();
+    assert_eq!(r.statistic, 50.0);
+    assert_eq!(r.method, TestMethod::NormalApproximation);
+    assert_close(r.p_value, 0.024463932481596062, APPROX);
+    // Shifting the data and mu0 together changes nothing
+    let shifted: Vec<f64> = x.iter().map(|v| v + 10.0).collect();
+    assert_eq!(signed_rank_test(&shifted, 10.0).unwrap(), r);
+}
+
+// d = ±1..=n, every third value negative: no ties, so the method switches
+// exactly at the size threshold. R: V = 817, p = 0.0417 (n = 49, exact);
+// V = 867, p = 0.02706 (n = 50, normal).
+#[test]
+fn signed_rank_switches_to_normal_above_threshold() {
+    let d = |n: usize| -> Vec<f64> {
+        (1..=n)
+            .map(|i| if i % 3 == 0 { -(i as f64) } else { i as f64 })
+            .collect()
+    };
+    assert_eq!(EXACT_MAX_SIZE, 49);
+    let exact = signed_rank_test(&d(49), 0.0).unwrap();
+    assert_eq!(exact.statistic, 817.0);
+    assert_eq!(exact.method, TestMethod::Exact);
+    assert_close(exact.p_value, 0.04170385423242351, EXACT);
+    let approx = signed_rank_test(&d(50), 0.0).unwrap();
+    assert_eq!(approx.statistic, 867.0);
+    assert_eq!(approx.method, TestMethod::NormalApproximation);
+    assert_close(approx.p_value, 0.027063492176047976, APPROX);
+}
+
+// R: W = 817, p = 0.1811 (49 vs 40, exact); W = 850, p = 0.2248 (50 vs 40,
+// normal).
+#[test]
+fn mann_whitney_switches_to_normal_above_threshold() {
+    let x: Vec<f64> = (0..50).map(|i| 2.0 * i as f64 + 0.5).collect();
+    let y: Vec<f64> = (0..40).map(|i| 3.0 * i as f64).collect();
+    let exact = mann_whitney_test(&x[..49], &y).unwrap();
+    assert_eq!(exact.statistic, 817.0);
+    assert_eq!(exact.method, TestMethod::Exact);
+    assert_close(exact.p_value, 0.18106849161125374, EXACT);
+    let approx = mann_whitney_test(&x, &y).unwrap();
+    assert_eq!(approx.statistic, 850.0);
+    assert_eq!(approx.method, TestMethod::NormalApproximation);
+    assert_close(approx.p_value, 0.22477181129913476, APPROX);
+}
+
+#[test]
+fn mann_whitney_swapping_samples_mirrors_the_statistic() {
+    let x = [0.80, 0.83, 1.89, 1.04, 1.45, 1.38, 1.91, 1.64, 0.73, 1.46];
+    let y = [1.15, 0.88, 0.90, 0.74, 1.21];
+    let xy = mann_whitney_test(&x, &y).unwrap();
+    let yx = mann_whitney_test(&y, &x).unwrap();
+    assert_eq!(xy.statistic + yx.statistic, 50.0);
+    assert_close(yx.p_value, xy.p_value, EXACT);
+}
+
+#[test]
+fn all_tied_gives_p_one() {
+    let r = mann_whitney_test(&[2.0, 2.0, 2.0], &[2.0, 2.0]).unwrap();
+    assert_eq!(r.statistic, 3.0);
+    assert_eq!(r.p_value, 1.0);
+    let r = signed_rank_test(&[1.0, -1.0, 1.0, -1.0], 0.0).unwrap();
+    assert_eq!(r.p_value, 1.0);
+}
+
+#[test]
+fn errors() {
+    let subject = |e: EstimatorError| match e {
+        EstimatorError::Assumption(a) => (a.violation().id, a.violation().subject),
+        EstimatorError::Other(msg) => panic!("unexpected: {msg}"),
+    };
+    assert_eq!(
+        subject(signed_rank_test(&[], 0.0).unwrap_err()),
+        (AssumptionId::Validity, Subject::X)
+    );
+    assert_eq!(
+        subject(signed_rank_test(&[1.0, 1.0], 1.0).unwrap_err()),
+        (AssumptionId::Domain, Subject::X)
+    );
+    assert!(matches!(
+        signed_rank_test(&[1.0], f64::NAN),
+        Err(EstimatorError::Other(_))
+    ));
+    assert_eq!(
+        subject(mann_whitney_test(&[1.0], &[f64::NAN]).unwrap_err()),
+        (AssumptionId::Validity, Subject::Y)
+    );
+}
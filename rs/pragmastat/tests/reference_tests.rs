@@ -1,6 +1,7 @@
 use float_cmp::approx_eq;
 use pragmastat::assumptions::EstimatorError;
 use pragmastat::estimators::raw;
+use pragmastat::reference::{self, CaseData, LoadError, ReferenceCase};
 use pragmastat::*;
 use serde::{Deserialize, Serialize};
 use std::fs;
@@ -79,7 +80,7 @@ struct RatioBoundsTestCase {
 }
 
 #[derive(Debug, Deserialize, Serialize)]
-struct DisparityBoundsInput {
+struct SeededBoundsInput {
     x: Vec<f64>,
     y: Vec<f64>,
     misrate: f64,
@@ -87,8 +88,8 @@ struct DisparityBoundsInput {
 }
 
 #[derive(Debug, Deserialize, Serialize)]
-struct DisparityBoundsTestCase {
-    input: DisparityBoundsInput,
+struct SeededBoundsTestCase {
+    input: SeededBoundsInput,
     output: Option<BoundsOutput>,
     expected_error: Option<ExpectedError>,
 }
@@ -473,6 +474,15 @@ fn test_disparity() {
     run_two_sample_tests("disparity", |x, y| raw::disparity(x, y, false), disparity);
 }
 
+#[test]
+fn test_avg_spread() {
+    run_two_sample_tests(
+        "avg-spread",
+        |x, y| raw::avg_spread(x, y, false),
+        avg_spread,
+    );
+}
+
 fn run_shift_bounds_tests() {
     let repo_root = find_repo_root();
     let test_data_dir = repo_root.join("tests").join("shift-bounds");
@@ -737,9 +747,16 @@ fn run_ratio_bounds_tests() {
     );
 }
 
-fn run_disparity_bounds_tests() {
+/// Runs every fixture of a randomized two-sample bounds estimator through the
+/// raw API (`assume_sorted = false`) and the Sample API. The fixtures carry the
+/// string seed of the shuffle; without one the unseeded variant is called.
+fn run_seeded_two_sample_bounds_tests<R, S>(estimator_name: &str, raw_func: R, sample_func: S)
+where
+    R: Fn(&[f64], &[f64], f64, Option<&str>) -> Result<raw::RawBounds, EstimatorError>,
+    S: Fn(&Sample, &Sample, f64, Option<&str>) -> Result<Bounds, EstimatorError>,
+{
     let repo_root = find_repo_root();
-    let test_data_dir = repo_root.join("tests").join("disparity-bounds");
+    let test_data_dir = repo_root.join("tests").join(estimator_name);
 
     if !test_data_dir.exists() {
         panic!("Test data directory not found: {:?}", test_data_dir);
@@ -768,51 +785,32 @@ fn run_disparity_bounds_tests() {
 
     for json_file in &json_files {
         let content = fs::read_to_string(json_file).unwrap();
-        let test_case: DisparityBoundsTestCase = serde_json::from_str(&content).unwrap();
+        let test_case: SeededBoundsTestCase = serde_json::from_str(&content).unwrap();
         let file_name = json_file.file_name().unwrap();
 
-        let seed = test_case.input.seed.as_deref();
+        let input = &test_case.input;
+        let seed = input.seed.as_deref();
 
-        // (1) raw API with assume_sorted = false. Disparity bounds are
-        // shuffle-based, so the fixtures always carry a seed.
-        let raw_result = match seed {
-            Some(s) => raw::disparity_bounds_with_seed(
-                &test_case.input.x,
-                &test_case.input.y,
-                test_case.input.misrate,
-                s,
-                false,
-            ),
-            None => raw::disparity_bounds(
-                &test_case.input.x,
-                &test_case.input.y,
-                test_case.input.misrate,
-                false,
-            ),
-        };
+        // (1) raw API with assume_sorted = false
         check_two_sample_bounds_raw(
             &mut failures,
             file_name,
             test_case.expected_error.as_ref(),
             test_case.output.as_ref(),
-            raw_result,
+            raw_func(&input.x, &input.y, input.misrate, seed),
         );
 
-        // (2) Sample API (seeded variant when the fixture has a seed). The Sample
-        // path passes the cached sorted view internally (assume_sorted = true),
-        // which is intended; the seed keeps the shuffle deterministic so it must
-        // still match the same fixture.
+        // (2) Sample API. The Sample path passes the cached sorted view
+        // internally (assume_sorted = true), which is intended; the seed keeps
+        // the shuffle deterministic so it must still match the same fixture.
         check_two_sample_bounds_sample(
             &mut failures,
             file_name,
             test_case.expected_error.as_ref(),
             test_case.output.as_ref(),
-            &test_case.input.x,
-            &test_case.input.y,
-            |x, y| match seed {
-                Some(s) => disparity_bounds_with_seed(x, y, test_case.input.misrate, s),
-                None => disparity_bounds(x, y, test_case.input.misrate),
-            },
+            &input.x,
+            &input.y,
+            |x, y| sample_func(x, y, input.misrate, seed),
         );
     }
 
@@ -835,339 +833,363 @@ fn test_ratio_bounds() {
 
 #[test]
 fn test_disparity_bounds() {
-    run_disparity_bounds_tests();
+    run_seeded_two_sample_bounds_tests(
+        "disparity-bounds",
+        |x, y, misrate, seed| match seed {
+            Some(s) => raw::disparity_bounds_with_seed(x, y, misrate, s, false),
+            None => raw::disparity_bounds(x, y, misrate, false),
+        },
+        |x, y, misrate, seed| match seed {
+            Some(s) => disparity_bounds_with_seed(x, y, misrate, s),
+            None => disparity_bounds(x, y, misrate),
+        },
+    );
 }
 
-// Rng reference tests
-
-#[derive(Debug, Deserialize)]
-struct UniformInput {
-    seed: i64,
-    count: usize,
+#[test]
+fn test_avg_spread_bounds() {
+    run_seeded_two_sample_bounds_tests(
+        "avg-spread-bounds",
+        |x, y, misrate, seed| match seed {
+            Some(s) => raw::avg_spread_bounds_with_seed(x, y, misrate, s, false),
+            None => raw::avg_spread_bounds(x, y, misrate, false),
+        },
+        |x, y, misrate, seed| match seed {
+            Some(s) => avg_spread_bounds_with_seed(x, y, misrate, s),
+            None => avg_spread_bounds(x, y, misrate),
+        },
+    );
 }
 
-#[derive(Debug, Deserialize)]
-struct UniformTestCase {
-    input: UniformInput,
-    output: Vec<f64>,
+// Rng and distribution reference tests, parsed and checked by `pragmastat::reference`
+
+fn any_case(_: &CaseData) -> bool {
+    true
 }
 
-#[derive(Debug, Deserialize)]
-struct UniformIntInput {
-    seed: i64,
-    min: i64,
-    max: i64,
-    count: usize,
+/// Verifies every fixture in `tests/<dir>` that `filter` selects.
+fn run_reference_tests(dir: &str, filter: fn(&CaseData) -> bool) {
+    let test_data_dir = find_repo_root().join("tests").join(dir);
+    let cases: Vec<ReferenceCase> = reference::load_dir(&test_data_dir)
+        .unwrap_or_else(|e| panic!("{e}"))
+        .into_iter()
+        .filter(|case| filter(&case.data))
+        .collect();
+
+    assert!(
+        !cases.is_empty(),
+        "No matching test files found in {:?}",
+        test_data_dir
+    );
+
+    let failures: Vec<String> = cases
+        .iter()
+        .map(reference::verify)
+        .filter(|result| !result.passed())
+        .map(|result| result.to_string())
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "Failed tests:\n{}",
+        failures.join("\n")
+    );
 }
 
-#[derive(Debug, Deserialize)]
-struct UniformIntTestCase {
-    input: UniformIntInput,
-    output: Vec<i64>,
+#[test]
+fn test_rng_uniform() {
+    run_reference_tests("rng", |c| matches!(c, CaseData::RngUniform(_)));
 }
 
-#[derive(Debug, Deserialize)]
-struct StringSeedInput {
-    seed: String,
-    count: usize,
+#[test]
+fn test_rng_normal() {
+    run_reference_tests("rng", |c| matches!(c, CaseData::RngNormal(_)));
 }
 
-#[derive(Debug, Deserialize)]
-struct StringSeedTestCase {
-    input: StringSeedInput,
-    output: Vec<f64>,
+#[test]
+fn test_rng_uniform_int() {
+    run_reference_tests("rng", |c| matches!(c, CaseData::RngUniformInt(_)));
 }
 
-#[derive(Debug, Deserialize)]
-struct UniformRangeInput {
-    seed: i64,
-    min: f64,
-    max: f64,
-    count: usize,
+#[test]
+fn test_rng_string_seed() {
+    run_reference_tests("rng", |c| matches!(c, CaseData::RngStringSeed(_)));
 }
 
-#[derive(Debug, Deserialize)]
-struct UniformRangeTestCase {
-    input: UniformRangeInput,
-    output: Vec<f64>,
+#[test]
+fn test_rng_uniform_range() {
+    run_reference_tests("rng", |c| matches!(c, CaseData::RngUniformRange(_)));
 }
 
-#[derive(Debug, Deserialize)]
-struct UniformF32Input {
-    seed: i64,
-    count: usize,
+#[test]
+fn test_rng_uniform_f32() {
+    run_reference_tests("rng", |c| matches!(c, CaseData::RngUniformF32(_)));
 }
 
-#[derive(Debug, Deserialize)]
-struct UniformF32TestCase {
-    input: UniformF32Input,
-    output: Vec<f32>,
+#[test]
+fn test_rng_uniform_i32() {
+    run_reference_tests("rng", |c| matches!(c, CaseData::RngUniformI32(_)));
 }
 
-#[derive(Debug, Deserialize)]
-struct UniformI32Input {
-    seed: i64,
-    min: i32,
-    max: i32,
-    count: usize,
+#[test]
+fn test_rng_uniform_bool() {
+    run_reference_tests("rng", |c| matches!(c, CaseData::RngUniformBool(_)));
 }
 
-#[derive(Debug, Deserialize)]
-struct UniformI32TestCase {
-    input: UniformI32Input,
-    output: Vec<i32>,
+#[test]
+fn test_rng_next_u64() {
+    run_reference_tests("rng", |c| matches!(c, CaseData::RngNextU64(_)));
 }
 
-#[derive(Debug, Deserialize)]
-struct UniformBoolInput {
-    seed: i64,
-    count: usize,
+#[test]
+fn test_rng_jump() {
+    run_reference_tests("rng", |c| matches!(c, CaseData::RngJump(_)));
+    run_reference_tests("rng", |c| matches!(c, CaseData::RngLongJump(_)));
 }
 
-#[derive(Debug, Deserialize)]
-struct UniformBoolTestCase {
-    input: UniformBoolInput,
-    output: Vec<bool>,
+#[test]
+fn test_rng_split() {
+    run_reference_tests("rng", |c| matches!(c, CaseData::RngSplit(_)));
 }
 
-#[derive(Debug, Deserialize)]
-struct ShuffleInput {
-    seed: i64,
-    x: Vec<f64>,
+#[test]
+fn test_shuffle() {
+    run_reference_tests("shuffle", any_case);
 }
 
-#[derive(Debug, Deserialize)]
-struct ShuffleTestCase {
-    input: ShuffleInput,
-    output: Vec<f64>,
+#[test]
+fn test_sample() {
+    run_reference_tests("sample", any_case);
 }
 
-#[derive(Debug, Deserialize)]
-struct SampleInput {
-    seed: i64,
-    x: Vec<f64>,
-    k: usize,
+#[test]
+fn test_resample() {
+    run_reference_tests("resample", any_case);
 }
 
-#[derive(Debug, Deserialize)]
-struct SampleTestCase {
-    input: SampleInput,
-    output: Vec<f64>,
+#[test]
+fn test_permutation() {
+    run_reference_tests("permutation", any_case);
 }
 
-// Distribution reference tests
+#[test]
+fn test_uniform_distribution() {
+    run_reference_tests("distributions/uniform", any_case);
+}
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct UniformDistInput {
-    seed: i64,
-    min: f64,
-    max: f64,
-    count: usize,
+#[test]
+fn test_uniform_int_distribution() {
+    run_reference_tests("distributions/uniform-int", any_case);
 }
 
-#[derive(Debug, Deserialize)]
-struct UniformDistTestCase {
-    input: UniformDistInput,
-    output: Vec<f64>,
+#[test]
+fn test_additive_distribution() {
+    run_reference_tests("distributions/additive", any_case);
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct AdditiveDistInput {
-    seed: i64,
-    mean: f64,
-    std_dev: f64,
-    count: usize,
+#[test]
+fn test_multiplic_distribution() {
+    run_reference_tests("distributions/multiplic", any_case);
 }
 
-#[derive(Debug, Deserialize)]
-struct AdditiveDistTestCase {
-    input: AdditiveDistInput,
-    output: Vec<f64>,
+#[test]
+fn test_exp_distribution() {
+    run_reference_tests("distributions/exp", any_case);
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct MultiplicDistInput {
-    seed: i64,
-    log_mean: f64,
-    log_std_dev: f64,
-    count: usize,
+#[test]
+fn test_power_distribution() {
+    run_reference_tests("distributions/power", any_case);
 }
 
-#[derive(Debug, Deserialize)]
-struct MultiplicDistTestCase {
-    input: MultiplicDistInput,
-    output: Vec<f64>,
+#[test]
+fn test_gamma_distribution() {
+    run_reference_tests("distributions/gamma", any_case);
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct ExpDistInput {
-    seed: i64,
-    rate: f64,
-    count: usize,
+#[test]
+fn test_chisquared_distribution() {
+    run_reference_tests("distributions/chisquared", any_case);
 }
 
-#[derive(Debug, Deserialize)]
-struct ExpDistTestCase {
-    input: ExpDistInput,
-    output: Vec<f64>,
+#[test]
+fn test_studentt_distribution() {
+    run_reference_tests("distributions/studentt", any_case);
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct PowerDistInput {
-    seed: i64,
-    min: f64,
-    shape: f64,
-    count: usize,
+#[test]
+fn test_poisson_distribution() {
+    run_reference_tests("distributions/poisson", any_case);
 }
 
-#[derive(Debug, Deserialize)]
-struct PowerDistTestCase {
-    input: PowerDistInput,
-    output: Vec<f64>,
+#[test]
+fn test_binomial_distribution() {
+    run_reference_tests("distributions/binomial", any_case);
 }
 
-fn run_rng_uniform_tests() {
-    let repo_root = find_repo_root();
-    let test_data_dir = repo_root.join("tests").join("rng");
+#[test]
+fn test_bivariate_additive_distribution() {
+    run_reference_tests("distributions/bivariate-additive", any_case);
+}
 
-    let json_files: Vec<_> = fs::read_dir(&test_data_dir)
-        .unwrap()
-        .filter_map(|entry| {
-            let entry = entry.unwrap();
-            let path = entry.path();
-            let name = path.file_name()?.to_str()?;
-            if name.starts_with("uniform-seed-") && name.ends_with(".json") {
-                Some(path)
-            } else {
-                None
-            }
-        })
-        .collect();
+#[test]
+fn test_additive_antithetic_distribution() {
+    run_reference_tests("distributions/additive-antithetic", any_case);
+}
 
-    assert!(!json_files.is_empty(), "No uniform seed test files found");
+#[test]
+fn test_gaussian_copula_distribution() {
+    run_reference_tests("distributions/gaussian-copula", any_case);
+}
 
-    for json_file in json_files {
-        let content = fs::read_to_string(&json_file).unwrap();
-        let test_case: UniformTestCase = serde_json::from_str(&content).unwrap();
+#[test]
+fn test_ar1_distribution() {
+    run_reference_tests("distributions/ar1", any_case);
+}
 
-        let mut rng = Rng::from_seed(test_case.input.seed);
-        let actual: Vec<f64> = (0..test_case.input.count)
-            .map(|_| rng.uniform_f64())
-            .collect();
+/// Every fixture directory the `reference` module reads.
+fn reference_dirs() -> Vec<PathBuf> {
+    let tests_dir = find_repo_root().join("tests");
+    let mut dirs: Vec<PathBuf> = ["rng", "shuffle", "sample", "resample", "permutation"]
+        .iter()
+        .map(|dir| tests_dir.join(dir))
+        .collect();
+    for entry in fs::read_dir(tests_dir.join("distributions")).unwrap() {
+        dirs.push(entry.unwrap().path());
+    }
+    dirs
+}
 
-        for (i, (actual_val, expected_val)) in
-            actual.iter().zip(test_case.output.iter()).enumerate()
-        {
+#[test]
+fn test_reference_round_trip() {
+    for dir in reference_dirs() {
+        for case in reference::load_dir(&dir).unwrap_or_else(|e| panic!("{e}")) {
+            let content = fs::read_to_string(&case.path).unwrap();
             assert!(
-                approx_eq!(f64, *actual_val, *expected_val, epsilon = 1e-15),
-                "Failed for test file: {:?}, index {}, expected: {}, got: {}",
-                json_file.file_name().unwrap(),
-                i,
-                expected_val,
-                actual_val
+                reference::fixture_matches(&content, &case.data),
+                "{:?}",
+                case.path
             );
+            assert_eq!(reference::load_file(&case.path).unwrap(), case);
         }
     }
 }
 
-fn run_rng_uniform_int_tests() {
-    let repo_root = find_repo_root();
-    let test_data_dir = repo_root.join("tests").join("rng");
-
-    let json_files: Vec<_> = fs::read_dir(&test_data_dir)
-        .unwrap()
-        .filter_map(|entry| {
-            let entry = entry.unwrap();
-            let path = entry.path();
-            let name = path.file_name()?.to_str()?;
-            if name.starts_with("uniform-int-") && name.ends_with(".json") {
-                Some(path)
-            } else {
-                None
-            }
-        })
-        .collect();
+#[test]
+fn test_write_fixture_keeps_old_spelling() {
+    #[derive(Serialize)]
+    struct Fixture {
+        seed: u64,
+        output: Vec<f64>,
+    }
+    let data = Fixture {
+        seed: 42,
+        output: vec![1.0, 0.5, -0.0],
+    };
+    let old = "{\n  \"seed\": 42,\n  \"output\": [\n    1.0,\n    0.5,\n    -0.0\n  ]\n}";
+    assert!(reference::fixture_matches(old, &data));
+    assert!(!reference::fixture_matches(
+        &old.replace("0.5", "0.25"),
+        &data
+    ));
+
+    let path = std::env::temp_dir().join(format!("pragmastat-fixture-{}.json", std::process::id()));
+    fs::write(&path, old).unwrap();
+    assert!(!reference::write_fixture(&path, &data).unwrap());
+    assert_eq!(fs::read_to_string(&path).unwrap(), old);
+    let changed = Fixture { seed: 43, ..data };
+    assert!(reference::write_fixture(&path, &changed).unwrap());
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        reference::to_json(&changed)
+    );
+    fs::remove_file(&path).unwrap();
+}
 
-    assert!(!json_files.is_empty(), "No uniform int test files found");
+#[test]
+fn test_reference_detects_mismatches() {
+    let tests_dir = find_repo_root().join("tests");
+    let load_first = |dir: &str| reference::load_dir(&tests_dir.join(dir)).unwrap()[0].clone();
 
-    for json_file in json_files {
-        let content = fs::read_to_string(&json_file).unwrap();
-        let test_case: UniformIntTestCase = serde_json::from_str(&content).unwrap();
+    let mut case = load_first("distributions/additive");
+    let CaseData::AdditiveDist(ref mut c) = case.data else {
+        panic!("unexpected schema for {:?}", case.path);
+    };
+    c.output[2] += 1e-9;
+    let result = reference::verify(&case);
+    assert_eq!(result.mismatches.len(), 1, "{result}");
+    assert_eq!(result.mismatches[0].location, "output[2]");
+
+    // Within the tolerance still passes
+    let CaseData::AdditiveDist(ref mut c) = case.data else {
+        unreachable!()
+    };
+    c.output[2] -= 1e-9 - 1e-14;
+    assert!(reference::verify(&case).passed());
 
-        let mut rng = Rng::from_seed(test_case.input.seed);
-        let actual: Vec<i64> = (0..test_case.input.count)
-            .map(|_| rng.uniform_i64(test_case.input.min, test_case.input.max))
-            .collect();
+    let mut case = load_first("distributions/bivariate-additive");
+    let CaseData::BivariateAdditiveDist(ref mut c) = case.data else {
+        panic!("unexpected schema for {:?}", case.path);
+    };
+    c.output.y.pop();
+    let result = reference::verify(&case);
+    assert_eq!(result.mismatches[0].location, "y.len", "{result}");
 
-        assert_eq!(
-            actual,
-            test_case.output,
-            "Failed for test file: {:?}",
-            json_file.file_name().unwrap()
-        );
-    }
+    let mut case = load_first("rng");
+    let CaseData::RngJump(ref mut c) = case.data else {
+        panic!("unexpected schema for {:?}", case.path);
+    };
+    c.output[0] = (c.output[0].parse::<u64>().unwrap() ^ 1).to_string();
+    let result = reference::verify(&case);
+    assert_eq!(result.mismatches[0].location, "output[0]", "{result}");
 }
 
-fn run_rng_string_seed_tests() {
-    let repo_root = find_repo_root();
-    let test_data_dir = repo_root.join("tests").join("rng");
-
-    let json_files: Vec<_> = fs::read_dir(&test_data_dir)
-        .unwrap()
-        .filter_map(|entry| {
-            let entry = entry.unwrap();
-            let path = entry.path();
-            let name = path.file_name()?.to_str()?;
-            if name.starts_with("uniform-string-") && name.ends_with(".json") {
-                Some(path)
-            } else {
-                None
-            }
-        })
-        .collect();
-
-    assert!(!json_files.is_empty(), "No string seed test files found");
+#[test]
+fn test_reference_load_errors() {
+    let center_dir = find_repo_root().join("tests").join("center");
+    assert!(matches!(
+        reference::load_dir(&center_dir),
+        Err(LoadError::UnknownSchema { .. })
+    ));
+
+    let dir = std::env::temp_dir()
+        .join(format!("pragmastat-reference-{}", std::process::id()))
+        .join("shuffle");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("broken.json"), r#"{"input": {"seed": 1}}"#).unwrap();
+    let result = reference::load_dir(&dir);
+    fs::remove_dir_all(dir.parent().unwrap()).unwrap();
+    assert!(matches!(result, Err(LoadError::Json { .. })));
+}
 
-    for json_file in json_files {
-        let content = fs::read_to_string(&json_file).unwrap();
-        let test_case: StringSeedTestCase = serde_json::from_str(&content).unwrap();
+// One-sample bounds tests
 
-        let mut rng = Rng::from_string(&test_case.input.seed);
-        let actual: Vec<f64> = (0..test_case.input.count)
-            .map(|_| rng.uniform_f64())
-            .collect();
+#[derive(Debug, Deserialize)]
+struct CenterBoundsInput {
+    x: Vec<f64>,
+    misrate: f64,
+}
 
-        for (i, (actual_val, expected_val)) in
-            actual.iter().zip(test_case.output.iter()).enumerate()
-        {
-            assert!(
-                approx_eq!(f64, *actual_val, *expected_val, epsilon = 1e-15),
-                "Failed for test file: {:?}, index {}, expected: {}, got: {}",
-                json_file.file_name().unwrap(),
-                i,
-                expected_val,
-                actual_val
-            );
-        }
-    }
+#[derive(Debug, Deserialize)]
+struct CenterBoundsTestCase {
+    input: CenterBoundsInput,
+    output: Option<BoundsOutput>,
+    expected_error: Option<ExpectedError>,
 }
 
-fn run_rng_uniform_range_tests() {
+fn run_center_bounds_tests() {
     let repo_root = find_repo_root();
-    let test_data_dir = repo_root.join("tests").join("rng");
+    let test_data_dir = repo_root.join("tests").join("center-bounds");
+
+    if !test_data_dir.exists() {
+        panic!("Test data directory not found: {:?}", test_data_dir);
+    }
 
     let json_files: Vec<_> = fs::read_dir(&test_data_dir)
         .unwrap()
         .filter_map(|entry| {
             let entry = entry.unwrap();
             let path = entry.path();
-            let name = path.file_name()?.to_str()?;
-            if name.starts_with("uniform-range-") && name.ends_with(".json") {
+            if path.extension()?.to_str()? == "json" {
                 Some(path)
             } else {
                 None
@@ -1175,668 +1197,44 @@ fn run_rng_uniform_range_tests() {
         })
         .collect();
 
-    assert!(!json_files.is_empty(), "No uniform range test files found");
+    assert!(
+        !json_files.is_empty(),
+        "No JSON test files found in {:?}",
+        test_data_dir
+    );
+
+    let mut failures = Vec::new();
 
-    for json_file in json_files {
-        let content = fs::read_to_string(&json_file).unwrap();
-        let test_case: UniformRangeTestCase = serde_json::from_str(&content).unwrap();
+    for json_file in &json_files {
+        let content = fs::read_to_string(json_file).unwrap();
+        let test_case: CenterBoundsTestCase = serde_json::from_str(&content).unwrap();
+        let file_name = json_file.file_name().unwrap();
 
-        let mut rng = Rng::from_seed(test_case.input.seed);
-        let actual: Vec<f64> = (0..test_case.input.count)
-            .map(|_| rng.uniform_f64_range(test_case.input.min, test_case.input.max))
-            .collect();
+        // (1) raw API with assume_sorted = false
+        check_one_sample_bounds_raw(
+            &mut failures,
+            file_name,
+            test_case.expected_error.as_ref(),
+            test_case.output.as_ref(),
+            raw::center_bounds(&test_case.input.x, test_case.input.misrate, false),
+        );
 
-        for (i, (actual_val, expected_val)) in
-            actual.iter().zip(test_case.output.iter()).enumerate()
-        {
-            assert!(
-                approx_eq!(f64, *actual_val, *expected_val, epsilon = 1e-12),
-                "Failed for test file: {:?}, index {}, expected: {}, got: {}",
-                json_file.file_name().unwrap(),
-                i,
-                expected_val,
-                actual_val
-            );
-        }
+        // (2) Sample API
+        check_one_sample_bounds_sample(
+            &mut failures,
+            file_name,
+            test_case.expected_error.as_ref(),
+            test_case.output.as_ref(),
+            &test_case.input.x,
+            |x| center_bounds(x, test_case.input.misrate),
+        );
     }
-}
-
-fn run_rng_uniform_f32_tests() {
-    let repo_root = find_repo_root();
-    let test_data_dir = repo_root.join("tests").join("rng");
-
-    let json_files: Vec<_> = fs::read_dir(&test_data_dir)
-        .unwrap()
-        .filter_map(|entry| {
-            let entry = entry.unwrap();
-            let path = entry.path();
-            let name = path.file_name()?.to_str()?;
-            if name.starts_with("uniform-f32-") && name.ends_with(".json") {
-                Some(path)
-            } else {
-                None
-            }
-        })
-        .collect();
-
-    assert!(!json_files.is_empty(), "No uniform f32 test files found");
-
-    for json_file in json_files {
-        let content = fs::read_to_string(&json_file).unwrap();
-        let test_case: UniformF32TestCase = serde_json::from_str(&content).unwrap();
-
-        let mut rng = Rng::from_seed(test_case.input.seed);
-        let actual: Vec<f32> = (0..test_case.input.count)
-            .map(|_| rng.uniform_f32())
-            .collect();
-
-        for (i, (actual_val, expected_val)) in
-            actual.iter().zip(test_case.output.iter()).enumerate()
-        {
-            assert!(
-                approx_eq!(f32, *actual_val, *expected_val, epsilon = 1e-7),
-                "Failed for test file: {:?}, index {}, expected: {}, got: {}",
-                json_file.file_name().unwrap(),
-                i,
-                expected_val,
-                actual_val
-            );
-        }
-    }
-}
-
-fn run_rng_uniform_i32_tests() {
-    let repo_root = find_repo_root();
-    let test_data_dir = repo_root.join("tests").join("rng");
-
-    let json_files: Vec<_> = fs::read_dir(&test_data_dir)
-        .unwrap()
-        .filter_map(|entry| {
-            let entry = entry.unwrap();
-            let path = entry.path();
-            let name = path.file_name()?.to_str()?;
-            if name.starts_with("uniform-i32-") && name.ends_with(".json") {
-                Some(path)
-            } else {
-                None
-            }
-        })
-        .collect();
-
-    assert!(!json_files.is_empty(), "No uniform i32 test files found");
-
-    for json_file in json_files {
-        let content = fs::read_to_string(&json_file).unwrap();
-        let test_case: UniformI32TestCase = serde_json::from_str(&content).unwrap();
-
-        let mut rng = Rng::from_seed(test_case.input.seed);
-        let actual: Vec<i32> = (0..test_case.input.count)
-            .map(|_| rng.uniform_i32(test_case.input.min, test_case.input.max))
-            .collect();
-
-        assert_eq!(
-            actual,
-            test_case.output,
-            "Failed for test file: {:?}",
-            json_file.file_name().unwrap()
-        );
-    }
-}
-
-fn run_rng_uniform_bool_tests() {
-    let repo_root = find_repo_root();
-    let test_data_dir = repo_root.join("tests").join("rng");
-
-    let json_files: Vec<_> = fs::read_dir(&test_data_dir)
-        .unwrap()
-        .filter_map(|entry| {
-            let entry = entry.unwrap();
-            let path = entry.path();
-            let name = path.file_name()?.to_str()?;
-            if name.starts_with("uniform-bool-seed-") && name.ends_with(".json") {
-                Some(path)
-            } else {
-                None
-            }
-        })
-        .collect();
-
-    assert!(!json_files.is_empty(), "No uniform bool test files found");
-
-    for json_file in json_files {
-        let content = fs::read_to_string(&json_file).unwrap();
-        let test_case: UniformBoolTestCase = serde_json::from_str(&content).unwrap();
-
-        let mut rng = Rng::from_seed(test_case.input.seed);
-        let actual: Vec<bool> = (0..test_case.input.count)
-            .map(|_| rng.uniform_bool())
-            .collect();
-
-        assert_eq!(
-            actual,
-            test_case.output,
-            "Failed for test file: {:?}",
-            json_file.file_name().unwrap()
-        );
-    }
-}
-
-fn run_shuffle_tests() {
-    let repo_root = find_repo_root();
-    let test_data_dir = repo_root.join("tests").join("shuffle");
-
-    let json_files: Vec<_> = fs::read_dir(&test_data_dir)
-        .unwrap()
-        .filter_map(|entry| {
-            let entry = entry.unwrap();
-            let path = entry.path();
-            if path.extension()?.to_str()? == "json" {
-                Some(path)
-            } else {
-                None
-            }
-        })
-        .collect();
-
-    assert!(!json_files.is_empty(), "No shuffle test files found");
-
-    for json_file in json_files {
-        let content = fs::read_to_string(&json_file).unwrap();
-        let test_case: ShuffleTestCase = serde_json::from_str(&content).unwrap();
-
-        let mut rng = Rng::from_seed(test_case.input.seed);
-        let actual = rng.shuffle(&test_case.input.x);
-
-        for (i, (actual_val, expected_val)) in
-            actual.iter().zip(test_case.output.iter()).enumerate()
-        {
-            assert!(
-                approx_eq!(f64, *actual_val, *expected_val, epsilon = 1e-15),
-                "Failed for test file: {:?}, index {}, expected: {}, got: {}",
-                json_file.file_name().unwrap(),
-                i,
-                expected_val,
-                actual_val
-            );
-        }
-    }
-}
-
-fn run_sample_tests() {
-    let repo_root = find_repo_root();
-    let test_data_dir = repo_root.join("tests").join("sample");
-
-    let json_files: Vec<_> = fs::read_dir(&test_data_dir)
-        .unwrap()
-        .filter_map(|entry| {
-            let entry = entry.unwrap();
-            let path = entry.path();
-            if path.extension()?.to_str()? == "json" {
-                Some(path)
-            } else {
-                None
-            }
-        })
-        .collect();
-
-    assert!(!json_files.is_empty(), "No sample test files found");
-
-    for json_file in json_files {
-        let content = fs::read_to_string(&json_file).unwrap();
-        let test_case: SampleTestCase = serde_json::from_str(&content).unwrap();
-
-        let mut rng = Rng::from_seed(test_case.input.seed);
-        let actual = rng.sample(&test_case.input.x, test_case.input.k);
-
-        for (i, (actual_val, expected_val)) in
-            actual.iter().zip(test_case.output.iter()).enumerate()
-        {
-            assert!(
-                approx_eq!(f64, *actual_val, *expected_val, epsilon = 1e-15),
-                "Failed for test file: {:?}, index {}, expected: {}, got: {}",
-                json_file.file_name().unwrap(),
-                i,
-                expected_val,
-                actual_val
-            );
-        }
-    }
-}
-
-fn run_resample_tests() {
-    let repo_root = find_repo_root();
-    let test_data_dir = repo_root.join("tests").join("resample");
-
-    let json_files: Vec<_> = fs::read_dir(&test_data_dir)
-        .unwrap()
-        .filter_map(|entry| {
-            let entry = entry.unwrap();
-            let path = entry.path();
-            if path.extension()?.to_str()? == "json" {
-                Some(path)
-            } else {
-                None
-            }
-        })
-        .collect();
-
-    assert!(!json_files.is_empty(), "No resample test files found");
-
-    for json_file in json_files {
-        let content = fs::read_to_string(&json_file).unwrap();
-        let test_case: SampleTestCase = serde_json::from_str(&content).unwrap();
-
-        let mut rng = Rng::from_seed(test_case.input.seed);
-        let actual = rng.resample(&test_case.input.x, test_case.input.k);
-
-        for (i, (actual_val, expected_val)) in
-            actual.iter().zip(test_case.output.iter()).enumerate()
-        {
-            assert!(
-                approx_eq!(f64, *actual_val, *expected_val, epsilon = 1e-15),
-                "Failed for test file: {:?}, index {}, expected: {}, got: {}",
-                json_file.file_name().unwrap(),
-                i,
-                expected_val,
-                actual_val
-            );
-        }
-    }
-}
-
-fn run_uniform_distribution_tests() {
-    let repo_root = find_repo_root();
-    let test_data_dir = repo_root
-        .join("tests")
-        .join("distributions")
-        .join("uniform");
-
-    let json_files: Vec<_> = fs::read_dir(&test_data_dir)
-        .unwrap()
-        .filter_map(|entry| {
-            let entry = entry.unwrap();
-            let path = entry.path();
-            if path.extension()?.to_str()? == "json" {
-                Some(path)
-            } else {
-                None
-            }
-        })
-        .collect();
-
-    assert!(
-        !json_files.is_empty(),
-        "No uniform distribution test files found"
-    );
-
-    for json_file in json_files {
-        let content = fs::read_to_string(&json_file).unwrap();
-        let test_case: UniformDistTestCase = serde_json::from_str(&content).unwrap();
-
-        let mut rng = Rng::from_seed(test_case.input.seed);
-        let dist = Uniform::new(test_case.input.min, test_case.input.max);
-        let actual: Vec<f64> = (0..test_case.input.count)
-            .map(|_| dist.sample(&mut rng))
-            .collect();
-
-        for (i, (actual_val, expected_val)) in
-            actual.iter().zip(test_case.output.iter()).enumerate()
-        {
-            assert!(
-                approx_eq!(f64, *actual_val, *expected_val, epsilon = 1e-12),
-                "Failed for test file: {:?}, index {}, expected: {}, got: {}",
-                json_file.file_name().unwrap(),
-                i,
-                expected_val,
-                actual_val
-            );
-        }
-    }
-}
-
-fn run_additive_distribution_tests() {
-    let repo_root = find_repo_root();
-    let test_data_dir = repo_root
-        .join("tests")
-        .join("distributions")
-        .join("additive");
-
-    let json_files: Vec<_> = fs::read_dir(&test_data_dir)
-        .unwrap()
-        .filter_map(|entry| {
-            let entry = entry.unwrap();
-            let path = entry.path();
-            if path.extension()?.to_str()? == "json" {
-                Some(path)
-            } else {
-                None
-            }
-        })
-        .collect();
-
-    assert!(
-        !json_files.is_empty(),
-        "No additive distribution test files found"
-    );
-
-    for json_file in json_files {
-        let content = fs::read_to_string(&json_file).unwrap();
-        let test_case: AdditiveDistTestCase = serde_json::from_str(&content).unwrap();
-
-        let mut rng = Rng::from_seed(test_case.input.seed);
-        let dist = Additive::new(test_case.input.mean, test_case.input.std_dev);
-        let actual: Vec<f64> = (0..test_case.input.count)
-            .map(|_| dist.sample(&mut rng))
-            .collect();
-
-        for (i, (actual_val, expected_val)) in
-            actual.iter().zip(test_case.output.iter()).enumerate()
-        {
-            assert!(
-                approx_eq!(f64, *actual_val, *expected_val, epsilon = 1e-12),
-                "Failed for test file: {:?}, index {}, expected: {}, got: {}",
-                json_file.file_name().unwrap(),
-                i,
-                expected_val,
-                actual_val
-            );
-        }
-    }
-}
-
-fn run_multiplic_distribution_tests() {
-    let repo_root = find_repo_root();
-    let test_data_dir = repo_root
-        .join("tests")
-        .join("distributions")
-        .join("multiplic");
-
-    let json_files: Vec<_> = fs::read_dir(&test_data_dir)
-        .unwrap()
-        .filter_map(|entry| {
-            let entry = entry.unwrap();
-            let path = entry.path();
-            if path.extension()?.to_str()? == "json" {
-                Some(path)
-            } else {
-                None
-            }
-        })
-        .collect();
-
-    assert!(
-        !json_files.is_empty(),
-        "No multiplic distribution test files found"
-    );
-
-    for json_file in json_files {
-        let content = fs::read_to_string(&json_file).unwrap();
-        let test_case: MultiplicDistTestCase = serde_json::from_str(&content).unwrap();
-
-        let mut rng = Rng::from_seed(test_case.input.seed);
-        let dist = Multiplic::new(test_case.input.log_mean, test_case.input.log_std_dev);
-        let actual: Vec<f64> = (0..test_case.input.count)
-            .map(|_| dist.sample(&mut rng))
-            .collect();
-
-        for (i, (actual_val, expected_val)) in
-            actual.iter().zip(test_case.output.iter()).enumerate()
-        {
-            assert!(
-                approx_eq!(f64, *actual_val, *expected_val, epsilon = 1e-12),
-                "Failed for test file: {:?}, index {}, expected: {}, got: {}",
-                json_file.file_name().unwrap(),
-                i,
-                expected_val,
-                actual_val
-            );
-        }
-    }
-}
-
-fn run_exp_distribution_tests() {
-    let repo_root = find_repo_root();
-    let test_data_dir = repo_root.join("tests").join("distributions").join("exp");
-
-    let json_files: Vec<_> = fs::read_dir(&test_data_dir)
-        .unwrap()
-        .filter_map(|entry| {
-            let entry = entry.unwrap();
-            let path = entry.path();
-            if path.extension()?.to_str()? == "json" {
-                Some(path)
-            } else {
-                None
-            }
-        })
-        .collect();
-
-    assert!(
-        !json_files.is_empty(),
-        "No exp distribution test files found"
-    );
-
-    for json_file in json_files {
-        let content = fs::read_to_string(&json_file).unwrap();
-        let test_case: ExpDistTestCase = serde_json::from_str(&content).unwrap();
-
-        let mut rng = Rng::from_seed(test_case.input.seed);
-        let dist = Exp::new(test_case.input.rate);
-        let actual: Vec<f64> = (0..test_case.input.count)
-            .map(|_| dist.sample(&mut rng))
-            .collect();
-
-        for (i, (actual_val, expected_val)) in
-            actual.iter().zip(test_case.output.iter()).enumerate()
-        {
-            assert!(
-                approx_eq!(f64, *actual_val, *expected_val, epsilon = 1e-12),
-                "Failed for test file: {:?}, index {}, expected: {}, got: {}",
-                json_file.file_name().unwrap(),
-                i,
-                expected_val,
-                actual_val
-            );
-        }
-    }
-}
-
-fn run_power_distribution_tests() {
-    let repo_root = find_repo_root();
-    let test_data_dir = repo_root.join("tests").join("distributions").join("power");
-
-    let json_files: Vec<_> = fs::read_dir(&test_data_dir)
-        .unwrap()
-        .filter_map(|entry| {
-            let entry = entry.unwrap();
-            let path = entry.path();
-            if path.extension()?.to_str()? == "json" {
-                Some(path)
-            } else {
-                None
-            }
-        })
-        .collect();
-
-    assert!(
-        !json_files.is_empty(),
-        "No power distribution test files found"
-    );
-
-    for json_file in json_files {
-        let content = fs::read_to_string(&json_file).unwrap();
-        let test_case: PowerDistTestCase = serde_json::from_str(&content).unwrap();
-
-        let mut rng = Rng::from_seed(test_case.input.seed);
-        let dist = Power::new(test_case.input.min, test_case.input.shape);
-        let actual: Vec<f64> = (0..test_case.input.count)
-            .map(|_| dist.sample(&mut rng))
-            .collect();
-
-        for (i, (actual_val, expected_val)) in
-            actual.iter().zip(test_case.output.iter()).enumerate()
-        {
-            assert!(
-                approx_eq!(f64, *actual_val, *expected_val, epsilon = 1e-12),
-                "Failed for test file: {:?}, index {}, expected: {}, got: {}",
-                json_file.file_name().unwrap(),
-                i,
-                expected_val,
-                actual_val
-            );
-        }
-    }
-}
-
-#[test]
-fn test_rng_uniform() {
-    run_rng_uniform_tests();
-}
-
-#[test]
-fn test_rng_uniform_int() {
-    run_rng_uniform_int_tests();
-}
-
-#[test]
-fn test_rng_string_seed() {
-    run_rng_string_seed_tests();
-}
-
-#[test]
-fn test_rng_uniform_range() {
-    run_rng_uniform_range_tests();
-}
-
-#[test]
-fn test_rng_uniform_f32() {
-    run_rng_uniform_f32_tests();
-}
-
-#[test]
-fn test_rng_uniform_i32() {
-    run_rng_uniform_i32_tests();
-}
-
-#[test]
-fn test_rng_uniform_bool() {
-    run_rng_uniform_bool_tests();
-}
-
-#[test]
-fn test_shuffle() {
-    run_shuffle_tests();
-}
-
-#[test]
-fn test_sample() {
-    run_sample_tests();
-}
-
-#[test]
-fn test_resample() {
-    run_resample_tests();
-}
-
-#[test]
-fn test_uniform_distribution() {
-    run_uniform_distribution_tests();
-}
-
-#[test]
-fn test_additive_distribution() {
-    run_additive_distribution_tests();
-}
-
-#[test]
-fn test_multiplic_distribution() {
-    run_multiplic_distribution_tests();
-}
-
-#[test]
-fn test_exp_distribution() {
-    run_exp_distribution_tests();
-}
-
-#[test]
-fn test_power_distribution() {
-    run_power_distribution_tests();
-}
-
-// One-sample bounds tests
-
-#[derive(Debug, Deserialize)]
-struct CenterBoundsInput {
-    x: Vec<f64>,
-    misrate: f64,
-}
-
-#[derive(Debug, Deserialize)]
-struct CenterBoundsTestCase {
-    input: CenterBoundsInput,
-    output: Option<BoundsOutput>,
-    expected_error: Option<ExpectedError>,
-}
-
-fn run_center_bounds_tests() {
-    let repo_root = find_repo_root();
-    let test_data_dir = repo_root.join("tests").join("center-bounds");
-
-    if !test_data_dir.exists() {
-        panic!("Test data directory not found: {:?}", test_data_dir);
-    }
-
-    let json_files: Vec<_> = fs::read_dir(&test_data_dir)
-        .unwrap()
-        .filter_map(|entry| {
-            let entry = entry.unwrap();
-            let path = entry.path();
-            if path.extension()?.to_str()? == "json" {
-                Some(path)
-            } else {
-                None
-            }
-        })
-        .collect();
-
-    assert!(
-        !json_files.is_empty(),
-        "No JSON test files found in {:?}",
-        test_data_dir
-    );
-
-    let mut failures = Vec::new();
-
-    for json_file in &json_files {
-        let content = fs::read_to_string(json_file).unwrap();
-        let test_case: CenterBoundsTestCase = serde_json::from_str(&content).unwrap();
-        let file_name = json_file.file_name().unwrap();
-
-        // (1) raw API with assume_sorted = false
-        check_one_sample_bounds_raw(
-            &mut failures,
-            file_name,
-            test_case.expected_error.as_ref(),
-            test_case.output.as_ref(),
-            raw::center_bounds(&test_case.input.x, test_case.input.misrate, false),
-        );
-
-        // (2) Sample API
-        check_one_sample_bounds_sample(
-            &mut failures,
-            file_name,
-            test_case.expected_error.as_ref(),
-            test_case.output.as_ref(),
-            &test_case.input.x,
-            |x| center_bounds(x, test_case.input.misrate),
-        );
-    }
-
-    assert!(
-        failures.is_empty(),
-        "Failed tests:\n{}",
-        failures.join("\n")
-    );
+
+    assert!(
+        failures.is_empty(),
+        "Failed tests:\n{}",
+        failures.join("\n")
+    );
 }
 
 /// Checks a raw one-sample bounds result against the fixture.
@@ -2003,3 +1401,249 @@ fn run_spread_bounds_tests() {
 fn test_spread_bounds() {
     run_spread_bounds_tests();
 }
+
+#[derive(Debug, Deserialize)]
+struct EstimatorGridInput {
+    distribution: String,
+    parameters: Vec<f64>,
+    seed: i64,
+    bounds_seed: String,
+    misrates: Vec<f64>,
+    x: Vec<f64>,
+    y: Vec<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EstimatorGridBounds {
+    misrate: f64,
+    center: Option<BoundsOutput>,
+    spread: Option<BoundsOutput>,
+    shift: Option<BoundsOutput>,
+    ratio: Option<BoundsOutput>,
+    disparity: Option<BoundsOutput>,
+    avg_spread: Option<BoundsOutput>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EstimatorGridOutput {
+    center: f64,
+    spread: f64,
+    shift: f64,
+    ratio: f64,
+    disparity: f64,
+    avg_spread: f64,
+    bounds: Vec<EstimatorGridBounds>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EstimatorGridTestCase {
+    input: EstimatorGridInput,
+    output: EstimatorGridOutput,
+    display_digits: usize,
+    display: EstimatorGridOutput,
+}
+
+fn estimator_grid_distribution(name: &str, parameters: &[f64]) -> Box<dyn Distribution> {
+    match name {
+        "additive" => Box::new(Additive::new(parameters[0], parameters[1])),
+        "uniform" => Box::new(Uniform::new(parameters[0], parameters[1])),
+        "exp" => Box::new(Exp::new(parameters[0])),
+        "multiplic" => Box::new(Multiplic::new(parameters[0], parameters[1])),
+        _ => panic!("Unknown distribution: {name}"),
+    }
+}
+
+fn check_grid_bounds(
+    failures: &mut Vec<String>,
+    label: &str,
+    actual: Result<raw::RawBounds, EstimatorError>,
+    expected: &Option<BoundsOutput>,
+) {
+    match (actual, expected) {
+        (Ok(a), Some(e)) => {
+            if !values_match(a.lower, e.lower) || !values_match(a.upper, e.upper) {
+                failures.push(format!(
+                    "{label}: expected [{}, {}], got [{}, {}]",
+                    e.lower, e.upper, a.lower, a.upper
+                ));
+            }
+        }
+        (Err(EstimatorError::Assumption(err)), None)
+            if err.violation().subject == assumptions::Subject::Misrate => {}
+        (actual, expected) => {
+            failures.push(format!("{label}: expected {expected:?}, got {actual:?}"))
+        }
+    }
+}
+
+fn round_significant(value: f64, digits: usize) -> f64 {
+    format!("{:.*e}", digits - 1, value).parse().unwrap()
+}
+
+fn check_grid_display(
+    failures: &mut Vec<String>,
+    file: &str,
+    output: &EstimatorGridOutput,
+    display: &EstimatorGridOutput,
+    digits: usize,
+) {
+    let mut pairs = vec![
+        ("center", output.center, display.center),
+        ("spread", output.spread, display.spread),
+        ("shift", output.shift, display.shift),
+        ("ratio", output.ratio, display.ratio),
+        ("disparity", output.disparity, display.disparity),
+        ("avg_spread", output.avg_spread, display.avg_spread),
+    ];
+    for (full, shown) in output.bounds.iter().zip(&display.bounds) {
+        for (name, f, s) in [
+            ("center_bounds", &full.center, &shown.center),
+            ("spread_bounds", &full.spread, &shown.spread),
+            ("shift_bounds", &full.shift, &shown.shift),
+            ("ratio_bounds", &full.ratio, &shown.ratio),
+            ("disparity_bounds", &full.disparity, &shown.disparity),
+            ("avg_spread_bounds", &full.avg_spread, &shown.avg_spread),
+        ] {
+            match (f, s) {
+                (Some(f), Some(s)) => {
+                    pairs.push((name, f.lower, s.lower));
+                    pairs.push((name, f.upper, s.upper));
+                }
+                (None, None) => {}
+                _ => failures.push(format!("{file} display {name}: presence mismatch")),
+            }
+        }
+    }
+    for (name, full, shown) in pairs {
+        if !values_match(round_significant(full, digits), shown) {
+            failures.push(format!(
+                "{file} display {name}: {full} rounds to {}, stored {shown}",
+                round_significant(full, digits)
+            ));
+        }
+    }
+}
+
+fn run_estimator_grid_tests() {
+    let test_data_dir = find_repo_root().join("tests").join("estimators");
+
+    let json_files: Vec<_> = fs::read_dir(&test_data_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.unwrap().path();
+            if path.extension()?.to_str()? == "json" {
+                Some(path)
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    assert!(!json_files.is_empty(), "No estimator grid test files found");
+
+    let mut failures = Vec::new();
+
+    for json_file in &json_files {
+        let content = fs::read_to_string(json_file).unwrap();
+        let test_case: EstimatorGridTestCase = serde_json::from_str(&content).unwrap();
+        let file = json_file.file_name().unwrap().to_string_lossy().to_string();
+        let input = &test_case.input;
+        let output = &test_case.output;
+        let (x, y) = (&input.x, &input.y);
+
+        // Inputs must be reproducible from the recorded distribution and seed
+        let distribution = estimator_grid_distribution(&input.distribution, &input.parameters);
+        let mut rng = Rng::from_seed(input.seed);
+        let draws_x = distribution.samples(&mut rng, x.len());
+        let draws_y = distribution.samples(&mut rng, y.len());
+        let reproduced = draws_x.iter().chain(&draws_y);
+        if !reproduced
+            .zip(x.iter().chain(y))
+            .all(|(a, e)| values_match(*a, *e))
+        {
+            failures.push(format!("{file}: inputs do not match the seeded draws"));
+        }
+
+        for (name, actual, expected) in [
+            ("center", raw::center(x, false), output.center),
+            ("spread", raw::spread(x, false), output.spread),
+            ("shift", raw::shift(x, y, false), output.shift),
+            ("ratio", raw::ratio(x, y, false), output.ratio),
+            ("disparity", raw::disparity(x, y, false), output.disparity),
+            (
+                "avg_spread",
+                raw::avg_spread(x, y, false),
+                output.avg_spread,
+            ),
+        ] {
+            match actual {
+                Ok(actual) if values_match(actual, expected) => {}
+                actual => failures.push(format!(
+                    "{file} {name}: expected {expected}, got {actual:?}"
+                )),
+            }
+        }
+
+        assert_eq!(input.misrates.len(), output.bounds.len(), "{file}");
+        for bounds in &output.bounds {
+            let misrate = bounds.misrate;
+            let seed = input.bounds_seed.as_str();
+            let label = |name: &str| format!("{file} {name} @ {misrate}");
+            check_grid_bounds(
+                &mut failures,
+                &label("center_bounds"),
+                raw::center_bounds(x, misrate, false),
+                &bounds.center,
+            );
+            check_grid_bounds(
+                &mut failures,
+                &label("spread_bounds"),
+                raw::spread_bounds_with_seed(x, misrate, seed, false),
+                &bounds.spread,
+            );
+            check_grid_bounds(
+                &mut failures,
+                &label("shift_bounds"),
+                raw::shift_bounds(x, y, misrate, false),
+                &bounds.shift,
+            );
+            check_grid_bounds(
+                &mut failures,
+                &label("ratio_bounds"),
+                raw::ratio_bounds(x, y, misrate, false),
+                &bounds.ratio,
+            );
+            check_grid_bounds(
+                &mut failures,
+                &label("disparity_bounds"),
+                raw::disparity_bounds_with_seed(x, y, misrate, seed, false),
+                &bounds.disparity,
+            );
+            check_grid_bounds(
+                &mut failures,
+                &label("avg_spread_bounds"),
+                raw::avg_spread_bounds_with_seed(x, y, misrate, seed, false),
+                &bounds.avg_spread,
+            );
+        }
+
+        check_grid_display(
+            &mut failures,
+            &file,
+            output,
+            &test_case.display,
+            test_case.display_digits,
+        );
+    }
+
+    assert!(
+        failures.is_empty(),
+        "Failed tests:\n{}",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn test_estimator_grid() {
+    run_estimator_grid_tests();
+}
@@ -0,0 +1,129 @@
+//! Tests for the sliding-window estimators.
+//!
+//! Every rolling output must equal the batch estimator on the current window
+//! contents bit-for-bit, including after many evictions and with tied values.
+
+use pragmastat::assumptions::{AssumptionId, EstimatorError, ViolationDetail};
+use pragmastat::distributions::{Additive, Distribution};
+use pragmastat::estimators::raw;
+use pragmastat::estimators::rolling::{IncrementalSpread, RollingCenter, RollingSpread};
+use pragmastat::Rng;
+
+fn assert_matches_batch(values: &[f64], window: usize) {
+    let mut center = RollingCenter::new(window);
+    let mut spread = RollingSpread::new(window);
+    for (i, &x) in values.iter().enumerate() {
+        let rolling_center = center.push(x);
+        let rolling_spread = spread.push(x);
+        if i + 1 < window {
+            assert_eq!((rolling_center, rolling_spread), (None, None));
+            continue;
+        }
+        let contents = &values[i + 1 - window..=i];
+        assert_eq!(
+            rolling_center,
+            Some(raw::center(contents, false).unwrap()),
+            "center at step {i}"
+        );
+        let expected_spread = raw::spread(contents, false).unwrap_or(0.0);
+        assert_eq!(rolling_spread, Some(expected_spread), "spread at step {i}");
+    }
+}
+
+#[test]
+fn matches_batch_on_random_stream() {
+    let mut rng = Rng::from_string("rolling-random");
+    let values = Additive::new(100.0, 15.0).sample_n(&mut rng, 400);
+    for window in [1, 2, 3, 10, 64, 150] {
+        assert_matches_batch(&values, window);
+    }
+}
+
+#[test]
+fn matches_batch_with_ties() {
+    let mut rng = Rng::from_string("rolling-ties");
+    let values: Vec<f64> = (0..300).map(|_| rng.uniform_i64(0, 6) as f64).collect();
+    for window in [4, 11, 50] {
+        assert_matches_batch(&values, window);
+    }
+}
+
+#[test]
+fn flat_window_has_zero_spread() {
+    let mut spread = RollingSpread::new(3);
+    for x in [5.0, 5.0, 5.0, 5.0] {
+        spread.push(x);
+    }
+    assert_eq!(spread.push(5.0), Some(0.0));
+    // [5, 5, 8]: differences {0, 3, 3}
+    assert_eq!(spread.push(8.0), Some(3.0));
+    assert_eq!(spread.window(), 3);
+}
+
+#[test]
+#[should_panic(expected = "window must be positive")]
+fn zero_window_panics() {
+    RollingCenter::new(0);
+}
+
+fn non_finite_index(err: EstimatorError) -> Vec<usize> {
+    let EstimatorError::Assumption(e) = &err else {
+        panic!("expected assumption error, got {err}");
+    };
+    assert_eq!(e.violation().id, AssumptionId::Validity);
+    match e.detail() {
+        Some(ViolationDetail::NonFiniteValues { indices }) => indices.clone(),
+        other => panic!("unexpected detail {other:?}"),
+    }
+}
+
+#[test]
+fn non_finite_value_is_rejected_without_changing_the_window() {
+    let mut center = RollingCenter::new(2);
+    let mut spread = RollingSpread::new(2);
+    for x in [1.0, 4.0] {
+        center.push(x);
+        spread.push(x);
+    }
+    for bad in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        assert_eq!(non_finite_index(center.try_push(bad).unwrap_err()), vec![2]);
+        assert_eq!(non_finite_index(spread.try_push(bad).unwrap_err()), vec![2]);
+    }
+    // The window is still [1, 4]; 9 evicts 1
+    assert_eq!(center.try_push(9.0).unwrap(), Some(6.5));
+    assert_eq!(spread.push(9.0), Some(5.0));
+
+    let mut incremental = IncrementalSpread::new();
+    incremental.push(1.0).unwrap();
+    assert_eq!(
+        non_finite_index(incremental.push(f64::NAN).unwrap_err()),
+        vec![1]
+    );
+    assert_eq!(incremental.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "validity(x)")]
+fn push_panics_on_non_finite_value() {
+    let mut center = RollingCenter::new(2);
+    center.push(1.0);
+    center.push(f64::NAN);
+}
+
+#[test]
+fn incremental_spread_matches_batch_after_every_push() {
+    let mut rng = Rng::from_string("incremental-spread");
+    let mut values = Additive::new(100.0, 15.0).sample_n(&mut rng, 300);
+    // Ties and repeats must not break the sorted insert
+    values.extend((0..100).map(|_| rng.uniform_i64(90, 110) as f64));
+
+    let mut spread = IncrementalSpread::new();
+    assert!(spread.is_empty());
+    assert_eq!(spread.value(), 0.0);
+    for (i, &x) in values.iter().enumerate() {
+        spread.push(x).unwrap();
+        let expected = raw::spread(&values[..=i], false).unwrap_or(0.0);
+        assert_eq!(spread.value(), expected, "spread after {} values", i + 1);
+    }
+    assert_eq!(spread.len(), values.len());
+}
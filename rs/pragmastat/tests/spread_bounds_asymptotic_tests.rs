@@ -0,0 +1,136 @@
+//! Tests for the asymptotic (resampling-free) spread bounds.
+
+use pragmastat::assumptions::{AssumptionId, EstimatorError, Subject, ViolationDetail};
+use pragmastat::distributions::{Additive, Distribution, Exp};
+use pragmastat::estimators::raw;
+use pragmastat::measurement_unit::MeasurementUnit;
+use pragmastat::{spread_bounds_asymptotic, Rng, Sample, SPREAD_BOUNDS_ASYMPTOTIC_MIN_SIZE};
+
+fn violation(err: EstimatorError) -> (AssumptionId, Subject) {
+    match err {
+        EstimatorError::Assumption(e) => (e.violation().id, e.violation().subject),
+        other => panic!("expected assumption error, got {other}"),
+    }
+}
+
+#[test]
+fn agrees_with_randomized_bounds_on_large_samples() {
+    let cases: [(&str, &dyn Distribution); 2] = [
+        ("additive", &Additive::new(10.0, 2.0)),
+        ("exp", &Exp::new(0.5)),
+    ];
+    for (name, distribution) in cases {
+        for n in [10_000, 100_000] {
+            let mut rng = Rng::from_string(&format!("spread-asymptotic-{name}-{n}"));
+            let x = distribution.samples(&mut rng, n);
+            let spread = raw::spread(&x, false).unwrap();
+            let seeded = raw::spread_bounds_with_seed(&x, 0.01, "agreement", false).unwrap();
+            let asymptotic = raw::spread_bounds_asymptotic(&x, 0.01, false).unwrap();
+
+            // Both estimate the same population quantity to O(1/sqrt(n))
+            let tolerance = 8.0 / (n as f64).sqrt();
+            let lower = (asymptotic.lower / seeded.lower - 1.0).abs();
+            let upper = (asymptotic.upper / seeded.upper - 1.0).abs();
+            assert!(lower < tolerance, "{name}, n = {n}: lower {lower}");
+            assert!(upper < tolerance, "{name}, n = {n}: upper {upper}");
+            assert!(asymptotic.lower <= spread && spread <= asymptotic.upper);
+            // Using all pairs makes the interval narrower
+            assert!(
+                asymptotic.upper - asymptotic.lower < seeded.upper - seeded.lower,
+                "{name}, n = {n}"
+            );
+        }
+    }
+}
+
+#[test]
+fn coverage_is_close_to_nominal() {
+    // Spread of Additive(0, 1) is sqrt(2) * Phi^-1(3/4)
+    let truth = std::f64::consts::SQRT_2 * 0.674_489_750_196_081_7;
+    let distribution = Additive::new(0.0, 1.0);
+    let mut rng = Rng::from_string("spread-asymptotic-coverage");
+    let replicates = 400;
+    let covered = (0..replicates)
+        .filter(|_| {
+            let x = distribution.samples(&mut rng, SPREAD_BOUNDS_ASYMPTOTIC_MIN_SIZE);
+            let b = raw::spread_bounds_asymptotic(&x, 0.1, false).unwrap();
+            b.lower <= truth && truth <= b.upper
+        })
+        .count();
+    let coverage = covered as f64 / replicates as f64;
+    assert!((coverage - 0.9).abs() < 0.04, "coverage {coverage}");
+}
+
+#[test]
+fn is_deterministic_and_order_independent() {
+    let mut rng = Rng::from_string("spread-asymptotic-order");
+    let x = Exp::new(1.0).samples(&mut rng, 5_000);
+    let mut sorted = x.clone();
+    sorted.sort_by(f64::total_cmp);
+    let bounds = raw::spread_bounds_asymptotic(&x, 0.05, false).unwrap();
+    assert_eq!(
+        bounds,
+        raw::spread_bounds_asymptotic(&x, 0.05, false).unwrap()
+    );
+    assert_eq!(
+        bounds,
+        raw::spread_bounds_asymptotic(&sorted, 0.05, true).unwrap()
+    );
+    let wider = raw::spread_bounds_asymptotic(&x, 0.001, false).unwrap();
+    assert!(wider.lower <= bounds.lower && bounds.upper <= wider.upper);
+}
+
+#[test]
+fn sample_bounds_carry_unit() {
+    let ms = MeasurementUnit::new("ms", "Time", "ms", "Millisecond", 1_000_000);
+    let mut rng = Rng::from_string("spread-asymptotic-sample");
+    let values = Additive::new(50.0, 5.0).samples(&mut rng, 2_000);
+    let raw_bounds = raw::spread_bounds_asymptotic(&values, 0.01, false).unwrap();
+    let x = Sample::with_unit(values, ms.clone()).unwrap();
+    let bounds = spread_bounds_asymptotic(&x, 0.01).unwrap();
+    assert_eq!(
+        (bounds.lower, bounds.upper),
+        (raw_bounds.lower, raw_bounds.upper)
+    );
+    assert_eq!(bounds.unit, ms);
+}
+
+#[test]
+fn rejects_invalid_inputs() {
+    let mut rng = Rng::from_string("spread-asymptotic-errors");
+    let x = Additive::new(0.0, 1.0).samples(&mut rng, SPREAD_BOUNDS_ASYMPTOTIC_MIN_SIZE);
+
+    let err = raw::spread_bounds_asymptotic(&x[1..], 0.01, false).unwrap_err();
+    let EstimatorError::Assumption(e) = &err else {
+        panic!("expected assumption error");
+    };
+    assert_eq!(e.violation().subject, Subject::X);
+    assert_eq!(
+        e.detail(),
+        Some(&ViolationDetail::SampleTooSmall {
+            n: SPREAD_BOUNDS_ASYMPTOTIC_MIN_SIZE - 1,
+            required: SPREAD_BOUNDS_ASYMPTOTIC_MIN_SIZE,
+        })
+    );
+    for misrate in [0.0, -0.1, 1.5, f64::NAN] {
+        assert_eq!(
+            violation(raw::spread_bounds_asymptotic(&x, misrate, false).unwrap_err()),
+            (AssumptionId::Domain, Subject::Misrate),
+            "misrate = {misrate}"
+        );
+    }
+    let mut invalid = x.clone();
+    invalid[7] = f64::NAN;
+    assert_eq!(
+        violation(raw::spread_bounds_asymptotic(&invalid, 0.01, false).unwrap_err()),
+        (AssumptionId::Validity, Subject::X)
+    );
+    // More than half of the pairs are ties
+    let tied: Vec<f64> = (0..2_000)
+        .map(|i| if i < 1_500 { 1.0 } else { 2.0 })
+        .collect();
+    assert_eq!(
+        violation(raw::spread_bounds_asymptotic(&tied, 0.01, false).unwrap_err()),
+        (AssumptionId::Sparity, Subject::X)
+    );
+}
@@ -0,0 +1,108 @@
+//! Tests for the spread ratio and its bounds.
+
+use pragmastat::distributions::{Additive, Distribution, Exp};
+use pragmastat::estimators::raw;
+use pragmastat::experiment::{coverage, Experiment};
+use pragmastat::*;
+
+fn draw(seed: &str, dist: &impl Distribution, n: usize) -> Vec<f64> {
+    dist.samples(&mut Rng::from_string(seed), n)
+}
+
+#[test]
+fn known_scale_factor_is_recovered_exactly() {
+    // Scaling by a power of two keeps every pairwise difference exact
+    let y = draw("spread-ratio-exact", &Additive::new(0.0, 1.0), 40);
+    let x: Vec<f64> = y.iter().map(|v| 4.0 * v).collect();
+    assert_eq!(raw::spread_ratio(&x, &y, false).unwrap(), 4.0);
+    assert_eq!(raw::spread_ratio(&y, &x, false).unwrap(), 0.25);
+    // Shifting either sample leaves the ratio unchanged
+    let shifted: Vec<f64> = x.iter().map(|v| v + 100.0).collect();
+    assert!((raw::spread_ratio(&shifted, &y, false).unwrap() - 4.0).abs() < 1e-12);
+}
+
+#[test]
+fn sample_api_uses_the_ratio_unit() {
+    let x = Sample::new(draw("spread-ratio-x", &Exp::new(1.0), 30)).unwrap();
+    let y = Sample::new(draw("spread-ratio-y", &Exp::new(1.0), 25)).unwrap();
+    let r = spread_ratio(&x, &y).unwrap();
+    assert_eq!(r.unit, measurement_unit::MeasurementUnit::ratio());
+    let expected = spread(&x).unwrap().value / spread(&y).unwrap().value;
+    assert_eq!(r.value, expected);
+}
+
+#[test]
+fn bounds_bracket_a_known_scale_difference() {
+    let x = Sample::new(draw("spread-ratio-wide", &Additive::new(5.0, 3.0), 200)).unwrap();
+    let y = Sample::new(draw("spread-ratio-narrow", &Additive::new(0.0, 1.0), 150)).unwrap();
+    let estimate = spread_ratio(&x, &y).unwrap().value;
+    let b = spread_ratio_bounds_with_seed(&x, &y, 0.05, "spread-ratio").unwrap();
+    assert!(
+        b.lower < estimate && estimate < b.upper,
+        "{b:?} vs {estimate}"
+    );
+    assert!(b.lower > 1.0, "x is clearly more dispersed: {b:?}");
+    assert!(b.lower < 3.0 && 3.0 < b.upper, "{b:?}");
+    assert_eq!(b.unit, measurement_unit::MeasurementUnit::ratio());
+}
+
+#[test]
+fn bounds_match_the_spread_bounds_they_combine() {
+    let x = draw("spread-ratio-combine-x", &Additive::new(0.0, 2.0), 60);
+    let y = draw("spread-ratio-combine-y", &Additive::new(0.0, 1.0), 40);
+    let b = raw::spread_ratio_bounds_with_seed(&x, &y, 0.1, "combine", false).unwrap();
+    let bx = raw::spread_bounds_with_seed(&x, 0.05, "combine", false).unwrap();
+    let by = raw::spread_bounds_with_seed(&y, 0.05, "combine", false).unwrap();
+    assert_eq!(b.lower, bx.lower / by.upper);
+    assert_eq!(b.upper, bx.upper / by.lower);
+    // Swapping the samples inverts the bounds (both streams share the seed)
+    let swapped = raw::spread_ratio_bounds_with_seed(&y, &x, 0.1, "combine", false).unwrap();
+    assert!((swapped.lower - 1.0 / b.upper).abs() < 1e-12);
+    assert!((swapped.upper - 1.0 / b.lower).abs() < 1e-12);
+}
+
+#[test]
+fn bounds_cover_the_true_ratio() {
+    let bounds = Experiment::new("spread-ratio-coverage").replicate(200, |rng| {
+        let x = Additive::new(0.0, 2.0).samples(rng, 30);
+        let y = Additive::new(0.0, 1.0).samples(rng, 30);
+        raw::spread_ratio_bounds_with_rng(&x, &y, 0.1, rng, false).unwrap()
+    });
+    let covered = coverage(&bounds, |b| b.lower <= 2.0 && 2.0 <= b.upper);
+    assert!(covered >= 0.9, "coverage {covered}");
+}
+
+#[test]
+fn zero_spread_is_a_sparity_error() {
+    let x = Sample::new((1..=10).map(f64::from).collect()).unwrap();
+    let mut tied = vec![5.0; 8];
+    tied.extend([6.0, 7.0]);
+    let y = Sample::new(tied).unwrap();
+    for err in [
+        spread_ratio(&x, &y).unwrap_err(),
+        spread_ratio_bounds_with_seed(&x, &y, 0.5, "sparity").unwrap_err(),
+    ] {
+        match err {
+            EstimatorError::Assumption(e) => {
+                assert_eq!(e.violation().id, AssumptionId::Sparity);
+                assert_eq!(e.violation().subject, Subject::Y);
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+    let e = spread_ratio(&y, &x).unwrap_err();
+    assert!(e.to_string().starts_with("spread_ratio: sparity(x)"), "{e}");
+}
+
+#[test]
+fn small_samples_reject_low_misrates() {
+    let x = Sample::new(vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+    let err = spread_ratio_bounds_with_seed(&x, &x, 0.01, "low").unwrap_err();
+    match err {
+        EstimatorError::Assumption(e) => {
+            assert_eq!(e.violation().id, AssumptionId::Domain);
+            assert_eq!(e.violation().subject, Subject::Misrate);
+        }
+        other => panic!("unexpected {other:?}"),
+    }
+}
@@ -0,0 +1,157 @@
+//! Tests for the asymptotic standard errors and inverse-variance pooling.
+//!
+//! The simulations compare the empirical standard deviation of the estimates
+//! over many Additive samples with the mean predicted standard error.
+
+use pragmastat::distributions::{Additive, Distribution};
+use pragmastat::estimators::raw;
+use pragmastat::experiment::{coverage, mean, Experiment};
+use pragmastat::*;
+
+const REPLICATIONS: usize = 2_000;
+
+fn std_dev(values: &[f64]) -> f64 {
+    let m = mean(values);
+    let ss: f64 = values.iter().map(|v| (v - m) * (v - m)).sum();
+    (ss / (values.len() - 1) as f64).sqrt()
+}
+
+fn assert_close(empirical: f64, predicted: f64) {
+    let error = (empirical / predicted - 1.0).abs();
+    assert!(
+        error < 0.05,
+        "empirical {empirical} vs predicted {predicted}"
+    );
+}
+
+#[test]
+fn center_stderr_matches_simulation() {
+    let runs = Experiment::new("center-stderr").replicate(REPLICATIONS, |rng| {
+        let x = Additive::new(10.0, 2.0).samples(rng, 400);
+        (
+            raw::center(&x, false).unwrap(),
+            raw::center_stderr(&x, false).unwrap(),
+        )
+    });
+    let centers: Vec<f64> = runs.iter().map(|r| r.0).collect();
+    let stderrs: Vec<f64> = runs.iter().map(|r| r.1).collect();
+    // √(π/3) · σ / √n
+    let formula = (std::f64::consts::PI / 3.0).sqrt() * 2.0 / 400f64.sqrt();
+    assert_close(mean(&stderrs), formula);
+    assert_close(std_dev(&centers), formula);
+}
+
+#[test]
+fn shift_stderr_matches_simulation_with_equal_spreads() {
+    let runs = Experiment::new("shift-stderr-equal").replicate(REPLICATIONS, |rng| {
+        let x = Additive::new(1.0, 1.0).samples(rng, 300);
+        let y = Additive::new(0.0, 1.0).samples(rng, 200);
+        (
+            raw::shift(&x, &y, false).unwrap(),
+            raw::shift_stderr(&x, &y, false).unwrap(),
+        )
+    });
+    let shifts: Vec<f64> = runs.iter().map(|r| r.0).collect();
+    let stderrs: Vec<f64> = runs.iter().map(|r| r.1).collect();
+    // (π/3) · σ² · (1/n + 1/m)
+    let formula = (std::f64::consts::PI / 3.0 * (1.0 / 300.0 + 1.0 / 200.0)).sqrt();
+    assert_close(mean(&stderrs), formula);
+    assert_close(std_dev(&shifts), formula);
+}
+
+#[test]
+fn shift_stderr_matches_simulation_with_unequal_spreads() {
+    let runs = Experiment::new("shift-stderr-unequal").replicate(REPLICATIONS, |rng| {
+        let x = Additive::new(0.0, 3.0).samples(rng, 150);
+        let y = Additive::new(0.0, 1.0).samples(rng, 350);
+        (
+            raw::shift(&x, &y, false).unwrap(),
+            raw::shift_stderr(&x, &y, false).unwrap(),
+        )
+    });
+    let shifts: Vec<f64> = runs.iter().map(|r| r.0).collect();
+    let stderrs: Vec<f64> = runs.iter().map(|r| r.1).collect();
+    assert_close(std_dev(&shifts), mean(&stderrs));
+    // The equal-spread formula with the pooled variance would be ~9% off here
+    let naive = (std::f64::consts::PI / 3.0 * 5.0 * (1.0 / 150.0 + 1.0 / 350.0)).sqrt();
+    assert!((std_dev(&shifts) / naive - 1.0).abs() > 0.05);
+}
+
+#[test]
+fn sample_stderr_units_and_errors() {
+    let ms = MeasurementUnit::new("ms", "Time", "ms", "Millisecond", 1_000_000);
+    let us = MeasurementUnit::new("us", "Time", "us", "Microsecond", 1_000);
+    let x = Sample::with_unit(vec![1.0, 2.0, 4.0, 7.0, 11.0], ms.clone()).unwrap();
+    let y = Sample::with_unit(vec![1000.0, 3000.0, 4000.0, 8000.0], us.clone()).unwrap();
+    assert_eq!(center_stderr(&x).unwrap().unit, ms);
+    let s = shift_stderr(&x, &y).unwrap();
+    assert_eq!(s.unit, us);
+    let x_us = x.convert_to(&us).unwrap();
+    assert_eq!(
+        s.value,
+        raw::shift_stderr(x_us.values(), y.values(), false).unwrap()
+    );
+
+    let mut values = vec![5.0; 8];
+    values.extend([6.0, 7.0]);
+    let tied = Sample::new(values).unwrap();
+    let err = center_stderr(&tied).unwrap_err();
+    assert!(err.to_string().contains("sparity(x)"), "{err}");
+    let err = shift_stderr(&Sample::new(vec![1.0, 2.0, 3.0]).unwrap(), &tied).unwrap_err();
+    assert!(err.to_string().contains("sparity(y)"), "{err}");
+}
+
+#[test]
+fn meta_weight_pools_by_inverse_variance() {
+    let mut rng = Rng::from_string("meta-weight");
+    let dist = Additive::new(5.0, 1.0);
+    let a = Sample::new(dist.samples(&mut rng, 40)).unwrap();
+    let b = Sample::new(dist.samples(&mut rng, 160)).unwrap();
+    let pooled = MetaWeight::new(&[&a, &b]).unwrap();
+
+    let wa = center_stderr(&a).unwrap().value.powi(-2);
+    let wb = center_stderr(&b).unwrap().value.powi(-2);
+    assert!((pooled.weights[0] - wa / (wa + wb)).abs() < 1e-12);
+    assert!((pooled.weights.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+    let expected = (wa * center(&a).unwrap().value + wb * center(&b).unwrap().value) / (wa + wb);
+    assert!((pooled.center.value - expected).abs() < 1e-12);
+    assert!((pooled.stderr.value - (wa + wb).sqrt().recip()).abs() < 1e-12);
+
+    let bounds = pooled.bounds(0.05).unwrap();
+    assert!(bounds.lower < pooled.center.value && pooled.center.value < bounds.upper);
+    assert!(matches!(
+        pooled.bounds(0.0),
+        Err(EstimatorError::Assumption(_))
+    ));
+}
+
+#[test]
+fn meta_weight_bounds_cover_the_common_center() {
+    let bounds = Experiment::new("meta-weight-coverage").replicate(REPLICATIONS, |rng| {
+        let samples: Vec<Sample> = [(30, 1.0), (80, 2.0), (200, 4.0)]
+            .iter()
+            .map(|&(n, sigma)| Sample::new(Additive::new(0.0, sigma).samples(rng, n)).unwrap())
+            .collect();
+        let refs: Vec<&Sample> = samples.iter().collect();
+        MetaWeight::new(&refs).unwrap().bounds(0.1).unwrap()
+    });
+    let covered = coverage(&bounds, |b| b.lower <= 0.0 && 0.0 <= b.upper);
+    assert!((covered - 0.9).abs() < 0.03, "coverage {covered}");
+}
+
+#[test]
+fn meta_weight_converts_units_and_rejects_bad_input() {
+    let s = MeasurementUnit::new("s", "Time", "s", "Second", 1_000_000_000);
+    let ms = MeasurementUnit::new("ms", "Time", "ms", "Millisecond", 1_000_000);
+    let a = Sample::with_unit(vec![1.0, 2.0, 3.0, 4.0, 5.0], s).unwrap();
+    let b = Sample::with_unit(vec![1000.0, 2000.0, 3000.0, 4000.0, 5000.0], ms.clone()).unwrap();
+    let pooled = MetaWeight::new(&[&a, &b]).unwrap();
+    assert_eq!(pooled.center.unit, ms);
+    assert!((pooled.center.value - 3000.0).abs() < 1e-9);
+    assert!((pooled.weights[0] - 0.5).abs() < 1e-12);
+
+    assert!(MetaWeight::new(&[]).is_err());
+    let number = Sample::new(vec![1.0, 2.0, 3.0]).unwrap();
+    let err = MetaWeight::new(&[&a, &number]).unwrap_err();
+    assert_eq!(err.to_string(), "can't convert Number to Second");
+}
@@ -0,0 +1,89 @@
+//! Tests for the fixed-memory streaming center.
+//!
+//! Data is written to a temporary file of little-endian `f64`s, small enough
+//! that the exact center can be computed for comparison.
+
+use pragmastat::distributions::{Additive, Distribution};
+use pragmastat::estimators::raw;
+use pragmastat::estimators::streaming::{center_from_reader, CenterSketch};
+use pragmastat::{AssumptionId, EstimatorError, Rng};
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::PathBuf;
+
+fn write_values(name: &str, values: &[f64]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "pragmastat-streaming-{name}-{}.bin",
+        std::process::id()
+    ));
+    let mut file = File::create(&path).unwrap();
+    for v in values {
+        file.write_all(&v.to_le_bytes()).unwrap();
+    }
+    path
+}
+
+#[test]
+fn sketch_is_close_to_exact_center() {
+    let mut rng = Rng::from_string("streaming-large");
+    let values = Additive::new(50.0, 1.0).sample_n(&mut rng, 200_000);
+    let path = write_values("large", &values);
+
+    let capacity = 20_000;
+    let approx = center_from_reader(File::open(&path).unwrap(), capacity, "sketch").unwrap();
+    let exact = raw::center(&values, false).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    // Documented standard error: sigma * sqrt(pi / 3) * sqrt(1/k - 1/n)
+    let n = values.len() as f64;
+    let k = capacity as f64;
+    let std_error = (std::f64::consts::PI / 3.0).sqrt() * (1.0 / k - 1.0 / n).sqrt();
+    assert!(
+        (approx - exact).abs() < 4.0 * std_error,
+        "sketch {approx} vs exact {exact} (standard error {std_error})"
+    );
+}
+
+#[test]
+fn sketch_is_exact_within_capacity() {
+    let mut rng = Rng::from_string("streaming-small");
+    let values = Additive::new(0.0, 3.0).sample_n(&mut rng, 5_000);
+    let path = write_values("small", &values);
+    let approx = center_from_reader(File::open(&path).unwrap(), 5_000, "sketch").unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(approx, raw::center(&values, false).unwrap());
+}
+
+#[test]
+fn sketch_is_deterministic_for_a_seed() {
+    let estimate = |seed: &str| {
+        let mut sketch = CenterSketch::new(100, seed);
+        for i in 0..10_000 {
+            sketch.push(((i * 7919) % 1000) as f64).unwrap();
+        }
+        assert!(!sketch.is_exact());
+        sketch.estimate().unwrap()
+    };
+    assert_eq!(estimate("a"), estimate("a"));
+}
+
+#[test]
+fn invalid_streams_are_rejected() {
+    let validity = |result: Result<f64, EstimatorError>| match result {
+        Err(EstimatorError::Assumption(e)) => e.violation().id == AssumptionId::Validity,
+        _ => false,
+    };
+    assert!(validity(center_from_reader(&[][..], 10, "s")));
+
+    let mut bytes: Vec<u8> = [1.0f64, f64::NAN, 2.0]
+        .iter()
+        .flat_map(|v| v.to_le_bytes())
+        .collect();
+    assert!(validity(center_from_reader(&bytes[..], 10, "s")));
+
+    bytes.truncate(8 + 3);
+    assert!(matches!(
+        center_from_reader(&bytes[..], 10, "s"),
+        Err(EstimatorError::Other(_))
+    ));
+}
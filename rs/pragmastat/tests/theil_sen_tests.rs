@@ -0,0 +1,82 @@
+//! Tests for the Theil-Sen robust regression line.
+
+use pragmastat::distributions::{Additive, Distribution};
+use pragmastat::estimators::raw;
+use pragmastat::{AssumptionId, EstimatorError, Rng, Subject};
+
+fn assumption(err: EstimatorError) -> (AssumptionId, Subject) {
+    match err {
+        EstimatorError::Assumption(e) => (e.violation().id, e.violation().subject),
+        other => panic!("expected an assumption error, got {other}"),
+    }
+}
+
+#[test]
+fn perfectly_linear_data() {
+    let x: Vec<f64> = (0..10).map(f64::from).collect();
+    let y: Vec<f64> = x.iter().map(|v| -1.5 * v + 4.0).collect();
+    assert_eq!(raw::theil_sen(&x, &y).unwrap(), (-1.5, 4.0));
+    assert_eq!(
+        raw::theil_sen(&[1.0, 3.0], &[2.0, 6.0]).unwrap(),
+        (2.0, 0.0)
+    );
+}
+
+#[test]
+fn ignores_outliers() {
+    let x: Vec<f64> = (1..=20).map(f64::from).collect();
+    let mut y: Vec<f64> = x.iter().map(|v| 3.0 * v - 2.0).collect();
+    y[4] = 1000.0;
+    y[15] = -500.0;
+    let (slope, intercept) = raw::theil_sen(&x, &y).unwrap();
+    assert!((slope - 3.0).abs() < 1e-12, "{slope}");
+    assert!((intercept + 2.0).abs() < 1e-12, "{intercept}");
+}
+
+#[test]
+fn is_order_independent_and_skips_vertical_pairs() {
+    let x = [4.0, 1.0, 3.0, 1.0, 2.0];
+    let y = [9.0, 3.0, 7.0, 3.5, 5.0];
+    let (slope, intercept) = raw::theil_sen(&x, &y).unwrap();
+    let (xr, yr): (Vec<f64>, Vec<f64>) = x.iter().zip(&y).rev().unzip();
+    assert_eq!(raw::theil_sen(&xr, &yr).unwrap(), (slope, intercept));
+    // The pair at x = 1 is skipped; the remaining slopes are mostly 2
+    assert_eq!(slope, 2.0);
+}
+
+#[test]
+fn recovers_noisy_trend() {
+    let mut rng = Rng::from_string("theil-sen");
+    let x: Vec<f64> = (0..200).map(|i| i as f64 / 10.0).collect();
+    let noise = Additive::new(0.0, 1.0).samples(&mut rng, x.len());
+    let y: Vec<f64> = x
+        .iter()
+        .zip(&noise)
+        .map(|(a, e)| 0.5 * a + 10.0 + e)
+        .collect();
+    let (slope, intercept) = raw::theil_sen(&x, &y).unwrap();
+    assert!((slope - 0.5).abs() < 0.05, "{slope}");
+    assert!((intercept - 10.0).abs() < 0.5, "{intercept}");
+}
+
+#[test]
+fn rejects_invalid_input() {
+    let err = raw::theil_sen(&[1.0, 2.0, 3.0], &[1.0, 2.0]).unwrap_err();
+    assert!(matches!(err, EstimatorError::Other(_)), "{err}");
+    assert_eq!(
+        assumption(raw::theil_sen(&[1.0], &[1.0]).unwrap_err()),
+        (AssumptionId::Domain, Subject::X)
+    );
+    assert_eq!(
+        assumption(raw::theil_sen(&[1.0, f64::NAN], &[1.0, 2.0]).unwrap_err()),
+        (AssumptionId::Validity, Subject::X)
+    );
+    assert_eq!(
+        assumption(raw::theil_sen(&[1.0, 2.0], &[1.0, f64::INFINITY]).unwrap_err()),
+        (AssumptionId::Validity, Subject::Y)
+    );
+    assert_eq!(
+        assumption(raw::theil_sen(&[2.0, 2.0, 2.0], &[1.0, 2.0, 3.0]).unwrap_err()),
+        (AssumptionId::Sparity, Subject::X)
+    );
+}
@@ -0,0 +1,83 @@
+//! Tests for `volatility` and `precision`, carried over from the legacy crate.
+//!
+//! Definitions: volatility = spread / |center|, precision = 2 * spread / sqrt(n).
+
+use pragmastat::estimators::raw;
+use pragmastat::{precision, volatility, AssumptionId, EstimatorError, MeasurementUnit, Sample};
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-9,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn volatility_expected_values() {
+    // center = 3, spread = 2
+    assert_close(
+        raw::volatility(&[1.0, 2.0, 3.0, 4.0, 5.0], false).unwrap(),
+        2.0 / 3.0,
+    );
+    // center = -2, spread = 1: the absolute center is used
+    assert_close(raw::volatility(&[-3.0, -2.0, -1.0], false).unwrap(), 0.5);
+}
+
+#[test]
+fn precision_expected_values() {
+    assert_close(
+        raw::precision(&[1.0, 2.0, 3.0, 4.0, 5.0], false).unwrap(),
+        4.0 / 5f64.sqrt(),
+    );
+    assert_close(
+        raw::precision(&[-3.0, -2.0, -1.0], false).unwrap(),
+        2.0 / 3f64.sqrt(),
+    );
+}
+
+#[test]
+fn volatility_zero_center_is_domain_error() {
+    match raw::volatility(&[-1.0, 0.0, 1.0], false).unwrap_err() {
+        EstimatorError::Assumption(e) => {
+            assert_eq!(e.violation().id, AssumptionId::Domain);
+        }
+        EstimatorError::Other(msg) => panic!("unexpected error: {msg}"),
+    }
+}
+
+#[test]
+fn tie_dominant_sample_fails_sparity() {
+    for err in [
+        raw::volatility(&[2.0; 5], false).unwrap_err(),
+        raw::precision(&[2.0; 5], false).unwrap_err(),
+    ] {
+        match err {
+            EstimatorError::Assumption(e) => assert_eq!(e.violation().id, AssumptionId::Sparity),
+            EstimatorError::Other(msg) => panic!("unexpected error: {msg}"),
+        }
+    }
+}
+
+#[test]
+fn sample_api_units() {
+    let ms = MeasurementUnit::new("ms", "Time", "ms", "Millisecond", 1_000_000);
+    let x = Sample::with_unit(vec![1.0, 2.0, 3.0, 4.0, 5.0], ms.clone()).unwrap();
+
+    let v = volatility(&x).unwrap();
+    assert_close(v.value, 2.0 / 3.0);
+    assert_eq!(v.unit, MeasurementUnit::ratio());
+    assert_eq!(x.volatility().unwrap().value, v.value);
+
+    let p = precision(&x).unwrap();
+    assert_close(p.value, 4.0 / 5f64.sqrt());
+    assert_eq!(p.unit, ms);
+    assert_eq!(x.precision().unwrap().value, p.value);
+}
+
+#[test]
+fn volatility_rejects_affine_units() {
+    let celsius = MeasurementUnit::affine("c", "Temperature", "°C", "Celsius", 1, 273.15);
+    let x = Sample::with_unit(vec![1.0, 2.0, 3.0], celsius).unwrap();
+    assert!(matches!(volatility(&x), Err(EstimatorError::Other(_))));
+    assert!(precision(&x).is_ok());
+}